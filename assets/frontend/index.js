@@ -23,4 +23,4 @@ import{j as y}from"./radix-CDPlHJ-s.js";import{r as C,d as _0,e as Cn,N as N0,B
 `+f[e].replace(" at new "," at ");return l.displayName&&b.includes("<anonymous>")&&(b=b.replace("<anonymous>",l.displayName)),b}while(1<=e&&0<=u);break}}}finally{Wn=!1,Error.prepareStackTrace=a}return(a=l?l.displayName||l.name:"")?La(a):""}function Tr(l){switch(l.tag){case 26:case 27:case 5:return La(l.type);case 16:return La("Lazy");case 13:return La("Suspense");case 19:return La("SuspenseList");case 0:case 15:return kn(l.type,!1);case 11:return kn(l.type.render,!1);case 1:return kn(l.type,!0);case 31:return La("Activity");default:return""}}function Sf(l){try{var t="";do t+=Tr(l),l=l.return;while(l);return t}catch(a){return`
 Error generating stack: `+a.message+`
 `+a.stack}}function dt(l){switch(typeof l){case"bigint":case"boolean":case"number":case"string":case"undefined":return l;case"object":return l;default:return""}}function pf(l){var t=l.type;return(l=l.nodeName)&&l.toLowerCase()==="input"&&(t==="checkbox"||t==="radio")}function zr(l){var t=pf(l)?"checked":"value",a=Object.getOwnPropertyDescriptor(l.constructor.prototype,t),e=""+l[t];if(!l.hasOwnProperty(t)&&typeof a<"u"&&typeof a.get=="function"&&typeof a.set=="function"){var u=a.get,n=a.set;return Object.defineProperty(l,t,{configurable:!0,get:function(){return u.call(this)},set:function(c){e=""+c,n.call(this,c)}}),Object.defineProperty(l,t,{enumerable:a.enumerable}),{getValue:function(){return e},setValue:function(c){e=""+c},stopTracking:function(){l._valueTracker=null,delete l[t]}}}}function ju(l){l._valueTracker||(l._valueTracker=zr(l))}function xf(l){if(!l)return!1;var t=l._valueTracker;if(!t)return!0;var a=t.getValue(),e="";return l&&(e=pf(l)?l.checked?"true":"false":l.value),l=e,l!==a?(t.setValue(l),!0):!1}function Hu(l){if(l=l||(typeof document<"u"?document:void 0),typeof l>"u")return null;try{return l.activeElement||l.body}catch{return l.body}}var Ar=/[\n"\\]/g;function rt(l){return l.replace(Ar,function(t){return"\\"+t.charCodeAt(0).toString(16)+" "})}function $n(l,t,a,e,u,n,c,i){l.name="",c!=null&&typeof c!="function"&&typeof c!="symbol"&&typeof c!="boolean"?l.type=c:l.removeAttribute("type"),t!=null?c==="number"?(t===0&&l.value===""||l.value!=t)&&(l.value=""+dt(t)):l.value!==""+dt(t)&&(l.value=""+dt(t)):c!=="submit"&&c!=="reset"||l.removeAttribute("value"),t!=null?Fn(l,c,dt(t)):a!=null?Fn(l,c,dt(a)):e!=null&&l.removeAttribute("value"),u==null&&n!=null&&(l.defaultChecked=!!n),u!=null&&(l.checked=u&&typeof u!="function"&&typeof u!="symbol"),i!=null&&typeof i!="function"&&typeof i!="symbol"&&typeof i!="boolean"?l.name=""+dt(i):l.removeAttribute("name")}function Tf(l,t,a,e,u,n,c,i){if(n!=null&&typeof n!="function"&&typeof n!="symbol"&&typeof n!="boolean"&&(l.type=n),t!=null||a!=null){if(!(n!=="submit"&&n!=="reset"||t!=null))return;a=a!=null?""+dt(a):"",t=t!=null?""+dt(t):a,i||t===l.value||(l.value=t),l.defaultValue=t}e=e??u,e=typeof e!="function"&&typeof e!="symbol"&&!!e,l.checked=i?l.checked:!!e,l.defaultChecked=!!e,c!=null&&typeof c!="function"&&typeof c!="symbol"&&typeof c!="boolean"&&(l.name=c)}function Fn(l,t,a){t==="number"&&Hu(l.ownerDocument)===l||l.defaultValue===""+a||(l.defaultValue=""+a)}function Ka(l,t,a,e){if(l=l.options,t){t={};for(var u=0;u<a.length;u++)t["$"+a[u]]=!0;for(a=0;a<l.length;a++)u=t.hasOwnProperty("$"+l[a].value),l[a].selected!==u&&(l[a].selected=u),u&&e&&(l[a].defaultSelected=!0)}else{for(a=""+dt(a),t=null,u=0;u<l.length;u++){if(l[u].value===a){l[u].selected=!0,e&&(l[u].defaultSelected=!0);return}t!==null||l[u].disabled||(t=l[u])}t!==null&&(t.selected=!0)}}function zf(l,t,a){if(t!=null&&(t=""+dt(t),t!==l.value&&(l.value=t),a==null)){l.defaultValue!==t&&(l.defaultValue=t);return}l.defaultValue=a!=null?""+dt(a):""}function Af(l,t,a,e){if(t==null){if(e!=null){if(a!=null)throw Error(v(92));if(Yl(e)){if(1<e.length)throw Error(v(93));e=e[0]}a=e}a==null&&(a=""),t=a}a=dt(t),l.defaultValue=a,e=l.textContent,e===a&&e!==""&&e!==null&&(l.value=e)}function Ja(l,t){if(t){var a=l.firstChild;if(a&&a===l.lastChild&&a.nodeType===3){a.nodeValue=t;return}}l.textContent=t}var Er=new Set("animationIterationCount aspectRatio borderImageOutset borderImageSlice borderImageWidth boxFlex boxFlexGroup boxOrdinalGroup columnCount columns flex flexGrow flexPositive flexShrink flexNegative flexOrder gridArea gridRow gridRowEnd gridRowSpan gridRowStart gridColumn gridColumnEnd gridColumnSpan gridColumnStart fontWeight lineClamp lineHeight opacity order orphans scale tabSize widows zIndex zoom fillOpacity floodOpacity stopOpacity strokeDasharray strokeDashoffset strokeMiterlimit strokeOpacity strokeWidth MozAnimationIterationCount MozBoxFlex MozBoxFlexGroup MozLineClamp msAnimationIterationCount msFlex msZoom msFlexGrow msFlexNegative msFlexOrder msFlexPositive msFlexShrink msGridColumn msGridColumnSpan msGridRow msGridRowSpan WebkitAnimationIterationCount WebkitBoxFlex WebKitBoxFlexGroup WebkitBoxOrdinalGroup WebkitColumnCount WebkitColumns WebkitFlex WebkitFlexGrow WebkitFlexPositive WebkitFlexShrink WebkitLineClamp".split(" "));function Ef(l,t,a){var e=t.indexOf("--")===0;a==null||typeof a=="boolean"||a===""?e?l.setProperty(t,""):t==="float"?l.cssFloat="":l[t]="":e?l.setProperty(t,a):typeof a!="number"||a===0||Er.has(t)?t==="float"?l.cssFloat=a:l[t]=(""+a).trim():l[t]=a+"px"}function Df(l,t,a){if(t!=null&&typeof t!="object")throw Error(v(62));if(l=l.style,a!=null){for(var e in a)!a.hasOwnProperty(e)||t!=null&&t.hasOwnProperty(e)||(e.indexOf("--")===0?l.setProperty(e,""):e==="float"?l.cssFloat="":l[e]="");for(var u in t)e=t[u],t.hasOwnProperty(u)&&a[u]!==e&&Ef(l,u,e)}else for(var n in t)t.hasOwnProperty(n)&&Ef(l,n,t[n])}function In(l){if(l.indexOf("-")===-1)return!1;switch(l){case"annotation-xml":case"color-profile":case"font-face":case"font-face-src":case"font-face-uri":case"font-face-format":case"font-face-name":case"missing-glyph":return!1;default:return!0}}var Dr=new Map([["acceptCharset","accept-charset"],["htmlFor","for"],["httpEquiv","http-equiv"],["crossOrigin","crossorigin"],["accentHeight","accent-height"],["alignmentBaseline","alignment-baseline"],["arabicForm","arabic-form"],["baselineShift","baseline-shift"],["capHeight","cap-height"],["clipPath","clip-path"],["clipRule","clip-rule"],["colorInterpolation","color-interpolation"],["colorInterpolationFilters","color-interpolation-filters"],["colorProfile","color-profile"],["colorRendering","color-rendering"],["dominantBaseline","dominant-baseline"],["enableBackground","enable-background"],["fillOpacity","fill-opacity"],["fillRule","fill-rule"],["floodColor","flood-color"],["floodOpacity","flood-opacity"],["fontFamily","font-family"],["fontSize","font-size"],["fontSizeAdjust","font-size-adjust"],["fontStretch","font-stretch"],["fontStyle","font-style"],["fontVariant","font-variant"],["fontWeight","font-weight"],["glyphName","glyph-name"],["glyphOrientationHorizontal","glyph-orientation-horizontal"],["glyphOrientationVertical","glyph-orientation-vertical"],["horizAdvX","horiz-adv-x"],["horizOriginX","horiz-origin-x"],["imageRendering","image-rendering"],["letterSpacing","letter-spacing"],["lightingColor","lighting-color"],["markerEnd","marker-end"],["markerMid","marker-mid"],["markerStart","marker-start"],["overlinePosition","overline-position"],["overlineThickness","overline-thickness"],["paintOrder","paint-order"],["panose-1","panose-1"],["pointerEvents","pointer-events"],["renderingIntent","rendering-intent"],["shapeRendering","shape-rendering"],["stopColor","stop-color"],["stopOpacity","stop-opacity"],["strikethroughPosition","strikethrough-position"],["strikethroughThickness","strikethrough-thickness"],["strokeDasharray","stroke-dasharray"],["strokeDashoffset","stroke-dashoffset"],["strokeLinecap","stroke-linecap"],["strokeLinejoin","stroke-linejoin"],["strokeMiterlimit","stroke-miterlimit"],["strokeOpacity","stroke-opacity"],["strokeWidth","stroke-width"],["textAnchor","text-anchor"],["textDecoration","text-decoration"],["textRendering","text-rendering"],["transformOrigin","transform-origin"],["underlinePosition","underline-position"],["underlineThickness","underline-thickness"],["unicodeBidi","unicode-bidi"],["unicodeRange","unicode-range"],["unitsPerEm","units-per-em"],["vAlphabetic","v-alphabetic"],["vHanging","v-hanging"],["vIdeographic","v-ideographic"],["vMathematical","v-mathematical"],["vectorEffect","vector-effect"],["vertAdvY","vert-adv-y"],["vertOriginX","vert-origin-x"],["vertOriginY","vert-origin-y"],["wordSpacing","word-spacing"],["writingMode","writing-mode"],["xmlnsXlink","xmlns:xlink"],["xHeight","x-height"]]),Mr=/^[\u0000-\u001F ]*j[\r\n\t]*a[\r\n\t]*v[\r\n\t]*a[\r\n\t]*s[\r\n\t]*c[\r\n\t]*r[\r\n\t]*i[\r\n\t]*p[\r\n\t]*t[\r\n\t]*:/i;function qu(l){return Mr.test(""+l)?"javascript:throw new Error('React has blocked a javascript: URL as a security precaution.')":l}var Pn=null;function lc(l){return l=l.target||l.srcElement||window,l.correspondingUseElement&&(l=l.correspondingUseElement),l.nodeType===3?l.parentNode:l}var Wa=null,ka=null;function Mf(l){var t=wa(l);if(t&&(l=t.stateNode)){var a=l[Kl]||null;l:switch(l=t.stateNode,t.type){case"input":if($n(l,a.value,a.defaultValue,a.defaultValue,a.checked,a.defaultChecked,a.type,a.name),t=a.name,a.type==="radio"&&t!=null){for(a=l;a.parentNode;)a=a.parentNode;for(a=a.querySelectorAll('input[name="'+rt(""+t)+'"][type="radio"]'),t=0;t<a.length;t++){var e=a[t];if(e!==l&&e.form===l.form){var u=e[Kl]||null;if(!u)throw Error(v(90));$n(e,u.value,u.defaultValue,u.defaultValue,u.checked,u.defaultChecked,u.type,u.name)}}for(t=0;t<a.length;t++)e=a[t],e.form===l.form&&xf(e)}break l;case"textarea":zf(l,a.value,a.defaultValue);break l;case"select":t=a.value,t!=null&&Ka(l,!!a.multiple,t,!1)}}}var tc=!1;function _f(l,t,a){if(tc)return l(t,a);tc=!0;try{var e=l(t);return e}finally{if(tc=!1,(Wa!==null||ka!==null)&&(pn(),Wa&&(t=Wa,l=ka,ka=Wa=null,Mf(t),l)))for(t=0;t<l.length;t++)Mf(l[t])}}function je(l,t){var a=l.stateNode;if(a===null)return null;var e=a[Kl]||null;if(e===null)return null;a=e[t];l:switch(t){case"onClick":case"onClickCapture":case"onDoubleClick":case"onDoubleClickCapture":case"onMouseDown":case"onMouseDownCapture":case"onMouseMove":case"onMouseMoveCapture":case"onMouseUp":case"onMouseUpCapture":case"onMouseEnter":(e=!e.disabled)||(l=l.type,e=!(l==="button"||l==="input"||l==="select"||l==="textarea")),l=!e;break l;default:l=!1}if(l)return null;if(a&&typeof a!="function")throw Error(v(231,t,typeof a));return a}var qt=!(typeof window>"u"||typeof window.document>"u"||typeof window.document.createElement>"u"),ac=!1;if(qt)try{var He={};Object.defineProperty(He,"passive",{get:function(){ac=!0}}),window.addEventListener("test",He,He),window.removeEventListener("test",He,He)}catch{ac=!1}var It=null,ec=null,Ru=null;function Nf(){if(Ru)return Ru;var l,t=ec,a=t.length,e,u="value"in It?It.value:It.textContent,n=u.length;for(l=0;l<a&&t[l]===u[l];l++);var c=a-l;for(e=1;e<=c&&t[a-e]===u[n-e];e++);return Ru=u.slice(l,1<e?1-e:void 0)}function Bu(l){var t=l.keyCode;return"charCode"in l?(l=l.charCode,l===0&&t===13&&(l=13)):l=t,l===10&&(l=13),32<=l||l===13?l:0}function Yu(){return!0}function Of(){return!1}function Jl(l){function t(a,e,u,n,c){this._reactName=a,this._targetInst=u,this.type=e,this.nativeEvent=n,this.target=c,this.currentTarget=null;for(var i in l)l.hasOwnProperty(i)&&(a=l[i],this[i]=a?a(n):n[i]);return this.isDefaultPrevented=(n.defaultPrevented!=null?n.defaultPrevented:n.returnValue===!1)?Yu:Of,this.isPropagationStopped=Of,this}return X(t.prototype,{preventDefault:function(){this.defaultPrevented=!0;var a=this.nativeEvent;a&&(a.preventDefault?a.preventDefault():typeof a.returnValue!="unknown"&&(a.returnValue=!1),this.isDefaultPrevented=Yu)},stopPropagation:function(){var a=this.nativeEvent;a&&(a.stopPropagation?a.stopPropagation():typeof a.cancelBubble!="unknown"&&(a.cancelBubble=!0),this.isPropagationStopped=Yu)},persist:function(){},isPersistent:Yu}),t}var za={eventPhase:0,bubbles:0,cancelable:0,timeStamp:function(l){return l.timeStamp||Date.now()},defaultPrevented:0,isTrusted:0},Qu=Jl(za),qe=X({},za,{view:0,detail:0}),_r=Jl(qe),uc,nc,Re,Xu=X({},qe,{screenX:0,screenY:0,clientX:0,clientY:0,pageX:0,pageY:0,ctrlKey:0,shiftKey:0,altKey:0,metaKey:0,getModifierState:ic,button:0,buttons:0,relatedTarget:function(l){return l.relatedTarget===void 0?l.fromElement===l.srcElement?l.toElement:l.fromElement:l.relatedTarget},movementX:function(l){return"movementX"in l?l.movementX:(l!==Re&&(Re&&l.type==="mousemove"?(uc=l.screenX-Re.screenX,nc=l.screenY-Re.screenY):nc=uc=0,Re=l),uc)},movementY:function(l){return"movementY"in l?l.movementY:nc}}),Uf=Jl(Xu),Nr=X({},Xu,{dataTransfer:0}),Or=Jl(Nr),Ur=X({},qe,{relatedTarget:0}),cc=Jl(Ur),jr=X({},za,{animationName:0,elapsedTime:0,pseudoElement:0}),Hr=Jl(jr),qr=X({},za,{clipboardData:function(l){return"clipboardData"in l?l.clipboardData:window.clipboardData}}),Rr=Jl(qr),Br=X({},za,{data:0}),jf=Jl(Br),Yr={Esc:"Escape",Spacebar:" ",Left:"ArrowLeft",Up:"ArrowUp",Right:"ArrowRight",Down:"ArrowDown",Del:"Delete",Win:"OS",Menu:"ContextMenu",Apps:"ContextMenu",Scroll:"ScrollLock",MozPrintableKey:"Unidentified"},Qr={8:"Backspace",9:"Tab",12:"Clear",13:"Enter",16:"Shift",17:"Control",18:"Alt",19:"Pause",20:"CapsLock",27:"Escape",32:" ",33:"PageUp",34:"PageDown",35:"End",36:"Home",37:"ArrowLeft",38:"ArrowUp",39:"ArrowRight",40:"ArrowDown",45:"Insert",46:"Delete",112:"F1",113:"F2",114:"F3",115:"F4",116:"F5",117:"F6",118:"F7",119:"F8",120:"F9",121:"F10",122:"F11",123:"F12",144:"NumLock",145:"ScrollLock",224:"Meta"},Xr={Alt:"altKey",Control:"ctrlKey",Meta:"metaKey",Shift:"shiftKey"};function Gr(l){var t=this.nativeEvent;return t.getModifierState?t.getModifierState(l):(l=Xr[l])?!!t[l]:!1}function ic(){return Gr}var Cr=X({},qe,{key:function(l){if(l.key){var t=Yr[l.key]||l.key;if(t!=="Unidentified")return t}return l.type==="keypress"?(l=Bu(l),l===13?"Enter":String.fromCharCode(l)):l.type==="keydown"||l.type==="keyup"?Qr[l.keyCode]||"Unidentified":""},code:0,location:0,ctrlKey:0,shiftKey:0,altKey:0,metaKey:0,repeat:0,locale:0,getModifierState:ic,charCode:function(l){return l.type==="keypress"?Bu(l):0},keyCode:function(l){return l.type==="keydown"||l.type==="keyup"?l.keyCode:0},which:function(l){return l.type==="keypress"?Bu(l):l.type==="keydown"||l.type==="keyup"?l.keyCode:0}}),wr=Jl(Cr),Zr=X({},Xu,{pointerId:0,width:0,height:0,pressure:0,tangentialPressure:0,tiltX:0,tiltY:0,twist:0,pointerType:0,isPrimary:0}),Hf=Jl(Zr),Vr=X({},qe,{touches:0,targetTouches:0,changedTouches:0,altKey:0,metaKey:0,ctrlKey:0,shiftKey:0,getModifierState:ic}),Lr=Jl(Vr),Kr=X({},za,{propertyName:0,elapsedTime:0,pseudoElement:0}),Jr=Jl(Kr),Wr=X({},Xu,{deltaX:function(l){return"deltaX"in l?l.deltaX:"wheelDeltaX"in l?-l.wheelDeltaX:0},deltaY:function(l){return"deltaY"in l?l.deltaY:"wheelDeltaY"in l?-l.wheelDeltaY:"wheelDelta"in l?-l.wheelDelta:0},deltaZ:0,deltaMode:0}),kr=Jl(Wr),$r=X({},za,{newState:0,oldState:0}),Fr=Jl($r),Ir=[9,13,27,32],fc=qt&&"CompositionEvent"in window,Be=null;qt&&"documentMode"in document&&(Be=document.documentMode);var Pr=qt&&"TextEvent"in window&&!Be,qf=qt&&(!fc||Be&&8<Be&&11>=Be),Rf=" ",Bf=!1;function Yf(l,t){switch(l){case"keyup":return Ir.indexOf(t.keyCode)!==-1;case"keydown":return t.keyCode!==229;case"keypress":case"mousedown":case"focusout":return!0;default:return!1}}function Qf(l){return l=l.detail,typeof l=="object"&&"data"in l?l.data:null}var $a=!1;function lh(l,t){switch(l){case"compositionend":return Qf(t);case"keypress":return t.which!==32?null:(Bf=!0,Rf);case"textInput":return l=t.data,l===Rf&&Bf?null:l;default:return null}}function th(l,t){if($a)return l==="compositionend"||!fc&&Yf(l,t)?(l=Nf(),Ru=ec=It=null,$a=!1,l):null;switch(l){case"paste":return null;case"keypress":if(!(t.ctrlKey||t.altKey||t.metaKey)||t.ctrlKey&&t.altKey){if(t.char&&1<t.char.length)return t.char;if(t.which)return String.fromCharCode(t.which)}return null;case"compositionend":return qf&&t.locale!=="ko"?null:t.data;default:return null}}var ah={color:!0,date:!0,datetime:!0,"datetime-local":!0,email:!0,month:!0,number:!0,password:!0,range:!0,search:!0,tel:!0,text:!0,time:!0,url:!0,week:!0};function Xf(l){var t=l&&l.nodeName&&l.nodeName.toLowerCase();return t==="input"?!!ah[l.type]:t==="textarea"}function Gf(l,t,a,e){Wa?ka?ka.push(e):ka=[e]:Wa=e,t=Dn(t,"onChange"),0<t.length&&(a=new Qu("onChange","change",null,a,e),l.push({event:a,listeners:t}))}var Ye=null,Qe=null;function eh(l){xd(l,0)}function Gu(l){var t=Ue(l);if(xf(t))return l}function Cf(l,t){if(l==="change")return t}var wf=!1;if(qt){var sc;if(qt){var oc="oninput"in document;if(!oc){var Zf=document.createElement("div");Zf.setAttribute("oninput","return;"),oc=typeof Zf.oninput=="function"}sc=oc}else sc=!1;wf=sc&&(!document.documentMode||9<document.documentMode)}function Vf(){Ye&&(Ye.detachEvent("onpropertychange",Lf),Qe=Ye=null)}function Lf(l){if(l.propertyName==="value"&&Gu(Qe)){var t=[];Gf(t,Qe,l,lc(l)),_f(eh,t)}}function uh(l,t,a){l==="focusin"?(Vf(),Ye=t,Qe=a,Ye.attachEvent("onpropertychange",Lf)):l==="focusout"&&Vf()}function nh(l){if(l==="selectionchange"||l==="keyup"||l==="keydown")return Gu(Qe)}function ch(l,t){if(l==="click")return Gu(t)}function ih(l,t){if(l==="input"||l==="change")return Gu(t)}function fh(l,t){return l===t&&(l!==0||1/l===1/t)||l!==l&&t!==t}var et=typeof Object.is=="function"?Object.is:fh;function Xe(l,t){if(et(l,t))return!0;if(typeof l!="object"||l===null||typeof t!="object"||t===null)return!1;var a=Object.keys(l),e=Object.keys(t);if(a.length!==e.length)return!1;for(e=0;e<a.length;e++){var u=a[e];if(!A.call(t,u)||!et(l[u],t[u]))return!1}return!0}function Kf(l){for(;l&&l.firstChild;)l=l.firstChild;return l}function Jf(l,t){var a=Kf(l);l=0;for(var e;a;){if(a.nodeType===3){if(e=l+a.textContent.length,l<=t&&e>=t)return{node:a,offset:t-l};l=e}l:{for(;a;){if(a.nextSibling){a=a.nextSibling;break l}a=a.parentNode}a=void 0}a=Kf(a)}}function Wf(l,t){return l&&t?l===t?!0:l&&l.nodeType===3?!1:t&&t.nodeType===3?Wf(l,t.parentNode):"contains"in l?l.contains(t):l.compareDocumentPosition?!!(l.compareDocumentPosition(t)&16):!1:!1}function kf(l){l=l!=null&&l.ownerDocument!=null&&l.ownerDocument.defaultView!=null?l.ownerDocument.defaultView:window;for(var t=Hu(l.document);t instanceof l.HTMLIFrameElement;){try{var a=typeof t.contentWindow.location.href=="string"}catch{a=!1}if(a)l=t.contentWindow;else break;t=Hu(l.document)}return t}function dc(l){var t=l&&l.nodeName&&l.nodeName.toLowerCase();return t&&(t==="input"&&(l.type==="text"||l.type==="search"||l.type==="tel"||l.type==="url"||l.type==="password")||t==="textarea"||l.contentEditable==="true")}var sh=qt&&"documentMode"in document&&11>=document.documentMode,Fa=null,rc=null,Ge=null,hc=!1;function $f(l,t,a){var e=a.window===a?a.document:a.nodeType===9?a:a.ownerDocument;hc||Fa==null||Fa!==Hu(e)||(e=Fa,"selectionStart"in e&&dc(e)?e={start:e.selectionStart,end:e.selectionEnd}:(e=(e.ownerDocument&&e.ownerDocument.defaultView||window).getSelection(),e={anchorNode:e.anchorNode,anchorOffset:e.anchorOffset,focusNode:e.focusNode,focusOffset:e.focusOffset}),Ge&&Xe(Ge,e)||(Ge=e,e=Dn(rc,"onSelect"),0<e.length&&(t=new Qu("onSelect","select",null,t,a),l.push({event:t,listeners:e}),t.target=Fa)))}function Aa(l,t){var a={};return a[l.toLowerCase()]=t.toLowerCase(),a["Webkit"+l]="webkit"+t,a["Moz"+l]="moz"+t,a}var Ia={animationend:Aa("Animation","AnimationEnd"),animationiteration:Aa("Animation","AnimationIteration"),animationstart:Aa("Animation","AnimationStart"),transitionrun:Aa("Transition","TransitionRun"),transitionstart:Aa("Transition","TransitionStart"),transitioncancel:Aa("Transition","TransitionCancel"),transitionend:Aa("Transition","TransitionEnd")},mc={},Ff={};qt&&(Ff=document.createElement("div").style,"AnimationEvent"in window||(delete Ia.animationend.animation,delete Ia.animationiteration.animation,delete Ia.animationstart.animation),"TransitionEvent"in window||delete Ia.transitionend.transition);function Ea(l){if(mc[l])return mc[l];if(!Ia[l])return l;var t=Ia[l],a;for(a in t)if(t.hasOwnProperty(a)&&a in Ff)return mc[l]=t[a];return l}var If=Ea("animationend"),Pf=Ea("animationiteration"),ls=Ea("animationstart"),oh=Ea("transitionrun"),dh=Ea("transitionstart"),rh=Ea("transitioncancel"),ts=Ea("transitionend"),as=new Map,yc="abort auxClick beforeToggle cancel canPlay canPlayThrough click close contextMenu copy cut drag dragEnd dragEnter dragExit dragLeave dragOver dragStart drop durationChange emptied encrypted ended error gotPointerCapture input invalid keyDown keyPress keyUp load loadedData loadedMetadata loadStart lostPointerCapture mouseDown mouseMove mouseOut mouseOver mouseUp paste pause play playing pointerCancel pointerDown pointerMove pointerOut pointerOver pointerUp progress rateChange reset resize seeked seeking stalled submit suspend timeUpdate touchCancel touchEnd touchStart volumeChange scroll toggle touchMove waiting wheel".split(" ");yc.push("scrollEnd");function Tt(l,t){as.set(l,t),Ta(t,[l])}var es=new WeakMap;function ht(l,t){if(typeof l=="object"&&l!==null){var a=es.get(l);return a!==void 0?a:(t={value:l,source:t,stack:Sf(t)},es.set(l,t),t)}return{value:l,source:t,stack:Sf(t)}}var mt=[],Pa=0,vc=0;function Cu(){for(var l=Pa,t=vc=Pa=0;t<l;){var a=mt[t];mt[t++]=null;var e=mt[t];mt[t++]=null;var u=mt[t];mt[t++]=null;var n=mt[t];if(mt[t++]=null,e!==null&&u!==null){var c=e.pending;c===null?u.next=u:(u.next=c.next,c.next=u),e.pending=u}n!==0&&us(a,u,n)}}function wu(l,t,a,e){mt[Pa++]=l,mt[Pa++]=t,mt[Pa++]=a,mt[Pa++]=e,vc|=e,l.lanes|=e,l=l.alternate,l!==null&&(l.lanes|=e)}function gc(l,t,a,e){return wu(l,t,a,e),Zu(l)}function le(l,t){return wu(l,null,null,t),Zu(l)}function us(l,t,a){l.lanes|=a;var e=l.alternate;e!==null&&(e.lanes|=a);for(var u=!1,n=l.return;n!==null;)n.childLanes|=a,e=n.alternate,e!==null&&(e.childLanes|=a),n.tag===22&&(l=n.stateNode,l===null||l._visibility&1||(u=!0)),l=n,n=n.return;return l.tag===3?(n=l.stateNode,u&&t!==null&&(u=31-at(a),l=n.hiddenUpdates,e=l[u],e===null?l[u]=[t]:e.push(t),t.lane=a|536870912),n):null}function Zu(l){if(50<du)throw du=0,zi=null,Error(v(185));for(var t=l.return;t!==null;)l=t,t=l.return;return l.tag===3?l.stateNode:null}var te={};function hh(l,t,a,e){this.tag=l,this.key=a,this.sibling=this.child=this.return=this.stateNode=this.type=this.elementType=null,this.index=0,this.refCleanup=this.ref=null,this.pendingProps=t,this.dependencies=this.memoizedState=this.updateQueue=this.memoizedProps=null,this.mode=e,this.subtreeFlags=this.flags=0,this.deletions=null,this.childLanes=this.lanes=0,this.alternate=null}function ut(l,t,a,e){return new hh(l,t,a,e)}function bc(l){return l=l.prototype,!(!l||!l.isReactComponent)}function Rt(l,t){var a=l.alternate;return a===null?(a=ut(l.tag,t,l.key,l.mode),a.elementType=l.elementType,a.type=l.type,a.stateNode=l.stateNode,a.alternate=l,l.alternate=a):(a.pendingProps=t,a.type=l.type,a.flags=0,a.subtreeFlags=0,a.deletions=null),a.flags=l.flags&65011712,a.childLanes=l.childLanes,a.lanes=l.lanes,a.child=l.child,a.memoizedProps=l.memoizedProps,a.memoizedState=l.memoizedState,a.updateQueue=l.updateQueue,t=l.dependencies,a.dependencies=t===null?null:{lanes:t.lanes,firstContext:t.firstContext},a.sibling=l.sibling,a.index=l.index,a.ref=l.ref,a.refCleanup=l.refCleanup,a}function ns(l,t){l.flags&=65011714;var a=l.alternate;return a===null?(l.childLanes=0,l.lanes=t,l.child=null,l.subtreeFlags=0,l.memoizedProps=null,l.memoizedState=null,l.updateQueue=null,l.dependencies=null,l.stateNode=null):(l.childLanes=a.childLanes,l.lanes=a.lanes,l.child=a.child,l.subtreeFlags=0,l.deletions=null,l.memoizedProps=a.memoizedProps,l.memoizedState=a.memoizedState,l.updateQueue=a.updateQueue,l.type=a.type,t=a.dependencies,l.dependencies=t===null?null:{lanes:t.lanes,firstContext:t.firstContext}),l}function Vu(l,t,a,e,u,n){var c=0;if(e=l,typeof l=="function")bc(l)&&(c=1);else if(typeof l=="string")c=y0(l,a,il.current)?26:l==="html"||l==="head"||l==="body"?27:5;else l:switch(l){case Vl:return l=ut(31,a,t,u),l.elementType=Vl,l.lanes=n,l;case El:return Da(a.children,u,n,t);case Cl:c=8,u|=24;break;case rl:return l=ut(12,a,t,u|2),l.elementType=rl,l.lanes=n,l;case pt:return l=ut(13,a,t,u),l.elementType=pt,l.lanes=n,l;case Bl:return l=ut(19,a,t,u),l.elementType=Bl,l.lanes=n,l;default:if(typeof l=="object"&&l!==null)switch(l.$$typeof){case ot:case Tl:c=10;break l;case Et:c=9;break l;case Fl:c=11;break l;case Zl:c=14;break l;case Ol:c=16,e=null;break l}c=29,a=Error(v(130,l===null?"null":typeof l,"")),e=null}return t=ut(c,a,t,u),t.elementType=l,t.type=e,t.lanes=n,t}function Da(l,t,a,e){return l=ut(7,l,e,t),l.lanes=a,l}function Sc(l,t,a){return l=ut(6,l,null,t),l.lanes=a,l}function pc(l,t,a){return t=ut(4,l.children!==null?l.children:[],l.key,t),t.lanes=a,t.stateNode={containerInfo:l.containerInfo,pendingChildren:null,implementation:l.implementation},t}var ae=[],ee=0,Lu=null,Ku=0,yt=[],vt=0,Ma=null,Bt=1,Yt="";function _a(l,t){ae[ee++]=Ku,ae[ee++]=Lu,Lu=l,Ku=t}function cs(l,t,a){yt[vt++]=Bt,yt[vt++]=Yt,yt[vt++]=Ma,Ma=l;var e=Bt;l=Yt;var u=32-at(e)-1;e&=~(1<<u),a+=1;var n=32-at(t)+u;if(30<n){var c=u-u%5;n=(e&(1<<c)-1).toString(32),e>>=c,u-=c,Bt=1<<32-at(t)+u|a<<u|e,Yt=n+l}else Bt=1<<n|a<<u|e,Yt=l}function xc(l){l.return!==null&&(_a(l,1),cs(l,1,0))}function Tc(l){for(;l===Lu;)Lu=ae[--ee],ae[ee]=null,Ku=ae[--ee],ae[ee]=null;for(;l===Ma;)Ma=yt[--vt],yt[vt]=null,Yt=yt[--vt],yt[vt]=null,Bt=yt[--vt],yt[vt]=null}var wl=null,yl=null,F=!1,Na=null,Mt=!1,zc=Error(v(519));function Oa(l){var t=Error(v(418,""));throw Ze(ht(t,l)),zc}function is(l){var t=l.stateNode,a=l.type,e=l.memoizedProps;switch(t[Ql]=l,t[Kl]=e,a){case"dialog":J("cancel",t),J("close",t);break;case"iframe":case"object":case"embed":J("load",t);break;case"video":case"audio":for(a=0;a<hu.length;a++)J(hu[a],t);break;case"source":J("error",t);break;case"img":case"image":case"link":J("error",t),J("load",t);break;case"details":J("toggle",t);break;case"input":J("invalid",t),Tf(t,e.value,e.defaultValue,e.checked,e.defaultChecked,e.type,e.name,!0),ju(t);break;case"select":J("invalid",t);break;case"textarea":J("invalid",t),Af(t,e.value,e.defaultValue,e.children),ju(t)}a=e.children,typeof a!="string"&&typeof a!="number"&&typeof a!="bigint"||t.textContent===""+a||e.suppressHydrationWarning===!0||Ed(t.textContent,a)?(e.popover!=null&&(J("beforetoggle",t),J("toggle",t)),e.onScroll!=null&&J("scroll",t),e.onScrollEnd!=null&&J("scrollend",t),e.onClick!=null&&(t.onclick=Mn),t=!0):t=!1,t||Oa(l)}function fs(l){for(wl=l.return;wl;)switch(wl.tag){case 5:case 13:Mt=!1;return;case 27:case 3:Mt=!0;return;default:wl=wl.return}}function Ce(l){if(l!==wl)return!1;if(!F)return fs(l),F=!0,!1;var t=l.tag,a;if((a=t!==3&&t!==27)&&((a=t===5)&&(a=l.type,a=!(a!=="form"&&a!=="button")||Xi(l.type,l.memoizedProps)),a=!a),a&&yl&&Oa(l),fs(l),t===13){if(l=l.memoizedState,l=l!==null?l.dehydrated:null,!l)throw Error(v(317));l:{for(l=l.nextSibling,t=0;l;){if(l.nodeType===8)if(a=l.data,a==="/$"){if(t===0){yl=At(l.nextSibling);break l}t--}else a!=="$"&&a!=="$!"&&a!=="$?"||t++;l=l.nextSibling}yl=null}}else t===27?(t=yl,ma(l.type)?(l=Zi,Zi=null,yl=l):yl=t):yl=wl?At(l.stateNode.nextSibling):null;return!0}function we(){yl=wl=null,F=!1}function ss(){var l=Na;return l!==null&&($l===null?$l=l:$l.push.apply($l,l),Na=null),l}function Ze(l){Na===null?Na=[l]:Na.push(l)}var Ac=V(null),Ua=null,Qt=null;function Pt(l,t,a){q(Ac,t._currentValue),t._currentValue=a}function Xt(l){l._currentValue=Ac.current,M(Ac)}function Ec(l,t,a){for(;l!==null;){var e=l.alternate;if((l.childLanes&t)!==t?(l.childLanes|=t,e!==null&&(e.childLanes|=t)):e!==null&&(e.childLanes&t)!==t&&(e.childLanes|=t),l===a)break;l=l.return}}function Dc(l,t,a,e){var u=l.child;for(u!==null&&(u.return=l);u!==null;){var n=u.dependencies;if(n!==null){var c=u.child;n=n.firstContext;l:for(;n!==null;){var i=n;n=u;for(var f=0;f<t.length;f++)if(i.context===t[f]){n.lanes|=a,i=n.alternate,i!==null&&(i.lanes|=a),Ec(n.return,a,l),e||(c=null);break l}n=i.next}}else if(u.tag===18){if(c=u.return,c===null)throw Error(v(341));c.lanes|=a,n=c.alternate,n!==null&&(n.lanes|=a),Ec(c,a,l),c=null}else c=u.child;if(c!==null)c.return=u;else for(c=u;c!==null;){if(c===l){c=null;break}if(u=c.sibling,u!==null){u.return=c.return,c=u;break}c=c.return}u=c}}function Ve(l,t,a,e){l=null;for(var u=t,n=!1;u!==null;){if(!n){if((u.flags&524288)!==0)n=!0;else if((u.flags&262144)!==0)break}if(u.tag===10){var c=u.alternate;if(c===null)throw Error(v(387));if(c=c.memoizedProps,c!==null){var i=u.type;et(u.pendingProps.value,c.value)||(l!==null?l.push(i):l=[i])}}else if(u===kt.current){if(c=u.alternate,c===null)throw Error(v(387));c.memoizedState.memoizedState!==u.memoizedState.memoizedState&&(l!==null?l.push(Su):l=[Su])}u=u.return}l!==null&&Dc(t,l,a,e),t.flags|=262144}function Ju(l){for(l=l.firstContext;l!==null;){if(!et(l.context._currentValue,l.memoizedValue))return!0;l=l.next}return!1}function ja(l){Ua=l,Qt=null,l=l.dependencies,l!==null&&(l.firstContext=null)}function Xl(l){return os(Ua,l)}function Wu(l,t){return Ua===null&&ja(l),os(l,t)}function os(l,t){var a=t._currentValue;if(t={context:t,memoizedValue:a,next:null},Qt===null){if(l===null)throw Error(v(308));Qt=t,l.dependencies={lanes:0,firstContext:t},l.flags|=524288}else Qt=Qt.next=t;return a}var mh=typeof AbortController<"u"?AbortController:function(){var l=[],t=this.signal={aborted:!1,addEventListener:function(a,e){l.push(e)}};this.abort=function(){t.aborted=!0,l.forEach(function(a){return a()})}},yh=T.unstable_scheduleCallback,vh=T.unstable_NormalPriority,zl={$$typeof:Tl,Consumer:null,Provider:null,_currentValue:null,_currentValue2:null,_threadCount:0};function Mc(){return{controller:new mh,data:new Map,refCount:0}}function Le(l){l.refCount--,l.refCount===0&&yh(vh,function(){l.controller.abort()})}var Ke=null,_c=0,ue=0,ne=null;function gh(l,t){if(Ke===null){var a=Ke=[];_c=0,ue=Oi(),ne={status:"pending",value:void 0,then:function(e){a.push(e)}}}return _c++,t.then(ds,ds),t}function ds(){if(--_c===0&&Ke!==null){ne!==null&&(ne.status="fulfilled");var l=Ke;Ke=null,ue=0,ne=null;for(var t=0;t<l.length;t++)(0,l[t])()}}function bh(l,t){var a=[],e={status:"pending",value:null,reason:null,then:function(u){a.push(u)}};return l.then(function(){e.status="fulfilled",e.value=t;for(var u=0;u<a.length;u++)(0,a[u])(t)},function(u){for(e.status="rejected",e.reason=u,u=0;u<a.length;u++)(0,a[u])(void 0)}),e}var rs=g.S;g.S=function(l,t){typeof t=="object"&&t!==null&&typeof t.then=="function"&&gh(l,t),rs!==null&&rs(l,t)};var Ha=V(null);function Nc(){var l=Ha.current;return l!==null?l:ol.pooledCache}function ku(l,t){t===null?q(Ha,Ha.current):q(Ha,t.pool)}function hs(){var l=Nc();return l===null?null:{parent:zl._currentValue,pool:l}}var Je=Error(v(460)),ms=Error(v(474)),$u=Error(v(542)),Oc={then:function(){}};function ys(l){return l=l.status,l==="fulfilled"||l==="rejected"}function Fu(){}function vs(l,t,a){switch(a=l[a],a===void 0?l.push(t):a!==t&&(t.then(Fu,Fu),t=a),t.status){case"fulfilled":return t.value;case"rejected":throw l=t.reason,bs(l),l;default:if(typeof t.status=="string")t.then(Fu,Fu);else{if(l=ol,l!==null&&100<l.shellSuspendCounter)throw Error(v(482));l=t,l.status="pending",l.then(function(e){if(t.status==="pending"){var u=t;u.status="fulfilled",u.value=e}},function(e){if(t.status==="pending"){var u=t;u.status="rejected",u.reason=e}})}switch(t.status){case"fulfilled":return t.value;case"rejected":throw l=t.reason,bs(l),l}throw We=t,Je}}var We=null;function gs(){if(We===null)throw Error(v(459));var l=We;return We=null,l}function bs(l){if(l===Je||l===$u)throw Error(v(483))}var la=!1;function Uc(l){l.updateQueue={baseState:l.memoizedState,firstBaseUpdate:null,lastBaseUpdate:null,shared:{pending:null,lanes:0,hiddenCallbacks:null},callbacks:null}}function jc(l,t){l=l.updateQueue,t.updateQueue===l&&(t.updateQueue={baseState:l.baseState,firstBaseUpdate:l.firstBaseUpdate,lastBaseUpdate:l.lastBaseUpdate,shared:l.shared,callbacks:null})}function ta(l){return{lane:l,tag:0,payload:null,callback:null,next:null}}function aa(l,t,a){var e=l.updateQueue;if(e===null)return null;if(e=e.shared,(tl&2)!==0){var u=e.pending;return u===null?t.next=t:(t.next=u.next,u.next=t),e.pending=t,t=Zu(l),us(l,null,a),t}return wu(l,e,t,a),Zu(l)}function ke(l,t,a){if(t=t.updateQueue,t!==null&&(t=t.shared,(a&4194048)!==0)){var e=t.lanes;e&=l.pendingLanes,a|=e,t.lanes=a,df(l,a)}}function Hc(l,t){var a=l.updateQueue,e=l.alternate;if(e!==null&&(e=e.updateQueue,a===e)){var u=null,n=null;if(a=a.firstBaseUpdate,a!==null){do{var c={lane:a.lane,tag:a.tag,payload:a.payload,callback:null,next:null};n===null?u=n=c:n=n.next=c,a=a.next}while(a!==null);n===null?u=n=t:n=n.next=t}else u=n=t;a={baseState:e.baseState,firstBaseUpdate:u,lastBaseUpdate:n,shared:e.shared,callbacks:e.callbacks},l.updateQueue=a;return}l=a.lastBaseUpdate,l===null?a.firstBaseUpdate=t:l.next=t,a.lastBaseUpdate=t}var qc=!1;function $e(){if(qc){var l=ne;if(l!==null)throw l}}function Fe(l,t,a,e){qc=!1;var u=l.updateQueue;la=!1;var n=u.firstBaseUpdate,c=u.lastBaseUpdate,i=u.shared.pending;if(i!==null){u.shared.pending=null;var f=i,r=f.next;f.next=null,c===null?n=r:c.next=r,c=f;var b=l.alternate;b!==null&&(b=b.updateQueue,i=b.lastBaseUpdate,i!==c&&(i===null?b.firstBaseUpdate=r:i.next=r,b.lastBaseUpdate=f))}if(n!==null){var x=u.baseState;c=0,b=r=f=null,i=n;do{var h=i.lane&-536870913,m=h!==i.lane;if(m?(W&h)===h:(e&h)===h){h!==0&&h===ue&&(qc=!0),b!==null&&(b=b.next={lane:0,tag:i.tag,payload:i.payload,callback:null,next:null});l:{var R=l,U=i;h=t;var nl=a;switch(U.tag){case 1:if(R=U.payload,typeof R=="function"){x=R.call(nl,x,h);break l}x=R;break l;case 3:R.flags=R.flags&-65537|128;case 0:if(R=U.payload,h=typeof R=="function"?R.call(nl,x,h):R,h==null)break l;x=X({},x,h);break l;case 2:la=!0}}h=i.callback,h!==null&&(l.flags|=64,m&&(l.flags|=8192),m=u.callbacks,m===null?u.callbacks=[h]:m.push(h))}else m={lane:h,tag:i.tag,payload:i.payload,callback:i.callback,next:null},b===null?(r=b=m,f=x):b=b.next=m,c|=h;if(i=i.next,i===null){if(i=u.shared.pending,i===null)break;m=i,i=m.next,m.next=null,u.lastBaseUpdate=m,u.shared.pending=null}}while(!0);b===null&&(f=x),u.baseState=f,u.firstBaseUpdate=r,u.lastBaseUpdate=b,n===null&&(u.shared.lanes=0),oa|=c,l.lanes=c,l.memoizedState=x}}function Ss(l,t){if(typeof l!="function")throw Error(v(191,l));l.call(t)}function ps(l,t){var a=l.callbacks;if(a!==null)for(l.callbacks=null,l=0;l<a.length;l++)Ss(a[l],t)}var ce=V(null),Iu=V(0);function xs(l,t){l=Kt,q(Iu,l),q(ce,t),Kt=l|t.baseLanes}function Rc(){q(Iu,Kt),q(ce,ce.current)}function Bc(){Kt=Iu.current,M(ce),M(Iu)}var ea=0,w=null,el=null,Sl=null,Pu=!1,ie=!1,qa=!1,ln=0,Ie=0,fe=null,Sh=0;function gl(){throw Error(v(321))}function Yc(l,t){if(t===null)return!1;for(var a=0;a<t.length&&a<l.length;a++)if(!et(l[a],t[a]))return!1;return!0}function Qc(l,t,a,e,u,n){return ea=n,w=t,t.memoizedState=null,t.updateQueue=null,t.lanes=0,g.H=l===null||l.memoizedState===null?uo:no,qa=!1,n=a(e,u),qa=!1,ie&&(n=zs(t,a,e,u)),Ts(l),n}function Ts(l){g.H=cn;var t=el!==null&&el.next!==null;if(ea=0,Sl=el=w=null,Pu=!1,Ie=0,fe=null,t)throw Error(v(300));l===null||Ml||(l=l.dependencies,l!==null&&Ju(l)&&(Ml=!0))}function zs(l,t,a,e){w=l;var u=0;do{if(ie&&(fe=null),Ie=0,ie=!1,25<=u)throw Error(v(301));if(u+=1,Sl=el=null,l.updateQueue!=null){var n=l.updateQueue;n.lastEffect=null,n.events=null,n.stores=null,n.memoCache!=null&&(n.memoCache.index=0)}g.H=Dh,n=t(a,e)}while(ie);return n}function ph(){var l=g.H,t=l.useState()[0];return t=typeof t.then=="function"?Pe(t):t,l=l.useState()[0],(el!==null?el.memoizedState:null)!==l&&(w.flags|=1024),t}function Xc(){var l=ln!==0;return ln=0,l}function Gc(l,t,a){t.updateQueue=l.updateQueue,t.flags&=-2053,l.lanes&=~a}function Cc(l){if(Pu){for(l=l.memoizedState;l!==null;){var t=l.queue;t!==null&&(t.pending=null),l=l.next}Pu=!1}ea=0,Sl=el=w=null,ie=!1,Ie=ln=0,fe=null}function Wl(){var l={memoizedState:null,baseState:null,baseQueue:null,queue:null,next:null};return Sl===null?w.memoizedState=Sl=l:Sl=Sl.next=l,Sl}function pl(){if(el===null){var l=w.alternate;l=l!==null?l.memoizedState:null}else l=el.next;var t=Sl===null?w.memoizedState:Sl.next;if(t!==null)Sl=t,el=l;else{if(l===null)throw w.alternate===null?Error(v(467)):Error(v(310));el=l,l={memoizedState:el.memoizedState,baseState:el.baseState,baseQueue:el.baseQueue,queue:el.queue,next:null},Sl===null?w.memoizedState=Sl=l:Sl=Sl.next=l}return Sl}function wc(){return{lastEffect:null,events:null,stores:null,memoCache:null}}function Pe(l){var t=Ie;return Ie+=1,fe===null&&(fe=[]),l=vs(fe,l,t),t=w,(Sl===null?t.memoizedState:Sl.next)===null&&(t=t.alternate,g.H=t===null||t.memoizedState===null?uo:no),l}function tn(l){if(l!==null&&typeof l=="object"){if(typeof l.then=="function")return Pe(l);if(l.$$typeof===Tl)return Xl(l)}throw Error(v(438,String(l)))}function Zc(l){var t=null,a=w.updateQueue;if(a!==null&&(t=a.memoCache),t==null){var e=w.alternate;e!==null&&(e=e.updateQueue,e!==null&&(e=e.memoCache,e!=null&&(t={data:e.data.map(function(u){return u.slice()}),index:0})))}if(t==null&&(t={data:[],index:0}),a===null&&(a=wc(),w.updateQueue=a),a.memoCache=t,a=t.data[t.index],a===void 0)for(a=t.data[t.index]=Array(l),e=0;e<l;e++)a[e]=Dt;return t.index++,a}function Gt(l,t){return typeof t=="function"?t(l):t}function an(l){var t=pl();return Vc(t,el,l)}function Vc(l,t,a){var e=l.queue;if(e===null)throw Error(v(311));e.lastRenderedReducer=a;var u=l.baseQueue,n=e.pending;if(n!==null){if(u!==null){var c=u.next;u.next=n.next,n.next=c}t.baseQueue=u=n,e.pending=null}if(n=l.baseState,u===null)l.memoizedState=n;else{t=u.next;var i=c=null,f=null,r=t,b=!1;do{var x=r.lane&-536870913;if(x!==r.lane?(W&x)===x:(ea&x)===x){var h=r.revertLane;if(h===0)f!==null&&(f=f.next={lane:0,revertLane:0,action:r.action,hasEagerState:r.hasEagerState,eagerState:r.eagerState,next:null}),x===ue&&(b=!0);else if((ea&h)===h){r=r.next,h===ue&&(b=!0);continue}else x={lane:0,revertLane:r.revertLane,action:r.action,hasEagerState:r.hasEagerState,eagerState:r.eagerState,next:null},f===null?(i=f=x,c=n):f=f.next=x,w.lanes|=h,oa|=h;x=r.action,qa&&a(n,x),n=r.hasEagerState?r.eagerState:a(n,x)}else h={lane:x,revertLane:r.revertLane,action:r.action,hasEagerState:r.hasEagerState,eagerState:r.eagerState,next:null},f===null?(i=f=h,c=n):f=f.next=h,w.lanes|=x,oa|=x;r=r.next}while(r!==null&&r!==t);if(f===null?c=n:f.next=i,!et(n,l.memoizedState)&&(Ml=!0,b&&(a=ne,a!==null)))throw a;l.memoizedState=n,l.baseState=c,l.baseQueue=f,e.lastRenderedState=n}return u===null&&(e.lanes=0),[l.memoizedState,e.dispatch]}function Lc(l){var t=pl(),a=t.queue;if(a===null)throw Error(v(311));a.lastRenderedReducer=l;var e=a.dispatch,u=a.pending,n=t.memoizedState;if(u!==null){a.pending=null;var c=u=u.next;do n=l(n,c.action),c=c.next;while(c!==u);et(n,t.memoizedState)||(Ml=!0),t.memoizedState=n,t.baseQueue===null&&(t.baseState=n),a.lastRenderedState=n}return[n,e]}function As(l,t,a){var e=w,u=pl(),n=F;if(n){if(a===void 0)throw Error(v(407));a=a()}else a=t();var c=!et((el||u).memoizedState,a);c&&(u.memoizedState=a,Ml=!0),u=u.queue;var i=Ms.bind(null,e,u,l);if(lu(2048,8,i,[l]),u.getSnapshot!==t||c||Sl!==null&&Sl.memoizedState.tag&1){if(e.flags|=2048,se(9,en(),Ds.bind(null,e,u,a,t),null),ol===null)throw Error(v(349));n||(ea&124)!==0||Es(e,t,a)}return a}function Es(l,t,a){l.flags|=16384,l={getSnapshot:t,value:a},t=w.updateQueue,t===null?(t=wc(),w.updateQueue=t,t.stores=[l]):(a=t.stores,a===null?t.stores=[l]:a.push(l))}function Ds(l,t,a,e){t.value=a,t.getSnapshot=e,_s(t)&&Ns(l)}function Ms(l,t,a){return a(function(){_s(t)&&Ns(l)})}function _s(l){var t=l.getSnapshot;l=l.value;try{var a=t();return!et(l,a)}catch{return!0}}function Ns(l){var t=le(l,2);t!==null&&st(t,l,2)}function Kc(l){var t=Wl();if(typeof l=="function"){var a=l;if(l=a(),qa){$t(!0);try{a()}finally{$t(!1)}}}return t.memoizedState=t.baseState=l,t.queue={pending:null,lanes:0,dispatch:null,lastRenderedReducer:Gt,lastRenderedState:l},t}function Os(l,t,a,e){return l.baseState=a,Vc(l,el,typeof e=="function"?e:Gt)}function xh(l,t,a,e,u){if(nn(l))throw Error(v(485));if(l=t.action,l!==null){var n={payload:u,action:l,next:null,isTransition:!0,status:"pending",value:null,reason:null,listeners:[],then:function(c){n.listeners.push(c)}};g.T!==null?a(!0):n.isTransition=!1,e(n),a=t.pending,a===null?(n.next=t.pending=n,Us(t,n)):(n.next=a.next,t.pending=a.next=n)}}function Us(l,t){var a=t.action,e=t.payload,u=l.state;if(t.isTransition){var n=g.T,c={};g.T=c;try{var i=a(u,e),f=g.S;f!==null&&f(c,i),js(l,t,i)}catch(r){Jc(l,t,r)}finally{g.T=n}}else try{n=a(u,e),js(l,t,n)}catch(r){Jc(l,t,r)}}function js(l,t,a){a!==null&&typeof a=="object"&&typeof a.then=="function"?a.then(function(e){Hs(l,t,e)},function(e){return Jc(l,t,e)}):Hs(l,t,a)}function Hs(l,t,a){t.status="fulfilled",t.value=a,qs(t),l.state=a,t=l.pending,t!==null&&(a=t.next,a===t?l.pending=null:(a=a.next,t.next=a,Us(l,a)))}function Jc(l,t,a){var e=l.pending;if(l.pending=null,e!==null){e=e.next;do t.status="rejected",t.reason=a,qs(t),t=t.next;while(t!==e)}l.action=null}function qs(l){l=l.listeners;for(var t=0;t<l.length;t++)(0,l[t])()}function Rs(l,t){return t}function Bs(l,t){if(F){var a=ol.formState;if(a!==null){l:{var e=w;if(F){if(yl){t:{for(var u=yl,n=Mt;u.nodeType!==8;){if(!n){u=null;break t}if(u=At(u.nextSibling),u===null){u=null;break t}}n=u.data,u=n==="F!"||n==="F"?u:null}if(u){yl=At(u.nextSibling),e=u.data==="F!";break l}}Oa(e)}e=!1}e&&(t=a[0])}}return a=Wl(),a.memoizedState=a.baseState=t,e={pending:null,lanes:0,dispatch:null,lastRenderedReducer:Rs,lastRenderedState:t},a.queue=e,a=to.bind(null,w,e),e.dispatch=a,e=Kc(!1),n=Ic.bind(null,w,!1,e.queue),e=Wl(),u={state:t,dispatch:null,action:l,pending:null},e.queue=u,a=xh.bind(null,w,u,n,a),u.dispatch=a,e.memoizedState=l,[t,a,!1]}function Ys(l){var t=pl();return Qs(t,el,l)}function Qs(l,t,a){if(t=Vc(l,t,Rs)[0],l=an(Gt)[0],typeof t=="object"&&t!==null&&typeof t.then=="function")try{var e=Pe(t)}catch(c){throw c===Je?$u:c}else e=t;t=pl();var u=t.queue,n=u.dispatch;return a!==t.memoizedState&&(w.flags|=2048,se(9,en(),Th.bind(null,u,a),null)),[e,n,l]}function Th(l,t){l.action=t}function Xs(l){var t=pl(),a=el;if(a!==null)return Qs(t,a,l);pl(),t=t.memoizedState,a=pl();var e=a.queue.dispatch;return a.memoizedState=l,[t,e,!1]}function se(l,t,a,e){return l={tag:l,create:a,deps:e,inst:t,next:null},t=w.updateQueue,t===null&&(t=wc(),w.updateQueue=t),a=t.lastEffect,a===null?t.lastEffect=l.next=l:(e=a.next,a.next=l,l.next=e,t.lastEffect=l),l}function en(){return{destroy:void 0,resource:void 0}}function Gs(){return pl().memoizedState}function un(l,t,a,e){var u=Wl();e=e===void 0?null:e,w.flags|=l,u.memoizedState=se(1|t,en(),a,e)}function lu(l,t,a,e){var u=pl();e=e===void 0?null:e;var n=u.memoizedState.inst;el!==null&&e!==null&&Yc(e,el.memoizedState.deps)?u.memoizedState=se(t,n,a,e):(w.flags|=l,u.memoizedState=se(1|t,n,a,e))}function Cs(l,t){un(8390656,8,l,t)}function ws(l,t){lu(2048,8,l,t)}function Zs(l,t){return lu(4,2,l,t)}function Vs(l,t){return lu(4,4,l,t)}function Ls(l,t){if(typeof t=="function"){l=l();var a=t(l);return function(){typeof a=="function"?a():t(null)}}if(t!=null)return l=l(),t.current=l,function(){t.current=null}}function Ks(l,t,a){a=a!=null?a.concat([l]):null,lu(4,4,Ls.bind(null,t,l),a)}function Wc(){}function Js(l,t){var a=pl();t=t===void 0?null:t;var e=a.memoizedState;return t!==null&&Yc(t,e[1])?e[0]:(a.memoizedState=[l,t],l)}function Ws(l,t){var a=pl();t=t===void 0?null:t;var e=a.memoizedState;if(t!==null&&Yc(t,e[1]))return e[0];if(e=l(),qa){$t(!0);try{l()}finally{$t(!1)}}return a.memoizedState=[e,t],e}function kc(l,t,a){return a===void 0||(ea&1073741824)!==0?l.memoizedState=t:(l.memoizedState=a,l=Io(),w.lanes|=l,oa|=l,a)}function ks(l,t,a,e){return et(a,t)?a:ce.current!==null?(l=kc(l,a,e),et(l,t)||(Ml=!0),l):(ea&42)===0?(Ml=!0,l.memoizedState=a):(l=Io(),w.lanes|=l,oa|=l,t)}function $s(l,t,a,e,u){var n=z.p;z.p=n!==0&&8>n?n:8;var c=g.T,i={};g.T=i,Ic(l,!1,t,a);try{var f=u(),r=g.S;if(r!==null&&r(i,f),f!==null&&typeof f=="object"&&typeof f.then=="function"){var b=bh(f,e);tu(l,t,b,ft(l))}else tu(l,t,e,ft(l))}catch(x){tu(l,t,{then:function(){},status:"rejected",reason:x},ft())}finally{z.p=n,g.T=c}}function zh(){}function $c(l,t,a,e){if(l.tag!==5)throw Error(v(476));var u=Fs(l).queue;$s(l,u,t,O,a===null?zh:function(){return Is(l),a(e)})}function Fs(l){var t=l.memoizedState;if(t!==null)return t;t={memoizedState:O,baseState:O,baseQueue:null,queue:{pending:null,lanes:0,dispatch:null,lastRenderedReducer:Gt,lastRenderedState:O},next:null};var a={};return t.next={memoizedState:a,baseState:a,baseQueue:null,queue:{pending:null,lanes:0,dispatch:null,lastRenderedReducer:Gt,lastRenderedState:a},next:null},l.memoizedState=t,l=l.alternate,l!==null&&(l.memoizedState=t),t}function Is(l){var t=Fs(l).next.queue;tu(l,t,{},ft())}function Fc(){return Xl(Su)}function Ps(){return pl().memoizedState}function lo(){return pl().memoizedState}function Ah(l){for(var t=l.return;t!==null;){switch(t.tag){case 24:case 3:var a=ft();l=ta(a);var e=aa(t,l,a);e!==null&&(st(e,t,a),ke(e,t,a)),t={cache:Mc()},l.payload=t;return}t=t.return}}function Eh(l,t,a){var e=ft();a={lane:e,revertLane:0,action:a,hasEagerState:!1,eagerState:null,next:null},nn(l)?ao(t,a):(a=gc(l,t,a,e),a!==null&&(st(a,l,e),eo(a,t,e)))}function to(l,t,a){var e=ft();tu(l,t,a,e)}function tu(l,t,a,e){var u={lane:e,revertLane:0,action:a,hasEagerState:!1,eagerState:null,next:null};if(nn(l))ao(t,u);else{var n=l.alternate;if(l.lanes===0&&(n===null||n.lanes===0)&&(n=t.lastRenderedReducer,n!==null))try{var c=t.lastRenderedState,i=n(c,a);if(u.hasEagerState=!0,u.eagerState=i,et(i,c))return wu(l,t,u,0),ol===null&&Cu(),!1}catch{}finally{}if(a=gc(l,t,u,e),a!==null)return st(a,l,e),eo(a,t,e),!0}return!1}function Ic(l,t,a,e){if(e={lane:2,revertLane:Oi(),action:e,hasEagerState:!1,eagerState:null,next:null},nn(l)){if(t)throw Error(v(479))}else t=gc(l,a,e,2),t!==null&&st(t,l,2)}function nn(l){var t=l.alternate;return l===w||t!==null&&t===w}function ao(l,t){ie=Pu=!0;var a=l.pending;a===null?t.next=t:(t.next=a.next,a.next=t),l.pending=t}function eo(l,t,a){if((a&4194048)!==0){var e=t.lanes;e&=l.pendingLanes,a|=e,t.lanes=a,df(l,a)}}var cn={readContext:Xl,use:tn,useCallback:gl,useContext:gl,useEffect:gl,useImperativeHandle:gl,useLayoutEffect:gl,useInsertionEffect:gl,useMemo:gl,useReducer:gl,useRef:gl,useState:gl,useDebugValue:gl,useDeferredValue:gl,useTransition:gl,useSyncExternalStore:gl,useId:gl,useHostTransitionStatus:gl,useFormState:gl,useActionState:gl,useOptimistic:gl,useMemoCache:gl,useCacheRefresh:gl},uo={readContext:Xl,use:tn,useCallback:function(l,t){return Wl().memoizedState=[l,t===void 0?null:t],l},useContext:Xl,useEffect:Cs,useImperativeHandle:function(l,t,a){a=a!=null?a.concat([l]):null,un(4194308,4,Ls.bind(null,t,l),a)},useLayoutEffect:function(l,t){return un(4194308,4,l,t)},useInsertionEffect:function(l,t){un(4,2,l,t)},useMemo:function(l,t){var a=Wl();t=t===void 0?null:t;var e=l();if(qa){$t(!0);try{l()}finally{$t(!1)}}return a.memoizedState=[e,t],e},useReducer:function(l,t,a){var e=Wl();if(a!==void 0){var u=a(t);if(qa){$t(!0);try{a(t)}finally{$t(!1)}}}else u=t;return e.memoizedState=e.baseState=u,l={pending:null,lanes:0,dispatch:null,lastRenderedReducer:l,lastRenderedState:u},e.queue=l,l=l.dispatch=Eh.bind(null,w,l),[e.memoizedState,l]},useRef:function(l){var t=Wl();return l={current:l},t.memoizedState=l},useState:function(l){l=Kc(l);var t=l.queue,a=to.bind(null,w,t);return t.dispatch=a,[l.memoizedState,a]},useDebugValue:Wc,useDeferredValue:function(l,t){var a=Wl();return kc(a,l,t)},useTransition:function(){var l=Kc(!1);return l=$s.bind(null,w,l.queue,!0,!1),Wl().memoizedState=l,[!1,l]},useSyncExternalStore:function(l,t,a){var e=w,u=Wl();if(F){if(a===void 0)throw Error(v(407));a=a()}else{if(a=t(),ol===null)throw Error(v(349));(W&124)!==0||Es(e,t,a)}u.memoizedState=a;var n={value:a,getSnapshot:t};return u.queue=n,Cs(Ms.bind(null,e,n,l),[l]),e.flags|=2048,se(9,en(),Ds.bind(null,e,n,a,t),null),a},useId:function(){var l=Wl(),t=ol.identifierPrefix;if(F){var a=Yt,e=Bt;a=(e&~(1<<32-at(e)-1)).toString(32)+a,t="«"+t+"R"+a,a=ln++,0<a&&(t+="H"+a.toString(32)),t+="»"}else a=Sh++,t="«"+t+"r"+a.toString(32)+"»";return l.memoizedState=t},useHostTransitionStatus:Fc,useFormState:Bs,useActionState:Bs,useOptimistic:function(l){var t=Wl();t.memoizedState=t.baseState=l;var a={pending:null,lanes:0,dispatch:null,lastRenderedReducer:null,lastRenderedState:null};return t.queue=a,t=Ic.bind(null,w,!0,a),a.dispatch=t,[l,t]},useMemoCache:Zc,useCacheRefresh:function(){return Wl().memoizedState=Ah.bind(null,w)}},no={readContext:Xl,use:tn,useCallback:Js,useContext:Xl,useEffect:ws,useImperativeHandle:Ks,useInsertionEffect:Zs,useLayoutEffect:Vs,useMemo:Ws,useReducer:an,useRef:Gs,useState:function(){return an(Gt)},useDebugValue:Wc,useDeferredValue:function(l,t){var a=pl();return ks(a,el.memoizedState,l,t)},useTransition:function(){var l=an(Gt)[0],t=pl().memoizedState;return[typeof l=="boolean"?l:Pe(l),t]},useSyncExternalStore:As,useId:Ps,useHostTransitionStatus:Fc,useFormState:Ys,useActionState:Ys,useOptimistic:function(l,t){var a=pl();return Os(a,el,l,t)},useMemoCache:Zc,useCacheRefresh:lo},Dh={readContext:Xl,use:tn,useCallback:Js,useContext:Xl,useEffect:ws,useImperativeHandle:Ks,useInsertionEffect:Zs,useLayoutEffect:Vs,useMemo:Ws,useReducer:Lc,useRef:Gs,useState:function(){return Lc(Gt)},useDebugValue:Wc,useDeferredValue:function(l,t){var a=pl();return el===null?kc(a,l,t):ks(a,el.memoizedState,l,t)},useTransition:function(){var l=Lc(Gt)[0],t=pl().memoizedState;return[typeof l=="boolean"?l:Pe(l),t]},useSyncExternalStore:As,useId:Ps,useHostTransitionStatus:Fc,useFormState:Xs,useActionState:Xs,useOptimistic:function(l,t){var a=pl();return el!==null?Os(a,el,l,t):(a.baseState=l,[l,a.queue.dispatch])},useMemoCache:Zc,useCacheRefresh:lo},oe=null,au=0;function fn(l){var t=au;return au+=1,oe===null&&(oe=[]),vs(oe,l,t)}function eu(l,t){t=t.props.ref,l.ref=t!==void 0?t:null}function sn(l,t){throw t.$$typeof===P?Error(v(525)):(l=Object.prototype.toString.call(t),Error(v(31,l==="[object Object]"?"object with keys {"+Object.keys(t).join(", ")+"}":l)))}function co(l){var t=l._init;return t(l._payload)}function io(l){function t(o,s){if(l){var d=o.deletions;d===null?(o.deletions=[s],o.flags|=16):d.push(s)}}function a(o,s){if(!l)return null;for(;s!==null;)t(o,s),s=s.sibling;return null}function e(o){for(var s=new Map;o!==null;)o.key!==null?s.set(o.key,o):s.set(o.index,o),o=o.sibling;return s}function u(o,s){return o=Rt(o,s),o.index=0,o.sibling=null,o}function n(o,s,d){return o.index=d,l?(d=o.alternate,d!==null?(d=d.index,d<s?(o.flags|=67108866,s):d):(o.flags|=67108866,s)):(o.flags|=1048576,s)}function c(o){return l&&o.alternate===null&&(o.flags|=67108866),o}function i(o,s,d,p){return s===null||s.tag!==6?(s=Sc(d,o.mode,p),s.return=o,s):(s=u(s,d),s.return=o,s)}function f(o,s,d,p){var E=d.type;return E===El?b(o,s,d.props.children,p,d.key):s!==null&&(s.elementType===E||typeof E=="object"&&E!==null&&E.$$typeof===Ol&&co(E)===s.type)?(s=u(s,d.props),eu(s,d),s.return=o,s):(s=Vu(d.type,d.key,d.props,null,o.mode,p),eu(s,d),s.return=o,s)}function r(o,s,d,p){return s===null||s.tag!==4||s.stateNode.containerInfo!==d.containerInfo||s.stateNode.implementation!==d.implementation?(s=pc(d,o.mode,p),s.return=o,s):(s=u(s,d.children||[]),s.return=o,s)}function b(o,s,d,p,E){return s===null||s.tag!==7?(s=Da(d,o.mode,p,E),s.return=o,s):(s=u(s,d),s.return=o,s)}function x(o,s,d){if(typeof s=="string"&&s!==""||typeof s=="number"||typeof s=="bigint")return s=Sc(""+s,o.mode,d),s.return=o,s;if(typeof s=="object"&&s!==null){switch(s.$$typeof){case G:return d=Vu(s.type,s.key,s.props,null,o.mode,d),eu(d,s),d.return=o,d;case xl:return s=pc(s,o.mode,d),s.return=o,s;case Ol:var p=s._init;return s=p(s._payload),x(o,s,d)}if(Yl(s)||Ul(s))return s=Da(s,o.mode,d,null),s.return=o,s;if(typeof s.then=="function")return x(o,fn(s),d);if(s.$$typeof===Tl)return x(o,Wu(o,s),d);sn(o,s)}return null}function h(o,s,d,p){var E=s!==null?s.key:null;if(typeof d=="string"&&d!==""||typeof d=="number"||typeof d=="bigint")return E!==null?null:i(o,s,""+d,p);if(typeof d=="object"&&d!==null){switch(d.$$typeof){case G:return d.key===E?f(o,s,d,p):null;case xl:return d.key===E?r(o,s,d,p):null;case Ol:return E=d._init,d=E(d._payload),h(o,s,d,p)}if(Yl(d)||Ul(d))return E!==null?null:b(o,s,d,p,null);if(typeof d.then=="function")return h(o,s,fn(d),p);if(d.$$typeof===Tl)return h(o,s,Wu(o,d),p);sn(o,d)}return null}function m(o,s,d,p,E){if(typeof p=="string"&&p!==""||typeof p=="number"||typeof p=="bigint")return o=o.get(d)||null,i(s,o,""+p,E);if(typeof p=="object"&&p!==null){switch(p.$$typeof){case G:return o=o.get(p.key===null?d:p.key)||null,f(s,o,p,E);case xl:return o=o.get(p.key===null?d:p.key)||null,r(s,o,p,E);case Ol:var L=p._init;return p=L(p._payload),m(o,s,d,p,E)}if(Yl(p)||Ul(p))return o=o.get(d)||null,b(s,o,p,E,null);if(typeof p.then=="function")return m(o,s,d,fn(p),E);if(p.$$typeof===Tl)return m(o,s,d,Wu(s,p),E);sn(s,p)}return null}function R(o,s,d,p){for(var E=null,L=null,N=s,j=s=0,Nl=null;N!==null&&j<d.length;j++){N.index>j?(Nl=N,N=null):Nl=N.sibling;var k=h(o,N,d[j],p);if(k===null){N===null&&(N=Nl);break}l&&N&&k.alternate===null&&t(o,N),s=n(k,s,j),L===null?E=k:L.sibling=k,L=k,N=Nl}if(j===d.length)return a(o,N),F&&_a(o,j),E;if(N===null){for(;j<d.length;j++)N=x(o,d[j],p),N!==null&&(s=n(N,s,j),L===null?E=N:L.sibling=N,L=N);return F&&_a(o,j),E}for(N=e(N);j<d.length;j++)Nl=m(N,o,j,d[j],p),Nl!==null&&(l&&Nl.alternate!==null&&N.delete(Nl.key===null?j:Nl.key),s=n(Nl,s,j),L===null?E=Nl:L.sibling=Nl,L=Nl);return l&&N.forEach(function(Sa){return t(o,Sa)}),F&&_a(o,j),E}function U(o,s,d,p){if(d==null)throw Error(v(151));for(var E=null,L=null,N=s,j=s=0,Nl=null,k=d.next();N!==null&&!k.done;j++,k=d.next()){N.index>j?(Nl=N,N=null):Nl=N.sibling;var Sa=h(o,N,k.value,p);if(Sa===null){N===null&&(N=Nl);break}l&&N&&Sa.alternate===null&&t(o,N),s=n(Sa,s,j),L===null?E=Sa:L.sibling=Sa,L=Sa,N=Nl}if(k.done)return a(o,N),F&&_a(o,j),E;if(N===null){for(;!k.done;j++,k=d.next())k=x(o,k.value,p),k!==null&&(s=n(k,s,j),L===null?E=k:L.sibling=k,L=k);return F&&_a(o,j),E}for(N=e(N);!k.done;j++,k=d.next())k=m(N,o,j,k.value,p),k!==null&&(l&&k.alternate!==null&&N.delete(k.key===null?j:k.key),s=n(k,s,j),L===null?E=k:L.sibling=k,L=k);return l&&N.forEach(function(M0){return t(o,M0)}),F&&_a(o,j),E}function nl(o,s,d,p){if(typeof d=="object"&&d!==null&&d.type===El&&d.key===null&&(d=d.props.children),typeof d=="object"&&d!==null){switch(d.$$typeof){case G:l:{for(var E=d.key;s!==null;){if(s.key===E){if(E=d.type,E===El){if(s.tag===7){a(o,s.sibling),p=u(s,d.props.children),p.return=o,o=p;break l}}else if(s.elementType===E||typeof E=="object"&&E!==null&&E.$$typeof===Ol&&co(E)===s.type){a(o,s.sibling),p=u(s,d.props),eu(p,d),p.return=o,o=p;break l}a(o,s);break}else t(o,s);s=s.sibling}d.type===El?(p=Da(d.props.children,o.mode,p,d.key),p.return=o,o=p):(p=Vu(d.type,d.key,d.props,null,o.mode,p),eu(p,d),p.return=o,o=p)}return c(o);case xl:l:{for(E=d.key;s!==null;){if(s.key===E)if(s.tag===4&&s.stateNode.containerInfo===d.containerInfo&&s.stateNode.implementation===d.implementation){a(o,s.sibling),p=u(s,d.children||[]),p.return=o,o=p;break l}else{a(o,s);break}else t(o,s);s=s.sibling}p=pc(d,o.mode,p),p.return=o,o=p}return c(o);case Ol:return E=d._init,d=E(d._payload),nl(o,s,d,p)}if(Yl(d))return R(o,s,d,p);if(Ul(d)){if(E=Ul(d),typeof E!="function")throw Error(v(150));return d=E.call(d),U(o,s,d,p)}if(typeof d.then=="function")return nl(o,s,fn(d),p);if(d.$$typeof===Tl)return nl(o,s,Wu(o,d),p);sn(o,d)}return typeof d=="string"&&d!==""||typeof d=="number"||typeof d=="bigint"?(d=""+d,s!==null&&s.tag===6?(a(o,s.sibling),p=u(s,d),p.return=o,o=p):(a(o,s),p=Sc(d,o.mode,p),p.return=o,o=p),c(o)):a(o,s)}return function(o,s,d,p){try{au=0;var E=nl(o,s,d,p);return oe=null,E}catch(N){if(N===Je||N===$u)throw N;var L=ut(29,N,null,o.mode);return L.lanes=p,L.return=o,L}finally{}}}var de=io(!0),fo=io(!1),gt=V(null),_t=null;function ua(l){var t=l.alternate;q(Al,Al.current&1),q(gt,l),_t===null&&(t===null||ce.current!==null||t.memoizedState!==null)&&(_t=l)}function so(l){if(l.tag===22){if(q(Al,Al.current),q(gt,l),_t===null){var t=l.alternate;t!==null&&t.memoizedState!==null&&(_t=l)}}else na()}function na(){q(Al,Al.current),q(gt,gt.current)}function Ct(l){M(gt),_t===l&&(_t=null),M(Al)}var Al=V(0);function on(l){for(var t=l;t!==null;){if(t.tag===13){var a=t.memoizedState;if(a!==null&&(a=a.dehydrated,a===null||a.data==="$?"||wi(a)))return t}else if(t.tag===19&&t.memoizedProps.revealOrder!==void 0){if((t.flags&128)!==0)return t}else if(t.child!==null){t.child.return=t,t=t.child;continue}if(t===l)break;for(;t.sibling===null;){if(t.return===null||t.return===l)return null;t=t.return}t.sibling.return=t.return,t=t.sibling}return null}function Pc(l,t,a,e){t=l.memoizedState,a=a(e,t),a=a==null?t:X({},t,a),l.memoizedState=a,l.lanes===0&&(l.updateQueue.baseState=a)}var li={enqueueSetState:function(l,t,a){l=l._reactInternals;var e=ft(),u=ta(e);u.payload=t,a!=null&&(u.callback=a),t=aa(l,u,e),t!==null&&(st(t,l,e),ke(t,l,e))},enqueueReplaceState:function(l,t,a){l=l._reactInternals;var e=ft(),u=ta(e);u.tag=1,u.payload=t,a!=null&&(u.callback=a),t=aa(l,u,e),t!==null&&(st(t,l,e),ke(t,l,e))},enqueueForceUpdate:function(l,t){l=l._reactInternals;var a=ft(),e=ta(a);e.tag=2,t!=null&&(e.callback=t),t=aa(l,e,a),t!==null&&(st(t,l,a),ke(t,l,a))}};function oo(l,t,a,e,u,n,c){return l=l.stateNode,typeof l.shouldComponentUpdate=="function"?l.shouldComponentUpdate(e,n,c):t.prototype&&t.prototype.isPureReactComponent?!Xe(a,e)||!Xe(u,n):!0}function ro(l,t,a,e){l=t.state,typeof t.componentWillReceiveProps=="function"&&t.componentWillReceiveProps(a,e),typeof t.UNSAFE_componentWillReceiveProps=="function"&&t.UNSAFE_componentWillReceiveProps(a,e),t.state!==l&&li.enqueueReplaceState(t,t.state,null)}function Ra(l,t){var a=t;if("ref"in t){a={};for(var e in t)e!=="ref"&&(a[e]=t[e])}if(l=l.defaultProps){a===t&&(a=X({},a));for(var u in l)a[u]===void 0&&(a[u]=l[u])}return a}var dn=typeof reportError=="function"?reportError:function(l){if(typeof window=="object"&&typeof window.ErrorEvent=="function"){var t=new window.ErrorEvent("error",{bubbles:!0,cancelable:!0,message:typeof l=="object"&&l!==null&&typeof l.message=="string"?String(l.message):String(l),error:l});if(!window.dispatchEvent(t))return}else if(typeof process=="object"&&typeof process.emit=="function"){process.emit("uncaughtException",l);return}console.error(l)};function ho(l){dn(l)}function mo(l){console.error(l)}function yo(l){dn(l)}function rn(l,t){try{var a=l.onUncaughtError;a(t.value,{componentStack:t.stack})}catch(e){setTimeout(function(){throw e})}}function vo(l,t,a){try{var e=l.onCaughtError;e(a.value,{componentStack:a.stack,errorBoundary:t.tag===1?t.stateNode:null})}catch(u){setTimeout(function(){throw u})}}function ti(l,t,a){return a=ta(a),a.tag=3,a.payload={element:null},a.callback=function(){rn(l,t)},a}function go(l){return l=ta(l),l.tag=3,l}function bo(l,t,a,e){var u=a.type.getDerivedStateFromError;if(typeof u=="function"){var n=e.value;l.payload=function(){return u(n)},l.callback=function(){vo(t,a,e)}}var c=a.stateNode;c!==null&&typeof c.componentDidCatch=="function"&&(l.callback=function(){vo(t,a,e),typeof u!="function"&&(da===null?da=new Set([this]):da.add(this));var i=e.stack;this.componentDidCatch(e.value,{componentStack:i!==null?i:""})})}function Mh(l,t,a,e,u){if(a.flags|=32768,e!==null&&typeof e=="object"&&typeof e.then=="function"){if(t=a.alternate,t!==null&&Ve(t,a,u,!0),a=gt.current,a!==null){switch(a.tag){case 13:return _t===null?Ei():a.alternate===null&&vl===0&&(vl=3),a.flags&=-257,a.flags|=65536,a.lanes=u,e===Oc?a.flags|=16384:(t=a.updateQueue,t===null?a.updateQueue=new Set([e]):t.add(e),Mi(l,e,u)),!1;case 22:return a.flags|=65536,e===Oc?a.flags|=16384:(t=a.updateQueue,t===null?(t={transitions:null,markerInstances:null,retryQueue:new Set([e])},a.updateQueue=t):(a=t.retryQueue,a===null?t.retryQueue=new Set([e]):a.add(e)),Mi(l,e,u)),!1}throw Error(v(435,a.tag))}return Mi(l,e,u),Ei(),!1}if(F)return t=gt.current,t!==null?((t.flags&65536)===0&&(t.flags|=256),t.flags|=65536,t.lanes=u,e!==zc&&(l=Error(v(422),{cause:e}),Ze(ht(l,a)))):(e!==zc&&(t=Error(v(423),{cause:e}),Ze(ht(t,a))),l=l.current.alternate,l.flags|=65536,u&=-u,l.lanes|=u,e=ht(e,a),u=ti(l.stateNode,e,u),Hc(l,u),vl!==4&&(vl=2)),!1;var n=Error(v(520),{cause:e});if(n=ht(n,a),ou===null?ou=[n]:ou.push(n),vl!==4&&(vl=2),t===null)return!0;e=ht(e,a),a=t;do{switch(a.tag){case 3:return a.flags|=65536,l=u&-u,a.lanes|=l,l=ti(a.stateNode,e,l),Hc(a,l),!1;case 1:if(t=a.type,n=a.stateNode,(a.flags&128)===0&&(typeof t.getDerivedStateFromError=="function"||n!==null&&typeof n.componentDidCatch=="function"&&(da===null||!da.has(n))))return a.flags|=65536,u&=-u,a.lanes|=u,u=go(u),bo(u,l,a,e),Hc(a,u),!1}a=a.return}while(a!==null);return!1}var So=Error(v(461)),Ml=!1;function Hl(l,t,a,e){t.child=l===null?fo(t,null,a,e):de(t,l.child,a,e)}function po(l,t,a,e,u){a=a.render;var n=t.ref;if("ref"in e){var c={};for(var i in e)i!=="ref"&&(c[i]=e[i])}else c=e;return ja(t),e=Qc(l,t,a,c,n,u),i=Xc(),l!==null&&!Ml?(Gc(l,t,u),wt(l,t,u)):(F&&i&&xc(t),t.flags|=1,Hl(l,t,e,u),t.child)}function xo(l,t,a,e,u){if(l===null){var n=a.type;return typeof n=="function"&&!bc(n)&&n.defaultProps===void 0&&a.compare===null?(t.tag=15,t.type=n,To(l,t,n,e,u)):(l=Vu(a.type,null,e,t,t.mode,u),l.ref=t.ref,l.return=t,t.child=l)}if(n=l.child,!si(l,u)){var c=n.memoizedProps;if(a=a.compare,a=a!==null?a:Xe,a(c,e)&&l.ref===t.ref)return wt(l,t,u)}return t.flags|=1,l=Rt(n,e),l.ref=t.ref,l.return=t,t.child=l}function To(l,t,a,e,u){if(l!==null){var n=l.memoizedProps;if(Xe(n,e)&&l.ref===t.ref)if(Ml=!1,t.pendingProps=e=n,si(l,u))(l.flags&131072)!==0&&(Ml=!0);else return t.lanes=l.lanes,wt(l,t,u)}return ai(l,t,a,e,u)}function zo(l,t,a){var e=t.pendingProps,u=e.children,n=l!==null?l.memoizedState:null;if(e.mode==="hidden"){if((t.flags&128)!==0){if(e=n!==null?n.baseLanes|a:a,l!==null){for(u=t.child=l.child,n=0;u!==null;)n=n|u.lanes|u.childLanes,u=u.sibling;t.childLanes=n&~e}else t.childLanes=0,t.child=null;return Ao(l,t,e,a)}if((a&536870912)!==0)t.memoizedState={baseLanes:0,cachePool:null},l!==null&&ku(t,n!==null?n.cachePool:null),n!==null?xs(t,n):Rc(),so(t);else return t.lanes=t.childLanes=536870912,Ao(l,t,n!==null?n.baseLanes|a:a,a)}else n!==null?(ku(t,n.cachePool),xs(t,n),na(),t.memoizedState=null):(l!==null&&ku(t,null),Rc(),na());return Hl(l,t,u,a),t.child}function Ao(l,t,a,e){var u=Nc();return u=u===null?null:{parent:zl._currentValue,pool:u},t.memoizedState={baseLanes:a,cachePool:u},l!==null&&ku(t,null),Rc(),so(t),l!==null&&Ve(l,t,e,!0),null}function hn(l,t){var a=t.ref;if(a===null)l!==null&&l.ref!==null&&(t.flags|=4194816);else{if(typeof a!="function"&&typeof a!="object")throw Error(v(284));(l===null||l.ref!==a)&&(t.flags|=4194816)}}function ai(l,t,a,e,u){return ja(t),a=Qc(l,t,a,e,void 0,u),e=Xc(),l!==null&&!Ml?(Gc(l,t,u),wt(l,t,u)):(F&&e&&xc(t),t.flags|=1,Hl(l,t,a,u),t.child)}function Eo(l,t,a,e,u,n){return ja(t),t.updateQueue=null,a=zs(t,e,a,u),Ts(l),e=Xc(),l!==null&&!Ml?(Gc(l,t,n),wt(l,t,n)):(F&&e&&xc(t),t.flags|=1,Hl(l,t,a,n),t.child)}function Do(l,t,a,e,u){if(ja(t),t.stateNode===null){var n=te,c=a.contextType;typeof c=="object"&&c!==null&&(n=Xl(c)),n=new a(e,n),t.memoizedState=n.state!==null&&n.state!==void 0?n.state:null,n.updater=li,t.stateNode=n,n._reactInternals=t,n=t.stateNode,n.props=e,n.state=t.memoizedState,n.refs={},Uc(t),c=a.contextType,n.context=typeof c=="object"&&c!==null?Xl(c):te,n.state=t.memoizedState,c=a.getDerivedStateFromProps,typeof c=="function"&&(Pc(t,a,c,e),n.state=t.memoizedState),typeof a.getDerivedStateFromProps=="function"||typeof n.getSnapshotBeforeUpdate=="function"||typeof n.UNSAFE_componentWillMount!="function"&&typeof n.componentWillMount!="function"||(c=n.state,typeof n.componentWillMount=="function"&&n.componentWillMount(),typeof n.UNSAFE_componentWillMount=="function"&&n.UNSAFE_componentWillMount(),c!==n.state&&li.enqueueReplaceState(n,n.state,null),Fe(t,e,n,u),$e(),n.state=t.memoizedState),typeof n.componentDidMount=="function"&&(t.flags|=4194308),e=!0}else if(l===null){n=t.stateNode;var i=t.memoizedProps,f=Ra(a,i);n.props=f;var r=n.context,b=a.contextType;c=te,typeof b=="object"&&b!==null&&(c=Xl(b));var x=a.getDerivedStateFromProps;b=typeof x=="function"||typeof n.getSnapshotBeforeUpdate=="function",i=t.pendingProps!==i,b||typeof n.UNSAFE_componentWillReceiveProps!="function"&&typeof n.componentWillReceiveProps!="function"||(i||r!==c)&&ro(t,n,e,c),la=!1;var h=t.memoizedState;n.state=h,Fe(t,e,n,u),$e(),r=t.memoizedState,i||h!==r||la?(typeof x=="function"&&(Pc(t,a,x,e),r=t.memoizedState),(f=la||oo(t,a,f,e,h,r,c))?(b||typeof n.UNSAFE_componentWillMount!="function"&&typeof n.componentWillMount!="function"||(typeof n.componentWillMount=="function"&&n.componentWillMount(),typeof n.UNSAFE_componentWillMount=="function"&&n.UNSAFE_componentWillMount()),typeof n.componentDidMount=="function"&&(t.flags|=4194308)):(typeof n.componentDidMount=="function"&&(t.flags|=4194308),t.memoizedProps=e,t.memoizedState=r),n.props=e,n.state=r,n.context=c,e=f):(typeof n.componentDidMount=="function"&&(t.flags|=4194308),e=!1)}else{n=t.stateNode,jc(l,t),c=t.memoizedProps,b=Ra(a,c),n.props=b,x=t.pendingProps,h=n.context,r=a.contextType,f=te,typeof r=="object"&&r!==null&&(f=Xl(r)),i=a.getDerivedStateFromProps,(r=typeof i=="function"||typeof n.getSnapshotBeforeUpdate=="function")||typeof n.UNSAFE_componentWillReceiveProps!="function"&&typeof n.componentWillReceiveProps!="function"||(c!==x||h!==f)&&ro(t,n,e,f),la=!1,h=t.memoizedState,n.state=h,Fe(t,e,n,u),$e();var m=t.memoizedState;c!==x||h!==m||la||l!==null&&l.dependencies!==null&&Ju(l.dependencies)?(typeof i=="function"&&(Pc(t,a,i,e),m=t.memoizedState),(b=la||oo(t,a,b,e,h,m,f)||l!==null&&l.dependencies!==null&&Ju(l.dependencies))?(r||typeof n.UNSAFE_componentWillUpdate!="function"&&typeof n.componentWillUpdate!="function"||(typeof n.componentWillUpdate=="function"&&n.componentWillUpdate(e,m,f),typeof n.UNSAFE_componentWillUpdate=="function"&&n.UNSAFE_componentWillUpdate(e,m,f)),typeof n.componentDidUpdate=="function"&&(t.flags|=4),typeof n.getSnapshotBeforeUpdate=="function"&&(t.flags|=1024)):(typeof n.componentDidUpdate!="function"||c===l.memoizedProps&&h===l.memoizedState||(t.flags|=4),typeof n.getSnapshotBeforeUpdate!="function"||c===l.memoizedProps&&h===l.memoizedState||(t.flags|=1024),t.memoizedProps=e,t.memoizedState=m),n.props=e,n.state=m,n.context=f,e=b):(typeof n.componentDidUpdate!="function"||c===l.memoizedProps&&h===l.memoizedState||(t.flags|=4),typeof n.getSnapshotBeforeUpdate!="function"||c===l.memoizedProps&&h===l.memoizedState||(t.flags|=1024),e=!1)}return n=e,hn(l,t),e=(t.flags&128)!==0,n||e?(n=t.stateNode,a=e&&typeof a.getDerivedStateFromError!="function"?null:n.render(),t.flags|=1,l!==null&&e?(t.child=de(t,l.child,null,u),t.child=de(t,null,a,u)):Hl(l,t,a,u),t.memoizedState=n.state,l=t.child):l=wt(l,t,u),l}function Mo(l,t,a,e){return we(),t.flags|=256,Hl(l,t,a,e),t.child}var ei={dehydrated:null,treeContext:null,retryLane:0,hydrationErrors:null};function ui(l){return{baseLanes:l,cachePool:hs()}}function ni(l,t,a){return l=l!==null?l.childLanes&~a:0,t&&(l|=bt),l}function _o(l,t,a){var e=t.pendingProps,u=!1,n=(t.flags&128)!==0,c;if((c=n)||(c=l!==null&&l.memoizedState===null?!1:(Al.current&2)!==0),c&&(u=!0,t.flags&=-129),c=(t.flags&32)!==0,t.flags&=-33,l===null){if(F){if(u?ua(t):na(),F){var i=yl,f;if(f=i){l:{for(f=i,i=Mt;f.nodeType!==8;){if(!i){i=null;break l}if(f=At(f.nextSibling),f===null){i=null;break l}}i=f}i!==null?(t.memoizedState={dehydrated:i,treeContext:Ma!==null?{id:Bt,overflow:Yt}:null,retryLane:536870912,hydrationErrors:null},f=ut(18,null,null,0),f.stateNode=i,f.return=t,t.child=f,wl=t,yl=null,f=!0):f=!1}f||Oa(t)}if(i=t.memoizedState,i!==null&&(i=i.dehydrated,i!==null))return wi(i)?t.lanes=32:t.lanes=536870912,null;Ct(t)}return i=e.children,e=e.fallback,u?(na(),u=t.mode,i=mn({mode:"hidden",children:i},u),e=Da(e,u,a,null),i.return=t,e.return=t,i.sibling=e,t.child=i,u=t.child,u.memoizedState=ui(a),u.childLanes=ni(l,c,a),t.memoizedState=ei,e):(ua(t),ci(t,i))}if(f=l.memoizedState,f!==null&&(i=f.dehydrated,i!==null)){if(n)t.flags&256?(ua(t),t.flags&=-257,t=ii(l,t,a)):t.memoizedState!==null?(na(),t.child=l.child,t.flags|=128,t=null):(na(),u=e.fallback,i=t.mode,e=mn({mode:"visible",children:e.children},i),u=Da(u,i,a,null),u.flags|=2,e.return=t,u.return=t,e.sibling=u,t.child=e,de(t,l.child,null,a),e=t.child,e.memoizedState=ui(a),e.childLanes=ni(l,c,a),t.memoizedState=ei,t=u);else if(ua(t),wi(i)){if(c=i.nextSibling&&i.nextSibling.dataset,c)var r=c.dgst;c=r,e=Error(v(419)),e.stack="",e.digest=c,Ze({value:e,source:null,stack:null}),t=ii(l,t,a)}else if(Ml||Ve(l,t,a,!1),c=(a&l.childLanes)!==0,Ml||c){if(c=ol,c!==null&&(e=a&-a,e=(e&42)!==0?1:Zn(e),e=(e&(c.suspendedLanes|a))!==0?0:e,e!==0&&e!==f.retryLane))throw f.retryLane=e,le(l,e),st(c,l,e),So;i.data==="$?"||Ei(),t=ii(l,t,a)}else i.data==="$?"?(t.flags|=192,t.child=l.child,t=null):(l=f.treeContext,yl=At(i.nextSibling),wl=t,F=!0,Na=null,Mt=!1,l!==null&&(yt[vt++]=Bt,yt[vt++]=Yt,yt[vt++]=Ma,Bt=l.id,Yt=l.overflow,Ma=t),t=ci(t,e.children),t.flags|=4096);return t}return u?(na(),u=e.fallback,i=t.mode,f=l.child,r=f.sibling,e=Rt(f,{mode:"hidden",children:e.children}),e.subtreeFlags=f.subtreeFlags&65011712,r!==null?u=Rt(r,u):(u=Da(u,i,a,null),u.flags|=2),u.return=t,e.return=t,e.sibling=u,t.child=e,e=u,u=t.child,i=l.child.memoizedState,i===null?i=ui(a):(f=i.cachePool,f!==null?(r=zl._currentValue,f=f.parent!==r?{parent:r,pool:r}:f):f=hs(),i={baseLanes:i.baseLanes|a,cachePool:f}),u.memoizedState=i,u.childLanes=ni(l,c,a),t.memoizedState=ei,e):(ua(t),a=l.child,l=a.sibling,a=Rt(a,{mode:"visible",children:e.children}),a.return=t,a.sibling=null,l!==null&&(c=t.deletions,c===null?(t.deletions=[l],t.flags|=16):c.push(l)),t.child=a,t.memoizedState=null,a)}function ci(l,t){return t=mn({mode:"visible",children:t},l.mode),t.return=l,l.child=t}function mn(l,t){return l=ut(22,l,null,t),l.lanes=0,l.stateNode={_visibility:1,_pendingMarkers:null,_retryCache:null,_transitions:null},l}function ii(l,t,a){return de(t,l.child,null,a),l=ci(t,t.pendingProps.children),l.flags|=2,t.memoizedState=null,l}function No(l,t,a){l.lanes|=t;var e=l.alternate;e!==null&&(e.lanes|=t),Ec(l.return,t,a)}function fi(l,t,a,e,u){var n=l.memoizedState;n===null?l.memoizedState={isBackwards:t,rendering:null,renderingStartTime:0,last:e,tail:a,tailMode:u}:(n.isBackwards=t,n.rendering=null,n.renderingStartTime=0,n.last=e,n.tail=a,n.tailMode=u)}function Oo(l,t,a){var e=t.pendingProps,u=e.revealOrder,n=e.tail;if(Hl(l,t,e.children,a),e=Al.current,(e&2)!==0)e=e&1|2,t.flags|=128;else{if(l!==null&&(l.flags&128)!==0)l:for(l=t.child;l!==null;){if(l.tag===13)l.memoizedState!==null&&No(l,a,t);else if(l.tag===19)No(l,a,t);else if(l.child!==null){l.child.return=l,l=l.child;continue}if(l===t)break l;for(;l.sibling===null;){if(l.return===null||l.return===t)break l;l=l.return}l.sibling.return=l.return,l=l.sibling}e&=1}switch(q(Al,e),u){case"forwards":for(a=t.child,u=null;a!==null;)l=a.alternate,l!==null&&on(l)===null&&(u=a),a=a.sibling;a=u,a===null?(u=t.child,t.child=null):(u=a.sibling,a.sibling=null),fi(t,!1,u,a,n);break;case"backwards":for(a=null,u=t.child,t.child=null;u!==null;){if(l=u.alternate,l!==null&&on(l)===null){t.child=u;break}l=u.sibling,u.sibling=a,a=u,u=l}fi(t,!0,a,null,n);break;case"together":fi(t,!1,null,null,void 0);break;default:t.memoizedState=null}return t.child}function wt(l,t,a){if(l!==null&&(t.dependencies=l.dependencies),oa|=t.lanes,(a&t.childLanes)===0)if(l!==null){if(Ve(l,t,a,!1),(a&t.childLanes)===0)return null}else return null;if(l!==null&&t.child!==l.child)throw Error(v(153));if(t.child!==null){for(l=t.child,a=Rt(l,l.pendingProps),t.child=a,a.return=t;l.sibling!==null;)l=l.sibling,a=a.sibling=Rt(l,l.pendingProps),a.return=t;a.sibling=null}return t.child}function si(l,t){return(l.lanes&t)!==0?!0:(l=l.dependencies,!!(l!==null&&Ju(l)))}function _h(l,t,a){switch(t.tag){case 3:jt(t,t.stateNode.containerInfo),Pt(t,zl,l.memoizedState.cache),we();break;case 27:case 5:De(t);break;case 4:jt(t,t.stateNode.containerInfo);break;case 10:Pt(t,t.type,t.memoizedProps.value);break;case 13:var e=t.memoizedState;if(e!==null)return e.dehydrated!==null?(ua(t),t.flags|=128,null):(a&t.child.childLanes)!==0?_o(l,t,a):(ua(t),l=wt(l,t,a),l!==null?l.sibling:null);ua(t);break;case 19:var u=(l.flags&128)!==0;if(e=(a&t.childLanes)!==0,e||(Ve(l,t,a,!1),e=(a&t.childLanes)!==0),u){if(e)return Oo(l,t,a);t.flags|=128}if(u=t.memoizedState,u!==null&&(u.rendering=null,u.tail=null,u.lastEffect=null),q(Al,Al.current),e)break;return null;case 22:case 23:return t.lanes=0,zo(l,t,a);case 24:Pt(t,zl,l.memoizedState.cache)}return wt(l,t,a)}function Uo(l,t,a){if(l!==null)if(l.memoizedProps!==t.pendingProps)Ml=!0;else{if(!si(l,a)&&(t.flags&128)===0)return Ml=!1,_h(l,t,a);Ml=(l.flags&131072)!==0}else Ml=!1,F&&(t.flags&1048576)!==0&&cs(t,Ku,t.index);switch(t.lanes=0,t.tag){case 16:l:{l=t.pendingProps;var e=t.elementType,u=e._init;if(e=u(e._payload),t.type=e,typeof e=="function")bc(e)?(l=Ra(e,l),t.tag=1,t=Do(null,t,e,l,a)):(t.tag=0,t=ai(null,t,e,l,a));else{if(e!=null){if(u=e.$$typeof,u===Fl){t.tag=11,t=po(null,t,e,l,a);break l}else if(u===Zl){t.tag=14,t=xo(null,t,e,l,a);break l}}throw t=Il(e)||e,Error(v(306,t,""))}}return t;case 0:return ai(l,t,t.type,t.pendingProps,a);case 1:return e=t.type,u=Ra(e,t.pendingProps),Do(l,t,e,u,a);case 3:l:{if(jt(t,t.stateNode.containerInfo),l===null)throw Error(v(387));e=t.pendingProps;var n=t.memoizedState;u=n.element,jc(l,t),Fe(t,e,null,a);var c=t.memoizedState;if(e=c.cache,Pt(t,zl,e),e!==n.cache&&Dc(t,[zl],a,!0),$e(),e=c.element,n.isDehydrated)if(n={element:e,isDehydrated:!1,cache:c.cache},t.updateQueue.baseState=n,t.memoizedState=n,t.flags&256){t=Mo(l,t,e,a);break l}else if(e!==u){u=ht(Error(v(424)),t),Ze(u),t=Mo(l,t,e,a);break l}else{switch(l=t.stateNode.containerInfo,l.nodeType){case 9:l=l.body;break;default:l=l.nodeName==="HTML"?l.ownerDocument.body:l}for(yl=At(l.firstChild),wl=t,F=!0,Na=null,Mt=!0,a=fo(t,null,e,a),t.child=a;a;)a.flags=a.flags&-3|4096,a=a.sibling}else{if(we(),e===u){t=wt(l,t,a);break l}Hl(l,t,e,a)}t=t.child}return t;case 26:return hn(l,t),l===null?(a=Rd(t.type,null,t.pendingProps,null))?t.memoizedState=a:F||(a=t.type,l=t.pendingProps,e=_n(Pl.current).createElement(a),e[Ql]=t,e[Kl]=l,Rl(e,a,l),Dl(e),t.stateNode=e):t.memoizedState=Rd(t.type,l.memoizedProps,t.pendingProps,l.memoizedState),null;case 27:return De(t),l===null&&F&&(e=t.stateNode=jd(t.type,t.pendingProps,Pl.current),wl=t,Mt=!0,u=yl,ma(t.type)?(Zi=u,yl=At(e.firstChild)):yl=u),Hl(l,t,t.pendingProps.children,a),hn(l,t),l===null&&(t.flags|=4194304),t.child;case 5:return l===null&&F&&((u=e=yl)&&(e=a0(e,t.type,t.pendingProps,Mt),e!==null?(t.stateNode=e,wl=t,yl=At(e.firstChild),Mt=!1,u=!0):u=!1),u||Oa(t)),De(t),u=t.type,n=t.pendingProps,c=l!==null?l.memoizedProps:null,e=n.children,Xi(u,n)?e=null:c!==null&&Xi(u,c)&&(t.flags|=32),t.memoizedState!==null&&(u=Qc(l,t,ph,null,null,a),Su._currentValue=u),hn(l,t),Hl(l,t,e,a),t.child;case 6:return l===null&&F&&((l=a=yl)&&(a=e0(a,t.pendingProps,Mt),a!==null?(t.stateNode=a,wl=t,yl=null,l=!0):l=!1),l||Oa(t)),null;case 13:return _o(l,t,a);case 4:return jt(t,t.stateNode.containerInfo),e=t.pendingProps,l===null?t.child=de(t,null,e,a):Hl(l,t,e,a),t.child;case 11:return po(l,t,t.type,t.pendingProps,a);case 7:return Hl(l,t,t.pendingProps,a),t.child;case 8:return Hl(l,t,t.pendingProps.children,a),t.child;case 12:return Hl(l,t,t.pendingProps.children,a),t.child;case 10:return e=t.pendingProps,Pt(t,t.type,e.value),Hl(l,t,e.children,a),t.child;case 9:return u=t.type._context,e=t.pendingProps.children,ja(t),u=Xl(u),e=e(u),t.flags|=1,Hl(l,t,e,a),t.child;case 14:return xo(l,t,t.type,t.pendingProps,a);case 15:return To(l,t,t.type,t.pendingProps,a);case 19:return Oo(l,t,a);case 31:return e=t.pendingProps,a=t.mode,e={mode:e.mode,children:e.children},l===null?(a=mn(e,a),a.ref=t.ref,t.child=a,a.return=t,t=a):(a=Rt(l.child,e),a.ref=t.ref,t.child=a,a.return=t,t=a),t;case 22:return zo(l,t,a);case 24:return ja(t),e=Xl(zl),l===null?(u=Nc(),u===null&&(u=ol,n=Mc(),u.pooledCache=n,n.refCount++,n!==null&&(u.pooledCacheLanes|=a),u=n),t.memoizedState={parent:e,cache:u},Uc(t),Pt(t,zl,u)):((l.lanes&a)!==0&&(jc(l,t),Fe(t,null,null,a),$e()),u=l.memoizedState,n=t.memoizedState,u.parent!==e?(u={parent:e,cache:e},t.memoizedState=u,t.lanes===0&&(t.memoizedState=t.updateQueue.baseState=u),Pt(t,zl,e)):(e=n.cache,Pt(t,zl,e),e!==u.cache&&Dc(t,[zl],a,!0))),Hl(l,t,t.pendingProps.children,a),t.child;case 29:throw t.pendingProps}throw Error(v(156,t.tag))}function Zt(l){l.flags|=4}function jo(l,t){if(t.type!=="stylesheet"||(t.state.loading&4)!==0)l.flags&=-16777217;else if(l.flags|=16777216,!Gd(t)){if(t=gt.current,t!==null&&((W&4194048)===W?_t!==null:(W&62914560)!==W&&(W&536870912)===0||t!==_t))throw We=Oc,ms;l.flags|=8192}}function yn(l,t){t!==null&&(l.flags|=4),l.flags&16384&&(t=l.tag!==22?sf():536870912,l.lanes|=t,ye|=t)}function uu(l,t){if(!F)switch(l.tailMode){case"hidden":t=l.tail;for(var a=null;t!==null;)t.alternate!==null&&(a=t),t=t.sibling;a===null?l.tail=null:a.sibling=null;break;case"collapsed":a=l.tail;for(var e=null;a!==null;)a.alternate!==null&&(e=a),a=a.sibling;e===null?t||l.tail===null?l.tail=null:l.tail.sibling=null:e.sibling=null}}function ml(l){var t=l.alternate!==null&&l.alternate.child===l.child,a=0,e=0;if(t)for(var u=l.child;u!==null;)a|=u.lanes|u.childLanes,e|=u.subtreeFlags&65011712,e|=u.flags&65011712,u.return=l,u=u.sibling;else for(u=l.child;u!==null;)a|=u.lanes|u.childLanes,e|=u.subtreeFlags,e|=u.flags,u.return=l,u=u.sibling;return l.subtreeFlags|=e,l.childLanes=a,t}function Nh(l,t,a){var e=t.pendingProps;switch(Tc(t),t.tag){case 31:case 16:case 15:case 0:case 11:case 7:case 8:case 12:case 9:case 14:return ml(t),null;case 1:return ml(t),null;case 3:return a=t.stateNode,e=null,l!==null&&(e=l.memoizedState.cache),t.memoizedState.cache!==e&&(t.flags|=2048),Xt(zl),jl(),a.pendingContext&&(a.context=a.pendingContext,a.pendingContext=null),(l===null||l.child===null)&&(Ce(t)?Zt(t):l===null||l.memoizedState.isDehydrated&&(t.flags&256)===0||(t.flags|=1024,ss())),ml(t),null;case 26:return a=t.memoizedState,l===null?(Zt(t),a!==null?(ml(t),jo(t,a)):(ml(t),t.flags&=-16777217)):a?a!==l.memoizedState?(Zt(t),ml(t),jo(t,a)):(ml(t),t.flags&=-16777217):(l.memoizedProps!==e&&Zt(t),ml(t),t.flags&=-16777217),null;case 27:Xa(t),a=Pl.current;var u=t.type;if(l!==null&&t.stateNode!=null)l.memoizedProps!==e&&Zt(t);else{if(!e){if(t.stateNode===null)throw Error(v(166));return ml(t),null}l=il.current,Ce(t)?is(t):(l=jd(u,e,a),t.stateNode=l,Zt(t))}return ml(t),null;case 5:if(Xa(t),a=t.type,l!==null&&t.stateNode!=null)l.memoizedProps!==e&&Zt(t);else{if(!e){if(t.stateNode===null)throw Error(v(166));return ml(t),null}if(l=il.current,Ce(t))is(t);else{switch(u=_n(Pl.current),l){case 1:l=u.createElementNS("http://www.w3.org/2000/svg",a);break;case 2:l=u.createElementNS("http://www.w3.org/1998/Math/MathML",a);break;default:switch(a){case"svg":l=u.createElementNS("http://www.w3.org/2000/svg",a);break;case"math":l=u.createElementNS("http://www.w3.org/1998/Math/MathML",a);break;case"script":l=u.createElement("div"),l.innerHTML="<script><\/script>",l=l.removeChild(l.firstChild);break;case"select":l=typeof e.is=="string"?u.createElement("select",{is:e.is}):u.createElement("select"),e.multiple?l.multiple=!0:e.size&&(l.size=e.size);break;default:l=typeof e.is=="string"?u.createElement(a,{is:e.is}):u.createElement(a)}}l[Ql]=t,l[Kl]=e;l:for(u=t.child;u!==null;){if(u.tag===5||u.tag===6)l.appendChild(u.stateNode);else if(u.tag!==4&&u.tag!==27&&u.child!==null){u.child.return=u,u=u.child;continue}if(u===t)break l;for(;u.sibling===null;){if(u.return===null||u.return===t)break l;u=u.return}u.sibling.return=u.return,u=u.sibling}t.stateNode=l;l:switch(Rl(l,a,e),a){case"button":case"input":case"select":case"textarea":l=!!e.autoFocus;break l;case"img":l=!0;break l;default:l=!1}l&&Zt(t)}}return ml(t),t.flags&=-16777217,null;case 6:if(l&&t.stateNode!=null)l.memoizedProps!==e&&Zt(t);else{if(typeof e!="string"&&t.stateNode===null)throw Error(v(166));if(l=Pl.current,Ce(t)){if(l=t.stateNode,a=t.memoizedProps,e=null,u=wl,u!==null)switch(u.tag){case 27:case 5:e=u.memoizedProps}l[Ql]=t,l=!!(l.nodeValue===a||e!==null&&e.suppressHydrationWarning===!0||Ed(l.nodeValue,a)),l||Oa(t)}else l=_n(l).createTextNode(e),l[Ql]=t,t.stateNode=l}return ml(t),null;case 13:if(e=t.memoizedState,l===null||l.memoizedState!==null&&l.memoizedState.dehydrated!==null){if(u=Ce(t),e!==null&&e.dehydrated!==null){if(l===null){if(!u)throw Error(v(318));if(u=t.memoizedState,u=u!==null?u.dehydrated:null,!u)throw Error(v(317));u[Ql]=t}else we(),(t.flags&128)===0&&(t.memoizedState=null),t.flags|=4;ml(t),u=!1}else u=ss(),l!==null&&l.memoizedState!==null&&(l.memoizedState.hydrationErrors=u),u=!0;if(!u)return t.flags&256?(Ct(t),t):(Ct(t),null)}if(Ct(t),(t.flags&128)!==0)return t.lanes=a,t;if(a=e!==null,l=l!==null&&l.memoizedState!==null,a){e=t.child,u=null,e.alternate!==null&&e.alternate.memoizedState!==null&&e.alternate.memoizedState.cachePool!==null&&(u=e.alternate.memoizedState.cachePool.pool);var n=null;e.memoizedState!==null&&e.memoizedState.cachePool!==null&&(n=e.memoizedState.cachePool.pool),n!==u&&(e.flags|=2048)}return a!==l&&a&&(t.child.flags|=8192),yn(t,t.updateQueue),ml(t),null;case 4:return jl(),l===null&&qi(t.stateNode.containerInfo),ml(t),null;case 10:return Xt(t.type),ml(t),null;case 19:if(M(Al),u=t.memoizedState,u===null)return ml(t),null;if(e=(t.flags&128)!==0,n=u.rendering,n===null)if(e)uu(u,!1);else{if(vl!==0||l!==null&&(l.flags&128)!==0)for(l=t.child;l!==null;){if(n=on(l),n!==null){for(t.flags|=128,uu(u,!1),l=n.updateQueue,t.updateQueue=l,yn(t,l),t.subtreeFlags=0,l=a,a=t.child;a!==null;)ns(a,l),a=a.sibling;return q(Al,Al.current&1|2),t.child}l=l.sibling}u.tail!==null&&lt()>bn&&(t.flags|=128,e=!0,uu(u,!1),t.lanes=4194304)}else{if(!e)if(l=on(n),l!==null){if(t.flags|=128,e=!0,l=l.updateQueue,t.updateQueue=l,yn(t,l),uu(u,!0),u.tail===null&&u.tailMode==="hidden"&&!n.alternate&&!F)return ml(t),null}else 2*lt()-u.renderingStartTime>bn&&a!==536870912&&(t.flags|=128,e=!0,uu(u,!1),t.lanes=4194304);u.isBackwards?(n.sibling=t.child,t.child=n):(l=u.last,l!==null?l.sibling=n:t.child=n,u.last=n)}return u.tail!==null?(t=u.tail,u.rendering=t,u.tail=t.sibling,u.renderingStartTime=lt(),t.sibling=null,l=Al.current,q(Al,e?l&1|2:l&1),t):(ml(t),null);case 22:case 23:return Ct(t),Bc(),e=t.memoizedState!==null,l!==null?l.memoizedState!==null!==e&&(t.flags|=8192):e&&(t.flags|=8192),e?(a&536870912)!==0&&(t.flags&128)===0&&(ml(t),t.subtreeFlags&6&&(t.flags|=8192)):ml(t),a=t.updateQueue,a!==null&&yn(t,a.retryQueue),a=null,l!==null&&l.memoizedState!==null&&l.memoizedState.cachePool!==null&&(a=l.memoizedState.cachePool.pool),e=null,t.memoizedState!==null&&t.memoizedState.cachePool!==null&&(e=t.memoizedState.cachePool.pool),e!==a&&(t.flags|=2048),l!==null&&M(Ha),null;case 24:return a=null,l!==null&&(a=l.memoizedState.cache),t.memoizedState.cache!==a&&(t.flags|=2048),Xt(zl),ml(t),null;case 25:return null;case 30:return null}throw Error(v(156,t.tag))}function Oh(l,t){switch(Tc(t),t.tag){case 1:return l=t.flags,l&65536?(t.flags=l&-65537|128,t):null;case 3:return Xt(zl),jl(),l=t.flags,(l&65536)!==0&&(l&128)===0?(t.flags=l&-65537|128,t):null;case 26:case 27:case 5:return Xa(t),null;case 13:if(Ct(t),l=t.memoizedState,l!==null&&l.dehydrated!==null){if(t.alternate===null)throw Error(v(340));we()}return l=t.flags,l&65536?(t.flags=l&-65537|128,t):null;case 19:return M(Al),null;case 4:return jl(),null;case 10:return Xt(t.type),null;case 22:case 23:return Ct(t),Bc(),l!==null&&M(Ha),l=t.flags,l&65536?(t.flags=l&-65537|128,t):null;case 24:return Xt(zl),null;case 25:return null;default:return null}}function Ho(l,t){switch(Tc(t),t.tag){case 3:Xt(zl),jl();break;case 26:case 27:case 5:Xa(t);break;case 4:jl();break;case 13:Ct(t);break;case 19:M(Al);break;case 10:Xt(t.type);break;case 22:case 23:Ct(t),Bc(),l!==null&&M(Ha);break;case 24:Xt(zl)}}function nu(l,t){try{var a=t.updateQueue,e=a!==null?a.lastEffect:null;if(e!==null){var u=e.next;a=u;do{if((a.tag&l)===l){e=void 0;var n=a.create,c=a.inst;e=n(),c.destroy=e}a=a.next}while(a!==u)}}catch(i){fl(t,t.return,i)}}function ca(l,t,a){try{var e=t.updateQueue,u=e!==null?e.lastEffect:null;if(u!==null){var n=u.next;e=n;do{if((e.tag&l)===l){var c=e.inst,i=c.destroy;if(i!==void 0){c.destroy=void 0,u=t;var f=a,r=i;try{r()}catch(b){fl(u,f,b)}}}e=e.next}while(e!==n)}}catch(b){fl(t,t.return,b)}}function qo(l){var t=l.updateQueue;if(t!==null){var a=l.stateNode;try{ps(t,a)}catch(e){fl(l,l.return,e)}}}function Ro(l,t,a){a.props=Ra(l.type,l.memoizedProps),a.state=l.memoizedState;try{a.componentWillUnmount()}catch(e){fl(l,t,e)}}function cu(l,t){try{var a=l.ref;if(a!==null){switch(l.tag){case 26:case 27:case 5:var e=l.stateNode;break;case 30:e=l.stateNode;break;default:e=l.stateNode}typeof a=="function"?l.refCleanup=a(e):a.current=e}}catch(u){fl(l,t,u)}}function Nt(l,t){var a=l.ref,e=l.refCleanup;if(a!==null)if(typeof e=="function")try{e()}catch(u){fl(l,t,u)}finally{l.refCleanup=null,l=l.alternate,l!=null&&(l.refCleanup=null)}else if(typeof a=="function")try{a(null)}catch(u){fl(l,t,u)}else a.current=null}function Bo(l){var t=l.type,a=l.memoizedProps,e=l.stateNode;try{l:switch(t){case"button":case"input":case"select":case"textarea":a.autoFocus&&e.focus();break l;case"img":a.src?e.src=a.src:a.srcSet&&(e.srcset=a.srcSet)}}catch(u){fl(l,l.return,u)}}function oi(l,t,a){try{var e=l.stateNode;Fh(e,l.type,a,t),e[Kl]=t}catch(u){fl(l,l.return,u)}}function Yo(l){return l.tag===5||l.tag===3||l.tag===26||l.tag===27&&ma(l.type)||l.tag===4}function di(l){l:for(;;){for(;l.sibling===null;){if(l.return===null||Yo(l.return))return null;l=l.return}for(l.sibling.return=l.return,l=l.sibling;l.tag!==5&&l.tag!==6&&l.tag!==18;){if(l.tag===27&&ma(l.type)||l.flags&2||l.child===null||l.tag===4)continue l;l.child.return=l,l=l.child}if(!(l.flags&2))return l.stateNode}}function ri(l,t,a){var e=l.tag;if(e===5||e===6)l=l.stateNode,t?(a.nodeType===9?a.body:a.nodeName==="HTML"?a.ownerDocument.body:a).insertBefore(l,t):(t=a.nodeType===9?a.body:a.nodeName==="HTML"?a.ownerDocument.body:a,t.appendChild(l),a=a._reactRootContainer,a!=null||t.onclick!==null||(t.onclick=Mn));else if(e!==4&&(e===27&&ma(l.type)&&(a=l.stateNode,t=null),l=l.child,l!==null))for(ri(l,t,a),l=l.sibling;l!==null;)ri(l,t,a),l=l.sibling}function vn(l,t,a){var e=l.tag;if(e===5||e===6)l=l.stateNode,t?a.insertBefore(l,t):a.appendChild(l);else if(e!==4&&(e===27&&ma(l.type)&&(a=l.stateNode),l=l.child,l!==null))for(vn(l,t,a),l=l.sibling;l!==null;)vn(l,t,a),l=l.sibling}function Qo(l){var t=l.stateNode,a=l.memoizedProps;try{for(var e=l.type,u=t.attributes;u.length;)t.removeAttributeNode(u[0]);Rl(t,e,a),t[Ql]=l,t[Kl]=a}catch(n){fl(l,l.return,n)}}var Vt=!1,bl=!1,hi=!1,Xo=typeof WeakSet=="function"?WeakSet:Set,_l=null;function Uh(l,t){if(l=l.containerInfo,Yi=qn,l=kf(l),dc(l)){if("selectionStart"in l)var a={start:l.selectionStart,end:l.selectionEnd};else l:{a=(a=l.ownerDocument)&&a.defaultView||window;var e=a.getSelection&&a.getSelection();if(e&&e.rangeCount!==0){a=e.anchorNode;var u=e.anchorOffset,n=e.focusNode;e=e.focusOffset;try{a.nodeType,n.nodeType}catch{a=null;break l}var c=0,i=-1,f=-1,r=0,b=0,x=l,h=null;t:for(;;){for(var m;x!==a||u!==0&&x.nodeType!==3||(i=c+u),x!==n||e!==0&&x.nodeType!==3||(f=c+e),x.nodeType===3&&(c+=x.nodeValue.length),(m=x.firstChild)!==null;)h=x,x=m;for(;;){if(x===l)break t;if(h===a&&++r===u&&(i=c),h===n&&++b===e&&(f=c),(m=x.nextSibling)!==null)break;x=h,h=x.parentNode}x=m}a=i===-1||f===-1?null:{start:i,end:f}}else a=null}a=a||{start:0,end:0}}else a=null;for(Qi={focusedElem:l,selectionRange:a},qn=!1,_l=t;_l!==null;)if(t=_l,l=t.child,(t.subtreeFlags&1024)!==0&&l!==null)l.return=t,_l=l;else for(;_l!==null;){switch(t=_l,n=t.alternate,l=t.flags,t.tag){case 0:break;case 11:case 15:break;case 1:if((l&1024)!==0&&n!==null){l=void 0,a=t,u=n.memoizedProps,n=n.memoizedState,e=a.stateNode;try{var R=Ra(a.type,u,a.elementType===a.type);l=e.getSnapshotBeforeUpdate(R,n),e.__reactInternalSnapshotBeforeUpdate=l}catch(U){fl(a,a.return,U)}}break;case 3:if((l&1024)!==0){if(l=t.stateNode.containerInfo,a=l.nodeType,a===9)Ci(l);else if(a===1)switch(l.nodeName){case"HEAD":case"HTML":case"BODY":Ci(l);break;default:l.textContent=""}}break;case 5:case 26:case 27:case 6:case 4:case 17:break;default:if((l&1024)!==0)throw Error(v(163))}if(l=t.sibling,l!==null){l.return=t.return,_l=l;break}_l=t.return}}function Go(l,t,a){var e=a.flags;switch(a.tag){case 0:case 11:case 15:ia(l,a),e&4&&nu(5,a);break;case 1:if(ia(l,a),e&4)if(l=a.stateNode,t===null)try{l.componentDidMount()}catch(c){fl(a,a.return,c)}else{var u=Ra(a.type,t.memoizedProps);t=t.memoizedState;try{l.componentDidUpdate(u,t,l.__reactInternalSnapshotBeforeUpdate)}catch(c){fl(a,a.return,c)}}e&64&&qo(a),e&512&&cu(a,a.return);break;case 3:if(ia(l,a),e&64&&(l=a.updateQueue,l!==null)){if(t=null,a.child!==null)switch(a.child.tag){case 27:case 5:t=a.child.stateNode;break;case 1:t=a.child.stateNode}try{ps(l,t)}catch(c){fl(a,a.return,c)}}break;case 27:t===null&&e&4&&Qo(a);case 26:case 5:ia(l,a),t===null&&e&4&&Bo(a),e&512&&cu(a,a.return);break;case 12:ia(l,a);break;case 13:ia(l,a),e&4&&Zo(l,a),e&64&&(l=a.memoizedState,l!==null&&(l=l.dehydrated,l!==null&&(a=Gh.bind(null,a),u0(l,a))));break;case 22:if(e=a.memoizedState!==null||Vt,!e){t=t!==null&&t.memoizedState!==null||bl,u=Vt;var n=bl;Vt=e,(bl=t)&&!n?fa(l,a,(a.subtreeFlags&8772)!==0):ia(l,a),Vt=u,bl=n}break;case 30:break;default:ia(l,a)}}function Co(l){var t=l.alternate;t!==null&&(l.alternate=null,Co(t)),l.child=null,l.deletions=null,l.sibling=null,l.tag===5&&(t=l.stateNode,t!==null&&Kn(t)),l.stateNode=null,l.return=null,l.dependencies=null,l.memoizedProps=null,l.memoizedState=null,l.pendingProps=null,l.stateNode=null,l.updateQueue=null}var dl=null,kl=!1;function Lt(l,t,a){for(a=a.child;a!==null;)wo(l,t,a),a=a.sibling}function wo(l,t,a){if(tt&&typeof tt.onCommitFiberUnmount=="function")try{tt.onCommitFiberUnmount(Me,a)}catch{}switch(a.tag){case 26:bl||Nt(a,t),Lt(l,t,a),a.memoizedState?a.memoizedState.count--:a.stateNode&&(a=a.stateNode,a.parentNode.removeChild(a));break;case 27:bl||Nt(a,t);var e=dl,u=kl;ma(a.type)&&(dl=a.stateNode,kl=!1),Lt(l,t,a),yu(a.stateNode),dl=e,kl=u;break;case 5:bl||Nt(a,t);case 6:if(e=dl,u=kl,dl=null,Lt(l,t,a),dl=e,kl=u,dl!==null)if(kl)try{(dl.nodeType===9?dl.body:dl.nodeName==="HTML"?dl.ownerDocument.body:dl).removeChild(a.stateNode)}catch(n){fl(a,t,n)}else try{dl.removeChild(a.stateNode)}catch(n){fl(a,t,n)}break;case 18:dl!==null&&(kl?(l=dl,Od(l.nodeType===9?l.body:l.nodeName==="HTML"?l.ownerDocument.body:l,a.stateNode),zu(l)):Od(dl,a.stateNode));break;case 4:e=dl,u=kl,dl=a.stateNode.containerInfo,kl=!0,Lt(l,t,a),dl=e,kl=u;break;case 0:case 11:case 14:case 15:bl||ca(2,a,t),bl||ca(4,a,t),Lt(l,t,a);break;case 1:bl||(Nt(a,t),e=a.stateNode,typeof e.componentWillUnmount=="function"&&Ro(a,t,e)),Lt(l,t,a);break;case 21:Lt(l,t,a);break;case 22:bl=(e=bl)||a.memoizedState!==null,Lt(l,t,a),bl=e;break;default:Lt(l,t,a)}}function Zo(l,t){if(t.memoizedState===null&&(l=t.alternate,l!==null&&(l=l.memoizedState,l!==null&&(l=l.dehydrated,l!==null))))try{zu(l)}catch(a){fl(t,t.return,a)}}function jh(l){switch(l.tag){case 13:case 19:var t=l.stateNode;return t===null&&(t=l.stateNode=new Xo),t;case 22:return l=l.stateNode,t=l._retryCache,t===null&&(t=l._retryCache=new Xo),t;default:throw Error(v(435,l.tag))}}function mi(l,t){var a=jh(l);t.forEach(function(e){var u=Ch.bind(null,l,e);a.has(e)||(a.add(e),e.then(u,u))})}function nt(l,t){var a=t.deletions;if(a!==null)for(var e=0;e<a.length;e++){var u=a[e],n=l,c=t,i=c;l:for(;i!==null;){switch(i.tag){case 27:if(ma(i.type)){dl=i.stateNode,kl=!1;break l}break;case 5:dl=i.stateNode,kl=!1;break l;case 3:case 4:dl=i.stateNode.containerInfo,kl=!0;break l}i=i.return}if(dl===null)throw Error(v(160));wo(n,c,u),dl=null,kl=!1,n=u.alternate,n!==null&&(n.return=null),u.return=null}if(t.subtreeFlags&13878)for(t=t.child;t!==null;)Vo(t,l),t=t.sibling}var zt=null;function Vo(l,t){var a=l.alternate,e=l.flags;switch(l.tag){case 0:case 11:case 14:case 15:nt(t,l),ct(l),e&4&&(ca(3,l,l.return),nu(3,l),ca(5,l,l.return));break;case 1:nt(t,l),ct(l),e&512&&(bl||a===null||Nt(a,a.return)),e&64&&Vt&&(l=l.updateQueue,l!==null&&(e=l.callbacks,e!==null&&(a=l.shared.hiddenCallbacks,l.shared.hiddenCallbacks=a===null?e:a.concat(e))));break;case 26:var u=zt;if(nt(t,l),ct(l),e&512&&(bl||a===null||Nt(a,a.return)),e&4){var n=a!==null?a.memoizedState:null;if(e=l.memoizedState,a===null)if(e===null)if(l.stateNode===null){l:{e=l.type,a=l.memoizedProps,u=u.ownerDocument||u;t:switch(e){case"title":n=u.getElementsByTagName("title")[0],(!n||n[Oe]||n[Ql]||n.namespaceURI==="http://www.w3.org/2000/svg"||n.hasAttribute("itemprop"))&&(n=u.createElement(e),u.head.insertBefore(n,u.querySelector("head > title"))),Rl(n,e,a),n[Ql]=l,Dl(n),e=n;break l;case"link":var c=Qd("link","href",u).get(e+(a.href||""));if(c){for(var i=0;i<c.length;i++)if(n=c[i],n.getAttribute("href")===(a.href==null||a.href===""?null:a.href)&&n.getAttribute("rel")===(a.rel==null?null:a.rel)&&n.getAttribute("title")===(a.title==null?null:a.title)&&n.getAttribute("crossorigin")===(a.crossOrigin==null?null:a.crossOrigin)){c.splice(i,1);break t}}n=u.createElement(e),Rl(n,e,a),u.head.appendChild(n);break;case"meta":if(c=Qd("meta","content",u).get(e+(a.content||""))){for(i=0;i<c.length;i++)if(n=c[i],n.getAttribute("content")===(a.content==null?null:""+a.content)&&n.getAttribute("name")===(a.name==null?null:a.name)&&n.getAttribute("property")===(a.property==null?null:a.property)&&n.getAttribute("http-equiv")===(a.httpEquiv==null?null:a.httpEquiv)&&n.getAttribute("charset")===(a.charSet==null?null:a.charSet)){c.splice(i,1);break t}}n=u.createElement(e),Rl(n,e,a),u.head.appendChild(n);break;default:throw Error(v(468,e))}n[Ql]=l,Dl(n),e=n}l.stateNode=e}else Xd(u,l.type,l.stateNode);else l.stateNode=Yd(u,e,l.memoizedProps);else n!==e?(n===null?a.stateNode!==null&&(a=a.stateNode,a.parentNode.removeChild(a)):n.count--,e===null?Xd(u,l.type,l.stateNode):Yd(u,e,l.memoizedProps)):e===null&&l.stateNode!==null&&oi(l,l.memoizedProps,a.memoizedProps)}break;case 27:nt(t,l),ct(l),e&512&&(bl||a===null||Nt(a,a.return)),a!==null&&e&4&&oi(l,l.memoizedProps,a.memoizedProps);break;case 5:if(nt(t,l),ct(l),e&512&&(bl||a===null||Nt(a,a.return)),l.flags&32){u=l.stateNode;try{Ja(u,"")}catch(m){fl(l,l.return,m)}}e&4&&l.stateNode!=null&&(u=l.memoizedProps,oi(l,u,a!==null?a.memoizedProps:u)),e&1024&&(hi=!0);break;case 6:if(nt(t,l),ct(l),e&4){if(l.stateNode===null)throw Error(v(162));e=l.memoizedProps,a=l.stateNode;try{a.nodeValue=e}catch(m){fl(l,l.return,m)}}break;case 3:if(Un=null,u=zt,zt=Nn(t.containerInfo),nt(t,l),zt=u,ct(l),e&4&&a!==null&&a.memoizedState.isDehydrated)try{zu(t.containerInfo)}catch(m){fl(l,l.return,m)}hi&&(hi=!1,Lo(l));break;case 4:e=zt,zt=Nn(l.stateNode.containerInfo),nt(t,l),ct(l),zt=e;break;case 12:nt(t,l),ct(l);break;case 13:nt(t,l),ct(l),l.child.flags&8192&&l.memoizedState!==null!=(a!==null&&a.memoizedState!==null)&&(pi=lt()),e&4&&(e=l.updateQueue,e!==null&&(l.updateQueue=null,mi(l,e)));break;case 22:u=l.memoizedState!==null;var f=a!==null&&a.memoizedState!==null,r=Vt,b=bl;if(Vt=r||u,bl=b||f,nt(t,l),bl=b,Vt=r,ct(l),e&8192)l:for(t=l.stateNode,t._visibility=u?t._visibility&-2:t._visibility|1,u&&(a===null||f||Vt||bl||Ba(l)),a=null,t=l;;){if(t.tag===5||t.tag===26){if(a===null){f=a=t;try{if(n=f.stateNode,u)c=n.style,typeof c.setProperty=="function"?c.setProperty("display","none","important"):c.display="none";else{i=f.stateNode;var x=f.memoizedProps.style,h=x!=null&&x.hasOwnProperty("display")?x.display:null;i.style.display=h==null||typeof h=="boolean"?"":(""+h).trim()}}catch(m){fl(f,f.return,m)}}}else if(t.tag===6){if(a===null){f=t;try{f.stateNode.nodeValue=u?"":f.memoizedProps}catch(m){fl(f,f.return,m)}}}else if((t.tag!==22&&t.tag!==23||t.memoizedState===null||t===l)&&t.child!==null){t.child.return=t,t=t.child;continue}if(t===l)break l;for(;t.sibling===null;){if(t.return===null||t.return===l)break l;a===t&&(a=null),t=t.return}a===t&&(a=null),t.sibling.return=t.return,t=t.sibling}e&4&&(e=l.updateQueue,e!==null&&(a=e.retryQueue,a!==null&&(e.retryQueue=null,mi(l,a))));break;case 19:nt(t,l),ct(l),e&4&&(e=l.updateQueue,e!==null&&(l.updateQueue=null,mi(l,e)));break;case 30:break;case 21:break;default:nt(t,l),ct(l)}}function ct(l){var t=l.flags;if(t&2){try{for(var a,e=l.return;e!==null;){if(Yo(e)){a=e;break}e=e.return}if(a==null)throw Error(v(160));switch(a.tag){case 27:var u=a.stateNode,n=di(l);vn(l,n,u);break;case 5:var c=a.stateNode;a.flags&32&&(Ja(c,""),a.flags&=-33);var i=di(l);vn(l,i,c);break;case 3:case 4:var f=a.stateNode.containerInfo,r=di(l);ri(l,r,f);break;default:throw Error(v(161))}}catch(b){fl(l,l.return,b)}l.flags&=-3}t&4096&&(l.flags&=-4097)}function Lo(l){if(l.subtreeFlags&1024)for(l=l.child;l!==null;){var t=l;Lo(t),t.tag===5&&t.flags&1024&&t.stateNode.reset(),l=l.sibling}}function ia(l,t){if(t.subtreeFlags&8772)for(t=t.child;t!==null;)Go(l,t.alternate,t),t=t.sibling}function Ba(l){for(l=l.child;l!==null;){var t=l;switch(t.tag){case 0:case 11:case 14:case 15:ca(4,t,t.return),Ba(t);break;case 1:Nt(t,t.return);var a=t.stateNode;typeof a.componentWillUnmount=="function"&&Ro(t,t.return,a),Ba(t);break;case 27:yu(t.stateNode);case 26:case 5:Nt(t,t.return),Ba(t);break;case 22:t.memoizedState===null&&Ba(t);break;case 30:Ba(t);break;default:Ba(t)}l=l.sibling}}function fa(l,t,a){for(a=a&&(t.subtreeFlags&8772)!==0,t=t.child;t!==null;){var e=t.alternate,u=l,n=t,c=n.flags;switch(n.tag){case 0:case 11:case 15:fa(u,n,a),nu(4,n);break;case 1:if(fa(u,n,a),e=n,u=e.stateNode,typeof u.componentDidMount=="function")try{u.componentDidMount()}catch(r){fl(e,e.return,r)}if(e=n,u=e.updateQueue,u!==null){var i=e.stateNode;try{var f=u.shared.hiddenCallbacks;if(f!==null)for(u.shared.hiddenCallbacks=null,u=0;u<f.length;u++)Ss(f[u],i)}catch(r){fl(e,e.return,r)}}a&&c&64&&qo(n),cu(n,n.return);break;case 27:Qo(n);case 26:case 5:fa(u,n,a),a&&e===null&&c&4&&Bo(n),cu(n,n.return);break;case 12:fa(u,n,a);break;case 13:fa(u,n,a),a&&c&4&&Zo(u,n);break;case 22:n.memoizedState===null&&fa(u,n,a),cu(n,n.return);break;case 30:break;default:fa(u,n,a)}t=t.sibling}}function yi(l,t){var a=null;l!==null&&l.memoizedState!==null&&l.memoizedState.cachePool!==null&&(a=l.memoizedState.cachePool.pool),l=null,t.memoizedState!==null&&t.memoizedState.cachePool!==null&&(l=t.memoizedState.cachePool.pool),l!==a&&(l!=null&&l.refCount++,a!=null&&Le(a))}function vi(l,t){l=null,t.alternate!==null&&(l=t.alternate.memoizedState.cache),t=t.memoizedState.cache,t!==l&&(t.refCount++,l!=null&&Le(l))}function Ot(l,t,a,e){if(t.subtreeFlags&10256)for(t=t.child;t!==null;)Ko(l,t,a,e),t=t.sibling}function Ko(l,t,a,e){var u=t.flags;switch(t.tag){case 0:case 11:case 15:Ot(l,t,a,e),u&2048&&nu(9,t);break;case 1:Ot(l,t,a,e);break;case 3:Ot(l,t,a,e),u&2048&&(l=null,t.alternate!==null&&(l=t.alternate.memoizedState.cache),t=t.memoizedState.cache,t!==l&&(t.refCount++,l!=null&&Le(l)));break;case 12:if(u&2048){Ot(l,t,a,e),l=t.stateNode;try{var n=t.memoizedProps,c=n.id,i=n.onPostCommit;typeof i=="function"&&i(c,t.alternate===null?"mount":"update",l.passiveEffectDuration,-0)}catch(f){fl(t,t.return,f)}}else Ot(l,t,a,e);break;case 13:Ot(l,t,a,e);break;case 23:break;case 22:n=t.stateNode,c=t.alternate,t.memoizedState!==null?n._visibility&2?Ot(l,t,a,e):iu(l,t):n._visibility&2?Ot(l,t,a,e):(n._visibility|=2,re(l,t,a,e,(t.subtreeFlags&10256)!==0)),u&2048&&yi(c,t);break;case 24:Ot(l,t,a,e),u&2048&&vi(t.alternate,t);break;default:Ot(l,t,a,e)}}function re(l,t,a,e,u){for(u=u&&(t.subtreeFlags&10256)!==0,t=t.child;t!==null;){var n=l,c=t,i=a,f=e,r=c.flags;switch(c.tag){case 0:case 11:case 15:re(n,c,i,f,u),nu(8,c);break;case 23:break;case 22:var b=c.stateNode;c.memoizedState!==null?b._visibility&2?re(n,c,i,f,u):iu(n,c):(b._visibility|=2,re(n,c,i,f,u)),u&&r&2048&&yi(c.alternate,c);break;case 24:re(n,c,i,f,u),u&&r&2048&&vi(c.alternate,c);break;default:re(n,c,i,f,u)}t=t.sibling}}function iu(l,t){if(t.subtreeFlags&10256)for(t=t.child;t!==null;){var a=l,e=t,u=e.flags;switch(e.tag){case 22:iu(a,e),u&2048&&yi(e.alternate,e);break;case 24:iu(a,e),u&2048&&vi(e.alternate,e);break;default:iu(a,e)}t=t.sibling}}var fu=8192;function he(l){if(l.subtreeFlags&fu)for(l=l.child;l!==null;)Jo(l),l=l.sibling}function Jo(l){switch(l.tag){case 26:he(l),l.flags&fu&&l.memoizedState!==null&&g0(zt,l.memoizedState,l.memoizedProps);break;case 5:he(l);break;case 3:case 4:var t=zt;zt=Nn(l.stateNode.containerInfo),he(l),zt=t;break;case 22:l.memoizedState===null&&(t=l.alternate,t!==null&&t.memoizedState!==null?(t=fu,fu=16777216,he(l),fu=t):he(l));break;default:he(l)}}function Wo(l){var t=l.alternate;if(t!==null&&(l=t.child,l!==null)){t.child=null;do t=l.sibling,l.sibling=null,l=t;while(l!==null)}}function su(l){var t=l.deletions;if((l.flags&16)!==0){if(t!==null)for(var a=0;a<t.length;a++){var e=t[a];_l=e,$o(e,l)}Wo(l)}if(l.subtreeFlags&10256)for(l=l.child;l!==null;)ko(l),l=l.sibling}function ko(l){switch(l.tag){case 0:case 11:case 15:su(l),l.flags&2048&&ca(9,l,l.return);break;case 3:su(l);break;case 12:su(l);break;case 22:var t=l.stateNode;l.memoizedState!==null&&t._visibility&2&&(l.return===null||l.return.tag!==13)?(t._visibility&=-3,gn(l)):su(l);break;default:su(l)}}function gn(l){var t=l.deletions;if((l.flags&16)!==0){if(t!==null)for(var a=0;a<t.length;a++){var e=t[a];_l=e,$o(e,l)}Wo(l)}for(l=l.child;l!==null;){switch(t=l,t.tag){case 0:case 11:case 15:ca(8,t,t.return),gn(t);break;case 22:a=t.stateNode,a._visibility&2&&(a._visibility&=-3,gn(t));break;default:gn(t)}l=l.sibling}}function $o(l,t){for(;_l!==null;){var a=_l;switch(a.tag){case 0:case 11:case 15:ca(8,a,t);break;case 23:case 22:if(a.memoizedState!==null&&a.memoizedState.cachePool!==null){var e=a.memoizedState.cachePool.pool;e!=null&&e.refCount++}break;case 24:Le(a.memoizedState.cache)}if(e=a.child,e!==null)e.return=a,_l=e;else l:for(a=l;_l!==null;){e=_l;var u=e.sibling,n=e.return;if(Co(e),e===a){_l=null;break l}if(u!==null){u.return=n,_l=u;break l}_l=n}}}var Hh={getCacheForType:function(l){var t=Xl(zl),a=t.data.get(l);return a===void 0&&(a=l(),t.data.set(l,a)),a}},qh=typeof WeakMap=="function"?WeakMap:Map,tl=0,ol=null,K=null,W=0,al=0,it=null,sa=!1,me=!1,gi=!1,Kt=0,vl=0,oa=0,Ya=0,bi=0,bt=0,ye=0,ou=null,$l=null,Si=!1,pi=0,bn=1/0,Sn=null,da=null,ql=0,ra=null,ve=null,ge=0,xi=0,Ti=null,Fo=null,du=0,zi=null;function ft(){if((tl&2)!==0&&W!==0)return W&-W;if(g.T!==null){var l=ue;return l!==0?l:Oi()}return rf()}function Io(){bt===0&&(bt=(W&536870912)===0||F?ff():536870912);var l=gt.current;return l!==null&&(l.flags|=32),bt}function st(l,t,a){(l===ol&&(al===2||al===9)||l.cancelPendingCommit!==null)&&(be(l,0),ha(l,W,bt,!1)),Ne(l,a),((tl&2)===0||l!==ol)&&(l===ol&&((tl&2)===0&&(Ya|=a),vl===4&&ha(l,W,bt,!1)),Ut(l))}function Po(l,t,a){if((tl&6)!==0)throw Error(v(327));var e=!a&&(t&124)===0&&(t&l.expiredLanes)===0||_e(l,t),u=e?Yh(l,t):Di(l,t,!0),n=e;do{if(u===0){me&&!e&&ha(l,t,0,!1);break}else{if(a=l.current.alternate,n&&!Rh(a)){u=Di(l,t,!1),n=!1;continue}if(u===2){if(n=t,l.errorRecoveryDisabledLanes&n)var c=0;else c=l.pendingLanes&-536870913,c=c!==0?c:c&536870912?536870912:0;if(c!==0){t=c;l:{var i=l;u=ou;var f=i.current.memoizedState.isDehydrated;if(f&&(be(i,c).flags|=256),c=Di(i,c,!1),c!==2){if(gi&&!f){i.errorRecoveryDisabledLanes|=n,Ya|=n,u=4;break l}n=$l,$l=u,n!==null&&($l===null?$l=n:$l.push.apply($l,n))}u=c}if(n=!1,u!==2)continue}}if(u===1){be(l,0),ha(l,t,0,!0);break}l:{switch(e=l,n=u,n){case 0:case 1:throw Error(v(345));case 4:if((t&4194048)!==t)break;case 6:ha(e,t,bt,!sa);break l;case 2:$l=null;break;case 3:case 5:break;default:throw Error(v(329))}if((t&62914560)===t&&(u=pi+300-lt(),10<u)){if(ha(e,t,bt,!sa),Nu(e,0,!0)!==0)break l;e.timeoutHandle=_d(ld.bind(null,e,a,$l,Sn,Si,t,bt,Ya,ye,sa,n,2,-0,0),u);break l}ld(e,a,$l,Sn,Si,t,bt,Ya,ye,sa,n,0,-0,0)}}break}while(!0);Ut(l)}function ld(l,t,a,e,u,n,c,i,f,r,b,x,h,m){if(l.timeoutHandle=-1,x=t.subtreeFlags,(x&8192||(x&16785408)===16785408)&&(bu={stylesheets:null,count:0,unsuspend:v0},Jo(t),x=b0(),x!==null)){l.cancelPendingCommit=x(id.bind(null,l,t,n,a,e,u,c,i,f,b,1,h,m)),ha(l,n,c,!r);return}id(l,t,n,a,e,u,c,i,f)}function Rh(l){for(var t=l;;){var a=t.tag;if((a===0||a===11||a===15)&&t.flags&16384&&(a=t.updateQueue,a!==null&&(a=a.stores,a!==null)))for(var e=0;e<a.length;e++){var u=a[e],n=u.getSnapshot;u=u.value;try{if(!et(n(),u))return!1}catch{return!1}}if(a=t.child,t.subtreeFlags&16384&&a!==null)a.return=t,t=a;else{if(t===l)break;for(;t.sibling===null;){if(t.return===null||t.return===l)return!0;t=t.return}t.sibling.return=t.return,t=t.sibling}}return!0}function ha(l,t,a,e){t&=~bi,t&=~Ya,l.suspendedLanes|=t,l.pingedLanes&=~t,e&&(l.warmLanes|=t),e=l.expirationTimes;for(var u=t;0<u;){var n=31-at(u),c=1<<n;e[n]=-1,u&=~c}a!==0&&of(l,a,t)}function pn(){return(tl&6)===0?(ru(0),!1):!0}function Ai(){if(K!==null){if(al===0)var l=K.return;else l=K,Qt=Ua=null,Cc(l),oe=null,au=0,l=K;for(;l!==null;)Ho(l.alternate,l),l=l.return;K=null}}function be(l,t){var a=l.timeoutHandle;a!==-1&&(l.timeoutHandle=-1,Ph(a)),a=l.cancelPendingCommit,a!==null&&(l.cancelPendingCommit=null,a()),Ai(),ol=l,K=a=Rt(l.current,null),W=t,al=0,it=null,sa=!1,me=_e(l,t),gi=!1,ye=bt=bi=Ya=oa=vl=0,$l=ou=null,Si=!1,(t&8)!==0&&(t|=t&32);var e=l.entangledLanes;if(e!==0)for(l=l.entanglements,e&=t;0<e;){var u=31-at(e),n=1<<u;t|=l[u],e&=~n}return Kt=t,Cu(),a}function td(l,t){w=null,g.H=cn,t===Je||t===$u?(t=gs(),al=3):t===ms?(t=gs(),al=4):al=t===So?8:t!==null&&typeof t=="object"&&typeof t.then=="function"?6:1,it=t,K===null&&(vl=1,rn(l,ht(t,l.current)))}function ad(){var l=g.H;return g.H=cn,l===null?cn:l}function ed(){var l=g.A;return g.A=Hh,l}function Ei(){vl=4,sa||(W&4194048)!==W&&gt.current!==null||(me=!0),(oa&134217727)===0&&(Ya&134217727)===0||ol===null||ha(ol,W,bt,!1)}function Di(l,t,a){var e=tl;tl|=2;var u=ad(),n=ed();(ol!==l||W!==t)&&(Sn=null,be(l,t)),t=!1;var c=vl;l:do try{if(al!==0&&K!==null){var i=K,f=it;switch(al){case 8:Ai(),c=6;break l;case 3:case 2:case 9:case 6:gt.current===null&&(t=!0);var r=al;if(al=0,it=null,Se(l,i,f,r),a&&me){c=0;break l}break;default:r=al,al=0,it=null,Se(l,i,f,r)}}Bh(),c=vl;break}catch(b){td(l,b)}while(!0);return t&&l.shellSuspendCounter++,Qt=Ua=null,tl=e,g.H=u,g.A=n,K===null&&(ol=null,W=0,Cu()),c}function Bh(){for(;K!==null;)ud(K)}function Yh(l,t){var a=tl;tl|=2;var e=ad(),u=ed();ol!==l||W!==t?(Sn=null,bn=lt()+500,be(l,t)):me=_e(l,t);l:do try{if(al!==0&&K!==null){t=K;var n=it;t:switch(al){case 1:al=0,it=null,Se(l,t,n,1);break;case 2:case 9:if(ys(n)){al=0,it=null,nd(t);break}t=function(){al!==2&&al!==9||ol!==l||(al=7),Ut(l)},n.then(t,t);break l;case 3:al=7;break l;case 4:al=5;break l;case 7:ys(n)?(al=0,it=null,nd(t)):(al=0,it=null,Se(l,t,n,7));break;case 5:var c=null;switch(K.tag){case 26:c=K.memoizedState;case 5:case 27:var i=K;if(!c||Gd(c)){al=0,it=null;var f=i.sibling;if(f!==null)K=f;else{var r=i.return;r!==null?(K=r,xn(r)):K=null}break t}}al=0,it=null,Se(l,t,n,5);break;case 6:al=0,it=null,Se(l,t,n,6);break;case 8:Ai(),vl=6;break l;default:throw Error(v(462))}}Qh();break}catch(b){td(l,b)}while(!0);return Qt=Ua=null,g.H=e,g.A=u,tl=a,K!==null?0:(ol=null,W=0,Cu(),vl)}function Qh(){for(;K!==null&&!sl();)ud(K)}function ud(l){var t=Uo(l.alternate,l,Kt);l.memoizedProps=l.pendingProps,t===null?xn(l):K=t}function nd(l){var t=l,a=t.alternate;switch(t.tag){case 15:case 0:t=Eo(a,t,t.pendingProps,t.type,void 0,W);break;case 11:t=Eo(a,t,t.pendingProps,t.type.render,t.ref,W);break;case 5:Cc(t);default:Ho(a,t),t=K=ns(t,Kt),t=Uo(a,t,Kt)}l.memoizedProps=l.pendingProps,t===null?xn(l):K=t}function Se(l,t,a,e){Qt=Ua=null,Cc(t),oe=null,au=0;var u=t.return;try{if(Mh(l,u,t,a,W)){vl=1,rn(l,ht(a,l.current)),K=null;return}}catch(n){if(u!==null)throw K=u,n;vl=1,rn(l,ht(a,l.current)),K=null;return}t.flags&32768?(F||e===1?l=!0:me||(W&536870912)!==0?l=!1:(sa=l=!0,(e===2||e===9||e===3||e===6)&&(e=gt.current,e!==null&&e.tag===13&&(e.flags|=16384))),cd(t,l)):xn(t)}function xn(l){var t=l;do{if((t.flags&32768)!==0){cd(t,sa);return}l=t.return;var a=Nh(t.alternate,t,Kt);if(a!==null){K=a;return}if(t=t.sibling,t!==null){K=t;return}K=t=l}while(t!==null);vl===0&&(vl=5)}function cd(l,t){do{var a=Oh(l.alternate,l);if(a!==null){a.flags&=32767,K=a;return}if(a=l.return,a!==null&&(a.flags|=32768,a.subtreeFlags=0,a.deletions=null),!t&&(l=l.sibling,l!==null)){K=l;return}K=l=a}while(l!==null);vl=6,K=null}function id(l,t,a,e,u,n,c,i,f){l.cancelPendingCommit=null;do Tn();while(ql!==0);if((tl&6)!==0)throw Error(v(327));if(t!==null){if(t===l.current)throw Error(v(177));if(n=t.lanes|t.childLanes,n|=vc,vr(l,a,n,c,i,f),l===ol&&(K=ol=null,W=0),ve=t,ra=l,ge=a,xi=n,Ti=u,Fo=e,(t.subtreeFlags&10256)!==0||(t.flags&10256)!==0?(l.callbackNode=null,l.callbackPriority=0,wh(Du,function(){return rd(),null})):(l.callbackNode=null,l.callbackPriority=0),e=(t.flags&13878)!==0,(t.subtreeFlags&13878)!==0||e){e=g.T,g.T=null,u=z.p,z.p=2,c=tl,tl|=4;try{Uh(l,t,a)}finally{tl=c,z.p=u,g.T=e}}ql=1,fd(),sd(),od()}}function fd(){if(ql===1){ql=0;var l=ra,t=ve,a=(t.flags&13878)!==0;if((t.subtreeFlags&13878)!==0||a){a=g.T,g.T=null;var e=z.p;z.p=2;var u=tl;tl|=4;try{Vo(t,l);var n=Qi,c=kf(l.containerInfo),i=n.focusedElem,f=n.selectionRange;if(c!==i&&i&&i.ownerDocument&&Wf(i.ownerDocument.documentElement,i)){if(f!==null&&dc(i)){var r=f.start,b=f.end;if(b===void 0&&(b=r),"selectionStart"in i)i.selectionStart=r,i.selectionEnd=Math.min(b,i.value.length);else{var x=i.ownerDocument||document,h=x&&x.defaultView||window;if(h.getSelection){var m=h.getSelection(),R=i.textContent.length,U=Math.min(f.start,R),nl=f.end===void 0?U:Math.min(f.end,R);!m.extend&&U>nl&&(c=nl,nl=U,U=c);var o=Jf(i,U),s=Jf(i,nl);if(o&&s&&(m.rangeCount!==1||m.anchorNode!==o.node||m.anchorOffset!==o.offset||m.focusNode!==s.node||m.focusOffset!==s.offset)){var d=x.createRange();d.setStart(o.node,o.offset),m.removeAllRanges(),U>nl?(m.addRange(d),m.extend(s.node,s.offset)):(d.setEnd(s.node,s.offset),m.addRange(d))}}}}for(x=[],m=i;m=m.parentNode;)m.nodeType===1&&x.push({element:m,left:m.scrollLeft,top:m.scrollTop});for(typeof i.focus=="function"&&i.focus(),i=0;i<x.length;i++){var p=x[i];p.element.scrollLeft=p.left,p.element.scrollTop=p.top}}qn=!!Yi,Qi=Yi=null}finally{tl=u,z.p=e,g.T=a}}l.current=t,ql=2}}function sd(){if(ql===2){ql=0;var l=ra,t=ve,a=(t.flags&8772)!==0;if((t.subtreeFlags&8772)!==0||a){a=g.T,g.T=null;var e=z.p;z.p=2;var u=tl;tl|=4;try{Go(l,t.alternate,t)}finally{tl=u,z.p=e,g.T=a}}ql=3}}function od(){if(ql===4||ql===3){ql=0,Ll();var l=ra,t=ve,a=ge,e=Fo;(t.subtreeFlags&10256)!==0||(t.flags&10256)!==0?ql=5:(ql=0,ve=ra=null,dd(l,l.pendingLanes));var u=l.pendingLanes;if(u===0&&(da=null),Vn(a),t=t.stateNode,tt&&typeof tt.onCommitFiberRoot=="function")try{tt.onCommitFiberRoot(Me,t,void 0,(t.current.flags&128)===128)}catch{}if(e!==null){t=g.T,u=z.p,z.p=2,g.T=null;try{for(var n=l.onRecoverableError,c=0;c<e.length;c++){var i=e[c];n(i.value,{componentStack:i.stack})}}finally{g.T=t,z.p=u}}(ge&3)!==0&&Tn(),Ut(l),u=l.pendingLanes,(a&4194090)!==0&&(u&42)!==0?l===zi?du++:(du=0,zi=l):du=0,ru(0)}}function dd(l,t){(l.pooledCacheLanes&=t)===0&&(t=l.pooledCache,t!=null&&(l.pooledCache=null,Le(t)))}function Tn(l){return fd(),sd(),od(),rd()}function rd(){if(ql!==5)return!1;var l=ra,t=xi;xi=0;var a=Vn(ge),e=g.T,u=z.p;try{z.p=32>a?32:a,g.T=null,a=Ti,Ti=null;var n=ra,c=ge;if(ql=0,ve=ra=null,ge=0,(tl&6)!==0)throw Error(v(331));var i=tl;if(tl|=4,ko(n.current),Ko(n,n.current,c,a),tl=i,ru(0,!1),tt&&typeof tt.onPostCommitFiberRoot=="function")try{tt.onPostCommitFiberRoot(Me,n)}catch{}return!0}finally{z.p=u,g.T=e,dd(l,t)}}function hd(l,t,a){t=ht(a,t),t=ti(l.stateNode,t,2),l=aa(l,t,2),l!==null&&(Ne(l,2),Ut(l))}function fl(l,t,a){if(l.tag===3)hd(l,l,a);else for(;t!==null;){if(t.tag===3){hd(t,l,a);break}else if(t.tag===1){var e=t.stateNode;if(typeof t.type.getDerivedStateFromError=="function"||typeof e.componentDidCatch=="function"&&(da===null||!da.has(e))){l=ht(a,l),a=go(2),e=aa(t,a,2),e!==null&&(bo(a,e,t,l),Ne(e,2),Ut(e));break}}t=t.return}}function Mi(l,t,a){var e=l.pingCache;if(e===null){e=l.pingCache=new qh;var u=new Set;e.set(t,u)}else u=e.get(t),u===void 0&&(u=new Set,e.set(t,u));u.has(a)||(gi=!0,u.add(a),l=Xh.bind(null,l,t,a),t.then(l,l))}function Xh(l,t,a){var e=l.pingCache;e!==null&&e.delete(t),l.pingedLanes|=l.suspendedLanes&a,l.warmLanes&=~a,ol===l&&(W&a)===a&&(vl===4||vl===3&&(W&62914560)===W&&300>lt()-pi?(tl&2)===0&&be(l,0):bi|=a,ye===W&&(ye=0)),Ut(l)}function md(l,t){t===0&&(t=sf()),l=le(l,t),l!==null&&(Ne(l,t),Ut(l))}function Gh(l){var t=l.memoizedState,a=0;t!==null&&(a=t.retryLane),md(l,a)}function Ch(l,t){var a=0;switch(l.tag){case 13:var e=l.stateNode,u=l.memoizedState;u!==null&&(a=u.retryLane);break;case 19:e=l.stateNode;break;case 22:e=l.stateNode._retryCache;break;default:throw Error(v(314))}e!==null&&e.delete(t),md(l,a)}function wh(l,t){return S(l,t)}var zn=null,pe=null,_i=!1,An=!1,Ni=!1,Qa=0;function Ut(l){l!==pe&&l.next===null&&(pe===null?zn=pe=l:pe=pe.next=l),An=!0,_i||(_i=!0,Vh())}function ru(l,t){if(!Ni&&An){Ni=!0;do for(var a=!1,e=zn;e!==null;){if(l!==0){var u=e.pendingLanes;if(u===0)var n=0;else{var c=e.suspendedLanes,i=e.pingedLanes;n=(1<<31-at(42|l)+1)-1,n&=u&~(c&~i),n=n&201326741?n&201326741|1:n?n|2:0}n!==0&&(a=!0,bd(e,n))}else n=W,n=Nu(e,e===ol?n:0,e.cancelPendingCommit!==null||e.timeoutHandle!==-1),(n&3)===0||_e(e,n)||(a=!0,bd(e,n));e=e.next}while(a);Ni=!1}}function Zh(){yd()}function yd(){An=_i=!1;var l=0;Qa!==0&&(Ih()&&(l=Qa),Qa=0);for(var t=lt(),a=null,e=zn;e!==null;){var u=e.next,n=vd(e,t);n===0?(e.next=null,a===null?zn=u:a.next=u,u===null&&(pe=a)):(a=e,(l!==0||(n&3)!==0)&&(An=!0)),e=u}ru(l)}function vd(l,t){for(var a=l.suspendedLanes,e=l.pingedLanes,u=l.expirationTimes,n=l.pendingLanes&-62914561;0<n;){var c=31-at(n),i=1<<c,f=u[c];f===-1?((i&a)===0||(i&e)!==0)&&(u[c]=yr(i,t)):f<=t&&(l.expiredLanes|=i),n&=~i}if(t=ol,a=W,a=Nu(l,l===t?a:0,l.cancelPendingCommit!==null||l.timeoutHandle!==-1),e=l.callbackNode,a===0||l===t&&(al===2||al===9)||l.cancelPendingCommit!==null)return e!==null&&e!==null&&_(e),l.callbackNode=null,l.callbackPriority=0;if((a&3)===0||_e(l,a)){if(t=a&-a,t===l.callbackPriority)return t;switch(e!==null&&_(e),Vn(a)){case 2:case 8:a=nf;break;case 32:a=Du;break;case 268435456:a=cf;break;default:a=Du}return e=gd.bind(null,l),a=S(a,e),l.callbackPriority=t,l.callbackNode=a,t}return e!==null&&e!==null&&_(e),l.callbackPriority=2,l.callbackNode=null,2}function gd(l,t){if(ql!==0&&ql!==5)return l.callbackNode=null,l.callbackPriority=0,null;var a=l.callbackNode;if(Tn()&&l.callbackNode!==a)return null;var e=W;return e=Nu(l,l===ol?e:0,l.cancelPendingCommit!==null||l.timeoutHandle!==-1),e===0?null:(Po(l,e,t),vd(l,lt()),l.callbackNode!=null&&l.callbackNode===a?gd.bind(null,l):null)}function bd(l,t){if(Tn())return null;Po(l,t,!0)}function Vh(){l0(function(){(tl&6)!==0?S(uf,Zh):yd()})}function Oi(){return Qa===0&&(Qa=ff()),Qa}function Sd(l){return l==null||typeof l=="symbol"||typeof l=="boolean"?null:typeof l=="function"?l:qu(""+l)}function pd(l,t){var a=t.ownerDocument.createElement("input");return a.name=t.name,a.value=t.value,l.id&&a.setAttribute("form",l.id),t.parentNode.insertBefore(a,t),l=new FormData(l),a.parentNode.removeChild(a),l}function Lh(l,t,a,e,u){if(t==="submit"&&a&&a.stateNode===u){var n=Sd((u[Kl]||null).action),c=e.submitter;c&&(t=(t=c[Kl]||null)?Sd(t.formAction):c.getAttribute("formAction"),t!==null&&(n=t,c=null));var i=new Qu("action","action",null,e,u);l.push({event:i,listeners:[{instance:null,listener:function(){if(e.defaultPrevented){if(Qa!==0){var f=c?pd(u,c):new FormData(u);$c(a,{pending:!0,data:f,method:u.method,action:n},null,f)}}else typeof n=="function"&&(i.preventDefault(),f=c?pd(u,c):new FormData(u),$c(a,{pending:!0,data:f,method:u.method,action:n},n,f))},currentTarget:u}]})}}for(var Ui=0;Ui<yc.length;Ui++){var ji=yc[Ui],Kh=ji.toLowerCase(),Jh=ji[0].toUpperCase()+ji.slice(1);Tt(Kh,"on"+Jh)}Tt(If,"onAnimationEnd"),Tt(Pf,"onAnimationIteration"),Tt(ls,"onAnimationStart"),Tt("dblclick","onDoubleClick"),Tt("focusin","onFocus"),Tt("focusout","onBlur"),Tt(oh,"onTransitionRun"),Tt(dh,"onTransitionStart"),Tt(rh,"onTransitionCancel"),Tt(ts,"onTransitionEnd"),Va("onMouseEnter",["mouseout","mouseover"]),Va("onMouseLeave",["mouseout","mouseover"]),Va("onPointerEnter",["pointerout","pointerover"]),Va("onPointerLeave",["pointerout","pointerover"]),Ta("onChange","change click focusin focusout input keydown keyup selectionchange".split(" ")),Ta("onSelect","focusout contextmenu dragend focusin keydown keyup mousedown mouseup selectionchange".split(" ")),Ta("onBeforeInput",["compositionend","keypress","textInput","paste"]),Ta("onCompositionEnd","compositionend focusout keydown keypress keyup mousedown".split(" ")),Ta("onCompositionStart","compositionstart focusout keydown keypress keyup mousedown".split(" ")),Ta("onCompositionUpdate","compositionupdate focusout keydown keypress keyup mousedown".split(" "));var hu="abort canplay canplaythrough durationchange emptied encrypted ended error loadeddata loadedmetadata loadstart pause play playing progress ratechange resize seeked seeking stalled suspend timeupdate volumechange waiting".split(" "),Wh=new Set("beforetoggle cancel close invalid load scroll scrollend toggle".split(" ").concat(hu));function xd(l,t){t=(t&4)!==0;for(var a=0;a<l.length;a++){var e=l[a],u=e.event;e=e.listeners;l:{var n=void 0;if(t)for(var c=e.length-1;0<=c;c--){var i=e[c],f=i.instance,r=i.currentTarget;if(i=i.listener,f!==n&&u.isPropagationStopped())break l;n=i,u.currentTarget=r;try{n(u)}catch(b){dn(b)}u.currentTarget=null,n=f}else for(c=0;c<e.length;c++){if(i=e[c],f=i.instance,r=i.currentTarget,i=i.listener,f!==n&&u.isPropagationStopped())break l;n=i,u.currentTarget=r;try{n(u)}catch(b){dn(b)}u.currentTarget=null,n=f}}}}function J(l,t){var a=t[Ln];a===void 0&&(a=t[Ln]=new Set);var e=l+"__bubble";a.has(e)||(Td(t,l,2,!1),a.add(e))}function Hi(l,t,a){var e=0;t&&(e|=4),Td(a,l,e,t)}var En="_reactListening"+Math.random().toString(36).slice(2);function qi(l){if(!l[En]){l[En]=!0,mf.forEach(function(a){a!=="selectionchange"&&(Wh.has(a)||Hi(a,!1,l),Hi(a,!0,l))});var t=l.nodeType===9?l:l.ownerDocument;t===null||t[En]||(t[En]=!0,Hi("selectionchange",!1,t))}}function Td(l,t,a,e){switch(Kd(t)){case 2:var u=x0;break;case 8:u=T0;break;default:u=Wi}a=u.bind(null,t,a,l),u=void 0,!ac||t!=="touchstart"&&t!=="touchmove"&&t!=="wheel"||(u=!0),e?u!==void 0?l.addEventListener(t,a,{capture:!0,passive:u}):l.addEventListener(t,a,!0):u!==void 0?l.addEventListener(t,a,{passive:u}):l.addEventListener(t,a,!1)}function Ri(l,t,a,e,u){var n=e;if((t&1)===0&&(t&2)===0&&e!==null)l:for(;;){if(e===null)return;var c=e.tag;if(c===3||c===4){var i=e.stateNode.containerInfo;if(i===u)break;if(c===4)for(c=e.return;c!==null;){var f=c.tag;if((f===3||f===4)&&c.stateNode.containerInfo===u)return;c=c.return}for(;i!==null;){if(c=Ca(i),c===null)return;if(f=c.tag,f===5||f===6||f===26||f===27){e=n=c;continue l}i=i.parentNode}}e=e.return}_f(function(){var r=n,b=lc(a),x=[];l:{var h=as.get(l);if(h!==void 0){var m=Qu,R=l;switch(l){case"keypress":if(Bu(a)===0)break l;case"keydown":case"keyup":m=wr;break;case"focusin":R="focus",m=cc;break;case"focusout":R="blur",m=cc;break;case"beforeblur":case"afterblur":m=cc;break;case"click":if(a.button===2)break l;case"auxclick":case"dblclick":case"mousedown":case"mousemove":case"mouseup":case"mouseout":case"mouseover":case"contextmenu":m=Uf;break;case"drag":case"dragend":case"dragenter":case"dragexit":case"dragleave":case"dragover":case"dragstart":case"drop":m=Or;break;case"touchcancel":case"touchend":case"touchmove":case"touchstart":m=Lr;break;case If:case Pf:case ls:m=Hr;break;case ts:m=Jr;break;case"scroll":case"scrollend":m=_r;break;case"wheel":m=kr;break;case"copy":case"cut":case"paste":m=Rr;break;case"gotpointercapture":case"lostpointercapture":case"pointercancel":case"pointerdown":case"pointermove":case"pointerout":case"pointerover":case"pointerup":m=Hf;break;case"toggle":case"beforetoggle":m=Fr}var U=(t&4)!==0,nl=!U&&(l==="scroll"||l==="scrollend"),o=U?h!==null?h+"Capture":null:h;U=[];for(var s=r,d;s!==null;){var p=s;if(d=p.stateNode,p=p.tag,p!==5&&p!==26&&p!==27||d===null||o===null||(p=je(s,o),p!=null&&U.push(mu(s,p,d))),nl)break;s=s.return}0<U.length&&(h=new m(h,R,null,a,b),x.push({event:h,listeners:U}))}}if((t&7)===0){l:{if(h=l==="mouseover"||l==="pointerover",m=l==="mouseout"||l==="pointerout",h&&a!==Pn&&(R=a.relatedTarget||a.fromElement)&&(Ca(R)||R[Ga]))break l;if((m||h)&&(h=b.window===b?b:(h=b.ownerDocument)?h.defaultView||h.parentWindow:window,m?(R=a.relatedTarget||a.toElement,m=r,R=R?Ca(R):null,R!==null&&(nl=B(R),U=R.tag,R!==nl||U!==5&&U!==27&&U!==6)&&(R=null)):(m=null,R=r),m!==R)){if(U=Uf,p="onMouseLeave",o="onMouseEnter",s="mouse",(l==="pointerout"||l==="pointerover")&&(U=Hf,p="onPointerLeave",o="onPointerEnter",s="pointer"),nl=m==null?h:Ue(m),d=R==null?h:Ue(R),h=new U(p,s+"leave",m,a,b),h.target=nl,h.relatedTarget=d,p=null,Ca(b)===r&&(U=new U(o,s+"enter",R,a,b),U.target=d,U.relatedTarget=nl,p=U),nl=p,m&&R)t:{for(U=m,o=R,s=0,d=U;d;d=xe(d))s++;for(d=0,p=o;p;p=xe(p))d++;for(;0<s-d;)U=xe(U),s--;for(;0<d-s;)o=xe(o),d--;for(;s--;){if(U===o||o!==null&&U===o.alternate)break t;U=xe(U),o=xe(o)}U=null}else U=null;m!==null&&zd(x,h,m,U,!1),R!==null&&nl!==null&&zd(x,nl,R,U,!0)}}l:{if(h=r?Ue(r):window,m=h.nodeName&&h.nodeName.toLowerCase(),m==="select"||m==="input"&&h.type==="file")var E=Cf;else if(Xf(h))if(wf)E=ih;else{E=nh;var L=uh}else m=h.nodeName,!m||m.toLowerCase()!=="input"||h.type!=="checkbox"&&h.type!=="radio"?r&&In(r.elementType)&&(E=Cf):E=ch;if(E&&(E=E(l,r))){Gf(x,E,a,b);break l}L&&L(l,h,r),l==="focusout"&&r&&h.type==="number"&&r.memoizedProps.value!=null&&Fn(h,"number",h.value)}switch(L=r?Ue(r):window,l){case"focusin":(Xf(L)||L.contentEditable==="true")&&(Fa=L,rc=r,Ge=null);break;case"focusout":Ge=rc=Fa=null;break;case"mousedown":hc=!0;break;case"contextmenu":case"mouseup":case"dragend":hc=!1,$f(x,a,b);break;case"selectionchange":if(sh)break;case"keydown":case"keyup":$f(x,a,b)}var N;if(fc)l:{switch(l){case"compositionstart":var j="onCompositionStart";break l;case"compositionend":j="onCompositionEnd";break l;case"compositionupdate":j="onCompositionUpdate";break l}j=void 0}else $a?Yf(l,a)&&(j="onCompositionEnd"):l==="keydown"&&a.keyCode===229&&(j="onCompositionStart");j&&(qf&&a.locale!=="ko"&&($a||j!=="onCompositionStart"?j==="onCompositionEnd"&&$a&&(N=Nf()):(It=b,ec="value"in It?It.value:It.textContent,$a=!0)),L=Dn(r,j),0<L.length&&(j=new jf(j,l,null,a,b),x.push({event:j,listeners:L}),N?j.data=N:(N=Qf(a),N!==null&&(j.data=N)))),(N=Pr?lh(l,a):th(l,a))&&(j=Dn(r,"onBeforeInput"),0<j.length&&(L=new jf("onBeforeInput","beforeinput",null,a,b),x.push({event:L,listeners:j}),L.data=N)),Lh(x,l,r,a,b)}xd(x,t)})}function mu(l,t,a){return{instance:l,listener:t,currentTarget:a}}function Dn(l,t){for(var a=t+"Capture",e=[];l!==null;){var u=l,n=u.stateNode;if(u=u.tag,u!==5&&u!==26&&u!==27||n===null||(u=je(l,a),u!=null&&e.unshift(mu(l,u,n)),u=je(l,t),u!=null&&e.push(mu(l,u,n))),l.tag===3)return e;l=l.return}return[]}function xe(l){if(l===null)return null;do l=l.return;while(l&&l.tag!==5&&l.tag!==27);return l||null}function zd(l,t,a,e,u){for(var n=t._reactName,c=[];a!==null&&a!==e;){var i=a,f=i.alternate,r=i.stateNode;if(i=i.tag,f!==null&&f===e)break;i!==5&&i!==26&&i!==27||r===null||(f=r,u?(r=je(a,n),r!=null&&c.unshift(mu(a,r,f))):u||(r=je(a,n),r!=null&&c.push(mu(a,r,f)))),a=a.return}c.length!==0&&l.push({event:t,listeners:c})}var kh=/\r\n?/g,$h=/\u0000|\uFFFD/g;function Ad(l){return(typeof l=="string"?l:""+l).replace(kh,`
-`).replace($h,"")}function Ed(l,t){return t=Ad(t),Ad(l)===t}function Mn(){}function ul(l,t,a,e,u,n){switch(a){case"children":typeof e=="string"?t==="body"||t==="textarea"&&e===""||Ja(l,e):(typeof e=="number"||typeof e=="bigint")&&t!=="body"&&Ja(l,""+e);break;case"className":Uu(l,"class",e);break;case"tabIndex":Uu(l,"tabindex",e);break;case"dir":case"role":case"viewBox":case"width":case"height":Uu(l,a,e);break;case"style":Df(l,e,n);break;case"data":if(t!=="object"){Uu(l,"data",e);break}case"src":case"href":if(e===""&&(t!=="a"||a!=="href")){l.removeAttribute(a);break}if(e==null||typeof e=="function"||typeof e=="symbol"||typeof e=="boolean"){l.removeAttribute(a);break}e=qu(""+e),l.setAttribute(a,e);break;case"action":case"formAction":if(typeof e=="function"){l.setAttribute(a,"javascript:throw new Error('A React form was unexpectedly submitted. If you called form.submit() manually, consider using form.requestSubmit() instead. If you\\'re trying to use event.stopPropagation() in a submit event handler, consider also calling event.preventDefault().')");break}else typeof n=="function"&&(a==="formAction"?(t!=="input"&&ul(l,t,"name",u.name,u,null),ul(l,t,"formEncType",u.formEncType,u,null),ul(l,t,"formMethod",u.formMethod,u,null),ul(l,t,"formTarget",u.formTarget,u,null)):(ul(l,t,"encType",u.encType,u,null),ul(l,t,"method",u.method,u,null),ul(l,t,"target",u.target,u,null)));if(e==null||typeof e=="symbol"||typeof e=="boolean"){l.removeAttribute(a);break}e=qu(""+e),l.setAttribute(a,e);break;case"onClick":e!=null&&(l.onclick=Mn);break;case"onScroll":e!=null&&J("scroll",l);break;case"onScrollEnd":e!=null&&J("scrollend",l);break;case"dangerouslySetInnerHTML":if(e!=null){if(typeof e!="object"||!("__html"in e))throw Error(v(61));if(a=e.__html,a!=null){if(u.children!=null)throw Error(v(60));l.innerHTML=a}}break;case"multiple":l.multiple=e&&typeof e!="function"&&typeof e!="symbol";break;case"muted":l.muted=e&&typeof e!="function"&&typeof e!="symbol";break;case"suppressContentEditableWarning":case"suppressHydrationWarning":case"defaultValue":case"defaultChecked":case"innerHTML":case"ref":break;case"autoFocus":break;case"xlinkHref":if(e==null||typeof e=="function"||typeof e=="boolean"||typeof e=="symbol"){l.removeAttribute("xlink:href");break}a=qu(""+e),l.setAttributeNS("http://www.w3.org/1999/xlink","xlink:href",a);break;case"contentEditable":case"spellCheck":case"draggable":case"value":case"autoReverse":case"externalResourcesRequired":case"focusable":case"preserveAlpha":e!=null&&typeof e!="function"&&typeof e!="symbol"?l.setAttribute(a,""+e):l.removeAttribute(a);break;case"inert":case"allowFullScreen":case"async":case"autoPlay":case"controls":case"default":case"defer":case"disabled":case"disablePictureInPicture":case"disableRemotePlayback":case"formNoValidate":case"hidden":case"loop":case"noModule":case"noValidate":case"open":case"playsInline":case"readOnly":case"required":case"reversed":case"scoped":case"seamless":case"itemScope":e&&typeof e!="function"&&typeof e!="symbol"?l.setAttribute(a,""):l.removeAttribute(a);break;case"capture":case"download":e===!0?l.setAttribute(a,""):e!==!1&&e!=null&&typeof e!="function"&&typeof e!="symbol"?l.setAttribute(a,e):l.removeAttribute(a);break;case"cols":case"rows":case"size":case"span":e!=null&&typeof e!="function"&&typeof e!="symbol"&&!isNaN(e)&&1<=e?l.setAttribute(a,e):l.removeAttribute(a);break;case"rowSpan":case"start":e==null||typeof e=="function"||typeof e=="symbol"||isNaN(e)?l.removeAttribute(a):l.setAttribute(a,e);break;case"popover":J("beforetoggle",l),J("toggle",l),Ou(l,"popover",e);break;case"xlinkActuate":Ht(l,"http://www.w3.org/1999/xlink","xlink:actuate",e);break;case"xlinkArcrole":Ht(l,"http://www.w3.org/1999/xlink","xlink:arcrole",e);break;case"xlinkRole":Ht(l,"http://www.w3.org/1999/xlink","xlink:role",e);break;case"xlinkShow":Ht(l,"http://www.w3.org/1999/xlink","xlink:show",e);break;case"xlinkTitle":Ht(l,"http://www.w3.org/1999/xlink","xlink:title",e);break;case"xlinkType":Ht(l,"http://www.w3.org/1999/xlink","xlink:type",e);break;case"xmlBase":Ht(l,"http://www.w3.org/XML/1998/namespace","xml:base",e);break;case"xmlLang":Ht(l,"http://www.w3.org/XML/1998/namespace","xml:lang",e);break;case"xmlSpace":Ht(l,"http://www.w3.org/XML/1998/namespace","xml:space",e);break;case"is":Ou(l,"is",e);break;case"innerText":case"textContent":break;default:(!(2<a.length)||a[0]!=="o"&&a[0]!=="O"||a[1]!=="n"&&a[1]!=="N")&&(a=Dr.get(a)||a,Ou(l,a,e))}}function Bi(l,t,a,e,u,n){switch(a){case"style":Df(l,e,n);break;case"dangerouslySetInnerHTML":if(e!=null){if(typeof e!="object"||!("__html"in e))throw Error(v(61));if(a=e.__html,a!=null){if(u.children!=null)throw Error(v(60));l.innerHTML=a}}break;case"children":typeof e=="string"?Ja(l,e):(typeof e=="number"||typeof e=="bigint")&&Ja(l,""+e);break;case"onScroll":e!=null&&J("scroll",l);break;case"onScrollEnd":e!=null&&J("scrollend",l);break;case"onClick":e!=null&&(l.onclick=Mn);break;case"suppressContentEditableWarning":case"suppressHydrationWarning":case"innerHTML":case"ref":break;case"innerText":case"textContent":break;default:if(!yf.hasOwnProperty(a))l:{if(a[0]==="o"&&a[1]==="n"&&(u=a.endsWith("Capture"),t=a.slice(2,u?a.length-7:void 0),n=l[Kl]||null,n=n!=null?n[a]:null,typeof n=="function"&&l.removeEventListener(t,n,u),typeof e=="function")){typeof n!="function"&&n!==null&&(a in l?l[a]=null:l.hasAttribute(a)&&l.removeAttribute(a)),l.addEventListener(t,e,u);break l}a in l?l[a]=e:e===!0?l.setAttribute(a,""):Ou(l,a,e)}}}function Rl(l,t,a){switch(t){case"div":case"span":case"svg":case"path":case"a":case"g":case"p":case"li":break;case"img":J("error",l),J("load",l);var e=!1,u=!1,n;for(n in a)if(a.hasOwnProperty(n)){var c=a[n];if(c!=null)switch(n){case"src":e=!0;break;case"srcSet":u=!0;break;case"children":case"dangerouslySetInnerHTML":throw Error(v(137,t));default:ul(l,t,n,c,a,null)}}u&&ul(l,t,"srcSet",a.srcSet,a,null),e&&ul(l,t,"src",a.src,a,null);return;case"input":J("invalid",l);var i=n=c=u=null,f=null,r=null;for(e in a)if(a.hasOwnProperty(e)){var b=a[e];if(b!=null)switch(e){case"name":u=b;break;case"type":c=b;break;case"checked":f=b;break;case"defaultChecked":r=b;break;case"value":n=b;break;case"defaultValue":i=b;break;case"children":case"dangerouslySetInnerHTML":if(b!=null)throw Error(v(137,t));break;default:ul(l,t,e,b,a,null)}}Tf(l,n,i,f,r,c,u,!1),ju(l);return;case"select":J("invalid",l),e=c=n=null;for(u in a)if(a.hasOwnProperty(u)&&(i=a[u],i!=null))switch(u){case"value":n=i;break;case"defaultValue":c=i;break;case"multiple":e=i;default:ul(l,t,u,i,a,null)}t=n,a=c,l.multiple=!!e,t!=null?Ka(l,!!e,t,!1):a!=null&&Ka(l,!!e,a,!0);return;case"textarea":J("invalid",l),n=u=e=null;for(c in a)if(a.hasOwnProperty(c)&&(i=a[c],i!=null))switch(c){case"value":e=i;break;case"defaultValue":u=i;break;case"children":n=i;break;case"dangerouslySetInnerHTML":if(i!=null)throw Error(v(91));break;default:ul(l,t,c,i,a,null)}Af(l,e,u,n),ju(l);return;case"option":for(f in a)if(a.hasOwnProperty(f)&&(e=a[f],e!=null))switch(f){case"selected":l.selected=e&&typeof e!="function"&&typeof e!="symbol";break;default:ul(l,t,f,e,a,null)}return;case"dialog":J("beforetoggle",l),J("toggle",l),J("cancel",l),J("close",l);break;case"iframe":case"object":J("load",l);break;case"video":case"audio":for(e=0;e<hu.length;e++)J(hu[e],l);break;case"image":J("error",l),J("load",l);break;case"details":J("toggle",l);break;case"embed":case"source":case"link":J("error",l),J("load",l);case"area":case"base":case"br":case"col":case"hr":case"keygen":case"meta":case"param":case"track":case"wbr":case"menuitem":for(r in a)if(a.hasOwnProperty(r)&&(e=a[r],e!=null))switch(r){case"children":case"dangerouslySetInnerHTML":throw Error(v(137,t));default:ul(l,t,r,e,a,null)}return;default:if(In(t)){for(b in a)a.hasOwnProperty(b)&&(e=a[b],e!==void 0&&Bi(l,t,b,e,a,void 0));return}}for(i in a)a.hasOwnProperty(i)&&(e=a[i],e!=null&&ul(l,t,i,e,a,null))}function Fh(l,t,a,e){switch(t){case"div":case"span":case"svg":case"path":case"a":case"g":case"p":case"li":break;case"input":var u=null,n=null,c=null,i=null,f=null,r=null,b=null;for(m in a){var x=a[m];if(a.hasOwnProperty(m)&&x!=null)switch(m){case"checked":break;case"value":break;case"defaultValue":f=x;default:e.hasOwnProperty(m)||ul(l,t,m,null,e,x)}}for(var h in e){var m=e[h];if(x=a[h],e.hasOwnProperty(h)&&(m!=null||x!=null))switch(h){case"type":n=m;break;case"name":u=m;break;case"checked":r=m;break;case"defaultChecked":b=m;break;case"value":c=m;break;case"defaultValue":i=m;break;case"children":case"dangerouslySetInnerHTML":if(m!=null)throw Error(v(137,t));break;default:m!==x&&ul(l,t,h,m,e,x)}}$n(l,c,i,f,r,b,n,u);return;case"select":m=c=i=h=null;for(n in a)if(f=a[n],a.hasOwnProperty(n)&&f!=null)switch(n){case"value":break;case"multiple":m=f;default:e.hasOwnProperty(n)||ul(l,t,n,null,e,f)}for(u in e)if(n=e[u],f=a[u],e.hasOwnProperty(u)&&(n!=null||f!=null))switch(u){case"value":h=n;break;case"defaultValue":i=n;break;case"multiple":c=n;default:n!==f&&ul(l,t,u,n,e,f)}t=i,a=c,e=m,h!=null?Ka(l,!!a,h,!1):!!e!=!!a&&(t!=null?Ka(l,!!a,t,!0):Ka(l,!!a,a?[]:"",!1));return;case"textarea":m=h=null;for(i in a)if(u=a[i],a.hasOwnProperty(i)&&u!=null&&!e.hasOwnProperty(i))switch(i){case"value":break;case"children":break;default:ul(l,t,i,null,e,u)}for(c in e)if(u=e[c],n=a[c],e.hasOwnProperty(c)&&(u!=null||n!=null))switch(c){case"value":h=u;break;case"defaultValue":m=u;break;case"children":break;case"dangerouslySetInnerHTML":if(u!=null)throw Error(v(91));break;default:u!==n&&ul(l,t,c,u,e,n)}zf(l,h,m);return;case"option":for(var R in a)if(h=a[R],a.hasOwnProperty(R)&&h!=null&&!e.hasOwnProperty(R))switch(R){case"selected":l.selected=!1;break;default:ul(l,t,R,null,e,h)}for(f in e)if(h=e[f],m=a[f],e.hasOwnProperty(f)&&h!==m&&(h!=null||m!=null))switch(f){case"selected":l.selected=h&&typeof h!="function"&&typeof h!="symbol";break;default:ul(l,t,f,h,e,m)}return;case"img":case"link":case"area":case"base":case"br":case"col":case"embed":case"hr":case"keygen":case"meta":case"param":case"source":case"track":case"wbr":case"menuitem":for(var U in a)h=a[U],a.hasOwnProperty(U)&&h!=null&&!e.hasOwnProperty(U)&&ul(l,t,U,null,e,h);for(r in e)if(h=e[r],m=a[r],e.hasOwnProperty(r)&&h!==m&&(h!=null||m!=null))switch(r){case"children":case"dangerouslySetInnerHTML":if(h!=null)throw Error(v(137,t));break;default:ul(l,t,r,h,e,m)}return;default:if(In(t)){for(var nl in a)h=a[nl],a.hasOwnProperty(nl)&&h!==void 0&&!e.hasOwnProperty(nl)&&Bi(l,t,nl,void 0,e,h);for(b in e)h=e[b],m=a[b],!e.hasOwnProperty(b)||h===m||h===void 0&&m===void 0||Bi(l,t,b,h,e,m);return}}for(var o in a)h=a[o],a.hasOwnProperty(o)&&h!=null&&!e.hasOwnProperty(o)&&ul(l,t,o,null,e,h);for(x in e)h=e[x],m=a[x],!e.hasOwnProperty(x)||h===m||h==null&&m==null||ul(l,t,x,h,e,m)}var Yi=null,Qi=null;function _n(l){return l.nodeType===9?l:l.ownerDocument}function Dd(l){switch(l){case"http://www.w3.org/2000/svg":return 1;case"http://www.w3.org/1998/Math/MathML":return 2;default:return 0}}function Md(l,t){if(l===0)switch(t){case"svg":return 1;case"math":return 2;default:return 0}return l===1&&t==="foreignObject"?0:l}function Xi(l,t){return l==="textarea"||l==="noscript"||typeof t.children=="string"||typeof t.children=="number"||typeof t.children=="bigint"||typeof t.dangerouslySetInnerHTML=="object"&&t.dangerouslySetInnerHTML!==null&&t.dangerouslySetInnerHTML.__html!=null}var Gi=null;function Ih(){var l=window.event;return l&&l.type==="popstate"?l===Gi?!1:(Gi=l,!0):(Gi=null,!1)}var _d=typeof setTimeout=="function"?setTimeout:void 0,Ph=typeof clearTimeout=="function"?clearTimeout:void 0,Nd=typeof Promise=="function"?Promise:void 0,l0=typeof queueMicrotask=="function"?queueMicrotask:typeof Nd<"u"?function(l){return Nd.resolve(null).then(l).catch(t0)}:_d;function t0(l){setTimeout(function(){throw l})}function ma(l){return l==="head"}function Od(l,t){var a=t,e=0,u=0;do{var n=a.nextSibling;if(l.removeChild(a),n&&n.nodeType===8)if(a=n.data,a==="/$"){if(0<e&&8>e){a=e;var c=l.ownerDocument;if(a&1&&yu(c.documentElement),a&2&&yu(c.body),a&4)for(a=c.head,yu(a),c=a.firstChild;c;){var i=c.nextSibling,f=c.nodeName;c[Oe]||f==="SCRIPT"||f==="STYLE"||f==="LINK"&&c.rel.toLowerCase()==="stylesheet"||a.removeChild(c),c=i}}if(u===0){l.removeChild(n),zu(t);return}u--}else a==="$"||a==="$?"||a==="$!"?u++:e=a.charCodeAt(0)-48;else e=0;a=n}while(a);zu(t)}function Ci(l){var t=l.firstChild;for(t&&t.nodeType===10&&(t=t.nextSibling);t;){var a=t;switch(t=t.nextSibling,a.nodeName){case"HTML":case"HEAD":case"BODY":Ci(a),Kn(a);continue;case"SCRIPT":case"STYLE":continue;case"LINK":if(a.rel.toLowerCase()==="stylesheet")continue}l.removeChild(a)}}function a0(l,t,a,e){for(;l.nodeType===1;){var u=a;if(l.nodeName.toLowerCase()!==t.toLowerCase()){if(!e&&(l.nodeName!=="INPUT"||l.type!=="hidden"))break}else if(e){if(!l[Oe])switch(t){case"meta":if(!l.hasAttribute("itemprop"))break;return l;case"link":if(n=l.getAttribute("rel"),n==="stylesheet"&&l.hasAttribute("data-precedence"))break;if(n!==u.rel||l.getAttribute("href")!==(u.href==null||u.href===""?null:u.href)||l.getAttribute("crossorigin")!==(u.crossOrigin==null?null:u.crossOrigin)||l.getAttribute("title")!==(u.title==null?null:u.title))break;return l;case"style":if(l.hasAttribute("data-precedence"))break;return l;case"script":if(n=l.getAttribute("src"),(n!==(u.src==null?null:u.src)||l.getAttribute("type")!==(u.type==null?null:u.type)||l.getAttribute("crossorigin")!==(u.crossOrigin==null?null:u.crossOrigin))&&n&&l.hasAttribute("async")&&!l.hasAttribute("itemprop"))break;return l;default:return l}}else if(t==="input"&&l.type==="hidden"){var n=u.name==null?null:""+u.name;if(u.type==="hidden"&&l.getAttribute("name")===n)return l}else return l;if(l=At(l.nextSibling),l===null)break}return null}function e0(l,t,a){if(t==="")return null;for(;l.nodeType!==3;)if((l.nodeType!==1||l.nodeName!=="INPUT"||l.type!=="hidden")&&!a||(l=At(l.nextSibling),l===null))return null;return l}function wi(l){return l.data==="$!"||l.data==="$?"&&l.ownerDocument.readyState==="complete"}function u0(l,t){var a=l.ownerDocument;if(l.data!=="$?"||a.readyState==="complete")t();else{var e=function(){t(),a.removeEventListener("DOMContentLoaded",e)};a.addEventListener("DOMContentLoaded",e),l._reactRetry=e}}function At(l){for(;l!=null;l=l.nextSibling){var t=l.nodeType;if(t===1||t===3)break;if(t===8){if(t=l.data,t==="$"||t==="$!"||t==="$?"||t==="F!"||t==="F")break;if(t==="/$")return null}}return l}var Zi=null;function Ud(l){l=l.previousSibling;for(var t=0;l;){if(l.nodeType===8){var a=l.data;if(a==="$"||a==="$!"||a==="$?"){if(t===0)return l;t--}else a==="/$"&&t++}l=l.previousSibling}return null}function jd(l,t,a){switch(t=_n(a),l){case"html":if(l=t.documentElement,!l)throw Error(v(452));return l;case"head":if(l=t.head,!l)throw Error(v(453));return l;case"body":if(l=t.body,!l)throw Error(v(454));return l;default:throw Error(v(451))}}function yu(l){for(var t=l.attributes;t.length;)l.removeAttributeNode(t[0]);Kn(l)}var St=new Map,Hd=new Set;function Nn(l){return typeof l.getRootNode=="function"?l.getRootNode():l.nodeType===9?l:l.ownerDocument}var Jt=z.d;z.d={f:n0,r:c0,D:i0,C:f0,L:s0,m:o0,X:r0,S:d0,M:h0};function n0(){var l=Jt.f(),t=pn();return l||t}function c0(l){var t=wa(l);t!==null&&t.tag===5&&t.type==="form"?Is(t):Jt.r(l)}var Te=typeof document>"u"?null:document;function qd(l,t,a){var e=Te;if(e&&typeof t=="string"&&t){var u=rt(t);u='link[rel="'+l+'"][href="'+u+'"]',typeof a=="string"&&(u+='[crossorigin="'+a+'"]'),Hd.has(u)||(Hd.add(u),l={rel:l,crossOrigin:a,href:t},e.querySelector(u)===null&&(t=e.createElement("link"),Rl(t,"link",l),Dl(t),e.head.appendChild(t)))}}function i0(l){Jt.D(l),qd("dns-prefetch",l,null)}function f0(l,t){Jt.C(l,t),qd("preconnect",l,t)}function s0(l,t,a){Jt.L(l,t,a);var e=Te;if(e&&l&&t){var u='link[rel="preload"][as="'+rt(t)+'"]';t==="image"&&a&&a.imageSrcSet?(u+='[imagesrcset="'+rt(a.imageSrcSet)+'"]',typeof a.imageSizes=="string"&&(u+='[imagesizes="'+rt(a.imageSizes)+'"]')):u+='[href="'+rt(l)+'"]';var n=u;switch(t){case"style":n=ze(l);break;case"script":n=Ae(l)}St.has(n)||(l=X({rel:"preload",href:t==="image"&&a&&a.imageSrcSet?void 0:l,as:t},a),St.set(n,l),e.querySelector(u)!==null||t==="style"&&e.querySelector(vu(n))||t==="script"&&e.querySelector(gu(n))||(t=e.createElement("link"),Rl(t,"link",l),Dl(t),e.head.appendChild(t)))}}function o0(l,t){Jt.m(l,t);var a=Te;if(a&&l){var e=t&&typeof t.as=="string"?t.as:"script",u='link[rel="modulepreload"][as="'+rt(e)+'"][href="'+rt(l)+'"]',n=u;switch(e){case"audioworklet":case"paintworklet":case"serviceworker":case"sharedworker":case"worker":case"script":n=Ae(l)}if(!St.has(n)&&(l=X({rel:"modulepreload",href:l},t),St.set(n,l),a.querySelector(u)===null)){switch(e){case"audioworklet":case"paintworklet":case"serviceworker":case"sharedworker":case"worker":case"script":if(a.querySelector(gu(n)))return}e=a.createElement("link"),Rl(e,"link",l),Dl(e),a.head.appendChild(e)}}}function d0(l,t,a){Jt.S(l,t,a);var e=Te;if(e&&l){var u=Za(e).hoistableStyles,n=ze(l);t=t||"default";var c=u.get(n);if(!c){var i={loading:0,preload:null};if(c=e.querySelector(vu(n)))i.loading=5;else{l=X({rel:"stylesheet",href:l,"data-precedence":t},a),(a=St.get(n))&&Vi(l,a);var f=c=e.createElement("link");Dl(f),Rl(f,"link",l),f._p=new Promise(function(r,b){f.onload=r,f.onerror=b}),f.addEventListener("load",function(){i.loading|=1}),f.addEventListener("error",function(){i.loading|=2}),i.loading|=4,On(c,t,e)}c={type:"stylesheet",instance:c,count:1,state:i},u.set(n,c)}}}function r0(l,t){Jt.X(l,t);var a=Te;if(a&&l){var e=Za(a).hoistableScripts,u=Ae(l),n=e.get(u);n||(n=a.querySelector(gu(u)),n||(l=X({src:l,async:!0},t),(t=St.get(u))&&Li(l,t),n=a.createElement("script"),Dl(n),Rl(n,"link",l),a.head.appendChild(n)),n={type:"script",instance:n,count:1,state:null},e.set(u,n))}}function h0(l,t){Jt.M(l,t);var a=Te;if(a&&l){var e=Za(a).hoistableScripts,u=Ae(l),n=e.get(u);n||(n=a.querySelector(gu(u)),n||(l=X({src:l,async:!0,type:"module"},t),(t=St.get(u))&&Li(l,t),n=a.createElement("script"),Dl(n),Rl(n,"link",l),a.head.appendChild(n)),n={type:"script",instance:n,count:1,state:null},e.set(u,n))}}function Rd(l,t,a,e){var u=(u=Pl.current)?Nn(u):null;if(!u)throw Error(v(446));switch(l){case"meta":case"title":return null;case"style":return typeof a.precedence=="string"&&typeof a.href=="string"?(t=ze(a.href),a=Za(u).hoistableStyles,e=a.get(t),e||(e={type:"style",instance:null,count:0,state:null},a.set(t,e)),e):{type:"void",instance:null,count:0,state:null};case"link":if(a.rel==="stylesheet"&&typeof a.href=="string"&&typeof a.precedence=="string"){l=ze(a.href);var n=Za(u).hoistableStyles,c=n.get(l);if(c||(u=u.ownerDocument||u,c={type:"stylesheet",instance:null,count:0,state:{loading:0,preload:null}},n.set(l,c),(n=u.querySelector(vu(l)))&&!n._p&&(c.instance=n,c.state.loading=5),St.has(l)||(a={rel:"preload",as:"style",href:a.href,crossOrigin:a.crossOrigin,integrity:a.integrity,media:a.media,hrefLang:a.hrefLang,referrerPolicy:a.referrerPolicy},St.set(l,a),n||m0(u,l,a,c.state))),t&&e===null)throw Error(v(528,""));return c}if(t&&e!==null)throw Error(v(529,""));return null;case"script":return t=a.async,a=a.src,typeof a=="string"&&t&&typeof t!="function"&&typeof t!="symbol"?(t=Ae(a),a=Za(u).hoistableScripts,e=a.get(t),e||(e={type:"script",instance:null,count:0,state:null},a.set(t,e)),e):{type:"void",instance:null,count:0,state:null};default:throw Error(v(444,l))}}function ze(l){return'href="'+rt(l)+'"'}function vu(l){return'link[rel="stylesheet"]['+l+"]"}function Bd(l){return X({},l,{"data-precedence":l.precedence,precedence:null})}function m0(l,t,a,e){l.querySelector('link[rel="preload"][as="style"]['+t+"]")?e.loading=1:(t=l.createElement("link"),e.preload=t,t.addEventListener("load",function(){return e.loading|=1}),t.addEventListener("error",function(){return e.loading|=2}),Rl(t,"link",a),Dl(t),l.head.appendChild(t))}function Ae(l){return'[src="'+rt(l)+'"]'}function gu(l){return"script[async]"+l}function Yd(l,t,a){if(t.count++,t.instance===null)switch(t.type){case"style":var e=l.querySelector('style[data-href~="'+rt(a.href)+'"]');if(e)return t.instance=e,Dl(e),e;var u=X({},a,{"data-href":a.href,"data-precedence":a.precedence,href:null,precedence:null});return e=(l.ownerDocument||l).createElement("style"),Dl(e),Rl(e,"style",u),On(e,a.precedence,l),t.instance=e;case"stylesheet":u=ze(a.href);var n=l.querySelector(vu(u));if(n)return t.state.loading|=4,t.instance=n,Dl(n),n;e=Bd(a),(u=St.get(u))&&Vi(e,u),n=(l.ownerDocument||l).createElement("link"),Dl(n);var c=n;return c._p=new Promise(function(i,f){c.onload=i,c.onerror=f}),Rl(n,"link",e),t.state.loading|=4,On(n,a.precedence,l),t.instance=n;case"script":return n=Ae(a.src),(u=l.querySelector(gu(n)))?(t.instance=u,Dl(u),u):(e=a,(u=St.get(n))&&(e=X({},a),Li(e,u)),l=l.ownerDocument||l,u=l.createElement("script"),Dl(u),Rl(u,"link",e),l.head.appendChild(u),t.instance=u);case"void":return null;default:throw Error(v(443,t.type))}else t.type==="stylesheet"&&(t.state.loading&4)===0&&(e=t.instance,t.state.loading|=4,On(e,a.precedence,l));return t.instance}function On(l,t,a){for(var e=a.querySelectorAll('link[rel="stylesheet"][data-precedence],style[data-precedence]'),u=e.length?e[e.length-1]:null,n=u,c=0;c<e.length;c++){var i=e[c];if(i.dataset.precedence===t)n=i;else if(n!==u)break}n?n.parentNode.insertBefore(l,n.nextSibling):(t=a.nodeType===9?a.head:a,t.insertBefore(l,t.firstChild))}function Vi(l,t){l.crossOrigin==null&&(l.crossOrigin=t.crossOrigin),l.referrerPolicy==null&&(l.referrerPolicy=t.referrerPolicy),l.title==null&&(l.title=t.title)}function Li(l,t){l.crossOrigin==null&&(l.crossOrigin=t.crossOrigin),l.referrerPolicy==null&&(l.referrerPolicy=t.referrerPolicy),l.integrity==null&&(l.integrity=t.integrity)}var Un=null;function Qd(l,t,a){if(Un===null){var e=new Map,u=Un=new Map;u.set(a,e)}else u=Un,e=u.get(a),e||(e=new Map,u.set(a,e));if(e.has(l))return e;for(e.set(l,null),a=a.getElementsByTagName(l),u=0;u<a.length;u++){var n=a[u];if(!(n[Oe]||n[Ql]||l==="link"&&n.getAttribute("rel")==="stylesheet")&&n.namespaceURI!=="http://www.w3.org/2000/svg"){var c=n.getAttribute(t)||"";c=l+c;var i=e.get(c);i?i.push(n):e.set(c,[n])}}return e}function Xd(l,t,a){l=l.ownerDocument||l,l.head.insertBefore(a,t==="title"?l.querySelector("head > title"):null)}function y0(l,t,a){if(a===1||t.itemProp!=null)return!1;switch(l){case"meta":case"title":return!0;case"style":if(typeof t.precedence!="string"||typeof t.href!="string"||t.href==="")break;return!0;case"link":if(typeof t.rel!="string"||typeof t.href!="string"||t.href===""||t.onLoad||t.onError)break;switch(t.rel){case"stylesheet":return l=t.disabled,typeof t.precedence=="string"&&l==null;default:return!0}case"script":if(t.async&&typeof t.async!="function"&&typeof t.async!="symbol"&&!t.onLoad&&!t.onError&&t.src&&typeof t.src=="string")return!0}return!1}function Gd(l){return!(l.type==="stylesheet"&&(l.state.loading&3)===0)}var bu=null;function v0(){}function g0(l,t,a){if(bu===null)throw Error(v(475));var e=bu;if(t.type==="stylesheet"&&(typeof a.media!="string"||matchMedia(a.media).matches!==!1)&&(t.state.loading&4)===0){if(t.instance===null){var u=ze(a.href),n=l.querySelector(vu(u));if(n){l=n._p,l!==null&&typeof l=="object"&&typeof l.then=="function"&&(e.count++,e=jn.bind(e),l.then(e,e)),t.state.loading|=4,t.instance=n,Dl(n);return}n=l.ownerDocument||l,a=Bd(a),(u=St.get(u))&&Vi(a,u),n=n.createElement("link"),Dl(n);var c=n;c._p=new Promise(function(i,f){c.onload=i,c.onerror=f}),Rl(n,"link",a),t.instance=n}e.stylesheets===null&&(e.stylesheets=new Map),e.stylesheets.set(t,l),(l=t.state.preload)&&(t.state.loading&3)===0&&(e.count++,t=jn.bind(e),l.addEventListener("load",t),l.addEventListener("error",t))}}function b0(){if(bu===null)throw Error(v(475));var l=bu;return l.stylesheets&&l.count===0&&Ki(l,l.stylesheets),0<l.count?function(t){var a=setTimeout(function(){if(l.stylesheets&&Ki(l,l.stylesheets),l.unsuspend){var e=l.unsuspend;l.unsuspend=null,e()}},6e4);return l.unsuspend=t,function(){l.unsuspend=null,clearTimeout(a)}}:null}function jn(){if(this.count--,this.count===0){if(this.stylesheets)Ki(this,this.stylesheets);else if(this.unsuspend){var l=this.unsuspend;this.unsuspend=null,l()}}}var Hn=null;function Ki(l,t){l.stylesheets=null,l.unsuspend!==null&&(l.count++,Hn=new Map,t.forEach(S0,l),Hn=null,jn.call(l))}function S0(l,t){if(!(t.state.loading&4)){var a=Hn.get(l);if(a)var e=a.get(null);else{a=new Map,Hn.set(l,a);for(var u=l.querySelectorAll("link[data-precedence],style[data-precedence]"),n=0;n<u.length;n++){var c=u[n];(c.nodeName==="LINK"||c.getAttribute("media")!=="not all")&&(a.set(c.dataset.precedence,c),e=c)}e&&a.set(null,e)}u=t.instance,c=u.getAttribute("data-precedence"),n=a.get(c)||e,n===e&&a.set(null,u),a.set(c,u),this.count++,e=jn.bind(this),u.addEventListener("load",e),u.addEventListener("error",e),n?n.parentNode.insertBefore(u,n.nextSibling):(l=l.nodeType===9?l.head:l,l.insertBefore(u,l.firstChild)),t.state.loading|=4}}var Su={$$typeof:Tl,Provider:null,Consumer:null,_currentValue:O,_currentValue2:O,_threadCount:0};function p0(l,t,a,e,u,n,c,i){this.tag=1,this.containerInfo=l,this.pingCache=this.current=this.pendingChildren=null,this.timeoutHandle=-1,this.callbackNode=this.next=this.pendingContext=this.context=this.cancelPendingCommit=null,this.callbackPriority=0,this.expirationTimes=wn(-1),this.entangledLanes=this.shellSuspendCounter=this.errorRecoveryDisabledLanes=this.expiredLanes=this.warmLanes=this.pingedLanes=this.suspendedLanes=this.pendingLanes=0,this.entanglements=wn(0),this.hiddenUpdates=wn(null),this.identifierPrefix=e,this.onUncaughtError=u,this.onCaughtError=n,this.onRecoverableError=c,this.pooledCache=null,this.pooledCacheLanes=0,this.formState=i,this.incompleteTransitions=new Map}function Cd(l,t,a,e,u,n,c,i,f,r,b,x){return l=new p0(l,t,a,c,i,f,r,x),t=1,n===!0&&(t|=24),n=ut(3,null,null,t),l.current=n,n.stateNode=l,t=Mc(),t.refCount++,l.pooledCache=t,t.refCount++,n.memoizedState={element:e,isDehydrated:a,cache:t},Uc(n),l}function wd(l){return l?(l=te,l):te}function Zd(l,t,a,e,u,n){u=wd(u),e.context===null?e.context=u:e.pendingContext=u,e=ta(t),e.payload={element:a},n=n===void 0?null:n,n!==null&&(e.callback=n),a=aa(l,e,t),a!==null&&(st(a,l,t),ke(a,l,t))}function Vd(l,t){if(l=l.memoizedState,l!==null&&l.dehydrated!==null){var a=l.retryLane;l.retryLane=a!==0&&a<t?a:t}}function Ji(l,t){Vd(l,t),(l=l.alternate)&&Vd(l,t)}function Ld(l){if(l.tag===13){var t=le(l,67108864);t!==null&&st(t,l,67108864),Ji(l,67108864)}}var qn=!0;function x0(l,t,a,e){var u=g.T;g.T=null;var n=z.p;try{z.p=2,Wi(l,t,a,e)}finally{z.p=n,g.T=u}}function T0(l,t,a,e){var u=g.T;g.T=null;var n=z.p;try{z.p=8,Wi(l,t,a,e)}finally{z.p=n,g.T=u}}function Wi(l,t,a,e){if(qn){var u=ki(e);if(u===null)Ri(l,t,e,Rn,a),Jd(l,e);else if(A0(u,l,t,a,e))e.stopPropagation();else if(Jd(l,e),t&4&&-1<z0.indexOf(l)){for(;u!==null;){var n=wa(u);if(n!==null)switch(n.tag){case 3:if(n=n.stateNode,n.current.memoizedState.isDehydrated){var c=xa(n.pendingLanes);if(c!==0){var i=n;for(i.pendingLanes|=2,i.entangledLanes|=2;c;){var f=1<<31-at(c);i.entanglements[1]|=f,c&=~f}Ut(n),(tl&6)===0&&(bn=lt()+500,ru(0))}}break;case 13:i=le(n,2),i!==null&&st(i,n,2),pn(),Ji(n,2)}if(n=ki(e),n===null&&Ri(l,t,e,Rn,a),n===u)break;u=n}u!==null&&e.stopPropagation()}else Ri(l,t,e,null,a)}}function ki(l){return l=lc(l),$i(l)}var Rn=null;function $i(l){if(Rn=null,l=Ca(l),l!==null){var t=B(l);if(t===null)l=null;else{var a=t.tag;if(a===13){if(l=Q(t),l!==null)return l;l=null}else if(a===3){if(t.stateNode.current.memoizedState.isDehydrated)return t.tag===3?t.stateNode.containerInfo:null;l=null}else t!==l&&(l=null)}}return Rn=l,null}function Kd(l){switch(l){case"beforetoggle":case"cancel":case"click":case"close":case"contextmenu":case"copy":case"cut":case"auxclick":case"dblclick":case"dragend":case"dragstart":case"drop":case"focusin":case"focusout":case"input":case"invalid":case"keydown":case"keypress":case"keyup":case"mousedown":case"mouseup":case"paste":case"pause":case"play":case"pointercancel":case"pointerdown":case"pointerup":case"ratechange":case"reset":case"resize":case"seeked":case"submit":case"toggle":case"touchcancel":case"touchend":case"touchstart":case"volumechange":case"change":case"selectionchange":case"textInput":case"compositionstart":case"compositionend":case"compositionupdate":case"beforeblur":case"afterblur":case"beforeinput":case"blur":case"fullscreenchange":case"focus":case"hashchange":case"popstate":case"select":case"selectstart":return 2;case"drag":case"dragenter":case"dragexit":case"dragleave":case"dragover":case"mousemove":case"mouseout":case"mouseover":case"pointermove":case"pointerout":case"pointerover":case"scroll":case"touchmove":case"wheel":case"mouseenter":case"mouseleave":case"pointerenter":case"pointerleave":return 8;case"message":switch(fr()){case uf:return 2;case nf:return 8;case Du:case sr:return 32;case cf:return 268435456;default:return 32}default:return 32}}var Fi=!1,ya=null,va=null,ga=null,pu=new Map,xu=new Map,ba=[],z0="mousedown mouseup touchcancel touchend touchstart auxclick dblclick pointercancel pointerdown pointerup dragend dragstart drop compositionend compositionstart keydown keypress keyup input textInput copy cut paste click change contextmenu reset".split(" ");function Jd(l,t){switch(l){case"focusin":case"focusout":ya=null;break;case"dragenter":case"dragleave":va=null;break;case"mouseover":case"mouseout":ga=null;break;case"pointerover":case"pointerout":pu.delete(t.pointerId);break;case"gotpointercapture":case"lostpointercapture":xu.delete(t.pointerId)}}function Tu(l,t,a,e,u,n){return l===null||l.nativeEvent!==n?(l={blockedOn:t,domEventName:a,eventSystemFlags:e,nativeEvent:n,targetContainers:[u]},t!==null&&(t=wa(t),t!==null&&Ld(t)),l):(l.eventSystemFlags|=e,t=l.targetContainers,u!==null&&t.indexOf(u)===-1&&t.push(u),l)}function A0(l,t,a,e,u){switch(t){case"focusin":return ya=Tu(ya,l,t,a,e,u),!0;case"dragenter":return va=Tu(va,l,t,a,e,u),!0;case"mouseover":return ga=Tu(ga,l,t,a,e,u),!0;case"pointerover":var n=u.pointerId;return pu.set(n,Tu(pu.get(n)||null,l,t,a,e,u)),!0;case"gotpointercapture":return n=u.pointerId,xu.set(n,Tu(xu.get(n)||null,l,t,a,e,u)),!0}return!1}function Wd(l){var t=Ca(l.target);if(t!==null){var a=B(t);if(a!==null){if(t=a.tag,t===13){if(t=Q(a),t!==null){l.blockedOn=t,gr(l.priority,function(){if(a.tag===13){var e=ft();e=Zn(e);var u=le(a,e);u!==null&&st(u,a,e),Ji(a,e)}});return}}else if(t===3&&a.stateNode.current.memoizedState.isDehydrated){l.blockedOn=a.tag===3?a.stateNode.containerInfo:null;return}}}l.blockedOn=null}function Bn(l){if(l.blockedOn!==null)return!1;for(var t=l.targetContainers;0<t.length;){var a=ki(l.nativeEvent);if(a===null){a=l.nativeEvent;var e=new a.constructor(a.type,a);Pn=e,a.target.dispatchEvent(e),Pn=null}else return t=wa(a),t!==null&&Ld(t),l.blockedOn=a,!1;t.shift()}return!0}function kd(l,t,a){Bn(l)&&a.delete(t)}function E0(){Fi=!1,ya!==null&&Bn(ya)&&(ya=null),va!==null&&Bn(va)&&(va=null),ga!==null&&Bn(ga)&&(ga=null),pu.forEach(kd),xu.forEach(kd)}function Yn(l,t){l.blockedOn===t&&(l.blockedOn=null,Fi||(Fi=!0,T.unstable_scheduleCallback(T.unstable_NormalPriority,E0)))}var Qn=null;function $d(l){Qn!==l&&(Qn=l,T.unstable_scheduleCallback(T.unstable_NormalPriority,function(){Qn===l&&(Qn=null);for(var t=0;t<l.length;t+=3){var a=l[t],e=l[t+1],u=l[t+2];if(typeof e!="function"){if($i(e||a)===null)continue;break}var n=wa(a);n!==null&&(l.splice(t,3),t-=3,$c(n,{pending:!0,data:u,method:a.method,action:e},e,u))}}))}function zu(l){function t(f){return Yn(f,l)}ya!==null&&Yn(ya,l),va!==null&&Yn(va,l),ga!==null&&Yn(ga,l),pu.forEach(t),xu.forEach(t);for(var a=0;a<ba.length;a++){var e=ba[a];e.blockedOn===l&&(e.blockedOn=null)}for(;0<ba.length&&(a=ba[0],a.blockedOn===null);)Wd(a),a.blockedOn===null&&ba.shift();if(a=(l.ownerDocument||l).$$reactFormReplay,a!=null)for(e=0;e<a.length;e+=3){var u=a[e],n=a[e+1],c=u[Kl]||null;if(typeof n=="function")c||$d(a);else if(c){var i=null;if(n&&n.hasAttribute("formAction")){if(u=n,c=n[Kl]||null)i=c.formAction;else if($i(u)!==null)continue}else i=c.action;typeof i=="function"?a[e+1]=i:(a.splice(e,3),e-=3),$d(a)}}}function Ii(l){this._internalRoot=l}Xn.prototype.render=Ii.prototype.render=function(l){var t=this._internalRoot;if(t===null)throw Error(v(409));var a=t.current,e=ft();Zd(a,e,l,t,null,null)},Xn.prototype.unmount=Ii.prototype.unmount=function(){var l=this._internalRoot;if(l!==null){this._internalRoot=null;var t=l.containerInfo;Zd(l.current,2,null,l,null,null),pn(),t[Ga]=null}};function Xn(l){this._internalRoot=l}Xn.prototype.unstable_scheduleHydration=function(l){if(l){var t=rf();l={blockedOn:null,target:l,priority:t};for(var a=0;a<ba.length&&t!==0&&t<ba[a].priority;a++);ba.splice(a,0,l),a===0&&Wd(l)}};var Fd=H.version;if(Fd!=="19.1.0")throw Error(v(527,Fd,"19.1.0"));z.findDOMNode=function(l){var t=l._reactInternals;if(t===void 0)throw typeof l.render=="function"?Error(v(188)):(l=Object.keys(l).join(","),Error(v(268,l)));return l=cl(t),l=l!==null?$(l):null,l=l===null?null:l.stateNode,l};var D0={bundleType:0,version:"19.1.0",rendererPackageName:"react-dom",currentDispatcherRef:g,reconcilerVersion:"19.1.0"};if(typeof __REACT_DEVTOOLS_GLOBAL_HOOK__<"u"){var Gn=__REACT_DEVTOOLS_GLOBAL_HOOK__;if(!Gn.isDisabled&&Gn.supportsFiber)try{Me=Gn.inject(D0),tt=Gn}catch{}}return Eu.createRoot=function(l,t){if(!Z(l))throw Error(v(299));var a=!1,e="",u=ho,n=mo,c=yo,i=null;return t!=null&&(t.unstable_strictMode===!0&&(a=!0),t.identifierPrefix!==void 0&&(e=t.identifierPrefix),t.onUncaughtError!==void 0&&(u=t.onUncaughtError),t.onCaughtError!==void 0&&(n=t.onCaughtError),t.onRecoverableError!==void 0&&(c=t.onRecoverableError),t.unstable_transitionCallbacks!==void 0&&(i=t.unstable_transitionCallbacks)),t=Cd(l,1,!1,null,null,a,e,u,n,c,i,null),l[Ga]=t.current,qi(l),new Ii(t)},Eu.hydrateRoot=function(l,t,a){if(!Z(l))throw Error(v(299));var e=!1,u="",n=ho,c=mo,i=yo,f=null,r=null;return a!=null&&(a.unstable_strictMode===!0&&(e=!0),a.identifierPrefix!==void 0&&(u=a.identifierPrefix),a.onUncaughtError!==void 0&&(n=a.onUncaughtError),a.onCaughtError!==void 0&&(c=a.onCaughtError),a.onRecoverableError!==void 0&&(i=a.onRecoverableError),a.unstable_transitionCallbacks!==void 0&&(f=a.unstable_transitionCallbacks),a.formState!==void 0&&(r=a.formState)),t=Cd(l,1,!0,t,a??null,e,u,n,c,i,f,r),t.context=wd(null),a=t.current,e=ft(),e=Zn(e),u=ta(e),u.callback=null,aa(a,u,e),a=e,t.current.lanes=a,Ne(t,a),Ut(t),l[Ga]=t.current,qi(l),new Xn(t)},Eu.version="19.1.0",Eu}var ur;function G0(){if(ur)return lf.exports;ur=1;function T(){if(!(typeof __REACT_DEVTOOLS_GLOBAL_HOOK__>"u"||typeof __REACT_DEVTOOLS_GLOBAL_HOOK__.checkDCE!="function"))try{__REACT_DEVTOOLS_GLOBAL_HOOK__.checkDCE(T)}catch(H){console.error(H)}}return T(),lf.exports=X0(),lf.exports}var C0=G0();const w0="modulepreload",Z0=function(T){return"/assets/frontend/"+T},nr={},ef=function(H,D,v){let Z=Promise.resolve();if(D&&D.length>0){let Q=function($){return Promise.all($.map(X=>Promise.resolve(X).then(P=>({status:"fulfilled",value:P}),P=>({status:"rejected",reason:P}))))};document.getElementsByTagName("link");const Y=document.querySelector("meta[property=csp-nonce]"),cl=(Y==null?void 0:Y.nonce)||(Y==null?void 0:Y.getAttribute("nonce"));Z=Q(D.map($=>{if($=Z0($),$ in nr)return;nr[$]=!0;const X=$.endsWith(".css"),P=X?'[rel="stylesheet"]':"";if(document.querySelector(`link[href="${$}"]${P}`))return;const G=document.createElement("link");if(G.rel=X?"stylesheet":w0,X||(G.as="script"),G.crossOrigin="",G.href=$,cl&&G.setAttribute("nonce",cl),document.head.appendChild(G),X)return new Promise((xl,El)=>{G.addEventListener("load",xl),G.addEventListener("error",()=>El(new Error(`Unable to preload CSS for ${$}`)))})}))}function B(Q){const Y=new Event("vite:preloadError",{cancelable:!0});if(Y.payload=Q,window.dispatchEvent(Y),!Y.defaultPrevented)throw Q}return Z.then(Q=>{for(const Y of Q||[])Y.status==="rejected"&&B(Y.reason);return H().catch(B)})};function pa(T,H){const[D,v]=C.useState(()=>{if(typeof window>"u")return H;try{const B=window.localStorage.getItem(T);return B?JSON.parse(B):H}catch(B){return console.error(`Error reading localStorage key "${T}":`,B),H}}),Z=C.useCallback(B=>{try{const Q=B instanceof Function?B(D):B;v(Q),typeof window<"u"&&window.localStorage.setItem(T,JSON.stringify(Q))}catch(Q){console.error(`Error saving to localStorage key "${T}":`,Q)}},[T,D]);return C.useEffect(()=>{const B=Q=>{if(Q.key===T&&Q.newValue!==null)try{v(JSON.parse(Q.newValue))}catch(Y){console.error(`Error parsing localStorage value for key "${T}":`,Y)}};return window.addEventListener("storage",B),()=>window.removeEventListener("storage",B)},[T]),[D,Z]}function Ee(...T){return H0(q0(T))}function Gl(T){const H=Date.now(),D=T.includes("?")?"&":"?";return`${T}${D}t=${H}`}function V0({className:T,...H}){return y.jsx("div",{"data-slot":"table-container",className:"relative w-full overflow-x-auto",children:y.jsx("table",{"data-slot":"table",className:Ee("w-full caption-bottom text-sm",T),...H})})}function um({className:T,...H}){return y.jsx("thead",{"data-slot":"table-header",className:Ee("[&_tr]:border-b",T),...H})}function L0({className:T,...H}){return y.jsx("tbody",{"data-slot":"table-body",className:Ee("[&_tr:last-child]:border-0",T),...H})}function K0({className:T,...H}){return y.jsx("tr",{"data-slot":"table-row",className:Ee("hover:bg-muted/50 data-[state=selected]:bg-muted border-b transition-colors",T),...H})}function nm({className:T,...H}){return y.jsx("th",{"data-slot":"table-head",className:Ee("text-foreground h-10 px-2 text-left align-middle font-medium whitespace-nowrap [&:has([role=checkbox])]:pr-0 [&>[role=checkbox]]:translate-y-[2px]",T),...H})}function cr({className:T,...H}){return y.jsx("td",{"data-slot":"table-cell",className:Ee("p-2 align-middle whitespace-nowrap [&:has([role=checkbox])]:pr-0 [&>[role=checkbox]]:translate-y-[2px]",T),...H})}const J0=({onConfigComplete:T,isEditMode:H=!1})=>{const[D,v]=C.useState({watch_directories:[""],whispercli_path:"",model_path:"",ffmpeg_path:"",ffprobe_path:""}),[Z,B]=C.useState(!1),[Q,Y]=C.useState([]),[cl,$]=C.useState(""),[X,P]=C.useState(!1),[G,xl]=C.useState([]),[El,Cl]=C.useState(!1),[rl,ot]=C.useState("custom"),[Et,Tl]=C.useState(null),[Fl,pt]=C.useState([]),[Bl,Zl]=C.useState(null),[Ol,Vl]=C.useState([]),[Dt,xt]=C.useState(null);C.useEffect(()=>{H&&M(),Ul(),Il(),Yl()},[H]);const Ul=async()=>{Cl(!0);try{const A=await fetch(Gl("/api/models"));if(A.ok){const S=await A.json();xl(S.models||[])}}catch(A){console.error("Error fetching models:",A)}finally{Cl(!1)}},Wt=async A=>{Tl(A);try{const S=await fetch(Gl("/api/models/download"),{method:"POST",headers:{"Content-Type":"application/json"},body:JSON.stringify({model_name:A})});if(S.ok)await Ul(),rl===A&&v(_=>({..._,model_name:A,model_path:""}));else{const _=await S.json();Y([_.message||"Failed to download model"])}}catch{Y(["Network error: Failed to download model"])}finally{Tl(null)}},Il=async()=>{try{const A=await fetch(Gl("/api/ffmpeg/tools"));if(A.ok){const S=await A.json();pt(S.tools)}else console.error("Failed to fetch FFmpeg tools")}catch(A){console.error("Error fetching FFmpeg tools:",A)}},Yl=async()=>{try{const A=await fetch(Gl("/api/whisper-cli/tools"));if(A.ok){const S=await A.json();Vl(S.tools)}else console.error("Failed to fetch Whisper-CLI tools")}catch(A){console.error("Error fetching Whisper-CLI tools:",A)}},g=async A=>{Zl(A);try{const S=await fetch(Gl("/api/ffmpeg/download"),{method:"POST",headers:{"Content-Type":"application/json"},body:JSON.stringify({tool_name:A})});if(S.ok)await Il(),await M();else{const _=await S.json();Y([_.message||"Failed to download FFmpeg tool"])}}catch{Y(["Network error: Failed to download FFmpeg tool"])}finally{Zl(null)}},z=async A=>{try{const S=await fetch(Gl("/api/ffmpeg/use-downloaded"),{method:"POST",headers:{"Content-Type":"application/json"},body:JSON.stringify({tool_name:A})});if(S.ok)await M(),await Il();else{const _=await S.json();Y([_.message||"Failed to use downloaded FFmpeg tool"])}}catch{Y(["Network error: Failed to use downloaded FFmpeg tool"])}},O=async A=>{const S=Fl.find(_=>_.name===A);S&&S.system_path?(v(_=>({..._,[`${A}_path`]:S.system_path})),$("")):Y([`System path not found for ${A}`])},I=async A=>{xt(A);try{const S=await fetch(Gl("/api/whisper-cli/download"),{method:"POST",headers:{"Content-Type":"application/json"},body:JSON.stringify({tool_name:A})});if(S.ok)await Yl(),await M();else{const _=await S.json();Y([_.message||"Failed to download Whisper-CLI tool"])}}catch{Y(["Network error: Failed to download Whisper-CLI tool"])}finally{xt(null)}},ll=async A=>{try{const S=await fetch(Gl("/api/whisper-cli/use-downloaded"),{method:"POST",headers:{"Content-Type":"application/json"},body:JSON.stringify({tool_name:A})});if(S.ok)await M(),await Yl();else{const _=await S.json();Y([_.message||"Failed to use downloaded Whisper-CLI tool"])}}catch{Y(["Network error: Failed to use downloaded Whisper-CLI tool"])}},V=async A=>{const S=Ol.find(_=>_.name===A);S&&S.system_path?(v(_=>({..._,[`${A}_path`]:S.system_path})),$("")):Y([`System path not found for ${A}`])},M=async()=>{var A;P(!0);try{const S=await fetch(Gl("/api/config"),{headers:{Accept:"application/json","Content-Type":"application/json"}});if(S.ok){const _=await S.json();if(_.success&&((A=_.data)!=null&&A.config)){const sl=_.data.config,Ll={watch_directories:sl.watch_directories&&sl.watch_directories.length>0?sl.watch_directories:[""],whispercli_path:sl.whispercli_path||"",model_path:sl.model_path||"",model_name:sl.model_name||"",ffmpeg_path:sl.ffmpeg_path||"",ffprobe_path:sl.ffprobe_path||"",password:sl.password||""};v(Ll),Ll.model_name?ot(Ll.model_name):Ll.model_path&&ot("custom")}}}catch(S){console.error("Error fetching configuration:",S)}finally{P(!1)}},q=()=>{var _,sl;const A=D.watch_directories.some(Ll=>Ll.trim()!==""),S=rl==="custom"&&((_=D.model_path)==null?void 0:_.trim())!==""||rl!=="custom"&&((sl=G.find(Ll=>Ll.name===rl))==null?void 0:sl.downloaded);return A&&D.whispercli_path.trim()!==""&&D.ffmpeg_path.trim()!==""&&D.ffprobe_path.trim()!==""&&S},il=(A,S)=>{v(_=>({..._,[A]:S}))},hl=(A,S)=>{const _=[...D.watch_directories];_[A]=S,v(sl=>({...sl,watch_directories:_}))},Pl=()=>{v(A=>({...A,watch_directories:[...A.watch_directories,""]}))},kt=A=>{if(D.watch_directories.length>1){const S=D.watch_directories.filter((_,sl)=>sl!==A);v(_=>({..._,watch_directories:S}))}},jt=()=>{T()},jl=A=>{ot(A),A==="custom"?v(S=>({...S,model_name:void 0,model_path:S.model_path||""})):G.find(_=>_.name===A)&&v(_=>({..._,model_name:A,model_path:""}))},De=async A=>{A.preventDefault(),B(!0),Y([]),$("");const S=D.watch_directories.filter(sl=>sl.trim()!=="");if(S.length===0){Y(["At least one watch directory is required"]),B(!1);return}const _={watch_directories:S,whispercli_path:D.whispercli_path,ffmpeg_path:D.ffmpeg_path,ffprobe_path:D.ffprobe_path};rl==="custom"?_.model_path=D.model_path:_.model_name=rl,D.password!==void 0&&(_.password=D.password);try{const sl=await fetch(Gl("/api/config"),{method:"POST",headers:{Accept:"application/json","Content-Type":"application/json"},body:JSON.stringify(_)}),Ll=await sl.json();if(sl.ok&&Ll.success)$(H?"Configuration updated successfully!":"Configuration saved successfully!"),setTimeout(()=>{T()},1500);else{const lt=H?"Failed to update configuration":"Failed to save configuration";Y(Ll.errors||[Ll.message||lt])}}catch{Y([H?"Network error: Failed to update configuration":"Network error: Failed to save configuration"])}finally{B(!1)}},Xa=()=>{var A;return y.jsxs("form",{onSubmit:De,className:"space-y-4",children:[y.jsxs("div",{children:[y.jsx("label",{className:"block text-sm font-medium text-foreground mb-2",children:"Watch Directories"}),y.jsxs("div",{className:"space-y-2",children:[y.jsx("div",{className:"border border-input rounded-md",children:y.jsx(V0,{children:y.jsx(L0,{children:D.watch_directories.map((S,_)=>y.jsxs(K0,{children:[y.jsx(cr,{className:"p-2",children:y.jsx("input",{type:"text",value:S,onChange:sl=>hl(_,sl.target.value),placeholder:"/path/to/your/videos",className:"w-full px-2 py-1 text-sm border-0 bg-transparent text-foreground focus:outline-none focus:ring-1 focus:ring-ring focus:ring-inset rounded"})}),y.jsx(cr,{className:"p-2 w-10",children:y.jsx("button",{type:"button",onClick:()=>kt(_),disabled:D.watch_directories.length===1,className:"p-1 text-destructive hover:bg-destructive/10 rounded disabled:opacity-50 disabled:cursor-not-allowed disabled:hover:bg-transparent",title:"Remove directory",children:y.jsx(R0,{className:"h-3 w-3"})})})]},_))})})}),y.jsxs("button",{type:"button",onClick:Pl,className:"flex items-center gap-1 px-2 py-1 text-xs bg-secondary text-secondary-foreground rounded hover:bg-secondary/80",children:[y.jsx(B0,{className:"h-3 w-3"}),"Add Directory"]})]}),y.jsx("p",{className:"text-xs text-muted-foreground mt-1",children:"Directories to monitor for video files. At least one directory is required."})]}),y.jsxs("div",{className:"space-y-4",children:[y.jsx("h3",{className:"text-sm font-semibold text-foreground",children:"Whisper-CLI Tool"}),Ol.map(S=>y.jsxs("div",{className:"border border-border rounded-lg p-4 space-y-3",children:[y.jsxs("div",{className:"flex items-center justify-between",children:[y.jsx("h4",{className:"text-sm font-medium text-foreground",children:"Whisper CLI"}),y.jsxs("span",{className:"text-xs text-muted-foreground",children:["Platform: ",S.platform]})]}),y.jsxs("div",{className:"space-y-2",children:[y.jsxs("div",{className:"text-xs space-y-1",children:[S.system_available&&y.jsxs("div",{className:"flex items-center gap-2 text-green-600",children:[y.jsx(Au,{className:"h-3 w-3"}),"Available in system PATH: ",S.system_path]}),S.downloaded&&y.jsxs("div",{className:"flex items-center gap-2 text-green-600",children:[y.jsx(Au,{className:"h-3 w-3"}),"Downloaded to: ",S.downloaded_path]}),!S.system_available&&!S.downloaded&&y.jsxs("div",{className:"flex items-center gap-2 text-yellow-600",children:[y.jsx(Id,{className:"h-3 w-3"}),"Not found in system PATH or downloads"]})]}),y.jsxs("div",{children:[y.jsx("label",{htmlFor:"whispercli_path",className:"block text-xs font-medium text-foreground mb-1",children:"Current Path"}),y.jsx("input",{type:"text",id:"whispercli_path",value:D.whispercli_path,onChange:_=>il("whispercli_path",_.target.value),placeholder:"/path/to/whisper-cli",className:"w-full px-3 py-2 border border-input bg-background text-foreground rounded-md focus:outline-none focus:ring-2 focus:ring-ring focus:border-transparent text-sm",required:!0})]}),y.jsxs("div",{className:"flex flex-wrap gap-2",children:[!S.downloaded&&y.jsxs("button",{type:"button",onClick:()=>I(S.name),disabled:Dt!==null,className:"flex items-center gap-2 px-3 py-1.5 text-xs bg-blue-600 text-white rounded hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed",children:[y.jsx(Pi,{className:"h-3 w-3"}),Dt===S.name?"Downloading...":"Download"]}),S.downloaded&&y.jsx("button",{type:"button",onClick:()=>ll(S.name),className:"px-3 py-1.5 text-xs bg-green-600 text-white rounded hover:bg-green-700",children:"Use Downloaded Version"}),S.system_available&&y.jsx("button",{type:"button",onClick:()=>V(S.name),className:"px-3 py-1.5 text-xs bg-gray-600 text-white rounded hover:bg-gray-700",children:"Use System Version"})]})]})]},S.name))]}),y.jsxs("div",{children:[y.jsx("label",{className:"block text-sm font-medium text-foreground mb-1",children:"Model Selection"}),y.jsxs("select",{value:rl,onChange:S=>jl(S.target.value),className:"w-full px-3 py-2 border border-input bg-background text-foreground rounded-md focus:outline-none focus:ring-2 focus:ring-ring focus:border-transparent",disabled:El,children:[y.jsx("option",{value:"custom",children:"Custom Model Path"}),G.length>0&&y.jsxs(y.Fragment,{children:[y.jsx("optgroup",{label:"Downloaded Models",children:G.filter(S=>S.downloaded).map(S=>y.jsxs("option",{value:S.name,children:[S.name," ✓"]},S.name))}),y.jsx("optgroup",{label:"Available Models",children:G.filter(S=>!S.downloaded).map(S=>y.jsx("option",{value:S.name,children:S.name},S.name))})]})]}),rl==="custom"?y.jsxs(y.Fragment,{children:[y.jsx("input",{type:"text",value:D.model_path||"",onChange:S=>il("model_path",S.target.value),placeholder:"/path/to/your/whisper.cpp/model.bin",className:"w-full mt-2 px-3 py-2 border border-input bg-background text-foreground rounded-md focus:outline-none focus:ring-2 focus:ring-ring focus:border-transparent",required:rl==="custom"}),y.jsx("p",{className:"text-xs text-muted-foreground mt-1",children:"Path to the Whisper model file (.bin)"})]}):rl!=="custom"&&y.jsx(y.Fragment,{children:(A=G.find(S=>S.name===rl))!=null&&A.downloaded?y.jsxs("div",{className:"mt-2 flex items-center gap-2 text-xs text-green-600",children:[y.jsx(Au,{className:"h-4 w-4"}),"Model is downloaded and ready to use"]}):y.jsxs("button",{type:"button",onClick:()=>Wt(rl),disabled:Et!==null,className:"mt-2 flex items-center gap-2 px-3 py-1.5 text-xs bg-blue-600 text-white rounded hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed",children:[y.jsx(Pi,{className:"h-3 w-3"}),Et===rl?"Downloading...":"Download Model"]})})]}),y.jsxs("div",{className:"space-y-4",children:[y.jsx("h3",{className:"text-sm font-semibold text-foreground",children:"FFmpeg Tools"}),Fl.map(S=>y.jsxs("div",{className:"border border-border rounded-lg p-4 space-y-3",children:[y.jsxs("div",{className:"flex items-center justify-between",children:[y.jsx("h4",{className:"text-sm font-medium text-foreground capitalize",children:S.name}),y.jsxs("span",{className:"text-xs text-muted-foreground",children:["Platform: ",S.platform]})]}),y.jsxs("div",{className:"space-y-2",children:[y.jsxs("div",{className:"text-xs space-y-1",children:[S.system_available&&y.jsxs("div",{className:"flex items-center gap-2 text-green-600",children:[y.jsx(Au,{className:"h-3 w-3"}),"Available in system PATH: ",S.system_path]}),S.downloaded&&y.jsxs("div",{className:"flex items-center gap-2 text-green-600",children:[y.jsx(Au,{className:"h-3 w-3"}),"Downloaded to: ",S.downloaded_path]}),!S.system_available&&!S.downloaded&&y.jsxs("div",{className:"flex items-center gap-2 text-yellow-600",children:[y.jsx(Id,{className:"h-3 w-3"}),"Not found in system PATH or downloads"]})]}),y.jsxs("div",{children:[y.jsx("label",{htmlFor:`${S.name}_path`,className:"block text-xs font-medium text-foreground mb-1",children:"Current Path"}),y.jsx("input",{type:"text",id:`${S.name}_path`,value:D[`${S.name}_path`],onChange:_=>il(`${S.name}_path`,_.target.value),placeholder:`/path/to/${S.name}`,className:"w-full px-3 py-2 border border-input bg-background text-foreground rounded-md focus:outline-none focus:ring-2 focus:ring-ring focus:border-transparent text-sm",required:!0})]}),y.jsxs("div",{className:"flex flex-wrap gap-2",children:[!S.downloaded&&y.jsxs("button",{type:"button",onClick:()=>g(S.name),disabled:Bl!==null,className:"flex items-center gap-2 px-3 py-1.5 text-xs bg-blue-600 text-white rounded hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed",children:[y.jsx(Pi,{className:"h-3 w-3"}),Bl===S.name?"Downloading...":"Download"]}),S.downloaded&&y.jsx("button",{type:"button",onClick:()=>z(S.name),className:"px-3 py-1.5 text-xs bg-green-600 text-white rounded hover:bg-green-700",children:"Use Downloaded Version"}),S.system_available&&y.jsx("button",{type:"button",onClick:()=>O(S.name),className:"px-3 py-1.5 text-xs bg-gray-600 text-white rounded hover:bg-gray-700",children:"Use System Version"})]})]})]},S.name))]}),y.jsxs("div",{children:[y.jsx("label",{htmlFor:"password",className:"block text-sm font-medium text-foreground mb-1",children:"API Password (optional)"}),y.jsx("input",{type:"password",id:"password",value:D.password||"",onChange:S=>il("password",S.target.value),placeholder:"Set or clear API password (leave blank to disable)",className:"w-full px-3 py-2 border border-input bg-background text-foreground rounded-md focus:outline-none focus:ring-2 focus:ring-ring focus:border-transparent"}),y.jsx("p",{className:"text-xs text-muted-foreground mt-1",children:"If set, all API requests require this password (via Basic Auth or cookie)."})]}),Q.length>0&&y.jsx("div",{className:"bg-destructive/10 border border-destructive/20 rounded-md p-3",children:y.jsx("div",{className:"text-sm text-destructive",children:y.jsx("ul",{className:"list-disc list-inside space-y-1",children:Q.map((S,_)=>y.jsx("li",{children:S},_))})})}),cl&&y.jsx("div",{className:"bg-accent/10 border border-accent/20 rounded-md p-3",children:y.jsx("div",{className:"text-sm text-accent-foreground",children:cl})}),y.jsxs("div",{className:"flex space-x-3",children:[y.jsx("button",{type:"submit",disabled:Z||!q(),className:"flex-1 bg-primary text-primary-foreground py-2 px-4 rounded-md hover:bg-primary/90 focus:outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2 disabled:opacity-50 disabled:cursor-not-allowed",children:Z?"Saving...":H?"Update Configuration":"Save Configuration"}),q()&&y.jsx("button",{type:"button",onClick:jt,disabled:Z,className:"flex-1 bg-secondary text-secondary-foreground py-2 px-4 rounded-md hover:bg-secondary/80 focus:outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2 disabled:opacity-50 disabled:cursor-not-allowed",children:"Cancel"})]})]})};return y.jsx("div",{className:"min-h-screen bg-background flex items-center justify-center px-4",children:y.jsxs("div",{className:"max-w-md w-full bg-card border border-border rounded-lg shadow-md p-6",children:[y.jsxs("div",{className:"text-center mb-6",children:[y.jsx("h1",{className:"text-2xl font-bold text-foreground mb-2",children:H?"Edit Configuration":"atci Setup"}),y.jsx("p",{className:"text-muted-foreground",children:H?"Update your configuration settings":"Configure the required paths to get started"})]}),X?y.jsx("div",{className:"text-center py-8",children:y.jsx("div",{className:"text-muted-foreground",children:"Loading configuration..."})}):Xa(),y.jsx("div",{className:"mt-6 text-center",children:y.jsxs("p",{className:"text-xs text-muted-foreground",children:["Configuration will be saved as ",y.jsx("code",{className:"bg-muted px-1 py-0.5 rounded text-xs",children:".atciconfig"})," in your home directory"]})})]})})},ir=C.createContext(void 0),cm=()=>{const T=C.useContext(ir);if(T===void 0)throw new Error("useFileContext must be used within a FileProvider");return T},W0=({children:T})=>{const[H,D]=C.useState([]),[v,Z]=pa("selectedWatchDirs",[]),[B,Q]=C.useState([]),[Y,cl]=pa("selectedSources",[]),[$,X]=C.useState([]),[P,G]=pa("showAllFiles",!1),[xl,El]=pa("filePage",0),[Cl,rl]=pa("filePageSize",25),[ot,Et]=pa("fileSortColumn","created_at"),[Tl,Fl]=pa("fileSortDirection","desc"),[pt,Bl]=C.useState(0),[Zl,Ol]=C.useState(0),[Vl,Dt]=C.useState({queue:[],currently_processing:null,processing_state:"idle",age_in_seconds:0}),[xt,Ul]=C.useState(!0),[Wt,Il]=C.useState(null),[Yl,g]=C.useState({}),z=async(V,M)=>{const q=V!==void 0?V:v,il=M!==void 0?M:Y;try{const hl=new URLSearchParams,Pl=[...q,...il].filter(Boolean);Pl.length>0&&hl.append("filter",Pl.join(",")),hl.append("page",xl.toString()),hl.append("page_size",Cl.toString()),hl.append("sort_by",ot),hl.append("sort_order",Tl==="asc"?"0":"1");const kt=`/api/files?${hl.toString()}`,jt=await fetch(Gl(kt));if(jt.ok){const jl=await jt.json();jl.success&&(jl.data&&typeof jl.data=="object"&&jl.data.files?(D(jl.data.files||[]),Bl(jl.data.pages||0),Ol(jl.data.total_records||0)):(D(jl.data||[]),Bl(0),Ol(0)))}}catch(hl){console.error("Error refreshing files:",hl)}},O=async()=>{try{const V=await fetch(Gl("/api/queue/status"));if(V.ok){const M=await V.json();if(M.success){if(Vl.currently_processing&&M.data.currently_processing!=Vl.currently_processing){z();const q=Vl.currently_processing.replace(/\.part\d+/,"");Yl[q]&&I(q)}Dt(M.data)}else Il(M.error);Il(null)}else throw new Error(`Failed to fetch queue status: ${V.status}`)}catch(V){console.error("Error fetching queue status:",V),Il(V instanceof Error?V.message:"Failed to fetch queue status")}finally{Ul(!1)}},I=async V=>{console.log("Fetching transcript for:",V),g(M=>({...M,[V]:{text:"",loading:!0,error:null}}));try{const M=await fetch(Gl(`/api/transcripts?video_path=${encodeURIComponent(V)}`));if(!M.ok)throw new Error(`Failed to fetch transcript: ${M.status} ${M.statusText}`);const q=await M.json();if(q.success){const il=q.data;g(hl=>({...hl,[V]:{text:il,loading:!1,error:null}}))}else throw new Error(`Failed to fetch transcript: ${q.error}`)}catch(M){g(q=>({...q,[V]:{text:"",loading:!1,error:M instanceof Error?M.message:"An unknown error occurred"}}))}};C.useEffect(()=>{(async()=>{try{const M=await fetch(Gl("/api/config"));if(M.ok){const q=await M.json();q.success&&Q(q.data.config.watch_directories||[])}}catch(M){console.error("Error fetching watch directories:",M)}})()},[]),C.useEffect(()=>{(async()=>{try{const M=await fetch(Gl("/api/sources"));if(M.ok){const q=await M.json();q.success&&X(q.data||[])}}catch(M){console.error("Error fetching sources:",M)}})()},[]),C.useEffect(()=>{P&&z()},[v,Y,P,xl,Cl,ot,Tl]),C.useEffect(()=>{const V=setInterval(()=>{O()},2e3);return()=>clearInterval(V)},[Vl.currently_processing]);const ll={files:H,setFiles:D,refreshFiles:z,selectedWatchDirs:v,setSelectedWatchDirs:Z,availableWatchDirs:B,setAvailableWatchDirs:Q,selectedSources:Y,setSelectedSources:cl,availableSources:$,setAvailableSources:X,showAllFiles:P,setShowAllFiles:G,page:xl,setPage:El,pageSize:Cl,setPageSize:rl,sortColumn:ot,setSortColumn:Et,sortDirection:Tl,setSortDirection:Fl,totalPages:pt,totalRecords:Zl,queueStatus:Vl,setQueueStatus:Dt,fetchQueueStatus:O,isQueueLoading:xt,queueError:Wt,transcriptData:Yl,setTranscriptData:g,fetchTranscript:I};return y.jsx(ir.Provider,{value:ll,children:T})},k0=C.lazy(()=>ef(()=>import("./HomePage-CqtsSxf4.js"),__vite__mapDeps([0,1,2,3,4,5,6,7]))),$0=C.lazy(()=>ef(()=>import("./ConfigPage-DWe62UL6.js"),__vite__mapDeps([7,1,2,3,6,4]))),F0=C.lazy(()=>ef(()=>import("./QueuePage-BtxpzuDR.js").then(T=>T.g),__vite__mapDeps([5,1,2,3,6])));function I0(){const[T,H]=C.useState(null),[D,v]=pa("isDarkMode",!0);C.useEffect(()=>{const B=document.documentElement,Q=document.body;D?(B.classList.add("dark"),Q.classList.add("dark")):(B.classList.remove("dark"),Q.classList.remove("dark"))},[D]);const Z=()=>{const B=!D;v(B);const Q=document.documentElement,Y=document.body;B?(Q.classList.add("dark"),Y.classList.add("dark")):(Q.classList.remove("dark"),Y.classList.remove("dark"))};return C.useEffect(()=>{(async()=>{try{const Q=await fetch(Gl("/api/config"),{headers:{Accept:"application/json","Content-Type":"application/json"}});if(Q.ok){const cl=(await Q.json()).data.config,$=cl.watch_directories&&cl.watch_directories.length>0,X=cl.whispercli_path,P=cl.model_path||cl.model_name,G=cl.ffmpeg_path,xl=cl.ffprobe_path;H(!!($&&X&&P&&G&&xl))}else H(!1)}catch(Q){console.error("Error checking configuration:",Q),H(!1)}})()},[]),T===null?y.jsxs("div",{className:`min-h-screen bg-background ${D?"dark":""} flex items-center justify-center`,children:[y.jsx("div",{className:"text-center",children:y.jsx("div",{className:"text-lg text-muted-foreground",children:"Loading..."})}),y.jsx("button",{onClick:Z,className:"fixed bottom-6 left-6 z-50 p-3 bg-card border border-border rounded-full shadow-lg hover:shadow-xl transition-all duration-200 hover:scale-105 text-foreground hover:bg-accent",title:D?"Switch to light mode":"Switch to dark mode",children:D?y.jsx(Pd,{className:"w-5 h-5"}):y.jsx(lr,{className:"w-5 h-5"})})]}):T===!1?y.jsxs(y.Fragment,{children:[y.jsx(J0,{onConfigComplete:()=>H(!0)}),y.jsx("button",{onClick:Z,className:"fixed bottom-6 left-6 z-50 p-3 bg-card border border-border rounded-full shadow-lg hover:shadow-xl transition-all duration-200 hover:scale-105 text-foreground hover:bg-accent",title:D?"Switch to light mode":"Switch to dark mode",children:D?y.jsx(Pd,{className:"w-5 h-5"}):y.jsx(lr,{className:"w-5 h-5"})})]}):y.jsxs("div",{className:`min-h-screen bg-background ${D?"dark":""}`,children:[y.jsx(W0,{children:y.jsx(C.Suspense,{fallback:y.jsx("div",{className:"flex items-center justify-center min-h-screen",children:y.jsx("div",{className:"text-lg text-muted-foreground",children:"Loading..."})}),children:y.jsxs(_0,{children:[y.jsx(Cn,{path:"/",element:y.jsx(k0,{})}),y.jsx(Cn,{path:"/config",element:y.jsx($0,{})}),y.jsx(Cn,{path:"/queue",element:y.jsx(F0,{})}),y.jsx(Cn,{path:"*",element:y.jsx(N0,{to:"/",replace:!0})})]})})}),y.jsx("button",{onClick:Z,className:"fixed bottom-6 left-6 z-50 p-3 bg-card border border-border rounded-full shadow-lg hover:shadow-xl transition-all duration-200 hover:scale-105 text-foreground hover:bg-accent",title:D?"Switch to light mode":"Switch to dark mode",children:D?y.jsx("svg",{className:"w-5 h-5",fill:"none",stroke:"currentColor",viewBox:"0 0 24 24",children:y.jsx("path",{strokeLinecap:"round",strokeLinejoin:"round",strokeWidth:2,d:"M12 3v1m0 16v1m9-9h-1M4 12H3m15.364 6.364l-.707-.707M6.343 6.343l-.707-.707m12.728 0l-.707.707M6.343 17.657l-.707.707M16 12a4 4 0 11-8 0 4 4 0 018 0z"})}):y.jsx("svg",{className:"w-5 h-5",fill:"none",stroke:"currentColor",viewBox:"0 0 24 24",children:y.jsx("path",{strokeLinecap:"round",strokeLinejoin:"round",strokeWidth:2,d:"M20.354 15.354A9 9 0 018.646 3.646 9.003 9.003 0 0012 21a9.003 9.003 0 008.354-5.646z"})})})]})}C0.createRoot(document.getElementById("root")).render(y.jsx(C.StrictMode,{children:y.jsx(O0,{basename:"/app",children:y.jsx(I0,{})})}));export{V0 as T,Gl as a,um as b,Ee as c,K0 as d,nm as e,L0 as f,cr as g,cm as u};
+`).replace($h,"")}function Ed(l,t){return t=Ad(t),Ad(l)===t}function Mn(){}function ul(l,t,a,e,u,n){switch(a){case"children":typeof e=="string"?t==="body"||t==="textarea"&&e===""||Ja(l,e):(typeof e=="number"||typeof e=="bigint")&&t!=="body"&&Ja(l,""+e);break;case"className":Uu(l,"class",e);break;case"tabIndex":Uu(l,"tabindex",e);break;case"dir":case"role":case"viewBox":case"width":case"height":Uu(l,a,e);break;case"style":Df(l,e,n);break;case"data":if(t!=="object"){Uu(l,"data",e);break}case"src":case"href":if(e===""&&(t!=="a"||a!=="href")){l.removeAttribute(a);break}if(e==null||typeof e=="function"||typeof e=="symbol"||typeof e=="boolean"){l.removeAttribute(a);break}e=qu(""+e),l.setAttribute(a,e);break;case"action":case"formAction":if(typeof e=="function"){l.setAttribute(a,"javascript:throw new Error('A React form was unexpectedly submitted. If you called form.submit() manually, consider using form.requestSubmit() instead. If you\\'re trying to use event.stopPropagation() in a submit event handler, consider also calling event.preventDefault().')");break}else typeof n=="function"&&(a==="formAction"?(t!=="input"&&ul(l,t,"name",u.name,u,null),ul(l,t,"formEncType",u.formEncType,u,null),ul(l,t,"formMethod",u.formMethod,u,null),ul(l,t,"formTarget",u.formTarget,u,null)):(ul(l,t,"encType",u.encType,u,null),ul(l,t,"method",u.method,u,null),ul(l,t,"target",u.target,u,null)));if(e==null||typeof e=="symbol"||typeof e=="boolean"){l.removeAttribute(a);break}e=qu(""+e),l.setAttribute(a,e);break;case"onClick":e!=null&&(l.onclick=Mn);break;case"onScroll":e!=null&&J("scroll",l);break;case"onScrollEnd":e!=null&&J("scrollend",l);break;case"dangerouslySetInnerHTML":if(e!=null){if(typeof e!="object"||!("__html"in e))throw Error(v(61));if(a=e.__html,a!=null){if(u.children!=null)throw Error(v(60));l.innerHTML=a}}break;case"multiple":l.multiple=e&&typeof e!="function"&&typeof e!="symbol";break;case"muted":l.muted=e&&typeof e!="function"&&typeof e!="symbol";break;case"suppressContentEditableWarning":case"suppressHydrationWarning":case"defaultValue":case"defaultChecked":case"innerHTML":case"ref":break;case"autoFocus":break;case"xlinkHref":if(e==null||typeof e=="function"||typeof e=="boolean"||typeof e=="symbol"){l.removeAttribute("xlink:href");break}a=qu(""+e),l.setAttributeNS("http://www.w3.org/1999/xlink","xlink:href",a);break;case"contentEditable":case"spellCheck":case"draggable":case"value":case"autoReverse":case"externalResourcesRequired":case"focusable":case"preserveAlpha":e!=null&&typeof e!="function"&&typeof e!="symbol"?l.setAttribute(a,""+e):l.removeAttribute(a);break;case"inert":case"allowFullScreen":case"async":case"autoPlay":case"controls":case"default":case"defer":case"disabled":case"disablePictureInPicture":case"disableRemotePlayback":case"formNoValidate":case"hidden":case"loop":case"noModule":case"noValidate":case"open":case"playsInline":case"readOnly":case"required":case"reversed":case"scoped":case"seamless":case"itemScope":e&&typeof e!="function"&&typeof e!="symbol"?l.setAttribute(a,""):l.removeAttribute(a);break;case"capture":case"download":e===!0?l.setAttribute(a,""):e!==!1&&e!=null&&typeof e!="function"&&typeof e!="symbol"?l.setAttribute(a,e):l.removeAttribute(a);break;case"cols":case"rows":case"size":case"span":e!=null&&typeof e!="function"&&typeof e!="symbol"&&!isNaN(e)&&1<=e?l.setAttribute(a,e):l.removeAttribute(a);break;case"rowSpan":case"start":e==null||typeof e=="function"||typeof e=="symbol"||isNaN(e)?l.removeAttribute(a):l.setAttribute(a,e);break;case"popover":J("beforetoggle",l),J("toggle",l),Ou(l,"popover",e);break;case"xlinkActuate":Ht(l,"http://www.w3.org/1999/xlink","xlink:actuate",e);break;case"xlinkArcrole":Ht(l,"http://www.w3.org/1999/xlink","xlink:arcrole",e);break;case"xlinkRole":Ht(l,"http://www.w3.org/1999/xlink","xlink:role",e);break;case"xlinkShow":Ht(l,"http://www.w3.org/1999/xlink","xlink:show",e);break;case"xlinkTitle":Ht(l,"http://www.w3.org/1999/xlink","xlink:title",e);break;case"xlinkType":Ht(l,"http://www.w3.org/1999/xlink","xlink:type",e);break;case"xmlBase":Ht(l,"http://www.w3.org/XML/1998/namespace","xml:base",e);break;case"xmlLang":Ht(l,"http://www.w3.org/XML/1998/namespace","xml:lang",e);break;case"xmlSpace":Ht(l,"http://www.w3.org/XML/1998/namespace","xml:space",e);break;case"is":Ou(l,"is",e);break;case"innerText":case"textContent":break;default:(!(2<a.length)||a[0]!=="o"&&a[0]!=="O"||a[1]!=="n"&&a[1]!=="N")&&(a=Dr.get(a)||a,Ou(l,a,e))}}function Bi(l,t,a,e,u,n){switch(a){case"style":Df(l,e,n);break;case"dangerouslySetInnerHTML":if(e!=null){if(typeof e!="object"||!("__html"in e))throw Error(v(61));if(a=e.__html,a!=null){if(u.children!=null)throw Error(v(60));l.innerHTML=a}}break;case"children":typeof e=="string"?Ja(l,e):(typeof e=="number"||typeof e=="bigint")&&Ja(l,""+e);break;case"onScroll":e!=null&&J("scroll",l);break;case"onScrollEnd":e!=null&&J("scrollend",l);break;case"onClick":e!=null&&(l.onclick=Mn);break;case"suppressContentEditableWarning":case"suppressHydrationWarning":case"innerHTML":case"ref":break;case"innerText":case"textContent":break;default:if(!yf.hasOwnProperty(a))l:{if(a[0]==="o"&&a[1]==="n"&&(u=a.endsWith("Capture"),t=a.slice(2,u?a.length-7:void 0),n=l[Kl]||null,n=n!=null?n[a]:null,typeof n=="function"&&l.removeEventListener(t,n,u),typeof e=="function")){typeof n!="function"&&n!==null&&(a in l?l[a]=null:l.hasAttribute(a)&&l.removeAttribute(a)),l.addEventListener(t,e,u);break l}a in l?l[a]=e:e===!0?l.setAttribute(a,""):Ou(l,a,e)}}}function Rl(l,t,a){switch(t){case"div":case"span":case"svg":case"path":case"a":case"g":case"p":case"li":break;case"img":J("error",l),J("load",l);var e=!1,u=!1,n;for(n in a)if(a.hasOwnProperty(n)){var c=a[n];if(c!=null)switch(n){case"src":e=!0;break;case"srcSet":u=!0;break;case"children":case"dangerouslySetInnerHTML":throw Error(v(137,t));default:ul(l,t,n,c,a,null)}}u&&ul(l,t,"srcSet",a.srcSet,a,null),e&&ul(l,t,"src",a.src,a,null);return;case"input":J("invalid",l);var i=n=c=u=null,f=null,r=null;for(e in a)if(a.hasOwnProperty(e)){var b=a[e];if(b!=null)switch(e){case"name":u=b;break;case"type":c=b;break;case"checked":f=b;break;case"defaultChecked":r=b;break;case"value":n=b;break;case"defaultValue":i=b;break;case"children":case"dangerouslySetInnerHTML":if(b!=null)throw Error(v(137,t));break;default:ul(l,t,e,b,a,null)}}Tf(l,n,i,f,r,c,u,!1),ju(l);return;case"select":J("invalid",l),e=c=n=null;for(u in a)if(a.hasOwnProperty(u)&&(i=a[u],i!=null))switch(u){case"value":n=i;break;case"defaultValue":c=i;break;case"multiple":e=i;default:ul(l,t,u,i,a,null)}t=n,a=c,l.multiple=!!e,t!=null?Ka(l,!!e,t,!1):a!=null&&Ka(l,!!e,a,!0);return;case"textarea":J("invalid",l),n=u=e=null;for(c in a)if(a.hasOwnProperty(c)&&(i=a[c],i!=null))switch(c){case"value":e=i;break;case"defaultValue":u=i;break;case"children":n=i;break;case"dangerouslySetInnerHTML":if(i!=null)throw Error(v(91));break;default:ul(l,t,c,i,a,null)}Af(l,e,u,n),ju(l);return;case"option":for(f in a)if(a.hasOwnProperty(f)&&(e=a[f],e!=null))switch(f){case"selected":l.selected=e&&typeof e!="function"&&typeof e!="symbol";break;default:ul(l,t,f,e,a,null)}return;case"dialog":J("beforetoggle",l),J("toggle",l),J("cancel",l),J("close",l);break;case"iframe":case"object":J("load",l);break;case"video":case"audio":for(e=0;e<hu.length;e++)J(hu[e],l);break;case"image":J("error",l),J("load",l);break;case"details":J("toggle",l);break;case"embed":case"source":case"link":J("error",l),J("load",l);case"area":case"base":case"br":case"col":case"hr":case"keygen":case"meta":case"param":case"track":case"wbr":case"menuitem":for(r in a)if(a.hasOwnProperty(r)&&(e=a[r],e!=null))switch(r){case"children":case"dangerouslySetInnerHTML":throw Error(v(137,t));default:ul(l,t,r,e,a,null)}return;default:if(In(t)){for(b in a)a.hasOwnProperty(b)&&(e=a[b],e!==void 0&&Bi(l,t,b,e,a,void 0));return}}for(i in a)a.hasOwnProperty(i)&&(e=a[i],e!=null&&ul(l,t,i,e,a,null))}function Fh(l,t,a,e){switch(t){case"div":case"span":case"svg":case"path":case"a":case"g":case"p":case"li":break;case"input":var u=null,n=null,c=null,i=null,f=null,r=null,b=null;for(m in a){var x=a[m];if(a.hasOwnProperty(m)&&x!=null)switch(m){case"checked":break;case"value":break;case"defaultValue":f=x;default:e.hasOwnProperty(m)||ul(l,t,m,null,e,x)}}for(var h in e){var m=e[h];if(x=a[h],e.hasOwnProperty(h)&&(m!=null||x!=null))switch(h){case"type":n=m;break;case"name":u=m;break;case"checked":r=m;break;case"defaultChecked":b=m;break;case"value":c=m;break;case"defaultValue":i=m;break;case"children":case"dangerouslySetInnerHTML":if(m!=null)throw Error(v(137,t));break;default:m!==x&&ul(l,t,h,m,e,x)}}$n(l,c,i,f,r,b,n,u);return;case"select":m=c=i=h=null;for(n in a)if(f=a[n],a.hasOwnProperty(n)&&f!=null)switch(n){case"value":break;case"multiple":m=f;default:e.hasOwnProperty(n)||ul(l,t,n,null,e,f)}for(u in e)if(n=e[u],f=a[u],e.hasOwnProperty(u)&&(n!=null||f!=null))switch(u){case"value":h=n;break;case"defaultValue":i=n;break;case"multiple":c=n;default:n!==f&&ul(l,t,u,n,e,f)}t=i,a=c,e=m,h!=null?Ka(l,!!a,h,!1):!!e!=!!a&&(t!=null?Ka(l,!!a,t,!0):Ka(l,!!a,a?[]:"",!1));return;case"textarea":m=h=null;for(i in a)if(u=a[i],a.hasOwnProperty(i)&&u!=null&&!e.hasOwnProperty(i))switch(i){case"value":break;case"children":break;default:ul(l,t,i,null,e,u)}for(c in e)if(u=e[c],n=a[c],e.hasOwnProperty(c)&&(u!=null||n!=null))switch(c){case"value":h=u;break;case"defaultValue":m=u;break;case"children":break;case"dangerouslySetInnerHTML":if(u!=null)throw Error(v(91));break;default:u!==n&&ul(l,t,c,u,e,n)}zf(l,h,m);return;case"option":for(var R in a)if(h=a[R],a.hasOwnProperty(R)&&h!=null&&!e.hasOwnProperty(R))switch(R){case"selected":l.selected=!1;break;default:ul(l,t,R,null,e,h)}for(f in e)if(h=e[f],m=a[f],e.hasOwnProperty(f)&&h!==m&&(h!=null||m!=null))switch(f){case"selected":l.selected=h&&typeof h!="function"&&typeof h!="symbol";break;default:ul(l,t,f,h,e,m)}return;case"img":case"link":case"area":case"base":case"br":case"col":case"embed":case"hr":case"keygen":case"meta":case"param":case"source":case"track":case"wbr":case"menuitem":for(var U in a)h=a[U],a.hasOwnProperty(U)&&h!=null&&!e.hasOwnProperty(U)&&ul(l,t,U,null,e,h);for(r in e)if(h=e[r],m=a[r],e.hasOwnProperty(r)&&h!==m&&(h!=null||m!=null))switch(r){case"children":case"dangerouslySetInnerHTML":if(h!=null)throw Error(v(137,t));break;default:ul(l,t,r,h,e,m)}return;default:if(In(t)){for(var nl in a)h=a[nl],a.hasOwnProperty(nl)&&h!==void 0&&!e.hasOwnProperty(nl)&&Bi(l,t,nl,void 0,e,h);for(b in e)h=e[b],m=a[b],!e.hasOwnProperty(b)||h===m||h===void 0&&m===void 0||Bi(l,t,b,h,e,m);return}}for(var o in a)h=a[o],a.hasOwnProperty(o)&&h!=null&&!e.hasOwnProperty(o)&&ul(l,t,o,null,e,h);for(x in e)h=e[x],m=a[x],!e.hasOwnProperty(x)||h===m||h==null&&m==null||ul(l,t,x,h,e,m)}var Yi=null,Qi=null;function _n(l){return l.nodeType===9?l:l.ownerDocument}function Dd(l){switch(l){case"http://www.w3.org/2000/svg":return 1;case"http://www.w3.org/1998/Math/MathML":return 2;default:return 0}}function Md(l,t){if(l===0)switch(t){case"svg":return 1;case"math":return 2;default:return 0}return l===1&&t==="foreignObject"?0:l}function Xi(l,t){return l==="textarea"||l==="noscript"||typeof t.children=="string"||typeof t.children=="number"||typeof t.children=="bigint"||typeof t.dangerouslySetInnerHTML=="object"&&t.dangerouslySetInnerHTML!==null&&t.dangerouslySetInnerHTML.__html!=null}var Gi=null;function Ih(){var l=window.event;return l&&l.type==="popstate"?l===Gi?!1:(Gi=l,!0):(Gi=null,!1)}var _d=typeof setTimeout=="function"?setTimeout:void 0,Ph=typeof clearTimeout=="function"?clearTimeout:void 0,Nd=typeof Promise=="function"?Promise:void 0,l0=typeof queueMicrotask=="function"?queueMicrotask:typeof Nd<"u"?function(l){return Nd.resolve(null).then(l).catch(t0)}:_d;function t0(l){setTimeout(function(){throw l})}function ma(l){return l==="head"}function Od(l,t){var a=t,e=0,u=0;do{var n=a.nextSibling;if(l.removeChild(a),n&&n.nodeType===8)if(a=n.data,a==="/$"){if(0<e&&8>e){a=e;var c=l.ownerDocument;if(a&1&&yu(c.documentElement),a&2&&yu(c.body),a&4)for(a=c.head,yu(a),c=a.firstChild;c;){var i=c.nextSibling,f=c.nodeName;c[Oe]||f==="SCRIPT"||f==="STYLE"||f==="LINK"&&c.rel.toLowerCase()==="stylesheet"||a.removeChild(c),c=i}}if(u===0){l.removeChild(n),zu(t);return}u--}else a==="$"||a==="$?"||a==="$!"?u++:e=a.charCodeAt(0)-48;else e=0;a=n}while(a);zu(t)}function Ci(l){var t=l.firstChild;for(t&&t.nodeType===10&&(t=t.nextSibling);t;){var a=t;switch(t=t.nextSibling,a.nodeName){case"HTML":case"HEAD":case"BODY":Ci(a),Kn(a);continue;case"SCRIPT":case"STYLE":continue;case"LINK":if(a.rel.toLowerCase()==="stylesheet")continue}l.removeChild(a)}}function a0(l,t,a,e){for(;l.nodeType===1;){var u=a;if(l.nodeName.toLowerCase()!==t.toLowerCase()){if(!e&&(l.nodeName!=="INPUT"||l.type!=="hidden"))break}else if(e){if(!l[Oe])switch(t){case"meta":if(!l.hasAttribute("itemprop"))break;return l;case"link":if(n=l.getAttribute("rel"),n==="stylesheet"&&l.hasAttribute("data-precedence"))break;if(n!==u.rel||l.getAttribute("href")!==(u.href==null||u.href===""?null:u.href)||l.getAttribute("crossorigin")!==(u.crossOrigin==null?null:u.crossOrigin)||l.getAttribute("title")!==(u.title==null?null:u.title))break;return l;case"style":if(l.hasAttribute("data-precedence"))break;return l;case"script":if(n=l.getAttribute("src"),(n!==(u.src==null?null:u.src)||l.getAttribute("type")!==(u.type==null?null:u.type)||l.getAttribute("crossorigin")!==(u.crossOrigin==null?null:u.crossOrigin))&&n&&l.hasAttribute("async")&&!l.hasAttribute("itemprop"))break;return l;default:return l}}else if(t==="input"&&l.type==="hidden"){var n=u.name==null?null:""+u.name;if(u.type==="hidden"&&l.getAttribute("name")===n)return l}else return l;if(l=At(l.nextSibling),l===null)break}return null}function e0(l,t,a){if(t==="")return null;for(;l.nodeType!==3;)if((l.nodeType!==1||l.nodeName!=="INPUT"||l.type!=="hidden")&&!a||(l=At(l.nextSibling),l===null))return null;return l}function wi(l){return l.data==="$!"||l.data==="$?"&&l.ownerDocument.readyState==="complete"}function u0(l,t){var a=l.ownerDocument;if(l.data!=="$?"||a.readyState==="complete")t();else{var e=function(){t(),a.removeEventListener("DOMContentLoaded",e)};a.addEventListener("DOMContentLoaded",e),l._reactRetry=e}}function At(l){for(;l!=null;l=l.nextSibling){var t=l.nodeType;if(t===1||t===3)break;if(t===8){if(t=l.data,t==="$"||t==="$!"||t==="$?"||t==="F!"||t==="F")break;if(t==="/$")return null}}return l}var Zi=null;function Ud(l){l=l.previousSibling;for(var t=0;l;){if(l.nodeType===8){var a=l.data;if(a==="$"||a==="$!"||a==="$?"){if(t===0)return l;t--}else a==="/$"&&t++}l=l.previousSibling}return null}function jd(l,t,a){switch(t=_n(a),l){case"html":if(l=t.documentElement,!l)throw Error(v(452));return l;case"head":if(l=t.head,!l)throw Error(v(453));return l;case"body":if(l=t.body,!l)throw Error(v(454));return l;default:throw Error(v(451))}}function yu(l){for(var t=l.attributes;t.length;)l.removeAttributeNode(t[0]);Kn(l)}var St=new Map,Hd=new Set;function Nn(l){return typeof l.getRootNode=="function"?l.getRootNode():l.nodeType===9?l:l.ownerDocument}var Jt=z.d;z.d={f:n0,r:c0,D:i0,C:f0,L:s0,m:o0,X:r0,S:d0,M:h0};function n0(){var l=Jt.f(),t=pn();return l||t}function c0(l){var t=wa(l);t!==null&&t.tag===5&&t.type==="form"?Is(t):Jt.r(l)}var Te=typeof document>"u"?null:document;function qd(l,t,a){var e=Te;if(e&&typeof t=="string"&&t){var u=rt(t);u='link[rel="'+l+'"][href="'+u+'"]',typeof a=="string"&&(u+='[crossorigin="'+a+'"]'),Hd.has(u)||(Hd.add(u),l={rel:l,crossOrigin:a,href:t},e.querySelector(u)===null&&(t=e.createElement("link"),Rl(t,"link",l),Dl(t),e.head.appendChild(t)))}}function i0(l){Jt.D(l),qd("dns-prefetch",l,null)}function f0(l,t){Jt.C(l,t),qd("preconnect",l,t)}function s0(l,t,a){Jt.L(l,t,a);var e=Te;if(e&&l&&t){var u='link[rel="preload"][as="'+rt(t)+'"]';t==="image"&&a&&a.imageSrcSet?(u+='[imagesrcset="'+rt(a.imageSrcSet)+'"]',typeof a.imageSizes=="string"&&(u+='[imagesizes="'+rt(a.imageSizes)+'"]')):u+='[href="'+rt(l)+'"]';var n=u;switch(t){case"style":n=ze(l);break;case"script":n=Ae(l)}St.has(n)||(l=X({rel:"preload",href:t==="image"&&a&&a.imageSrcSet?void 0:l,as:t},a),St.set(n,l),e.querySelector(u)!==null||t==="style"&&e.querySelector(vu(n))||t==="script"&&e.querySelector(gu(n))||(t=e.createElement("link"),Rl(t,"link",l),Dl(t),e.head.appendChild(t)))}}function o0(l,t){Jt.m(l,t);var a=Te;if(a&&l){var e=t&&typeof t.as=="string"?t.as:"script",u='link[rel="modulepreload"][as="'+rt(e)+'"][href="'+rt(l)+'"]',n=u;switch(e){case"audioworklet":case"paintworklet":case"serviceworker":case"sharedworker":case"worker":case"script":n=Ae(l)}if(!St.has(n)&&(l=X({rel:"modulepreload",href:l},t),St.set(n,l),a.querySelector(u)===null)){switch(e){case"audioworklet":case"paintworklet":case"serviceworker":case"sharedworker":case"worker":case"script":if(a.querySelector(gu(n)))return}e=a.createElement("link"),Rl(e,"link",l),Dl(e),a.head.appendChild(e)}}}function d0(l,t,a){Jt.S(l,t,a);var e=Te;if(e&&l){var u=Za(e).hoistableStyles,n=ze(l);t=t||"default";var c=u.get(n);if(!c){var i={loading:0,preload:null};if(c=e.querySelector(vu(n)))i.loading=5;else{l=X({rel:"stylesheet",href:l,"data-precedence":t},a),(a=St.get(n))&&Vi(l,a);var f=c=e.createElement("link");Dl(f),Rl(f,"link",l),f._p=new Promise(function(r,b){f.onload=r,f.onerror=b}),f.addEventListener("load",function(){i.loading|=1}),f.addEventListener("error",function(){i.loading|=2}),i.loading|=4,On(c,t,e)}c={type:"stylesheet",instance:c,count:1,state:i},u.set(n,c)}}}function r0(l,t){Jt.X(l,t);var a=Te;if(a&&l){var e=Za(a).hoistableScripts,u=Ae(l),n=e.get(u);n||(n=a.querySelector(gu(u)),n||(l=X({src:l,async:!0},t),(t=St.get(u))&&Li(l,t),n=a.createElement("script"),Dl(n),Rl(n,"link",l),a.head.appendChild(n)),n={type:"script",instance:n,count:1,state:null},e.set(u,n))}}function h0(l,t){Jt.M(l,t);var a=Te;if(a&&l){var e=Za(a).hoistableScripts,u=Ae(l),n=e.get(u);n||(n=a.querySelector(gu(u)),n||(l=X({src:l,async:!0,type:"module"},t),(t=St.get(u))&&Li(l,t),n=a.createElement("script"),Dl(n),Rl(n,"link",l),a.head.appendChild(n)),n={type:"script",instance:n,count:1,state:null},e.set(u,n))}}function Rd(l,t,a,e){var u=(u=Pl.current)?Nn(u):null;if(!u)throw Error(v(446));switch(l){case"meta":case"title":return null;case"style":return typeof a.precedence=="string"&&typeof a.href=="string"?(t=ze(a.href),a=Za(u).hoistableStyles,e=a.get(t),e||(e={type:"style",instance:null,count:0,state:null},a.set(t,e)),e):{type:"void",instance:null,count:0,state:null};case"link":if(a.rel==="stylesheet"&&typeof a.href=="string"&&typeof a.precedence=="string"){l=ze(a.href);var n=Za(u).hoistableStyles,c=n.get(l);if(c||(u=u.ownerDocument||u,c={type:"stylesheet",instance:null,count:0,state:{loading:0,preload:null}},n.set(l,c),(n=u.querySelector(vu(l)))&&!n._p&&(c.instance=n,c.state.loading=5),St.has(l)||(a={rel:"preload",as:"style",href:a.href,crossOrigin:a.crossOrigin,integrity:a.integrity,media:a.media,hrefLang:a.hrefLang,referrerPolicy:a.referrerPolicy},St.set(l,a),n||m0(u,l,a,c.state))),t&&e===null)throw Error(v(528,""));return c}if(t&&e!==null)throw Error(v(529,""));return null;case"script":return t=a.async,a=a.src,typeof a=="string"&&t&&typeof t!="function"&&typeof t!="symbol"?(t=Ae(a),a=Za(u).hoistableScripts,e=a.get(t),e||(e={type:"script",instance:null,count:0,state:null},a.set(t,e)),e):{type:"void",instance:null,count:0,state:null};default:throw Error(v(444,l))}}function ze(l){return'href="'+rt(l)+'"'}function vu(l){return'link[rel="stylesheet"]['+l+"]"}function Bd(l){return X({},l,{"data-precedence":l.precedence,precedence:null})}function m0(l,t,a,e){l.querySelector('link[rel="preload"][as="style"]['+t+"]")?e.loading=1:(t=l.createElement("link"),e.preload=t,t.addEventListener("load",function(){return e.loading|=1}),t.addEventListener("error",function(){return e.loading|=2}),Rl(t,"link",a),Dl(t),l.head.appendChild(t))}function Ae(l){return'[src="'+rt(l)+'"]'}function gu(l){return"script[async]"+l}function Yd(l,t,a){if(t.count++,t.instance===null)switch(t.type){case"style":var e=l.querySelector('style[data-href~="'+rt(a.href)+'"]');if(e)return t.instance=e,Dl(e),e;var u=X({},a,{"data-href":a.href,"data-precedence":a.precedence,href:null,precedence:null});return e=(l.ownerDocument||l).createElement("style"),Dl(e),Rl(e,"style",u),On(e,a.precedence,l),t.instance=e;case"stylesheet":u=ze(a.href);var n=l.querySelector(vu(u));if(n)return t.state.loading|=4,t.instance=n,Dl(n),n;e=Bd(a),(u=St.get(u))&&Vi(e,u),n=(l.ownerDocument||l).createElement("link"),Dl(n);var c=n;return c._p=new Promise(function(i,f){c.onload=i,c.onerror=f}),Rl(n,"link",e),t.state.loading|=4,On(n,a.precedence,l),t.instance=n;case"script":return n=Ae(a.src),(u=l.querySelector(gu(n)))?(t.instance=u,Dl(u),u):(e=a,(u=St.get(n))&&(e=X({},a),Li(e,u)),l=l.ownerDocument||l,u=l.createElement("script"),Dl(u),Rl(u,"link",e),l.head.appendChild(u),t.instance=u);case"void":return null;default:throw Error(v(443,t.type))}else t.type==="stylesheet"&&(t.state.loading&4)===0&&(e=t.instance,t.state.loading|=4,On(e,a.precedence,l));return t.instance}function On(l,t,a){for(var e=a.querySelectorAll('link[rel="stylesheet"][data-precedence],style[data-precedence]'),u=e.length?e[e.length-1]:null,n=u,c=0;c<e.length;c++){var i=e[c];if(i.dataset.precedence===t)n=i;else if(n!==u)break}n?n.parentNode.insertBefore(l,n.nextSibling):(t=a.nodeType===9?a.head:a,t.insertBefore(l,t.firstChild))}function Vi(l,t){l.crossOrigin==null&&(l.crossOrigin=t.crossOrigin),l.referrerPolicy==null&&(l.referrerPolicy=t.referrerPolicy),l.title==null&&(l.title=t.title)}function Li(l,t){l.crossOrigin==null&&(l.crossOrigin=t.crossOrigin),l.referrerPolicy==null&&(l.referrerPolicy=t.referrerPolicy),l.integrity==null&&(l.integrity=t.integrity)}var Un=null;function Qd(l,t,a){if(Un===null){var e=new Map,u=Un=new Map;u.set(a,e)}else u=Un,e=u.get(a),e||(e=new Map,u.set(a,e));if(e.has(l))return e;for(e.set(l,null),a=a.getElementsByTagName(l),u=0;u<a.length;u++){var n=a[u];if(!(n[Oe]||n[Ql]||l==="link"&&n.getAttribute("rel")==="stylesheet")&&n.namespaceURI!=="http://www.w3.org/2000/svg"){var c=n.getAttribute(t)||"";c=l+c;var i=e.get(c);i?i.push(n):e.set(c,[n])}}return e}function Xd(l,t,a){l=l.ownerDocument||l,l.head.insertBefore(a,t==="title"?l.querySelector("head > title"):null)}function y0(l,t,a){if(a===1||t.itemProp!=null)return!1;switch(l){case"meta":case"title":return!0;case"style":if(typeof t.precedence!="string"||typeof t.href!="string"||t.href==="")break;return!0;case"link":if(typeof t.rel!="string"||typeof t.href!="string"||t.href===""||t.onLoad||t.onError)break;switch(t.rel){case"stylesheet":return l=t.disabled,typeof t.precedence=="string"&&l==null;default:return!0}case"script":if(t.async&&typeof t.async!="function"&&typeof t.async!="symbol"&&!t.onLoad&&!t.onError&&t.src&&typeof t.src=="string")return!0}return!1}function Gd(l){return!(l.type==="stylesheet"&&(l.state.loading&3)===0)}var bu=null;function v0(){}function g0(l,t,a){if(bu===null)throw Error(v(475));var e=bu;if(t.type==="stylesheet"&&(typeof a.media!="string"||matchMedia(a.media).matches!==!1)&&(t.state.loading&4)===0){if(t.instance===null){var u=ze(a.href),n=l.querySelector(vu(u));if(n){l=n._p,l!==null&&typeof l=="object"&&typeof l.then=="function"&&(e.count++,e=jn.bind(e),l.then(e,e)),t.state.loading|=4,t.instance=n,Dl(n);return}n=l.ownerDocument||l,a=Bd(a),(u=St.get(u))&&Vi(a,u),n=n.createElement("link"),Dl(n);var c=n;c._p=new Promise(function(i,f){c.onload=i,c.onerror=f}),Rl(n,"link",a),t.instance=n}e.stylesheets===null&&(e.stylesheets=new Map),e.stylesheets.set(t,l),(l=t.state.preload)&&(t.state.loading&3)===0&&(e.count++,t=jn.bind(e),l.addEventListener("load",t),l.addEventListener("error",t))}}function b0(){if(bu===null)throw Error(v(475));var l=bu;return l.stylesheets&&l.count===0&&Ki(l,l.stylesheets),0<l.count?function(t){var a=setTimeout(function(){if(l.stylesheets&&Ki(l,l.stylesheets),l.unsuspend){var e=l.unsuspend;l.unsuspend=null,e()}},6e4);return l.unsuspend=t,function(){l.unsuspend=null,clearTimeout(a)}}:null}function jn(){if(this.count--,this.count===0){if(this.stylesheets)Ki(this,this.stylesheets);else if(this.unsuspend){var l=this.unsuspend;this.unsuspend=null,l()}}}var Hn=null;function Ki(l,t){l.stylesheets=null,l.unsuspend!==null&&(l.count++,Hn=new Map,t.forEach(S0,l),Hn=null,jn.call(l))}function S0(l,t){if(!(t.state.loading&4)){var a=Hn.get(l);if(a)var e=a.get(null);else{a=new Map,Hn.set(l,a);for(var u=l.querySelectorAll("link[data-precedence],style[data-precedence]"),n=0;n<u.length;n++){var c=u[n];(c.nodeName==="LINK"||c.getAttribute("media")!=="not all")&&(a.set(c.dataset.precedence,c),e=c)}e&&a.set(null,e)}u=t.instance,c=u.getAttribute("data-precedence"),n=a.get(c)||e,n===e&&a.set(null,u),a.set(c,u),this.count++,e=jn.bind(this),u.addEventListener("load",e),u.addEventListener("error",e),n?n.parentNode.insertBefore(u,n.nextSibling):(l=l.nodeType===9?l.head:l,l.insertBefore(u,l.firstChild)),t.state.loading|=4}}var Su={$$typeof:Tl,Provider:null,Consumer:null,_currentValue:O,_currentValue2:O,_threadCount:0};function p0(l,t,a,e,u,n,c,i){this.tag=1,this.containerInfo=l,this.pingCache=this.current=this.pendingChildren=null,this.timeoutHandle=-1,this.callbackNode=this.next=this.pendingContext=this.context=this.cancelPendingCommit=null,this.callbackPriority=0,this.expirationTimes=wn(-1),this.entangledLanes=this.shellSuspendCounter=this.errorRecoveryDisabledLanes=this.expiredLanes=this.warmLanes=this.pingedLanes=this.suspendedLanes=this.pendingLanes=0,this.entanglements=wn(0),this.hiddenUpdates=wn(null),this.identifierPrefix=e,this.onUncaughtError=u,this.onCaughtError=n,this.onRecoverableError=c,this.pooledCache=null,this.pooledCacheLanes=0,this.formState=i,this.incompleteTransitions=new Map}function Cd(l,t,a,e,u,n,c,i,f,r,b,x){return l=new p0(l,t,a,c,i,f,r,x),t=1,n===!0&&(t|=24),n=ut(3,null,null,t),l.current=n,n.stateNode=l,t=Mc(),t.refCount++,l.pooledCache=t,t.refCount++,n.memoizedState={element:e,isDehydrated:a,cache:t},Uc(n),l}function wd(l){return l?(l=te,l):te}function Zd(l,t,a,e,u,n){u=wd(u),e.context===null?e.context=u:e.pendingContext=u,e=ta(t),e.payload={element:a},n=n===void 0?null:n,n!==null&&(e.callback=n),a=aa(l,e,t),a!==null&&(st(a,l,t),ke(a,l,t))}function Vd(l,t){if(l=l.memoizedState,l!==null&&l.dehydrated!==null){var a=l.retryLane;l.retryLane=a!==0&&a<t?a:t}}function Ji(l,t){Vd(l,t),(l=l.alternate)&&Vd(l,t)}function Ld(l){if(l.tag===13){var t=le(l,67108864);t!==null&&st(t,l,67108864),Ji(l,67108864)}}var qn=!0;function x0(l,t,a,e){var u=g.T;g.T=null;var n=z.p;try{z.p=2,Wi(l,t,a,e)}finally{z.p=n,g.T=u}}function T0(l,t,a,e){var u=g.T;g.T=null;var n=z.p;try{z.p=8,Wi(l,t,a,e)}finally{z.p=n,g.T=u}}function Wi(l,t,a,e){if(qn){var u=ki(e);if(u===null)Ri(l,t,e,Rn,a),Jd(l,e);else if(A0(u,l,t,a,e))e.stopPropagation();else if(Jd(l,e),t&4&&-1<z0.indexOf(l)){for(;u!==null;){var n=wa(u);if(n!==null)switch(n.tag){case 3:if(n=n.stateNode,n.current.memoizedState.isDehydrated){var c=xa(n.pendingLanes);if(c!==0){var i=n;for(i.pendingLanes|=2,i.entangledLanes|=2;c;){var f=1<<31-at(c);i.entanglements[1]|=f,c&=~f}Ut(n),(tl&6)===0&&(bn=lt()+500,ru(0))}}break;case 13:i=le(n,2),i!==null&&st(i,n,2),pn(),Ji(n,2)}if(n=ki(e),n===null&&Ri(l,t,e,Rn,a),n===u)break;u=n}u!==null&&e.stopPropagation()}else Ri(l,t,e,null,a)}}function ki(l){return l=lc(l),$i(l)}var Rn=null;function $i(l){if(Rn=null,l=Ca(l),l!==null){var t=B(l);if(t===null)l=null;else{var a=t.tag;if(a===13){if(l=Q(t),l!==null)return l;l=null}else if(a===3){if(t.stateNode.current.memoizedState.isDehydrated)return t.tag===3?t.stateNode.containerInfo:null;l=null}else t!==l&&(l=null)}}return Rn=l,null}function Kd(l){switch(l){case"beforetoggle":case"cancel":case"click":case"close":case"contextmenu":case"copy":case"cut":case"auxclick":case"dblclick":case"dragend":case"dragstart":case"drop":case"focusin":case"focusout":case"input":case"invalid":case"keydown":case"keypress":case"keyup":case"mousedown":case"mouseup":case"paste":case"pause":case"play":case"pointercancel":case"pointerdown":case"pointerup":case"ratechange":case"reset":case"resize":case"seeked":case"submit":case"toggle":case"touchcancel":case"touchend":case"touchstart":case"volumechange":case"change":case"selectionchange":case"textInput":case"compositionstart":case"compositionend":case"compositionupdate":case"beforeblur":case"afterblur":case"beforeinput":case"blur":case"fullscreenchange":case"focus":case"hashchange":case"popstate":case"select":case"selectstart":return 2;case"drag":case"dragenter":case"dragexit":case"dragleave":case"dragover":case"mousemove":case"mouseout":case"mouseover":case"pointermove":case"pointerout":case"pointerover":case"scroll":case"touchmove":case"wheel":case"mouseenter":case"mouseleave":case"pointerenter":case"pointerleave":return 8;case"message":switch(fr()){case uf:return 2;case nf:return 8;case Du:case sr:return 32;case cf:return 268435456;default:return 32}default:return 32}}var Fi=!1,ya=null,va=null,ga=null,pu=new Map,xu=new Map,ba=[],z0="mousedown mouseup touchcancel touchend touchstart auxclick dblclick pointercancel pointerdown pointerup dragend dragstart drop compositionend compositionstart keydown keypress keyup input textInput copy cut paste click change contextmenu reset".split(" ");function Jd(l,t){switch(l){case"focusin":case"focusout":ya=null;break;case"dragenter":case"dragleave":va=null;break;case"mouseover":case"mouseout":ga=null;break;case"pointerover":case"pointerout":pu.delete(t.pointerId);break;case"gotpointercapture":case"lostpointercapture":xu.delete(t.pointerId)}}function Tu(l,t,a,e,u,n){return l===null||l.nativeEvent!==n?(l={blockedOn:t,domEventName:a,eventSystemFlags:e,nativeEvent:n,targetContainers:[u]},t!==null&&(t=wa(t),t!==null&&Ld(t)),l):(l.eventSystemFlags|=e,t=l.targetContainers,u!==null&&t.indexOf(u)===-1&&t.push(u),l)}function A0(l,t,a,e,u){switch(t){case"focusin":return ya=Tu(ya,l,t,a,e,u),!0;case"dragenter":return va=Tu(va,l,t,a,e,u),!0;case"mouseover":return ga=Tu(ga,l,t,a,e,u),!0;case"pointerover":var n=u.pointerId;return pu.set(n,Tu(pu.get(n)||null,l,t,a,e,u)),!0;case"gotpointercapture":return n=u.pointerId,xu.set(n,Tu(xu.get(n)||null,l,t,a,e,u)),!0}return!1}function Wd(l){var t=Ca(l.target);if(t!==null){var a=B(t);if(a!==null){if(t=a.tag,t===13){if(t=Q(a),t!==null){l.blockedOn=t,gr(l.priority,function(){if(a.tag===13){var e=ft();e=Zn(e);var u=le(a,e);u!==null&&st(u,a,e),Ji(a,e)}});return}}else if(t===3&&a.stateNode.current.memoizedState.isDehydrated){l.blockedOn=a.tag===3?a.stateNode.containerInfo:null;return}}}l.blockedOn=null}function Bn(l){if(l.blockedOn!==null)return!1;for(var t=l.targetContainers;0<t.length;){var a=ki(l.nativeEvent);if(a===null){a=l.nativeEvent;var e=new a.constructor(a.type,a);Pn=e,a.target.dispatchEvent(e),Pn=null}else return t=wa(a),t!==null&&Ld(t),l.blockedOn=a,!1;t.shift()}return!0}function kd(l,t,a){Bn(l)&&a.delete(t)}function E0(){Fi=!1,ya!==null&&Bn(ya)&&(ya=null),va!==null&&Bn(va)&&(va=null),ga!==null&&Bn(ga)&&(ga=null),pu.forEach(kd),xu.forEach(kd)}function Yn(l,t){l.blockedOn===t&&(l.blockedOn=null,Fi||(Fi=!0,T.unstable_scheduleCallback(T.unstable_NormalPriority,E0)))}var Qn=null;function $d(l){Qn!==l&&(Qn=l,T.unstable_scheduleCallback(T.unstable_NormalPriority,function(){Qn===l&&(Qn=null);for(var t=0;t<l.length;t+=3){var a=l[t],e=l[t+1],u=l[t+2];if(typeof e!="function"){if($i(e||a)===null)continue;break}var n=wa(a);n!==null&&(l.splice(t,3),t-=3,$c(n,{pending:!0,data:u,method:a.method,action:e},e,u))}}))}function zu(l){function t(f){return Yn(f,l)}ya!==null&&Yn(ya,l),va!==null&&Yn(va,l),ga!==null&&Yn(ga,l),pu.forEach(t),xu.forEach(t);for(var a=0;a<ba.length;a++){var e=ba[a];e.blockedOn===l&&(e.blockedOn=null)}for(;0<ba.length&&(a=ba[0],a.blockedOn===null);)Wd(a),a.blockedOn===null&&ba.shift();if(a=(l.ownerDocument||l).$$reactFormReplay,a!=null)for(e=0;e<a.length;e+=3){var u=a[e],n=a[e+1],c=u[Kl]||null;if(typeof n=="function")c||$d(a);else if(c){var i=null;if(n&&n.hasAttribute("formAction")){if(u=n,c=n[Kl]||null)i=c.formAction;else if($i(u)!==null)continue}else i=c.action;typeof i=="function"?a[e+1]=i:(a.splice(e,3),e-=3),$d(a)}}}function Ii(l){this._internalRoot=l}Xn.prototype.render=Ii.prototype.render=function(l){var t=this._internalRoot;if(t===null)throw Error(v(409));var a=t.current,e=ft();Zd(a,e,l,t,null,null)},Xn.prototype.unmount=Ii.prototype.unmount=function(){var l=this._internalRoot;if(l!==null){this._internalRoot=null;var t=l.containerInfo;Zd(l.current,2,null,l,null,null),pn(),t[Ga]=null}};function Xn(l){this._internalRoot=l}Xn.prototype.unstable_scheduleHydration=function(l){if(l){var t=rf();l={blockedOn:null,target:l,priority:t};for(var a=0;a<ba.length&&t!==0&&t<ba[a].priority;a++);ba.splice(a,0,l),a===0&&Wd(l)}};var Fd=H.version;if(Fd!=="19.1.0")throw Error(v(527,Fd,"19.1.0"));z.findDOMNode=function(l){var t=l._reactInternals;if(t===void 0)throw typeof l.render=="function"?Error(v(188)):(l=Object.keys(l).join(","),Error(v(268,l)));return l=cl(t),l=l!==null?$(l):null,l=l===null?null:l.stateNode,l};var D0={bundleType:0,version:"19.1.0",rendererPackageName:"react-dom",currentDispatcherRef:g,reconcilerVersion:"19.1.0"};if(typeof __REACT_DEVTOOLS_GLOBAL_HOOK__<"u"){var Gn=__REACT_DEVTOOLS_GLOBAL_HOOK__;if(!Gn.isDisabled&&Gn.supportsFiber)try{Me=Gn.inject(D0),tt=Gn}catch{}}return Eu.createRoot=function(l,t){if(!Z(l))throw Error(v(299));var a=!1,e="",u=ho,n=mo,c=yo,i=null;return t!=null&&(t.unstable_strictMode===!0&&(a=!0),t.identifierPrefix!==void 0&&(e=t.identifierPrefix),t.onUncaughtError!==void 0&&(u=t.onUncaughtError),t.onCaughtError!==void 0&&(n=t.onCaughtError),t.onRecoverableError!==void 0&&(c=t.onRecoverableError),t.unstable_transitionCallbacks!==void 0&&(i=t.unstable_transitionCallbacks)),t=Cd(l,1,!1,null,null,a,e,u,n,c,i,null),l[Ga]=t.current,qi(l),new Ii(t)},Eu.hydrateRoot=function(l,t,a){if(!Z(l))throw Error(v(299));var e=!1,u="",n=ho,c=mo,i=yo,f=null,r=null;return a!=null&&(a.unstable_strictMode===!0&&(e=!0),a.identifierPrefix!==void 0&&(u=a.identifierPrefix),a.onUncaughtError!==void 0&&(n=a.onUncaughtError),a.onCaughtError!==void 0&&(c=a.onCaughtError),a.onRecoverableError!==void 0&&(i=a.onRecoverableError),a.unstable_transitionCallbacks!==void 0&&(f=a.unstable_transitionCallbacks),a.formState!==void 0&&(r=a.formState)),t=Cd(l,1,!0,t,a??null,e,u,n,c,i,f,r),t.context=wd(null),a=t.current,e=ft(),e=Zn(e),u=ta(e),u.callback=null,aa(a,u,e),a=e,t.current.lanes=a,Ne(t,a),Ut(t),l[Ga]=t.current,qi(l),new Xn(t)},Eu.version="19.1.0",Eu}var ur;function G0(){if(ur)return lf.exports;ur=1;function T(){if(!(typeof __REACT_DEVTOOLS_GLOBAL_HOOK__>"u"||typeof __REACT_DEVTOOLS_GLOBAL_HOOK__.checkDCE!="function"))try{__REACT_DEVTOOLS_GLOBAL_HOOK__.checkDCE(T)}catch(H){console.error(H)}}return T(),lf.exports=X0(),lf.exports}var C0=G0();const w0="modulepreload",Z0=function(T){return"/assets/frontend/"+T},nr={},ef=function(H,D,v){let Z=Promise.resolve();if(D&&D.length>0){let Q=function($){return Promise.all($.map(X=>Promise.resolve(X).then(P=>({status:"fulfilled",value:P}),P=>({status:"rejected",reason:P}))))};document.getElementsByTagName("link");const Y=document.querySelector("meta[property=csp-nonce]"),cl=(Y==null?void 0:Y.nonce)||(Y==null?void 0:Y.getAttribute("nonce"));Z=Q(D.map($=>{if($=Z0($),$ in nr)return;nr[$]=!0;const X=$.endsWith(".css"),P=X?'[rel="stylesheet"]':"";if(document.querySelector(`link[href="${$}"]${P}`))return;const G=document.createElement("link");if(G.rel=X?"stylesheet":w0,X||(G.as="script"),G.crossOrigin="",G.href=$,cl&&G.setAttribute("nonce",cl),document.head.appendChild(G),X)return new Promise((xl,El)=>{G.addEventListener("load",xl),G.addEventListener("error",()=>El(new Error(`Unable to preload CSS for ${$}`)))})}))}function B(Q){const Y=new Event("vite:preloadError",{cancelable:!0});if(Y.payload=Q,window.dispatchEvent(Y),!Y.defaultPrevented)throw Q}return Z.then(Q=>{for(const Y of Q||[])Y.status==="rejected"&&B(Y.reason);return H().catch(B)})};function pa(T,H){const[D,v]=C.useState(()=>{if(typeof window>"u")return H;try{const B=window.localStorage.getItem(T);return B?JSON.parse(B):H}catch(B){return console.error(`Error reading localStorage key "${T}":`,B),H}}),Z=C.useCallback(B=>{try{const Q=B instanceof Function?B(D):B;v(Q),typeof window<"u"&&window.localStorage.setItem(T,JSON.stringify(Q))}catch(Q){console.error(`Error saving to localStorage key "${T}":`,Q)}},[T,D]);return C.useEffect(()=>{const B=Q=>{if(Q.key===T&&Q.newValue!==null)try{v(JSON.parse(Q.newValue))}catch(Y){console.error(`Error parsing localStorage value for key "${T}":`,Y)}};return window.addEventListener("storage",B),()=>window.removeEventListener("storage",B)},[T]),[D,Z]}function Ee(...T){return H0(q0(T))}function Gl(T){const H=Date.now(),D=T.includes("?")?"&":"?";return`${T}${D}t=${H}`}function V0({className:T,...H}){return y.jsx("div",{"data-slot":"table-container",className:"relative w-full overflow-x-auto",children:y.jsx("table",{"data-slot":"table",className:Ee("w-full caption-bottom text-sm",T),...H})})}function um({className:T,...H}){return y.jsx("thead",{"data-slot":"table-header",className:Ee("[&_tr]:border-b",T),...H})}function L0({className:T,...H}){return y.jsx("tbody",{"data-slot":"table-body",className:Ee("[&_tr:last-child]:border-0",T),...H})}function K0({className:T,...H}){return y.jsx("tr",{"data-slot":"table-row",className:Ee("hover:bg-muted/50 data-[state=selected]:bg-muted border-b transition-colors",T),...H})}function nm({className:T,...H}){return y.jsx("th",{"data-slot":"table-head",className:Ee("text-foreground h-10 px-2 text-left align-middle font-medium whitespace-nowrap [&:has([role=checkbox])]:pr-0 [&>[role=checkbox]]:translate-y-[2px]",T),...H})}function cr({className:T,...H}){return y.jsx("td",{"data-slot":"table-cell",className:Ee("p-2 align-middle whitespace-nowrap [&:has([role=checkbox])]:pr-0 [&>[role=checkbox]]:translate-y-[2px]",T),...H})}const J0=({onConfigComplete:T,isEditMode:H=!1})=>{const[D,v]=C.useState({watch_directories:[""],whispercli_path:"",model_path:"",ffmpeg_path:"",ffprobe_path:""}),[Z,B]=C.useState(!1),[Q,Y]=C.useState([]),[cl,$]=C.useState(""),[X,P]=C.useState(!1),[G,xl]=C.useState([]),[El,Cl]=C.useState(!1),[rl,ot]=C.useState("custom"),[Et,Tl]=C.useState(null),[Fl,pt]=C.useState([]),[Bl,Zl]=C.useState(null),[Ol,Vl]=C.useState([]),[Dt,xt]=C.useState(null);C.useEffect(()=>{H&&M(),Ul(),Il(),Yl()},[H]);const Ul=async()=>{Cl(!0);try{const A=await fetch(Gl("/api/models"));if(A.ok){const S=await A.json();xl(S.models||[])}}catch(A){console.error("Error fetching models:",A)}finally{Cl(!1)}},Wt=async A=>{Tl(A);try{const S=await fetch(Gl("/api/models/download"),{method:"POST",headers:{"Content-Type":"application/json"},body:JSON.stringify({model_name:A})});if(S.ok)await Ul(),rl===A&&v(_=>({..._,model_name:A,model_path:""}));else{const _=await S.json();Y([_.message||"Failed to download model"])}}catch{Y(["Network error: Failed to download model"])}finally{Tl(null)}},Il=async()=>{try{const A=await fetch(Gl("/api/ffmpeg/tools"));if(A.ok){const S=await A.json();pt(S.tools)}else console.error("Failed to fetch FFmpeg tools")}catch(A){console.error("Error fetching FFmpeg tools:",A)}},Yl=async()=>{try{const A=await fetch(Gl("/api/whisper-cli/tools"));if(A.ok){const S=await A.json();Vl(S.tools)}else console.error("Failed to fetch Whisper-CLI tools")}catch(A){console.error("Error fetching Whisper-CLI tools:",A)}},g=async A=>{Zl(A);try{const S=await fetch(Gl("/api/ffmpeg/download"),{method:"POST",headers:{"Content-Type":"application/json"},body:JSON.stringify({tool_name:A})});if(S.ok)await Il(),await M();else{const _=await S.json();Y([_.message||"Failed to download FFmpeg tool"])}}catch{Y(["Network error: Failed to download FFmpeg tool"])}finally{Zl(null)}},z=async A=>{try{const S=await fetch(Gl("/api/ffmpeg/use-downloaded"),{method:"POST",headers:{"Content-Type":"application/json"},body:JSON.stringify({tool_name:A})});if(S.ok)await M(),await Il();else{const _=await S.json();Y([_.message||"Failed to use downloaded FFmpeg tool"])}}catch{Y(["Network error: Failed to use downloaded FFmpeg tool"])}},O=async A=>{const S=Fl.find(_=>_.name===A);S&&S.system_path?(v(_=>({..._,[`${A}_path`]:S.system_path})),$("")):Y([`System path not found for ${A}`])},I=async A=>{xt(A);try{const S=await fetch(Gl("/api/whisper-cli/download"),{method:"POST",headers:{"Content-Type":"application/json"},body:JSON.stringify({tool_name:A})});if(S.ok)await Yl(),await M();else{const _=await S.json();Y([_.message||"Failed to download Whisper-CLI tool"])}}catch{Y(["Network error: Failed to download Whisper-CLI tool"])}finally{xt(null)}},ll=async A=>{try{const S=await fetch(Gl("/api/whisper-cli/use-downloaded"),{method:"POST",headers:{"Content-Type":"application/json"},body:JSON.stringify({tool_name:A})});if(S.ok)await M(),await Yl();else{const _=await S.json();Y([_.message||"Failed to use downloaded Whisper-CLI tool"])}}catch{Y(["Network error: Failed to use downloaded Whisper-CLI tool"])}},V=async A=>{const S=Ol.find(_=>_.name===A);S&&S.system_path?(v(_=>({..._,[`${A}_path`]:S.system_path})),$("")):Y([`System path not found for ${A}`])},M=async()=>{var A;P(!0);try{const S=await fetch(Gl("/api/config"),{headers:{Accept:"application/json","Content-Type":"application/json"}});if(S.ok){const _=await S.json();if(_.success&&((A=_.data)!=null&&A.config)){const sl=_.data.config,Ll={watch_directories:sl.watch_directories&&sl.watch_directories.length>0?sl.watch_directories:[""],whispercli_path:sl.whispercli_path||"",model_path:sl.model_path||"",model_name:sl.model_name||"",ffmpeg_path:sl.ffmpeg_path||"",ffprobe_path:sl.ffprobe_path||"",password:sl.password||""};v(Ll),Ll.model_name?ot(Ll.model_name):Ll.model_path&&ot("custom")}}}catch(S){console.error("Error fetching configuration:",S)}finally{P(!1)}},q=()=>{var _,sl;const A=D.watch_directories.some(Ll=>Ll.trim()!==""),S=rl==="custom"&&((_=D.model_path)==null?void 0:_.trim())!==""||rl!=="custom"&&((sl=G.find(Ll=>Ll.name===rl))==null?void 0:sl.downloaded);return A&&D.whispercli_path.trim()!==""&&D.ffmpeg_path.trim()!==""&&D.ffprobe_path.trim()!==""&&S},il=(A,S)=>{v(_=>({..._,[A]:S}))},hl=(A,S)=>{const _=[...D.watch_directories];_[A]=S,v(sl=>({...sl,watch_directories:_}))},Pl=()=>{v(A=>({...A,watch_directories:[...A.watch_directories,""]}))},kt=A=>{if(D.watch_directories.length>1){const S=D.watch_directories.filter((_,sl)=>sl!==A);v(_=>({..._,watch_directories:S}))}},jt=()=>{T()},jl=A=>{ot(A),A==="custom"?v(S=>({...S,model_name:void 0,model_path:S.model_path||""})):G.find(_=>_.name===A)&&v(_=>({..._,model_name:A,model_path:""}))},De=async A=>{A.preventDefault(),B(!0),Y([]),$("");const S=D.watch_directories.filter(sl=>sl.trim()!=="");if(S.length===0){Y(["At least one watch directory is required"]),B(!1);return}const _={watch_directories:S,whispercli_path:D.whispercli_path,ffmpeg_path:D.ffmpeg_path,ffprobe_path:D.ffprobe_path};rl==="custom"?_.model_path=D.model_path:_.model_name=rl,D.password!==void 0&&(_.password=D.password);try{const sl=await fetch(Gl("/api/config"),{method:"POST",headers:{Accept:"application/json","Content-Type":"application/json"},body:JSON.stringify(_)}),Ll=await sl.json();if(sl.ok&&Ll.success)$(H?"Configuration updated successfully!":"Configuration saved successfully!"),setTimeout(()=>{T()},1500);else{const lt=H?"Failed to update configuration":"Failed to save configuration";Y(Ll.errors||[Ll.message||lt])}}catch{Y([H?"Network error: Failed to update configuration":"Network error: Failed to save configuration"])}finally{B(!1)}},Xa=()=>{var A;return y.jsxs("form",{onSubmit:De,className:"space-y-4",children:[y.jsxs("div",{children:[y.jsx("label",{className:"block text-sm font-medium text-foreground mb-2",children:"Watch Directories"}),y.jsxs("div",{className:"space-y-2",children:[y.jsx("div",{className:"border border-input rounded-md",children:y.jsx(V0,{children:y.jsx(L0,{children:D.watch_directories.map((S,_)=>y.jsxs(K0,{children:[y.jsx(cr,{className:"p-2",children:y.jsx("input",{type:"text",value:S,onChange:sl=>hl(_,sl.target.value),placeholder:"/path/to/your/videos",className:"w-full px-2 py-1 text-sm border-0 bg-transparent text-foreground focus:outline-none focus:ring-1 focus:ring-ring focus:ring-inset rounded"})}),y.jsx(cr,{className:"p-2 w-10",children:y.jsx("button",{type:"button",onClick:()=>kt(_),disabled:D.watch_directories.length===1,className:"p-1 text-destructive hover:bg-destructive/10 rounded disabled:opacity-50 disabled:cursor-not-allowed disabled:hover:bg-transparent",title:"Remove directory",children:y.jsx(R0,{className:"h-3 w-3"})})})]},_))})})}),y.jsxs("button",{type:"button",onClick:Pl,className:"flex items-center gap-1 px-2 py-1 text-xs bg-secondary text-secondary-foreground rounded hover:bg-secondary/80",children:[y.jsx(B0,{className:"h-3 w-3"}),"Add Directory"]})]}),y.jsx("p",{className:"text-xs text-muted-foreground mt-1",children:"Directories to monitor for video files. At least one directory is required."})]}),y.jsxs("div",{className:"space-y-4",children:[y.jsx("h3",{className:"text-sm font-semibold text-foreground",children:"Whisper-CLI Tool"}),Ol.map(S=>y.jsxs("div",{className:"border border-border rounded-lg p-4 space-y-3",children:[y.jsxs("div",{className:"flex items-center justify-between",children:[y.jsx("h4",{className:"text-sm font-medium text-foreground",children:"Whisper CLI"}),y.jsxs("span",{className:"text-xs text-muted-foreground",children:["Platform: ",S.platform]})]}),y.jsxs("div",{className:"space-y-2",children:[y.jsxs("div",{className:"text-xs space-y-1",children:[S.system_available&&y.jsxs("div",{className:"flex items-center gap-2 text-green-600",children:[y.jsx(Au,{className:"h-3 w-3"}),"Available in system PATH: ",S.system_path]}),S.downloaded&&y.jsxs("div",{className:"flex items-center gap-2 text-green-600",children:[y.jsx(Au,{className:"h-3 w-3"}),"Downloaded to: ",S.downloaded_path]}),!S.system_available&&!S.downloaded&&y.jsxs("div",{className:"flex items-center gap-2 text-yellow-600",children:[y.jsx(Id,{className:"h-3 w-3"}),"Not found in system PATH or downloads"]})]}),y.jsxs("div",{children:[y.jsx("label",{htmlFor:"whispercli_path",className:"block text-xs font-medium text-foreground mb-1",children:"Current Path"}),y.jsx("input",{type:"text",id:"whispercli_path",value:D.whispercli_path,onChange:_=>il("whispercli_path",_.target.value),placeholder:"/path/to/whisper-cli",className:"w-full px-3 py-2 border border-input bg-background text-foreground rounded-md focus:outline-none focus:ring-2 focus:ring-ring focus:border-transparent text-sm",required:!0})]}),y.jsxs("div",{className:"flex flex-wrap gap-2",children:[!S.downloaded&&y.jsxs("button",{type:"button",onClick:()=>I(S.name),disabled:Dt!==null,className:"flex items-center gap-2 px-3 py-1.5 text-xs bg-blue-600 text-white rounded hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed",children:[y.jsx(Pi,{className:"h-3 w-3"}),Dt===S.name?"Downloading...":"Download"]}),S.downloaded&&y.jsx("button",{type:"button",onClick:()=>ll(S.name),className:"px-3 py-1.5 text-xs bg-green-600 text-white rounded hover:bg-green-700",children:"Use Downloaded Version"}),S.system_available&&y.jsx("button",{type:"button",onClick:()=>V(S.name),className:"px-3 py-1.5 text-xs bg-gray-600 text-white rounded hover:bg-gray-700",children:"Use System Version"})]})]})]},S.name))]}),y.jsxs("div",{children:[y.jsx("label",{className:"block text-sm font-medium text-foreground mb-1",children:"Model Selection"}),y.jsxs("select",{value:rl,onChange:S=>jl(S.target.value),className:"w-full px-3 py-2 border border-input bg-background text-foreground rounded-md focus:outline-none focus:ring-2 focus:ring-ring focus:border-transparent",disabled:El,children:[y.jsx("option",{value:"custom",children:"Custom Model Path"}),G.length>0&&y.jsxs(y.Fragment,{children:[y.jsx("optgroup",{label:"Downloaded Models",children:G.filter(S=>S.downloaded).map(S=>y.jsxs("option",{value:S.name,children:[S.name," ✓"]},S.name))}),y.jsx("optgroup",{label:"Available Models",children:G.filter(S=>!S.downloaded).map(S=>y.jsx("option",{value:S.name,children:S.name},S.name))})]})]}),rl==="custom"?y.jsxs(y.Fragment,{children:[y.jsx("input",{type:"text",value:D.model_path||"",onChange:S=>il("model_path",S.target.value),placeholder:"/path/to/your/whisper.cpp/model.bin",className:"w-full mt-2 px-3 py-2 border border-input bg-background text-foreground rounded-md focus:outline-none focus:ring-2 focus:ring-ring focus:border-transparent",required:rl==="custom"}),y.jsx("p",{className:"text-xs text-muted-foreground mt-1",children:"Path to the Whisper model file (.bin)"})]}):rl!=="custom"&&y.jsx(y.Fragment,{children:(A=G.find(S=>S.name===rl))!=null&&A.downloaded?y.jsxs("div",{className:"mt-2 flex items-center gap-2 text-xs text-green-600",children:[y.jsx(Au,{className:"h-4 w-4"}),"Model is downloaded and ready to use"]}):y.jsxs("button",{type:"button",onClick:()=>Wt(rl),disabled:Et!==null,className:"mt-2 flex items-center gap-2 px-3 py-1.5 text-xs bg-blue-600 text-white rounded hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed",children:[y.jsx(Pi,{className:"h-3 w-3"}),Et===rl?"Downloading...":"Download Model"]})})]}),y.jsxs("div",{className:"space-y-4",children:[y.jsx("h3",{className:"text-sm font-semibold text-foreground",children:"FFmpeg Tools"}),Fl.map(S=>y.jsxs("div",{className:"border border-border rounded-lg p-4 space-y-3",children:[y.jsxs("div",{className:"flex items-center justify-between",children:[y.jsx("h4",{className:"text-sm font-medium text-foreground capitalize",children:S.name}),y.jsxs("span",{className:"text-xs text-muted-foreground",children:["Platform: ",S.platform]})]}),y.jsxs("div",{className:"space-y-2",children:[y.jsxs("div",{className:"text-xs space-y-1",children:[S.system_available&&y.jsxs("div",{className:"flex items-center gap-2 text-green-600",children:[y.jsx(Au,{className:"h-3 w-3"}),"Available in system PATH: ",S.system_path]}),S.downloaded&&y.jsxs("div",{className:"flex items-center gap-2 text-green-600",children:[y.jsx(Au,{className:"h-3 w-3"}),"Downloaded to: ",S.downloaded_path]}),!S.system_available&&!S.downloaded&&y.jsxs("div",{className:"flex items-center gap-2 text-yellow-600",children:[y.jsx(Id,{className:"h-3 w-3"}),"Not found in system PATH or downloads"]})]}),y.jsxs("div",{children:[y.jsx("label",{htmlFor:`${S.name}_path`,className:"block text-xs font-medium text-foreground mb-1",children:"Current Path"}),y.jsx("input",{type:"text",id:`${S.name}_path`,value:D[`${S.name}_path`],onChange:_=>il(`${S.name}_path`,_.target.value),placeholder:`/path/to/${S.name}`,className:"w-full px-3 py-2 border border-input bg-background text-foreground rounded-md focus:outline-none focus:ring-2 focus:ring-ring focus:border-transparent text-sm",required:!0})]}),y.jsxs("div",{className:"flex flex-wrap gap-2",children:[!S.downloaded&&y.jsxs("button",{type:"button",onClick:()=>g(S.name),disabled:Bl!==null,className:"flex items-center gap-2 px-3 py-1.5 text-xs bg-blue-600 text-white rounded hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed",children:[y.jsx(Pi,{className:"h-3 w-3"}),Bl===S.name?"Downloading...":"Download"]}),S.downloaded&&y.jsx("button",{type:"button",onClick:()=>z(S.name),className:"px-3 py-1.5 text-xs bg-green-600 text-white rounded hover:bg-green-700",children:"Use Downloaded Version"}),S.system_available&&y.jsx("button",{type:"button",onClick:()=>O(S.name),className:"px-3 py-1.5 text-xs bg-gray-600 text-white rounded hover:bg-gray-700",children:"Use System Version"})]})]})]},S.name))]}),y.jsxs("div",{children:[y.jsx("label",{htmlFor:"password",className:"block text-sm font-medium text-foreground mb-1",children:"API Password (optional)"}),y.jsx("input",{type:"password",id:"password",value:D.password||"",onChange:S=>il("password",S.target.value),placeholder:"Set or clear API password (leave blank to disable)",className:"w-full px-3 py-2 border border-input bg-background text-foreground rounded-md focus:outline-none focus:ring-2 focus:ring-ring focus:border-transparent"}),y.jsx("p",{className:"text-xs text-muted-foreground mt-1",children:"If set, all API requests require this password (via Basic Auth or cookie)."})]}),Q.length>0&&y.jsx("div",{className:"bg-destructive/10 border border-destructive/20 rounded-md p-3",children:y.jsx("div",{className:"text-sm text-destructive",children:y.jsx("ul",{className:"list-disc list-inside space-y-1",children:Q.map((S,_)=>y.jsx("li",{children:S},_))})})}),cl&&y.jsx("div",{className:"bg-accent/10 border border-accent/20 rounded-md p-3",children:y.jsx("div",{className:"text-sm text-accent-foreground",children:cl})}),y.jsxs("div",{className:"flex space-x-3",children:[y.jsx("button",{type:"submit",disabled:Z||!q(),className:"flex-1 bg-primary text-primary-foreground py-2 px-4 rounded-md hover:bg-primary/90 focus:outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2 disabled:opacity-50 disabled:cursor-not-allowed",children:Z?"Saving...":H?"Update Configuration":"Save Configuration"}),q()&&y.jsx("button",{type:"button",onClick:jt,disabled:Z,className:"flex-1 bg-secondary text-secondary-foreground py-2 px-4 rounded-md hover:bg-secondary/80 focus:outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2 disabled:opacity-50 disabled:cursor-not-allowed",children:"Cancel"})]})]})};return y.jsx("div",{className:"min-h-screen bg-background flex items-center justify-center px-4",children:y.jsxs("div",{className:"max-w-md w-full bg-card border border-border rounded-lg shadow-md p-6",children:[y.jsxs("div",{className:"text-center mb-6",children:[y.jsx("h1",{className:"text-2xl font-bold text-foreground mb-2",children:H?"Edit Configuration":"atci Setup"}),y.jsx("p",{className:"text-muted-foreground",children:H?"Update your configuration settings":"Configure the required paths to get started"})]}),X?y.jsx("div",{className:"text-center py-8",children:y.jsx("div",{className:"text-muted-foreground",children:"Loading configuration..."})}):Xa(),y.jsx("div",{className:"mt-6 text-center",children:y.jsxs("p",{className:"text-xs text-muted-foreground",children:["Configuration will be saved as ",y.jsx("code",{className:"bg-muted px-1 py-0.5 rounded text-xs",children:".atciconfig"})," in your home directory"]})})]})})},ir=C.createContext(void 0),cm=()=>{const T=C.useContext(ir);if(T===void 0)throw new Error("useFileContext must be used within a FileProvider");return T},W0=({children:T})=>{const[H,D]=C.useState([]),[v,Z]=pa("selectedWatchDirs",[]),[B,Q]=C.useState([]),[Y,cl]=pa("selectedSources",[]),[$,X]=C.useState([]),[P,G]=pa("showAllFiles",!1),[xl,El]=pa("filePage",0),[Cl,rl]=pa("filePageSize",25),[ot,Et]=pa("fileSortColumn","created_at"),[Tl,Fl]=pa("fileSortDirection","desc"),[pt,Bl]=C.useState(0),[Zl,Ol]=C.useState(0),[Vl,Dt]=C.useState({queue:[],currently_processing:null,processing_state:"idle",age_in_seconds:0}),[xt,Ul]=C.useState(!0),[Wt,Il]=C.useState(null),[Yl,g]=C.useState({}),z=async(V,M)=>{const q=V!==void 0?V:v,il=M!==void 0?M:Y;try{const hl=new URLSearchParams,Pl=[...q,...il].filter(Boolean);Pl.length>0&&hl.append("filter",Pl.join(",")),hl.append("page",xl.toString()),hl.append("page_size",Cl.toString()),hl.append("sort_by",ot),hl.append("sort_order",Tl==="asc"?"0":"1");const kt=`/api/files?${hl.toString()}`,jt=await fetch(Gl(kt));if(jt.ok){const jl=await jt.json();jl.success&&(jl.data&&typeof jl.data=="object"&&jl.data.files?(D(jl.data.files||[]),Bl(jl.data.pages||0),Ol(jl.data.total_records||0)):(D(jl.data||[]),Bl(0),Ol(0)))}}catch(hl){console.error("Error refreshing files:",hl)}},O=async()=>{try{const V=await fetch(Gl("/api/queue/status"));if(V.ok){const M=await V.json();if(M.success){if(Vl.currently_processing&&M.data.currently_processing!=Vl.currently_processing){z();const q=Vl.currently_processing.replace(/\.part\d+/,"");Yl[q]&&I(q)}Dt(M.data)}else Il(M.error);Il(null)}else throw new Error(`Failed to fetch queue status: ${V.status}`)}catch(V){console.error("Error fetching queue status:",V),Il(V instanceof Error?V.message:"Failed to fetch queue status")}finally{Ul(!1)}},I=async V=>{console.log("Fetching transcript for:",V),g(M=>({...M,[V]:{text:"",loading:!0,error:null}}));try{const M=await fetch(Gl(`/api/transcripts?video_path=${encodeURIComponent(V)}`));if(!M.ok)throw new Error(`Failed to fetch transcript: ${M.status} ${M.statusText}`);const q=await M.json();if(q.success){const il=q.data;g(hl=>({...hl,[V]:{text:il,loading:!1,error:null}}))}else throw new Error(`Failed to fetch transcript: ${q.error}`)}catch(M){g(q=>({...q,[V]:{text:"",loading:!1,error:M instanceof Error?M.message:"An unknown error occurred"}}))}};C.useEffect(()=>{(async()=>{try{const M=await fetch(Gl("/api/config"));if(M.ok){const q=await M.json();q.success&&Q(q.data.config.watch_directories||[])}}catch(M){console.error("Error fetching watch directories:",M)}})()},[]),C.useEffect(()=>{(async()=>{try{const M=await fetch(Gl("/api/sources"));if(M.ok){const q=await M.json();q.success&&X(q.data||[])}}catch(M){console.error("Error fetching sources:",M)}})()},[]),C.useEffect(()=>{P&&z()},[v,Y,P,xl,Cl,ot,Tl]),C.useEffect(()=>{const V=setInterval(()=>{O()},2e3);return()=>clearInterval(V)},[Vl.currently_processing]);const ll={files:H,setFiles:D,refreshFiles:z,selectedWatchDirs:v,setSelectedWatchDirs:Z,availableWatchDirs:B,setAvailableWatchDirs:Q,selectedSources:Y,setSelectedSources:cl,availableSources:$,setAvailableSources:X,showAllFiles:P,setShowAllFiles:G,page:xl,setPage:El,pageSize:Cl,setPageSize:rl,sortColumn:ot,setSortColumn:Et,sortDirection:Tl,setSortDirection:Fl,totalPages:pt,totalRecords:Zl,queueStatus:Vl,setQueueStatus:Dt,fetchQueueStatus:O,isQueueLoading:xt,queueError:Wt,transcriptData:Yl,setTranscriptData:g,fetchTranscript:I};return y.jsx(ir.Provider,{value:ll,children:T})},k0=C.lazy(()=>ef(()=>import("./HomePage-CqtsSxf4.js"),__vite__mapDeps([0,1,2,3,4,5,6,7]))),$0=C.lazy(()=>ef(()=>import("./ConfigPage-DWe62UL6.js"),__vite__mapDeps([7,1,2,3,6,4]))),F0=C.lazy(()=>ef(()=>import("./QueuePage-BtxpzuDR.js").then(T=>T.g),__vite__mapDeps([5,1,2,3,6])));function I0(){const[T,H]=C.useState(null),[D,v]=pa("isDarkMode",!0);C.useEffect(()=>{const B=document.documentElement,Q=document.body;D?(B.classList.add("dark"),Q.classList.add("dark")):(B.classList.remove("dark"),Q.classList.remove("dark"))},[D]);const Z=()=>{const B=!D;v(B);const Q=document.documentElement,Y=document.body;B?(Q.classList.add("dark"),Y.classList.add("dark")):(Q.classList.remove("dark"),Y.classList.remove("dark"))};return C.useEffect(()=>{(async()=>{try{const Q=await fetch(Gl("/api/config"),{headers:{Accept:"application/json","Content-Type":"application/json"}});if(Q.ok){const cl=(await Q.json()).data.config,$=cl.watch_directories&&cl.watch_directories.length>0,X=cl.whispercli_path,P=cl.model_path||cl.model_name,G=cl.ffmpeg_path,xl=cl.ffprobe_path;H(!!($&&X&&P&&G&&xl))}else H(!1)}catch(Q){console.error("Error checking configuration:",Q),H(!1)}})()},[]),T===null?y.jsxs("div",{className:`min-h-screen bg-background ${D?"dark":""} flex items-center justify-center`,children:[y.jsx("div",{className:"text-center",children:y.jsx("div",{className:"text-lg text-muted-foreground",children:"Loading..."})}),y.jsx("button",{onClick:Z,className:"fixed bottom-6 left-6 z-50 p-3 bg-card border border-border rounded-full shadow-lg hover:shadow-xl transition-all duration-200 hover:scale-105 text-foreground hover:bg-accent",title:D?"Switch to light mode":"Switch to dark mode",children:D?y.jsx(Pd,{className:"w-5 h-5"}):y.jsx(lr,{className:"w-5 h-5"})})]}):T===!1?y.jsxs(y.Fragment,{children:[y.jsx(J0,{onConfigComplete:()=>H(!0)}),y.jsx("button",{onClick:Z,className:"fixed bottom-6 left-6 z-50 p-3 bg-card border border-border rounded-full shadow-lg hover:shadow-xl transition-all duration-200 hover:scale-105 text-foreground hover:bg-accent",title:D?"Switch to light mode":"Switch to dark mode",children:D?y.jsx(Pd,{className:"w-5 h-5"}):y.jsx(lr,{className:"w-5 h-5"})})]}):y.jsxs("div",{className:`min-h-screen bg-background ${D?"dark":""}`,children:[y.jsx(W0,{children:y.jsx(C.Suspense,{fallback:y.jsx("div",{className:"flex items-center justify-center min-h-screen",children:y.jsx("div",{className:"text-lg text-muted-foreground",children:"Loading..."})}),children:y.jsxs(_0,{children:[y.jsx(Cn,{path:"/",element:y.jsx(k0,{})}),y.jsx(Cn,{path:"/config",element:y.jsx($0,{})}),y.jsx(Cn,{path:"/queue",element:y.jsx(F0,{})}),y.jsx(Cn,{path:"*",element:y.jsx(N0,{to:"/",replace:!0})})]})})}),y.jsx("button",{onClick:Z,className:"fixed bottom-6 left-6 z-50 p-3 bg-card border border-border rounded-full shadow-lg hover:shadow-xl transition-all duration-200 hover:scale-105 text-foreground hover:bg-accent",title:D?"Switch to light mode":"Switch to dark mode",children:D?y.jsx("svg",{className:"w-5 h-5",fill:"none",stroke:"currentColor",viewBox:"0 0 24 24",children:y.jsx("path",{strokeLinecap:"round",strokeLinejoin:"round",strokeWidth:2,d:"M12 3v1m0 16v1m9-9h-1M4 12H3m15.364 6.364l-.707-.707M6.343 6.343l-.707-.707m12.728 0l-.707.707M6.343 17.657l-.707.707M16 12a4 4 0 11-8 0 4 4 0 018 0z"})}):y.jsx("svg",{className:"w-5 h-5",fill:"none",stroke:"currentColor",viewBox:"0 0 24 24",children:y.jsx("path",{strokeLinecap:"round",strokeLinejoin:"round",strokeWidth:2,d:"M20.354 15.354A9 9 0 018.646 3.646 9.003 9.003 0 0012 21a9.003 9.003 0 008.354-5.646z"})})})]})}const Qx=window.fetch.bind(window);window.fetch=async(...t)=>{const e=await Qx(...t);if(e.status===401){const n=window.location.pathname+window.location.search;window.location.href=`/auth?redirect=${encodeURIComponent(n)}`}return e};C0.createRoot(document.getElementById("root")).render(y.jsx(C.StrictMode,{children:y.jsx(O0,{basename:"/app",children:y.jsx(I0,{})})}));export{V0 as T,Gl as a,um as b,Ee as c,K0 as d,nm as e,L0 as f,cr as g,cm as u};
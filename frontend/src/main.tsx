@@ -4,6 +4,17 @@ import { BrowserRouter } from 'react-router-dom'
 import './index.css'
 import App from './App.tsx'
 
+// Send the user back to the login page when their session has expired
+const originalFetch = window.fetch.bind(window)
+window.fetch = async (...args: Parameters<typeof fetch>) => {
+  const response = await originalFetch(...args)
+  if (response.status === 401) {
+    const redirect = window.location.pathname + window.location.search
+    window.location.href = `/auth?redirect=${encodeURIComponent(redirect)}`
+  }
+  return response
+}
+
 createRoot(document.getElementById('root')!).render(
   <StrictMode>
     <BrowserRouter basename="/app">
@@ -158,7 +158,7 @@ function HomePageContent() {
       if (response.ok) {
         const data = await response.json()
         if (data.success) {
-          setSearchLineNumbers(data.data.map((result: any) => {
+          setSearchLineNumbers(data.data.results.map((result: any) => {
             return {
               [result.file_path]: result.matches.map((match: any) => match.line_number)
             }
@@ -167,7 +167,7 @@ function HomePageContent() {
           }, {}))
           
           // Collect video info from search results
-          const videoInfos: FileRow[] = data.data.map((result: any) => {
+          const videoInfos: FileRow[] = data.data.results.map((result: any) => {
             // Get video info from the first match (all matches have the same video info)
             const firstMatch = result.matches[0]
             if (firstMatch && firstMatch.video_info) {
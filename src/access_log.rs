@@ -0,0 +1,169 @@
+// atci (andrew's transcript and clipping interface)
+// Copyright (C) 2025 Andrew Nissen
+
+use crate::config;
+use crate::web::UnixSocketPeers;
+use chrono::{DateTime, Local};
+use rocket::fairing::{Fairing, Info, Kind};
+use rocket::{Data, Request, Response};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::time::Instant;
+
+/// Appends a line per web request to `access_log_path` in the `access_log_format` format.
+/// The config is read on every response so logging can be turned on or off without a restart.
+pub struct RequestLogger;
+
+/// When the request arrived, stashed in Rocket's request-local cache by `on_request`
+struct RequestStart(Instant);
+
+struct AccessLogEntry<'a> {
+    method: &'a str,
+    path: &'a str,
+    status_code: u16,
+    duration_ms: u128,
+    ip: String,
+    timestamp: DateTime<Local>,
+    bytes: Option<usize>,
+    referer: Option<&'a str>,
+    user_agent: Option<&'a str>,
+}
+
+fn json_line(entry: &AccessLogEntry) -> String {
+    serde_json::json!({
+        "method": entry.method,
+        "path": entry.path,
+        "status_code": entry.status_code,
+        "duration_ms": entry.duration_ms,
+        "ip": entry.ip,
+        "timestamp": entry.timestamp.to_rfc3339(),
+    })
+    .to_string()
+}
+
+/// The Apache/nginx combined format, with the request duration in milliseconds appended
+fn combined_line(entry: &AccessLogEntry) -> String {
+    format!(
+        "{} - - [{}] \"{} {} HTTP/1.1\" {} {} \"{}\" \"{}\" {}",
+        entry.ip,
+        entry.timestamp.format("%d/%b/%Y:%H:%M:%S %z"),
+        entry.method,
+        entry.path,
+        entry.status_code,
+        entry
+            .bytes
+            .map(|b| b.to_string())
+            .unwrap_or_else(|| "-".to_string()),
+        entry.referer.unwrap_or("-"),
+        entry.user_agent.unwrap_or("-"),
+        entry.duration_ms
+    )
+}
+
+/// The client's IP, or `unix` for a request forwarded from the Unix socket
+fn client_label(request: &Request<'_>) -> String {
+    let proxied = request
+        .rocket()
+        .state::<UnixSocketPeers>()
+        .zip(request.remote())
+        .is_some_and(|(peers, remote)| peers.contains(remote.port()));
+    if proxied {
+        return "unix".to_string();
+    }
+    request
+        .client_ip()
+        .map(|ip| ip.to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+#[rocket::async_trait]
+impl Fairing for RequestLogger {
+    fn info(&self) -> Info {
+        Info {
+            name: "Request Logger",
+            kind: Kind::Request | Kind::Response,
+        }
+    }
+
+    async fn on_request(&self, request: &mut Request<'_>, _: &mut Data<'_>) {
+        request.local_cache(|| RequestStart(Instant::now()));
+    }
+
+    async fn on_response<'r>(&self, request: &'r Request<'_>, response: &mut Response<'r>) {
+        let cfg = config::load_config_or_default();
+        if cfg.access_log_path.is_empty() {
+            return;
+        }
+
+        let start = request.local_cache(|| RequestStart(Instant::now()));
+        let path = request.uri().to_string();
+        let entry = AccessLogEntry {
+            method: request.method().as_str(),
+            path: &path,
+            status_code: response.status().code,
+            duration_ms: start.0.elapsed().as_millis(),
+            ip: client_label(request),
+            timestamp: Local::now(),
+            bytes: response.body().preset_size(),
+            referer: request.headers().get_one("Referer"),
+            user_agent: request.headers().get_one("User-Agent"),
+        };
+
+        let line = match cfg.access_log_format.as_str() {
+            "combined" => combined_line(&entry),
+            _ => json_line(&entry),
+        };
+
+        let result = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&cfg.access_log_path)
+            .and_then(|mut file| writeln!(file, "{}", line));
+        if let Err(e) = result {
+            eprintln!("Error writing access log {}: {}", cfg.access_log_path, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn sample_entry() -> AccessLogEntry<'static> {
+        AccessLogEntry {
+            method: "GET",
+            path: "/api/queue",
+            status_code: 200,
+            duration_ms: 12,
+            ip: "127.0.0.1".to_string(),
+            timestamp: Local.with_ymd_and_hms(2025, 10, 16, 13, 55, 36).unwrap(),
+            bytes: None,
+            referer: None,
+            user_agent: Some("curl/8.0"),
+        }
+    }
+
+    #[test]
+    fn test_json_line_has_request_fields() {
+        let line: serde_json::Value = serde_json::from_str(&json_line(&sample_entry())).unwrap();
+        assert_eq!(line["method"], "GET");
+        assert_eq!(line["path"], "/api/queue");
+        assert_eq!(line["status_code"], 200);
+        assert_eq!(line["duration_ms"], 12);
+        assert_eq!(line["ip"], "127.0.0.1");
+        assert!(
+            line["timestamp"]
+                .as_str()
+                .unwrap()
+                .starts_with("2025-10-16T13:55:36")
+        );
+    }
+
+    #[test]
+    fn test_combined_line_format() {
+        let line = combined_line(&sample_entry());
+        assert!(line.starts_with("127.0.0.1 - - [16/Oct/2025:13:55:36 "));
+        assert!(line.ends_with("\"GET /api/queue HTTP/1.1\" 200 - \"-\" \"curl/8.0\" 12"));
+    }
+}
@@ -5,9 +5,68 @@ use base64::{Engine, engine::general_purpose};
 use rocket::http::Status;
 use rocket::outcome::Outcome;
 use rocket::request::{self, FromRequest, Request};
+use sha2::{Digest, Sha256};
 
 pub struct AuthGuard;
 
+/// Whether the request was rejected because its login session ran out
+pub struct SessionExpired(pub bool);
+
+/// Build the `auth_token` cookie value for a login at `issued_at` (unix seconds).
+/// The password is hashed in so the timestamp can't be changed without knowing it.
+pub fn session_token(password: &str, issued_at: i64) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(format!("{}:{}", issued_at, password).as_bytes());
+    format!("{}.{:x}", issued_at, hasher.finalize())
+}
+
+/// The token API clients send as `Authorization: Bearer <token>`. It is derived from the
+/// password, so changing the password revokes every token handed out for the old one.
+pub fn api_token(password: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(format!("atci-api-token:{}", password).as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Compare two byte strings without returning early at the first difference
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+fn check_bearer_token(token: &str, password: &str) -> bool {
+    constant_time_eq(token.trim().as_bytes(), api_token(password).as_bytes())
+}
+
+#[derive(Debug, PartialEq)]
+enum SessionState {
+    Valid,
+    Expired,
+    Invalid,
+}
+
+fn check_session_token(token: &str, password: &str, timeout_mins: u64, now: i64) -> SessionState {
+    let Some(issued_at) = token
+        .split_once('.')
+        .and_then(|(issued_at, _)| issued_at.parse::<i64>().ok())
+    else {
+        return SessionState::Invalid;
+    };
+
+    if !constant_time_eq(
+        session_token(password, issued_at).as_bytes(),
+        token.as_bytes(),
+    ) {
+        return SessionState::Invalid;
+    }
+
+    let timeout_secs = i64::try_from(timeout_mins.saturating_mul(60)).unwrap_or(i64::MAX);
+    if timeout_mins > 0 && now.saturating_sub(issued_at) > timeout_secs {
+        return SessionState::Expired;
+    }
+
+    SessionState::Valid
+}
+
 #[rocket::async_trait]
 impl<'r> FromRequest<'r> for AuthGuard {
     type Error = ();
@@ -22,8 +81,25 @@ impl<'r> FromRequest<'r> for AuthGuard {
         };
 
         // Check cookie first
-        if let Some(cookie) = request.cookies().get("auth_token")
-            && cookie.value() == password
+        if let Some(cookie) = request.cookies().get("auth_token") {
+            match check_session_token(
+                cookie.value(),
+                password,
+                config.session_timeout_mins,
+                chrono::Utc::now().timestamp(),
+            ) {
+                SessionState::Valid => return Outcome::Success(AuthGuard),
+                SessionState::Expired => {
+                    request.local_cache(|| SessionExpired(true));
+                }
+                SessionState::Invalid => {}
+            }
+        }
+
+        // Check bearer token
+        if let Some(auth_header) = request.headers().get_one("Authorization")
+            && let Some(token) = auth_header.strip_prefix("Bearer ")
+            && check_bearer_token(token, password)
         {
             return Outcome::Success(AuthGuard);
         }
@@ -34,7 +110,7 @@ impl<'r> FromRequest<'r> for AuthGuard {
             && let Ok(decoded) = general_purpose::STANDARD.decode(basic_auth)
             && let Ok(credentials) = String::from_utf8(decoded)
             && let Some((_username, auth_password)) = credentials.split_once(':')
-            && auth_password == password
+            && constant_time_eq(auth_password.as_bytes(), password.as_bytes())
         {
             return Outcome::Success(AuthGuard);
         }
@@ -42,3 +118,57 @@ impl<'r> FromRequest<'r> for AuthGuard {
         Outcome::Error((Status::Unauthorized, ()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_session_token() {
+        let token = session_token("secret", 1_000);
+
+        assert_eq!(
+            check_session_token(&token, "secret", 10, 1_000 + 599),
+            SessionState::Valid
+        );
+        assert_eq!(
+            check_session_token(&token, "secret", 10, 1_000 + 601),
+            SessionState::Expired
+        );
+        assert_eq!(
+            check_session_token(&token, "secret", 0, 1_000_000),
+            SessionState::Valid
+        );
+        assert_eq!(
+            check_session_token(&token, "secret", u64::MAX, i64::MAX),
+            SessionState::Valid
+        );
+        assert_eq!(
+            check_session_token(&token, "other", 10, 1_000),
+            SessionState::Invalid
+        );
+        assert_eq!(
+            check_session_token("2000.forged", "secret", 10, 2_000),
+            SessionState::Invalid
+        );
+        assert_eq!(
+            check_session_token("secret", "secret", 10, 1_000),
+            SessionState::Invalid
+        );
+    }
+
+    #[test]
+    fn test_check_bearer_token() {
+        let token = api_token("secret");
+
+        assert_eq!(token.len(), 64);
+        assert!(check_bearer_token(&token, "secret"));
+        assert!(!check_bearer_token(&token, "other"));
+        assert!(!check_bearer_token("secret", "secret"));
+        assert!(!check_bearer_token(&token[..63], "secret"));
+        assert!(!check_bearer_token(
+            &session_token("secret", 1_000),
+            "secret"
+        ));
+    }
+}
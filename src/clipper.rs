@@ -14,7 +14,7 @@ use uuid::Uuid;
 
 fn get_video_extensions() -> Vec<&'static str> {
     vec![
-        "mp4", "avi", "mov", "mkv", "wmv", "flv", "webm", "m4v", "ts",
+        "mp4", "avi", "mov", "mkv", "wmv", "flv", "webm", "m4v", "ts", "m2ts", "mts", "mxf", "dv",
     ]
 }
 
@@ -65,10 +65,19 @@ pub fn grab_frame(
     text: Option<&str>,
     font_size: Option<u32>,
     width: Option<u32>,
+    output: Option<&Path>,
 ) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
     let cfg: crate::AtciConfig = crate::config::load_config()?;
     let ffprobe_path = Path::new(&cfg.ffprobe_path);
 
+    if let Some(output) = output
+        && let Some(parent) = output.parent()
+        && !parent.as_os_str().is_empty()
+        && !parent.is_dir()
+    {
+        return Err(format!("Output directory {} does not exist", parent.display()).into());
+    }
+
     // Parse time format
     let time_format = TimeFormat::parse(time)?;
 
@@ -100,8 +109,10 @@ pub fn grab_frame(
     let _time_str = format!("{:.3}", time_seconds);
     let _font_size_part = font_size.map(|fs| format!("_fs{}", fs)).unwrap_or_default();
 
-    let temp_frame_name = format!("{}.png", Uuid::new_v4());
-    let temp_frame_path = std::env::temp_dir().join(&temp_frame_name);
+    let temp_frame_path = match output {
+        Some(output) => output.to_path_buf(),
+        None => std::env::temp_dir().join(format!("{}.png", Uuid::new_v4())),
+    };
 
     // if temp_frame_path.exists() {
     //     return Ok(temp_frame_path);
@@ -122,6 +133,140 @@ pub fn grab_frame(
     }
 }
 
+/// Extra settings for clip generation beyond the source, time range and caption
+#[derive(Debug, Clone, Default)]
+pub struct ClipOptions {
+    pub font_size: Option<u32>,
+    pub auto_crop: bool,
+    /// Write the clip here instead of the auto-generated temp path
+    pub output: Option<std::path::PathBuf>,
+    /// Reformat the clip as 1080x1920 portrait, filling the space around the source with this
+    pub portrait: Option<PortraitBackground>,
+    /// Pad the clip out to this display aspect ratio
+    pub aspect_ratio: Option<AspectRatio>,
+    /// Encode mp4 clips in two passes at the bitrate that fits this many megabytes
+    pub two_pass_target_mb: Option<f32>,
+    /// Write a WebVTT chapters file next to the clip
+    pub chapters_file: bool,
+}
+
+/// A display aspect ratio such as `16:9`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AspectRatio {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl AspectRatio {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let invalid = || {
+            format!(
+                "Invalid aspect ratio '{}', expected W:H such as 16:9",
+                value
+            )
+        };
+        let (width, height) = value.split_once(':').ok_or_else(invalid)?;
+        let width: u32 = width.trim().parse().map_err(|_| invalid())?;
+        let height: u32 = height.trim().parse().map_err(|_| invalid())?;
+        if width == 0 || height == 0 {
+            return Err(invalid());
+        }
+        Ok(AspectRatio { width, height })
+    }
+}
+
+/// Filter that pads the source with black bars until it has the requested
+/// display aspect ratio, so nothing is stretched or cropped.
+///
+/// The display aspect ratio (DAR) is the shape the picture is shown at: the
+/// frame's width:height multiplied by the sample (pixel) aspect ratio (SAR).
+/// Sources with non-square pixels (anamorphic DVDs, some camcorders) would be
+/// padded by the wrong amount, so they're first scaled to square pixels and
+/// marked `setsar=1`, after which the padded frame's size alone gives the DAR.
+/// `setdar` then records the ratio in the stream for players that read it.
+fn aspect_ratio_filter(ratio: AspectRatio) -> String {
+    let (w, h) = (ratio.width, ratio.height);
+    format!(
+        "scale='trunc(iw*sar/2)*2':ih,setsar=1,\
+         pad='ceil(max(iw,ih*{w}/{h})/2)*2':'ceil(max(ih,iw*{h}/{w})/2)*2':(ow-iw)/2:(oh-ih)/2,\
+         setdar={w}/{h}"
+    )
+}
+
+/// How the bars around a landscape video are filled when reformatting it as portrait
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PortraitBackground {
+    /// A blurred, zoomed-in copy of the video itself
+    Blur,
+    Black,
+    White,
+}
+
+impl PortraitBackground {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "blur" => Some(PortraitBackground::Blur),
+            "black" => Some(PortraitBackground::Black),
+            "white" => Some(PortraitBackground::White),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PortraitBackground::Blur => "blur",
+            PortraitBackground::Black => "black",
+            PortraitBackground::White => "white",
+        }
+    }
+}
+
+const PORTRAIT_WIDTH: u32 = 1080;
+const PORTRAIT_HEIGHT: u32 = 1920;
+
+/// Filter graph that fits the source inside a 1080x1920 frame over the chosen background
+fn portrait_filter(background: PortraitBackground) -> String {
+    let (w, h) = (PORTRAIT_WIDTH, PORTRAIT_HEIGHT);
+    match background {
+        PortraitBackground::Blur => format!(
+            "split[portrait_bg][portrait_fg];\
+             [portrait_bg]scale={w}:{h}:force_original_aspect_ratio=increase,crop={w}:{h},boxblur=20:5[portrait_blurred];\
+             [portrait_fg]scale={w}:{h}:force_original_aspect_ratio=decrease[portrait_scaled];\
+             [portrait_blurred][portrait_scaled]overlay=(W-w)/2:(H-h)/2,setsar=1"
+        ),
+        PortraitBackground::Black | PortraitBackground::White => format!(
+            "scale={w}:{h}:force_original_aspect_ratio=decrease,pad={w}:{h}:(ow-iw)/2:(oh-ih)/2:color={},setsar=1",
+            background.as_str()
+        ),
+    }
+}
+
+/// The `length:` metadata is rounded to whole seconds, so allow ending a little past it
+const DURATION_TOLERANCE_SECS: f64 = 0.5;
+
+/// Reject ranges ffmpeg would turn into an empty or truncated clip
+fn check_clip_range(
+    start_secs: f64,
+    end_secs: f64,
+    video_duration: Option<f64>,
+) -> Result<(), String> {
+    if start_secs >= end_secs {
+        return Err(format!(
+            "Start time {:.1}s must be before end time {:.1}s",
+            start_secs, end_secs
+        ));
+    }
+    if let Some(duration) = video_duration
+        && end_secs > duration + DURATION_TOLERANCE_SECS
+    {
+        return Err(format!(
+            "End time {:.1}s is past the end of the video ({:.1}s)",
+            end_secs, duration
+        ));
+    }
+    Ok(())
+}
+
 pub fn clip(
     path: &Path,
     start: &str,
@@ -131,6 +276,99 @@ pub fn clip(
     format: &str,
     font_size: Option<u32>,
 ) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+    let options = ClipOptions {
+        font_size,
+        ..Default::default()
+    };
+    clip_with_options(path, start, end, text, display_text, format, &options)
+}
+
+pub fn clip_with_options(
+    path: &Path,
+    start: &str,
+    end: &str,
+    text: Option<&str>,
+    display_text: bool,
+    format: &str,
+    options: &ClipOptions,
+) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+    let clip_path = generate_clip(path, start, end, text, display_text, format, options)?;
+
+    let Some(output) = &options.output else {
+        if options.chapters_file {
+            write_chapters_file(&clip_path, path, start, end, text)?;
+        }
+        return Ok(clip_path);
+    };
+
+    if output.extension().and_then(|ext| ext.to_str()) != Some(format) {
+        eprintln!(
+            "Warning: output path {} does not have a .{} extension",
+            output.display(),
+            format
+        );
+    }
+    if let Some(parent) = output.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        fs::create_dir_all(parent)?;
+    }
+    fs::copy(&clip_path, output)?;
+    if options.chapters_file {
+        write_chapters_file(output, path, start, end, text)?;
+    }
+
+    Ok(output.clone())
+}
+
+/// A WebVTT file with one chapter spanning the whole clip, titled with the caption text
+/// or the source video's name
+fn chapters_vtt(title: &str, duration_secs: f64) -> String {
+    format!(
+        "WEBVTT\n\n{} --> {}\n{}\n",
+        crate::transcripts::format_cue_timestamp(0),
+        crate::transcripts::format_cue_timestamp((duration_secs * 1000.0).round() as u64),
+        title
+    )
+}
+
+fn write_chapters_file(
+    clip_path: &Path,
+    source_path: &Path,
+    start: &str,
+    end: &str,
+    text: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let cfg: crate::AtciConfig = crate::config::load_config()?;
+    let ffprobe_path = Path::new(&cfg.ffprobe_path);
+    let duration_secs = TimeFormat::parse(end)?.to_seconds(source_path, ffprobe_path)?
+        - TimeFormat::parse(start)?.to_seconds(source_path, ffprobe_path)?;
+
+    let title = match text {
+        Some(text) if !text.trim().is_empty() => text.trim().to_string(),
+        _ => source_path
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string(),
+    };
+    fs::write(
+        clip_path.with_extension("vtt"),
+        chapters_vtt(&title, duration_secs),
+    )?;
+    Ok(())
+}
+
+fn generate_clip(
+    path: &Path,
+    start: &str,
+    end: &str,
+    text: Option<&str>,
+    display_text: bool,
+    format: &str,
+    options: &ClipOptions,
+) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+    let font_size = options.font_size;
     let cfg: crate::AtciConfig = crate::config::load_config()?;
     let ffprobe_path = Path::new(&cfg.ffprobe_path);
 
@@ -144,9 +382,10 @@ pub fn clip(
 
     validate_video_file(path)?;
 
-    if end_seconds <= start_seconds {
-        return Err("End time must be greater than start time".into());
-    }
+    let video_duration = crate::metadata::get_metadata_fields(path)
+        .and_then(|m| m.length)
+        .and_then(|length| crate::metadata::parse_length_secs(&length));
+    check_clip_range(start_seconds, end_seconds, video_duration)?;
 
     // Create a static filename using SHA256 hash of all attributes
     let caption_part = match display_text || text.is_some() {
@@ -165,10 +404,42 @@ pub fn clip(
     let format_param = format;
     let font_size_part = font_size.map(|fs| format!("fs{}", fs)).unwrap_or_default();
 
+    // Audio clips have no picture to crop
+    let crop_filter = if options.auto_crop && format != "mp3" {
+        detect_crop(path, &cfg)?
+    } else {
+        None
+    };
+    let crop_part = crop_filter.clone().unwrap_or_default();
+    let portrait = options.portrait.filter(|_| format != "mp3");
+    let portrait_part = portrait
+        .map(|bg| format!("portrait_{}", bg.as_str()))
+        .unwrap_or_default();
+    let aspect_ratio = options.aspect_ratio.filter(|_| format != "mp3");
+    let aspect_ratio_part = aspect_ratio
+        .map(|ratio| format!("ar{}x{}", ratio.width, ratio.height))
+        .unwrap_or_default();
+    if options.two_pass_target_mb.is_some() && format != "mp4" {
+        return Err("Two-pass encoding is only supported for mp4 clips".into());
+    }
+    let two_pass_part = options
+        .two_pass_target_mb
+        .map(|mb| format!("2pass{}", mb))
+        .unwrap_or_default();
+
     // Combine all attributes into a single string for hashing
     let combined_attributes = format!(
-        "clip_{}_{}_{}_{}_{}.{}",
-        start_time_str, end_time_str, caption_part, font_size_part, format_param, display_text
+        "clip_{}_{}_{}_{}_{}_{}_{}_{}{}.{}",
+        start_time_str,
+        end_time_str,
+        caption_part,
+        font_size_part,
+        crop_part,
+        portrait_part,
+        aspect_ratio_part,
+        two_pass_part,
+        format_param,
+        display_text
     );
 
     // Generate SHA256 hash
@@ -228,6 +499,31 @@ pub fn clip(
         }
     };
 
+    // Reformat before the caption is drawn so it lands inside the portrait frame
+    let video_args = match portrait {
+        Some(background) => prepend_video_filter(video_args, &portrait_filter(background)),
+        None => video_args,
+    };
+    let video_args = match aspect_ratio {
+        Some(ratio) => prepend_video_filter(video_args, &aspect_ratio_filter(ratio)),
+        None => video_args,
+    };
+    let video_args = match crop_filter {
+        Some(crop_filter) => prepend_video_filter(video_args, &crop_filter),
+        None => video_args,
+    };
+
+    if let Some(target_mb) = options.two_pass_target_mb {
+        let bitrate_kbps = two_pass_bitrate_kbps(target_mb, duration);
+        let passlog = std::env::temp_dir().join(format!("atci_2pass_{}", hash));
+        let result = run_two_pass(&cfg.ffmpeg_path, &video_args, bitrate_kbps, &passlog);
+        for suffix in ["-0.log", "-0.log.mbtree"] {
+            let _ = fs::remove_file(format!("{}{}", passlog.to_string_lossy(), suffix));
+        }
+        result?;
+        return Ok(temp_clip_path);
+    }
+
     let mut cmd = Command::new(&cfg.ffmpeg_path);
     cmd.args(&video_args);
 
@@ -241,6 +537,182 @@ pub fn clip(
     }
 }
 
+/// Audio is re-encoded at this fixed bitrate in two-pass mode so the size budget can account for it
+const TWO_PASS_AUDIO_KBPS: u32 = 128;
+
+/// Video bitrate that makes a clip of `duration_secs` come out at `target_mb`, leaving room
+/// for the audio track
+fn two_pass_bitrate_kbps(target_mb: f32, duration_secs: f64) -> u32 {
+    (target_mb as f64 * 8192.0 / duration_secs - TWO_PASS_AUDIO_KBPS as f64)
+        .floor()
+        .max(1.0) as u32
+}
+
+/// Turn single-pass mp4 arguments into the two passes of a VBR encode. The first
+/// pass only analyses the video, so it drops audio and discards its output. The second
+/// encodes audio at TWO_PASS_AUDIO_KBPS, since a copied track's size isn't known up front.
+fn two_pass_args(
+    args: &[String],
+    bitrate_kbps: u32,
+    passlog: &Path,
+) -> Result<(Vec<String>, Vec<String>), Box<dyn std::error::Error>> {
+    let (output, args) = args.split_last().ok_or("Missing clip output path")?;
+
+    // A CRF would override the bitrate target, and the audio codec is replaced below
+    let mut base = Vec::with_capacity(args.len());
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if ["-crf", "-c:a", "-b:a"].contains(&arg.as_str()) {
+            iter.next();
+        } else {
+            base.push(arg.clone());
+        }
+    }
+
+    let pass = |number: &str| {
+        let mut pass_args = base.clone();
+        pass_args.extend([
+            "-b:v".to_string(),
+            format!("{}k", bitrate_kbps),
+            "-pass".to_string(),
+            number.to_string(),
+            "-passlogfile".to_string(),
+            passlog.to_string_lossy().to_string(),
+        ]);
+        pass_args
+    };
+
+    let null_device = if cfg!(target_os = "windows") {
+        "NUL"
+    } else {
+        "/dev/null"
+    };
+    let mut first = pass("1");
+    first.extend(["-an", "-f", "null", null_device].map(String::from));
+
+    let mut second = pass("2");
+    second.extend([
+        "-c:a".to_string(),
+        "aac".to_string(),
+        "-b:a".to_string(),
+        format!("{}k", TWO_PASS_AUDIO_KBPS),
+        output.clone(),
+    ]);
+
+    Ok((first, second))
+}
+
+fn run_two_pass(
+    ffmpeg_path: &str,
+    args: &[String],
+    bitrate_kbps: u32,
+    passlog: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (first, second) = two_pass_args(args, bitrate_kbps, passlog)?;
+
+    for (number, pass_args) in [(1, first), (2, second)] {
+        let output = Command::new(ffmpeg_path).args(&pass_args).output()?;
+        if !output.status.success() {
+            return Err(format!(
+                "Error in two-pass encoding pass {} with ffmpeg: {}",
+                number,
+                String::from_utf8_lossy(&output.stderr)
+            )
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Run cropdetect on one second around the middle of the video and return the
+/// detected `crop=W:H:X:Y` filter, caching the result per video in the database
+fn detect_crop(
+    path: &Path,
+    cfg: &crate::AtciConfig,
+) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    let path_str = path.to_string_lossy().to_string();
+    let modified_at = fs::metadata(path)?
+        .modified()
+        .map(|t| chrono::DateTime::<chrono::Utc>::from(t).to_rfc3339())
+        .unwrap_or_default();
+
+    let conn = crate::db::get_connection()?;
+    let cached: Option<String> = conn
+        .query_row(
+            "SELECT crop FROM crop_detections WHERE video_path = ?1 AND modified_at = ?2",
+            [&path_str, &modified_at],
+            |row| row.get(0),
+        )
+        .ok();
+    if let Some(crop) = cached {
+        return Ok(Some(crop).filter(|c| !c.is_empty()));
+    }
+
+    let duration = get_video_duration_seconds(path, Path::new(&cfg.ffprobe_path))?;
+    let midpoint = (duration / 2.0 - 0.5).max(0.0);
+
+    let output = Command::new(&cfg.ffmpeg_path)
+        .args(["-ss", &format!("{}", midpoint), "-i"])
+        .arg(path)
+        .args(["-t", "1", "-vf", "cropdetect", "-f", "null", "-"])
+        .output()?;
+
+    if !output.status.success() {
+        let error_msg = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Error running cropdetect with ffmpeg: {}", error_msg).into());
+    }
+
+    let crop = parse_cropdetect_output(&String::from_utf8_lossy(&output.stderr));
+
+    conn.execute(
+        "INSERT OR REPLACE INTO crop_detections (video_path, modified_at, crop) VALUES (?1, ?2, ?3)",
+        [&path_str, &modified_at, &crop.clone().unwrap_or_default()],
+    )?;
+
+    Ok(crop)
+}
+
+fn parse_cropdetect_output(stderr: &str) -> Option<String> {
+    // cropdetect logs a suggestion per frame, the last one has seen the most frames
+    stderr
+        .lines()
+        .filter_map(|line| line.rsplit_once("crop=").map(|(_, crop)| crop.trim()))
+        .next_back()
+        .map(|crop| format!("crop={}", crop))
+}
+
+fn prepend_video_filter(mut args: Vec<String>, filter: &str) -> Vec<String> {
+    if let Some(index) = args.iter().position(|arg| arg == "-vf") {
+        args[index + 1] = format!("{},{}", filter, args[index + 1]);
+    } else if let Some(index) = args.iter().position(|arg| arg == "-c:v") {
+        args.splice(index..index, ["-vf".to_string(), filter.to_string()]);
+    }
+    args
+}
+
+pub fn get_video_duration_seconds(
+    video_path: &Path,
+    ffprobe_path: &Path,
+) -> Result<f64, Box<dyn std::error::Error>> {
+    let output = Command::new(ffprobe_path)
+        .args([
+            "-v",
+            "error",
+            "-show_entries",
+            "format=duration",
+            "-of",
+            "default=noprint_wrappers=1:nokey=1",
+        ])
+        .arg(video_path)
+        .output()?;
+
+    let duration_str = String::from_utf8(output.stdout)?.trim().to_string();
+    duration_str
+        .parse::<f64>()
+        .map_err(|_| format!("Invalid duration: {}", duration_str).into())
+}
+
 pub fn concatenate_videos(video_paths: &[PathBuf]) -> Result<PathBuf, Box<dyn std::error::Error>> {
     if video_paths.is_empty() {
         return Err("No video paths provided for concatenation".into());
@@ -380,6 +852,69 @@ pub fn concatenate_videos(video_paths: &[PathBuf]) -> Result<PathBuf, Box<dyn st
     }
 }
 
+/// Quote a path for an ffmpeg concat demuxer list, where a literal `'` has to be written as `'\''`
+fn concat_list_entry(path: &Path) -> String {
+    format!("file '{}'", path.to_string_lossy().replace('\'', "'\\''"))
+}
+
+/// Join clips end to end with the concat demuxer without re-encoding, so every clip must
+/// already share the same codecs and dimensions (e.g. clips from the same source video).
+/// Use `concatenate_videos` to normalize mismatched inputs first.
+pub fn concatenate_clips(
+    clips: &[PathBuf],
+    output: PathBuf,
+) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    if clips.is_empty() {
+        return Err("No clips provided for concatenation".into());
+    }
+    for clip in clips {
+        if !clip.is_file() {
+            return Err(format!("Clip not found: {}", clip.display()).into());
+        }
+    }
+
+    let cfg: crate::AtciConfig = crate::config::load_config()?;
+
+    let concat_list_path = std::env::temp_dir().join(format!("concat_list_{}.txt", Uuid::new_v4()));
+    let mut concat_file = fs::File::create(&concat_list_path)?;
+    for clip in clips {
+        // The concat demuxer resolves relative entries against the list file's directory
+        let clip = fs::canonicalize(clip)?;
+        writeln!(concat_file, "{}", concat_list_entry(&clip))?;
+    }
+    drop(concat_file);
+
+    let result = Command::new(&cfg.ffmpeg_path)
+        .args(["-f", "concat", "-safe", "0", "-i"])
+        .arg(&concat_list_path)
+        .args(["-c", "copy", "-y"])
+        .arg(&output)
+        .output();
+
+    let _ = fs::remove_file(&concat_list_path);
+
+    let output_result = result?;
+    if output_result.status.success() {
+        Ok(output)
+    } else {
+        let error_msg = String::from_utf8_lossy(&output_result.stderr);
+        Err(format!("Error concatenating clips: {}", error_msg).into())
+    }
+}
+
+/// Split a transcript cue timing line such as `00:05:25.920 --> 00:05:46.060` into its
+/// start and end, so one copied line can be passed as both clip times
+pub fn parse_cue_range(input: &str) -> Option<(String, String)> {
+    let (start, end) = input.trim().split_once(" --> ")?;
+    let start = start.trim();
+    // Cue settings such as `align:start` may follow the end time
+    let end = end.split_whitespace().next()?;
+    if start.is_empty() {
+        return None;
+    }
+    Some((start.to_string(), end.to_string()))
+}
+
 #[derive(Debug, Clone)]
 pub enum TimeFormat {
     Seconds(f64),
@@ -502,6 +1037,8 @@ fn get_video_fps(
 }
 
 fn parse_timestamp_to_seconds(timestamp: &str) -> Result<f64, Box<dyn std::error::Error>> {
+    // SRT timestamps use a comma before the milliseconds
+    let timestamp = timestamp.trim().replace(',', ".");
     let parts: Vec<&str> = timestamp.split(':').collect();
 
     match parts.len() {
@@ -1156,7 +1693,7 @@ pub fn web_frame(
     let width = query.width.as_deref().and_then(|s| s.parse().ok());
 
     // Call the grab_frame function and get the output path
-    match grab_frame(video_path, &query.time, text, font_size, width) {
+    match grab_frame(video_path, &query.time, text, font_size, width, None) {
         Ok(output_path) => fs::read(&output_path)
             .map(|data| (rocket::http::ContentType::PNG, data))
             .map_err(|_| status::BadRequest("Error reading generated frame")),
@@ -1164,6 +1701,227 @@ pub fn web_frame(
     }
 }
 
+const PREVIEW_DURATION_SECS: f64 = 5.0;
+
+fn preview_cache_dir() -> PathBuf {
+    let home_dir = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+    home_dir.join(".atci").join("previews")
+}
+
+/// Return a cached 5 second clip from the middle of the video, generating it if needed.
+/// Previews are keyed by the video's path and modification time, so edits produce a new one.
+pub fn get_or_create_preview(video_path: &Path) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    validate_video_file(video_path)?;
+
+    let modified_at = fs::metadata(video_path)?
+        .modified()
+        .map(|t| chrono::DateTime::<chrono::Utc>::from(t).to_rfc3339())
+        .unwrap_or_default();
+    let mut hasher = Sha256::new();
+    hasher.update(format!("{}_{}", video_path.display(), modified_at).as_bytes());
+    let hash = format!("{:x}", hasher.finalize());
+
+    let cache_dir = preview_cache_dir();
+    let preview_path = cache_dir.join(format!("{}.mp4", hash));
+
+    if preview_path.exists() {
+        // Bump the modification time so eviction treats it as recently used
+        let _ = fs::File::options()
+            .append(true)
+            .open(&preview_path)
+            .and_then(|file| file.set_modified(std::time::SystemTime::now()));
+        return Ok(preview_path);
+    }
+
+    let cfg: crate::AtciConfig = crate::config::load_config()?;
+    let duration = get_video_duration_seconds(video_path, Path::new(&cfg.ffprobe_path))?;
+    let start = (duration / 2.0 - PREVIEW_DURATION_SECS / 2.0).max(0.0);
+    let end = (start + PREVIEW_DURATION_SECS).min(duration);
+
+    let clip_path = clip(
+        video_path,
+        &format!("{:.3}", start),
+        &format!("{:.3}", end),
+        None,
+        false,
+        "mp4",
+        None,
+    )?;
+
+    fs::create_dir_all(&cache_dir)?;
+    fs::copy(&clip_path, &preview_path)?;
+    let _ = fs::remove_file(&clip_path);
+
+    evict_previews(&cache_dir, cfg.preview_cache_size_mb * 1024 * 1024)?;
+
+    Ok(preview_path)
+}
+
+/// Delete the least recently used previews until the directory fits in `max_bytes`
+fn evict_previews(cache_dir: &Path, max_bytes: u64) -> Result<(), Box<dyn std::error::Error>> {
+    let mut previews: Vec<(std::time::SystemTime, u64, PathBuf)> = fs::read_dir(cache_dir)?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok()?;
+            if !metadata.is_file() {
+                return None;
+            }
+            Some((metadata.modified().ok()?, metadata.len(), entry.path()))
+        })
+        .collect();
+    previews.sort_by_key(|(modified, _, _)| *modified);
+
+    let mut total_bytes: u64 = previews.iter().map(|(_, size, _)| size).sum();
+    for (_, size, path) in previews {
+        if total_bytes <= max_bytes {
+            break;
+        }
+        fs::remove_file(&path)?;
+        total_bytes -= size;
+    }
+
+    Ok(())
+}
+
+#[get("/api/files/<path>/preview")]
+pub async fn web_file_preview(
+    _auth: AuthGuard,
+    path: String,
+) -> Result<rocket::fs::NamedFile, status::BadRequest<String>> {
+    let preview_path = rocket::tokio::task::spawn_blocking(move || {
+        get_or_create_preview(Path::new(&path)).map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| status::BadRequest(format!("Preview task failed: {}", e)))?
+    .map_err(|e| status::BadRequest(format!("Error creating preview: {}", e)))?;
+
+    rocket::fs::NamedFile::open(&preview_path)
+        .await
+        .map_err(|e| status::BadRequest(format!("Error reading preview: {}", e)))
+}
+
+/// Audio segments are regenerated after this long, so repeated playback reuses one
+const AUDIO_SEGMENT_TTL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+fn audio_segment_cache_dir() -> PathBuf {
+    std::env::temp_dir().join("atci_audio_segments")
+}
+
+/// Return an MP3 of the video's audio between `start` and `end` seconds, reusing one
+/// generated within the last hour. Segments are keyed by path, modification time and range.
+pub fn get_or_create_audio_segment(
+    video_path: &Path,
+    start: f64,
+    end: f64,
+) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    validate_video_file(video_path)?;
+
+    let modified_at = fs::metadata(video_path)?
+        .modified()
+        .map(|t| chrono::DateTime::<chrono::Utc>::from(t).to_rfc3339())
+        .unwrap_or_default();
+    let mut hasher = Sha256::new();
+    hasher.update(
+        format!(
+            "{}_{}_{:.3}_{:.3}",
+            video_path.display(),
+            modified_at,
+            start,
+            end
+        )
+        .as_bytes(),
+    );
+    let hash = format!("{:x}", hasher.finalize());
+
+    let cache_dir = audio_segment_cache_dir();
+    let segment_path = cache_dir.join(format!("{}.mp3", hash));
+
+    let now = std::time::SystemTime::now();
+    if fs::metadata(&segment_path)
+        .and_then(|metadata| metadata.modified())
+        .is_ok_and(|modified| !is_expired(modified, now, AUDIO_SEGMENT_TTL))
+    {
+        return Ok(segment_path);
+    }
+
+    let clip_path = clip(
+        video_path,
+        &format!("{:.3}", start),
+        &format!("{:.3}", end),
+        None,
+        false,
+        "mp3",
+        None,
+    )?;
+
+    fs::create_dir_all(&cache_dir)?;
+    fs::copy(&clip_path, &segment_path)?;
+    let _ = fs::remove_file(&clip_path);
+
+    evict_expired_files(&cache_dir, now, AUDIO_SEGMENT_TTL)?;
+
+    Ok(segment_path)
+}
+
+fn is_expired(
+    modified: std::time::SystemTime,
+    now: std::time::SystemTime,
+    ttl: std::time::Duration,
+) -> bool {
+    now.duration_since(modified).is_ok_and(|age| age >= ttl)
+}
+
+/// Delete files in `cache_dir` last written more than `ttl` before `now`
+fn evict_expired_files(
+    cache_dir: &Path,
+    now: std::time::SystemTime,
+    ttl: std::time::Duration,
+) -> Result<(), Box<dyn std::error::Error>> {
+    for entry in fs::read_dir(cache_dir)?.filter_map(|entry| entry.ok()) {
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.is_file()
+            && let Ok(modified) = metadata.modified()
+            && is_expired(modified, now, ttl)
+        {
+            fs::remove_file(entry.path())?;
+        }
+    }
+    Ok(())
+}
+
+#[derive(rocket::Responder)]
+#[response(content_type = "audio/mpeg")]
+pub struct AudioSegmentResponse {
+    file: rocket::fs::NamedFile,
+    disposition: rocket::http::Header<'static>,
+}
+
+#[get("/api/transcripts/<path>/audio-segment?<start>&<end>")]
+pub async fn web_audio_segment(
+    _auth: AuthGuard,
+    path: String,
+    start: f64,
+    end: f64,
+) -> Result<AudioSegmentResponse, status::BadRequest<String>> {
+    let segment_path = rocket::tokio::task::spawn_blocking(move || {
+        get_or_create_audio_segment(Path::new(&path), start, end).map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| status::BadRequest(format!("Audio segment task failed: {}", e)))?
+    .map_err(|e| status::BadRequest(format!("Error creating audio segment: {}", e)))?;
+
+    let file = rocket::fs::NamedFile::open(&segment_path)
+        .await
+        .map_err(|e| status::BadRequest(format!("Error reading audio segment: {}", e)))?;
+
+    Ok(AudioSegmentResponse {
+        file,
+        disposition: rocket::http::Header::new("Content-Disposition", "inline"),
+    })
+}
+
 #[get("/clip/view?<query..>")]
 pub fn web_clip_view(_auth: AuthGuard, query: ClipQuery) -> rocket_dyn_templates::Template {
     use rocket_dyn_templates::context;
@@ -1212,6 +1970,63 @@ pub fn web_clip_view(_auth: AuthGuard, query: ClipQuery) -> rocket_dyn_templates
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_evict_expired_files_keeps_fresh_segments() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let stale = dir.path().join("stale.mp3");
+        let fresh = dir.path().join("fresh.mp3");
+        fs::write(&stale, b"old").unwrap();
+        fs::write(&fresh, b"new").unwrap();
+
+        let now = std::time::SystemTime::now();
+        fs::File::options()
+            .append(true)
+            .open(&stale)
+            .unwrap()
+            .set_modified(now - AUDIO_SEGMENT_TTL - std::time::Duration::from_secs(1))
+            .unwrap();
+
+        evict_expired_files(dir.path(), now, AUDIO_SEGMENT_TTL).unwrap();
+
+        assert!(!stale.exists());
+        assert!(fresh.exists());
+    }
+
+    #[test]
+    fn test_chapters_vtt_spans_clip() {
+        assert_eq!(
+            chapters_vtt("Opening remarks", 7.5),
+            "WEBVTT\n\n00:00:00.000 --> 00:00:07.500\nOpening remarks\n"
+        );
+    }
+
+    #[test]
+    fn test_check_clip_range() {
+        assert!(check_clip_range(1.0, 2.0, None).is_ok());
+        assert!(check_clip_range(1.0, 90.4, Some(90.0)).is_ok());
+        assert_eq!(
+            check_clip_range(10.5, 9.0, None).unwrap_err(),
+            "Start time 10.5s must be before end time 9.0s"
+        );
+        assert!(check_clip_range(5.0, 5.0, None).is_err());
+        assert_eq!(
+            check_clip_range(80.0, 95.0, Some(90.0)).unwrap_err(),
+            "End time 95.0s is past the end of the video (90.0s)"
+        );
+    }
+
+    #[test]
+    fn test_concat_list_entry_escapes_single_quotes() {
+        assert_eq!(
+            concat_list_entry(Path::new("/clips/a.mp4")),
+            "file '/clips/a.mp4'"
+        );
+        assert_eq!(
+            concat_list_entry(Path::new("/clips/it's.mp4")),
+            "file '/clips/it'\\''s.mp4'"
+        );
+    }
+
     #[test]
     fn test_time_format_parse_seconds() {
         let result = TimeFormat::parse("10.5").unwrap();
@@ -1251,6 +2066,25 @@ mod tests {
         assert_eq!(parse_timestamp_to_seconds("00:01:30.5").unwrap(), 90.5);
     }
 
+    #[test]
+    fn test_parse_cue_range() {
+        assert_eq!(
+            parse_cue_range("00:05:25.920 --> 00:05:46.060"),
+            Some(("00:05:25.920".to_string(), "00:05:46.060".to_string()))
+        );
+        assert_eq!(
+            parse_cue_range(" 00:00:01,000 --> 00:00:02,500 align:start "),
+            Some(("00:00:01,000".to_string(), "00:00:02,500".to_string()))
+        );
+        assert_eq!(parse_cue_range("00:05:25.920"), None);
+        assert_eq!(parse_cue_range(" --> 00:00:02.000"), None);
+    }
+
+    #[test]
+    fn test_parse_timestamp_to_seconds_srt_comma() {
+        assert_eq!(parse_timestamp_to_seconds("00:01:30,500").unwrap(), 90.5);
+    }
+
     #[test]
     fn test_parse_timestamp_invalid_format() {
         assert!(parse_timestamp_to_seconds("invalid").is_err());
@@ -1263,6 +2097,154 @@ mod tests {
         assert!(TimeFormat::parse("f").is_err());
     }
 
+    #[test]
+    fn test_parse_cropdetect_output() {
+        let stderr = "[Parsed_cropdetect_0 @ 0x1] x1:0 x2:1919 y1:138 y2:941 w:1920 h:800 x:0 y:140 pts:1 t:0.04 crop=1920:800:0:140
+[Parsed_cropdetect_0 @ 0x1] x1:0 x2:1919 y1:136 y2:943 w:1920 h:800 x:0 y:140 pts:2 t:0.08 crop=1920:804:0:138";
+        assert_eq!(
+            parse_cropdetect_output(stderr),
+            Some("crop=1920:804:0:138".to_string())
+        );
+        assert_eq!(parse_cropdetect_output("no crop here"), None);
+    }
+
+    #[test]
+    fn test_two_pass_args() {
+        assert_eq!(two_pass_bitrate_kbps(8.0, 16.0), 4096 - TWO_PASS_AUDIO_KBPS);
+        assert_eq!(two_pass_bitrate_kbps(0.01, 60.0), 1);
+
+        let args: Vec<String> = [
+            "-i", "in.mp4", "-crf", "28", "-c:a", "copy", "-y", "out.mp4",
+        ]
+        .map(String::from)
+        .to_vec();
+        let (first, second) = two_pass_args(&args, 4096, Path::new("/tmp/log")).unwrap();
+
+        assert!(!first.contains(&"-crf".to_string()));
+        assert!(!first.contains(&"out.mp4".to_string()));
+        assert_eq!(
+            first[first.len() - 8..first.len() - 1],
+            [
+                "-pass",
+                "1",
+                "-passlogfile",
+                "/tmp/log",
+                "-an",
+                "-f",
+                "null"
+            ]
+        );
+        assert_eq!(
+            second,
+            [
+                "-i",
+                "in.mp4",
+                "-y",
+                "-b:v",
+                "4096k",
+                "-pass",
+                "2",
+                "-passlogfile",
+                "/tmp/log",
+                "-c:a",
+                "aac",
+                "-b:a",
+                "128k",
+                "out.mp4"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_aspect_ratio() {
+        assert_eq!(
+            AspectRatio::parse("16:9"),
+            Ok(AspectRatio {
+                width: 16,
+                height: 9
+            })
+        );
+        assert!(AspectRatio::parse("16x9").is_err());
+        assert!(AspectRatio::parse("0:1").is_err());
+
+        let filter = aspect_ratio_filter(AspectRatio::parse("1:1").unwrap());
+        assert!(filter.starts_with("scale='trunc(iw*sar/2)*2':ih,setsar=1,pad="));
+        assert!(filter.ends_with(",setdar=1/1"));
+    }
+
+    #[test]
+    fn test_portrait_filter() {
+        let blur = portrait_filter(PortraitBackground::Blur);
+        assert!(blur.starts_with("split[portrait_bg][portrait_fg];"));
+        assert!(blur.contains("boxblur"));
+        assert!(blur.contains("overlay=(W-w)/2:(H-h)/2"));
+
+        assert_eq!(
+            portrait_filter(PortraitBackground::Black),
+            "scale=1080:1920:force_original_aspect_ratio=decrease,pad=1080:1920:(ow-iw)/2:(oh-ih)/2:color=black,setsar=1"
+        );
+        assert_eq!(
+            PortraitBackground::from_name("white"),
+            Some(PortraitBackground::White)
+        );
+        assert_eq!(PortraitBackground::from_name("green"), None);
+    }
+
+    #[test]
+    fn test_evict_previews_removes_oldest_first() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let now = std::time::SystemTime::now();
+
+        for (i, name) in ["oldest.mp4", "middle.mp4", "newest.mp4"]
+            .iter()
+            .enumerate()
+        {
+            let path = temp_dir.path().join(name);
+            fs::write(&path, vec![0u8; 100]).unwrap();
+            let age = std::time::Duration::from_secs(60 * (3 - i as u64));
+            fs::File::options()
+                .append(true)
+                .open(&path)
+                .unwrap()
+                .set_modified(now - age)
+                .unwrap();
+        }
+
+        evict_previews(temp_dir.path(), 250).unwrap();
+
+        assert!(!temp_dir.path().join("oldest.mp4").exists());
+        assert!(temp_dir.path().join("middle.mp4").exists());
+        assert!(temp_dir.path().join("newest.mp4").exists());
+    }
+
+    #[test]
+    fn test_prepend_video_filter() {
+        let args: Vec<String> = ["-i", "in.mp4", "-vf", "fps=10", "out.gif"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let args = prepend_video_filter(args, "crop=100:50:0:10");
+        assert_eq!(args[3], "crop=100:50:0:10,fps=10");
+
+        let args: Vec<String> = ["-i", "in.mp4", "-c:v", "libx264", "out.mp4"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let args = prepend_video_filter(args, "crop=100:50:0:10");
+        assert_eq!(
+            args,
+            vec![
+                "-i",
+                "in.mp4",
+                "-vf",
+                "crop=100:50:0:10",
+                "-c:v",
+                "libx264",
+                "out.mp4"
+            ]
+        );
+    }
+
     #[test]
     fn test_time_format_parse_invalid_seconds() {
         assert!(TimeFormat::parse("invalid").is_err());
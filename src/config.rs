@@ -4,8 +4,9 @@
 use crate::auth::AuthGuard;
 use crate::files;
 use crate::web::ApiResponse;
+use rocket::response::status::BadRequest;
 use rocket::serde::json::Json;
-use rocket::{get, post};
+use rocket::{get, patch, post};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::path::Path;
@@ -19,6 +20,42 @@ fn default_stream_chunk_size() -> u32 {
     60
 }
 
+fn default_processing_timeout_secs() -> u64 {
+    3600
+}
+
+fn default_inter_processing_delay_secs() -> u32 {
+    0
+}
+
+fn default_watch_poll_interval_secs() -> u64 {
+    10
+}
+
+fn default_min_video_size_bytes() -> u64 {
+    1048576
+}
+
+fn default_session_timeout_mins() -> u64 {
+    1440
+}
+
+fn default_preview_cache_size_mb() -> u64 {
+    500
+}
+
+fn default_exclusive_lock_timeout_secs() -> u64 {
+    30
+}
+
+fn default_max_concurrent_transcriptions() -> u32 {
+    1
+}
+
+fn default_access_log_format() -> String {
+    "json".to_string()
+}
+
 fn default_hostname() -> String {
     "http://localhost:4620".to_string()
 }
@@ -64,6 +101,9 @@ fn default_color_text_highlight() -> String {
     "#ffffff".to_string()
 }
 
+/// Fields whose values are never echoed back when reporting config changes
+pub const SECRET_FIELDS: &[&str] = &["password"];
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AtciConfig {
     #[serde(default)]
@@ -87,6 +127,33 @@ pub struct AtciConfig {
     pub processing_failure_command: String,
     #[serde(default = "default_stream_chunk_size")]
     pub stream_chunk_size: u32,
+    #[serde(default = "default_processing_timeout_secs")]
+    pub processing_timeout_secs: u64,
+    #[serde(default = "default_inter_processing_delay_secs")]
+    pub inter_processing_delay_secs: u32,
+    #[serde(default = "default_watch_poll_interval_secs")]
+    pub watch_poll_interval_secs: u64,
+    #[serde(default = "default_min_video_size_bytes")]
+    pub min_video_size_bytes: u64,
+    #[serde(default = "default_session_timeout_mins")]
+    pub session_timeout_mins: u64,
+    #[serde(default = "default_preview_cache_size_mb")]
+    pub preview_cache_size_mb: u64,
+    #[serde(default = "default_exclusive_lock_timeout_secs")]
+    pub exclusive_lock_timeout_secs: u64,
+    #[serde(default)]
+    pub access_log_path: String,
+    #[serde(default = "default_access_log_format")]
+    pub access_log_format: String,
+    #[serde(default)]
+    pub max_queue_size: Option<u32>,
+    #[serde(default)]
+    pub max_file_size_gb: Option<f32>,
+    #[serde(default = "default_max_concurrent_transcriptions")]
+    pub max_concurrent_transcriptions: u32,
+    /// Set by `config benchmark`: seconds of audio whisper transcribes per wall-clock second
+    #[serde(default)]
+    pub last_benchmark_speed_ratio: Option<f64>,
     #[serde(default = "default_hostname")]
     pub hostname: String,
     // Color configuration
@@ -132,6 +199,19 @@ impl Default for AtciConfig {
             processing_success_command: String::new(),
             processing_failure_command: String::new(),
             stream_chunk_size: 60,
+            processing_timeout_secs: 3600,
+            inter_processing_delay_secs: 0,
+            watch_poll_interval_secs: 10,
+            min_video_size_bytes: 1048576,
+            session_timeout_mins: 1440,
+            preview_cache_size_mb: 500,
+            exclusive_lock_timeout_secs: 30,
+            access_log_path: String::new(),
+            access_log_format: "json".to_string(),
+            max_queue_size: None,
+            max_file_size_gb: None,
+            max_concurrent_transcriptions: 1,
+            last_benchmark_speed_ratio: None,
             hostname: "http://localhost:4620".to_string(),
             color_buffer_bg: default_color_buffer_bg(),
             color_header_bg: default_color_header_bg(),
@@ -148,6 +228,11 @@ impl Default for AtciConfig {
 }
 
 pub fn load_config() -> Result<AtciConfig, confy::ConfyError> {
+    Ok(apply_env_overrides(load_stored_config()?))
+}
+
+/// The config as saved on disk, without environment overrides
+fn load_stored_config() -> Result<AtciConfig, confy::ConfyError> {
     if let Ok(config_path) = std::env::var("ATCI_CONFIG_PATH") {
         confy::load_path(&config_path)
     } else {
@@ -155,11 +240,243 @@ pub fn load_config() -> Result<AtciConfig, confy::ConfyError> {
     }
 }
 
+/// Config fields that have an ATCI_<FIELD_NAME> environment variable set, with its value
+fn env_overrides(cfg: &AtciConfig) -> Vec<(String, String)> {
+    let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(cfg) else {
+        return Vec::new();
+    };
+
+    fields
+        .keys()
+        .filter_map(|field| {
+            std::env::var(format!("ATCI_{}", field.to_uppercase()))
+                .ok()
+                .map(|value| (field.clone(), value))
+        })
+        .collect()
+}
+
+/// Override config fields with ATCI_<FIELD_NAME> environment variables when present.
+/// Values are parsed like `config set`, except lists which are ':'-separated.
+fn apply_env_overrides(mut cfg: AtciConfig) -> AtciConfig {
+    for (field, env_value) in env_overrides(&cfg) {
+        let result = match field.as_str() {
+            "watch_directories" => {
+                cfg.watch_directories = env_value
+                    .split(':')
+                    .filter(|s| !s.is_empty())
+                    .map(|s| s.to_string())
+                    .collect();
+                Ok(())
+            }
+            _ => set_config_field(&mut cfg, &field, &env_value),
+        };
+
+        if let Err(e) = result {
+            eprintln!(
+                "Warning: ignoring ATCI_{}={}: {}",
+                field.to_uppercase(),
+                env_value,
+                e
+            );
+        }
+    }
+
+    cfg
+}
+
+/// Put back the on-disk value of any field that still holds its environment override, so
+/// overrides such as ATCI_PASSWORD never get written to the config file. Fields the caller
+/// changed away from the override are saved as usual.
+fn without_env_overrides(config: &AtciConfig) -> AtciConfig {
+    let Ok(stored) = load_stored_config() else {
+        return config.clone();
+    };
+    let overridden = apply_env_overrides(stored.clone());
+    let (
+        Ok(serde_json::Value::Object(mut fields)),
+        Ok(serde_json::Value::Object(overridden_fields)),
+        Ok(serde_json::Value::Object(stored_fields)),
+    ) = (
+        serde_json::to_value(config),
+        serde_json::to_value(&overridden),
+        serde_json::to_value(&stored),
+    )
+    else {
+        return stored;
+    };
+
+    let mut changed = false;
+    for (field, _) in env_overrides(&stored) {
+        if fields.get(&field) == overridden_fields.get(&field)
+            && let Some(stored_value) = stored_fields.get(&field)
+        {
+            fields.insert(field, stored_value.clone());
+            changed = true;
+        }
+    }
+
+    if !changed {
+        return config.clone();
+    }
+
+    // Falling back to the stored config loses this save rather than leaking an override
+    serde_json::from_value(serde_json::Value::Object(fields)).unwrap_or(stored)
+}
+
 pub fn load_config_or_default() -> AtciConfig {
     load_config().unwrap_or_default()
 }
 
-pub fn store_config(config: &AtciConfig) -> Result<(), confy::ConfyError> {
+/// A config value that is likely to cause problems at runtime
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigError {
+    pub field: &'static str,
+    pub value: String,
+    pub description: String,
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} = {:?}: {}", self.field, self.value, self.description)
+    }
+}
+
+pub trait Validate {
+    fn validate(&self) -> Vec<ConfigError>;
+}
+
+impl Validate for AtciConfig {
+    fn validate(&self) -> Vec<ConfigError> {
+        let mut errors = Vec::new();
+        let mut invalid = |field: &'static str, value: String, description: &str| {
+            errors.push(ConfigError {
+                field,
+                value,
+                description: description.to_string(),
+            });
+        };
+
+        // Bare command names are looked up on PATH, so only check values that look like paths
+        for (field, value) in [
+            ("ffmpeg_path", &self.ffmpeg_path),
+            ("ffprobe_path", &self.ffprobe_path),
+            ("whispercli_path", &self.whispercli_path),
+        ] {
+            if Path::new(value).components().count() > 1 && !Path::new(value).is_file() {
+                invalid(field, value.clone(), "file does not exist");
+            }
+        }
+
+        for dir in &self.watch_directories {
+            if !Path::new(dir).is_dir() {
+                invalid("watch_directories", dir.clone(), "directory does not exist");
+            }
+        }
+
+        for (field, value) in [
+            ("stream_chunk_size", self.stream_chunk_size as u64),
+            ("processing_timeout_secs", self.processing_timeout_secs),
+            ("watch_poll_interval_secs", self.watch_poll_interval_secs),
+            (
+                "max_concurrent_transcriptions",
+                self.max_concurrent_transcriptions as u64,
+            ),
+        ] {
+            if value == 0 {
+                invalid(field, value.to_string(), "must be greater than 0");
+            }
+        }
+
+        if !self.hostname.starts_with("http://") && !self.hostname.starts_with("https://") {
+            invalid(
+                "hostname",
+                self.hostname.clone(),
+                "must start with http:// or https://",
+            );
+        }
+
+        for (field, value) in [
+            ("color_buffer_bg", &self.color_buffer_bg),
+            ("color_header_bg", &self.color_header_bg),
+            ("color_text_primary", &self.color_text_primary),
+            ("color_border_primary", &self.color_border_primary),
+            ("color_selection", &self.color_selection),
+            ("color_success", &self.color_success),
+            ("color_disabled", &self.color_disabled),
+            ("color_info", &self.color_info),
+            ("color_error", &self.color_error),
+            ("color_text_highlight", &self.color_text_highlight),
+        ] {
+            if let Err(e) = validate_hex_color(value) {
+                invalid(field, value.clone(), &e);
+            }
+        }
+
+        if let Some(max_gb) = self.max_file_size_gb
+            && max_gb <= 0.0
+        {
+            invalid(
+                "max_file_size_gb",
+                max_gb.to_string(),
+                "must be greater than 0",
+            );
+        }
+
+        if !matches!(self.access_log_format.as_str(), "json" | "combined") {
+            invalid(
+                "access_log_format",
+                self.access_log_format.clone(),
+                "must be json or combined",
+            );
+        }
+
+        if let Some(mismatch) = ffmpeg_version_mismatch(&self.ffmpeg_path, &self.ffprobe_path) {
+            invalid("ffprobe_path", self.ffprobe_path.clone(), &mismatch);
+        }
+
+        errors
+    }
+}
+
+/// The version from the first line of `<tool> -version`, e.g. "6.1.1" from
+/// "ffprobe version 6.1.1 Copyright (c) 2007-2023 the FFmpeg developers"
+fn parse_ffmpeg_version(output: &str) -> Option<String> {
+    let first_line = output.lines().next()?;
+    let (_, rest) = first_line.split_once(" version ")?;
+    rest.split_whitespace().next().map(|v| v.to_string())
+}
+
+fn tool_version(path: &str) -> Option<String> {
+    let output = Command::new(path).arg("-version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_ffmpeg_version(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Describe the mismatch when ffmpeg and ffprobe come from different FFmpeg builds,
+/// which can differ in codec support. Tools that can't be run are left to other checks.
+pub fn ffmpeg_version_mismatch(ffmpeg_path: &str, ffprobe_path: &str) -> Option<String> {
+    if ffmpeg_path.is_empty() || ffprobe_path.is_empty() {
+        return None;
+    }
+    let ffmpeg_version = tool_version(ffmpeg_path)?;
+    let ffprobe_version = tool_version(ffprobe_path)?;
+    (ffmpeg_version != ffprobe_version).then(|| {
+        format!(
+            "is ffprobe version {} but ffmpeg_path is version {}; use both from the same build",
+            ffprobe_version, ffmpeg_version
+        )
+    })
+}
+
+/// Save the config, returning any invalid values for the caller to report. Invalid values
+/// are still saved so a half-finished setup can be completed later.
+pub fn store_config(config: &AtciConfig) -> Result<Vec<ConfigError>, confy::ConfyError> {
+    let errors = config.validate();
+
+    let config = &without_env_overrides(config);
     let result;
     if let Ok(config_path) = std::env::var("ATCI_CONFIG_PATH") {
         result = confy::store_path(&config_path, config)
@@ -169,7 +486,15 @@ pub fn store_config(config: &AtciConfig) -> Result<(), confy::ConfyError> {
 
     let _ = files::get_and_save_video_info_from_disk();
 
-    result
+    result.map(|()| errors)
+}
+
+/// `store_config` for CLI commands, printing invalid values as warnings
+pub fn store_config_and_warn(config: &AtciConfig) -> Result<(), confy::ConfyError> {
+    for error in store_config(config)? {
+        eprintln!("Warning: invalid config value {}", error);
+    }
+    Ok(())
 }
 
 pub fn get_config_path_sha() -> String {
@@ -212,6 +537,8 @@ pub fn set_config_field(cfg: &mut AtciConfig, field: &str, value: &str) -> Resul
         "password" => cfg.password = Some(value.to_string()),
         "processing_success_command" => cfg.processing_success_command = value.to_string(),
         "processing_failure_command" => cfg.processing_failure_command = value.to_string(),
+        "access_log_path" => cfg.access_log_path = value.to_string(),
+        "access_log_format" => cfg.access_log_format = value.to_string(),
         "watch_directories" => {
             // For watch_directories, treat the value as a single directory to add
             if !cfg.watch_directories.contains(&value.to_string()) {
@@ -233,6 +560,80 @@ pub fn set_config_field(cfg: &mut AtciConfig, field: &str, value: &str) -> Resul
                 .parse::<u32>()
                 .map_err(|_| format!("Invalid number value for stream_chunk_size: {}", value))?;
         }
+        "processing_timeout_secs" => {
+            cfg.processing_timeout_secs = value.parse::<u64>().map_err(|_| {
+                format!(
+                    "Invalid number value for processing_timeout_secs: {}",
+                    value
+                )
+            })?;
+        }
+        "inter_processing_delay_secs" => {
+            cfg.inter_processing_delay_secs = value.parse::<u32>().map_err(|_| {
+                format!(
+                    "Invalid number value for inter_processing_delay_secs: {}",
+                    value
+                )
+            })?;
+        }
+        "watch_poll_interval_secs" => {
+            let secs = value.parse::<u64>().map_err(|_| {
+                format!(
+                    "Invalid number value for watch_poll_interval_secs: {}",
+                    value
+                )
+            })?;
+            if secs == 0 {
+                return Err("watch_poll_interval_secs must be greater than 0".to_string());
+            }
+            cfg.watch_poll_interval_secs = secs;
+        }
+        "min_video_size_bytes" => {
+            cfg.min_video_size_bytes = value
+                .parse::<u64>()
+                .map_err(|_| format!("Invalid number value for min_video_size_bytes: {}", value))?;
+        }
+        "max_queue_size" if value.trim().is_empty() => cfg.max_queue_size = None,
+        "max_queue_size" => {
+            cfg.max_queue_size = Some(
+                value
+                    .parse::<u32>()
+                    .map_err(|_| format!("Invalid number value for max_queue_size: {}", value))?,
+            );
+        }
+        "max_file_size_gb" if value.trim().is_empty() => cfg.max_file_size_gb = None,
+        "max_file_size_gb" => {
+            cfg.max_file_size_gb =
+                Some(value.parse::<f32>().map_err(|_| {
+                    format!("Invalid number value for max_file_size_gb: {}", value)
+                })?);
+        }
+        "session_timeout_mins" => {
+            cfg.session_timeout_mins = value
+                .parse::<u64>()
+                .map_err(|_| format!("Invalid number value for session_timeout_mins: {}", value))?;
+        }
+        "preview_cache_size_mb" => {
+            cfg.preview_cache_size_mb = value.parse::<u64>().map_err(|_| {
+                format!("Invalid number value for preview_cache_size_mb: {}", value)
+            })?;
+        }
+        "exclusive_lock_timeout_secs" => {
+            cfg.exclusive_lock_timeout_secs = value.parse::<u64>().map_err(|_| {
+                format!(
+                    "Invalid number value for exclusive_lock_timeout_secs: {}",
+                    value
+                )
+            })?;
+        }
+        "max_concurrent_transcriptions" => {
+            cfg.max_concurrent_transcriptions = value.parse::<u32>().map_err(|_| {
+                format!(
+                    "Invalid number value for max_concurrent_transcriptions: {}",
+                    value
+                )
+            })?;
+        }
         "hostname" => cfg.hostname = value.to_string(),
         "color_buffer_bg" => cfg.color_buffer_bg = validate_hex_color(value)?,
         "color_header_bg" => cfg.color_header_bg = validate_hex_color(value)?,
@@ -271,16 +672,80 @@ fn validate_hex_color(hex: &str) -> Result<String, String> {
     Ok(hex.to_string())
 }
 
+fn config_saved_message(errors: &[ConfigError]) -> String {
+    if errors.is_empty() {
+        "Config updated successfully".to_string()
+    } else {
+        let errors: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+        format!("Config updated with invalid values: {}", errors.join("; "))
+    }
+}
+
 #[post("/api/config", data = "<config>")]
 pub fn web_set_config(_auth: AuthGuard, config: Json<AtciConfig>) -> Json<ApiResponse<String>> {
     match store_config(&config) {
-        Ok(()) => Json(ApiResponse::success(
-            "Config updated successfully".to_string(),
-        )),
+        Ok(errors) => Json(ApiResponse::success(config_saved_message(&errors))),
         Err(e) => Json(ApiResponse::error(format!("Error saving config: {}", e))),
     }
 }
 
+/// Unset one of the optional fields, as a JSON `null` does in a PATCH
+fn clear_optional_field(cfg: &mut AtciConfig, field: &str) -> Result<(), String> {
+    match field {
+        "password" => cfg.password = None,
+        "max_queue_size" => cfg.max_queue_size = None,
+        "max_file_size_gb" => cfg.max_file_size_gb = None,
+        _ => return Err(format!("{} can't be unset", field)),
+    }
+    Ok(())
+}
+
+/// Apply a partial update from the web UI. Strings go through `set_config_field`,
+/// JSON numbers and booleans are parsed the same way, and `null` unsets optional fields.
+fn apply_config_patch(
+    cfg: &mut AtciConfig,
+    fields: &serde_json::Map<String, serde_json::Value>,
+) -> Result<(), String> {
+    for (field, value) in fields.iter() {
+        match (field.as_str(), value) {
+            (_, serde_json::Value::Null) => clear_optional_field(cfg, field)?,
+            ("watch_directories", serde_json::Value::Array(dirs)) => {
+                cfg.watch_directories.clear();
+                dirs.iter().try_for_each(|dir| match dir.as_str() {
+                    Some(dir) => set_config_field(cfg, field, dir),
+                    None => Err(format!("Invalid value for {}: {}", field, dir)),
+                })?
+            }
+            (_, serde_json::Value::String(value)) => set_config_field(cfg, field, value)?,
+            (_, serde_json::Value::Bool(_) | serde_json::Value::Number(_)) => {
+                set_config_field(cfg, field, &value.to_string())?
+            }
+            _ => return Err(format!("Invalid value for {}: {}", field, value)),
+        }
+    }
+    Ok(())
+}
+
+#[patch("/api/config", data = "<fields>")]
+pub fn web_patch_config(
+    _auth: AuthGuard,
+    fields: Json<serde_json::Map<String, serde_json::Value>>,
+) -> Result<Json<ApiResponse<String>>, BadRequest<Json<ApiResponse<String>>>> {
+    let mut cfg = load_config_or_default();
+
+    if let Err(e) = apply_config_patch(&mut cfg, &fields) {
+        return Err(BadRequest(Json(ApiResponse::error(e))));
+    }
+
+    match store_config(&cfg) {
+        Ok(errors) => Ok(Json(ApiResponse::success(config_saved_message(&errors)))),
+        Err(e) => Ok(Json(ApiResponse::error(format!(
+            "Error saving config: {}",
+            e
+        )))),
+    }
+}
+
 /// Execute a command with the video file path piped as input in detached mode
 /// The command will continue running after atci exits
 pub fn execute_processing_command(
@@ -354,3 +819,53 @@ pub fn execute_processing_command(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patch(json: &str) -> serde_json::Map<String, serde_json::Value> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_patch_null_unsets_optional_fields() {
+        let mut cfg = AtciConfig {
+            password: Some("secret".to_string()),
+            max_queue_size: Some(10),
+            max_file_size_gb: Some(2.5),
+            ..Default::default()
+        };
+
+        apply_config_patch(
+            &mut cfg,
+            &patch(r#"{"password": null, "max_queue_size": null, "max_file_size_gb": null}"#),
+        )
+        .unwrap();
+
+        assert_eq!(cfg.password, None);
+        assert_eq!(cfg.max_queue_size, None);
+        assert_eq!(cfg.max_file_size_gb, None);
+    }
+
+    #[test]
+    fn test_patch_null_rejects_required_fields() {
+        let mut cfg = AtciConfig::default();
+        let err = apply_config_patch(&mut cfg, &patch(r#"{"ffmpeg_path": null}"#)).unwrap_err();
+        assert_eq!(err, "ffmpeg_path can't be unset");
+    }
+
+    #[test]
+    fn test_patch_sets_numbers_and_strings() {
+        let mut cfg = AtciConfig::default();
+        apply_config_patch(
+            &mut cfg,
+            &patch(r#"{"max_queue_size": 5, "max_file_size_gb": "1.5", "allow_whisper": false}"#),
+        )
+        .unwrap();
+
+        assert_eq!(cfg.max_queue_size, Some(5));
+        assert_eq!(cfg.max_file_size_gb, Some(1.5));
+        assert!(!cfg.allow_whisper);
+    }
+}
@@ -11,8 +11,33 @@ pub fn get_db_path() -> std::path::PathBuf {
     home_dir.join(".atci").join(db_name)
 }
 
+/// Schema changes made after the baseline in `init_database`, applied in order by
+/// `migrate`. Append new entries here rather than editing the baseline so that
+/// existing databases keep their data; never change or renumber an applied one.
+const MIGRATIONS: &[(i64, &str)] = &[
+    (
+        1,
+        // Cancel requests target a single in-flight job; unscoped ones from before this
+        // can't be matched to a job any more
+        "ALTER TABLE cancel_requests ADD COLUMN path TEXT;
+         DELETE FROM cancel_requests WHERE path IS NULL;",
+    ),
+    (
+        2,
+        // A finished file keeps its slot while the queue waits inter_processing_delay_secs
+        "ALTER TABLE currently_processing ADD COLUMN cooling_down_until TEXT;",
+    ),
+    (
+        3,
+        // The video's duration, probed once so progress polls don't run ffprobe each time
+        "ALTER TABLE currently_processing ADD COLUMN duration_secs REAL;",
+    ),
+];
+
+/// Baseline schema. A database from before versioned migrations, or one with an
+/// unknown baseline, is dropped and recreated here.
 fn init_database(conn: &Connection) -> SqliteResult<()> {
-    const SCHEMA_VERSION: &str = "20251016-1";
+    const SCHEMA_VERSION: &str = "20251016-7";
 
     // Create schema_version table if it doesn't exist
     conn.execute(
@@ -37,6 +62,11 @@ fn init_database(conn: &Connection) -> SqliteResult<()> {
         conn.execute("DROP TABLE IF EXISTS currently_processing", [])?;
         conn.execute("DROP TABLE IF EXISTS video_parts", [])?;
         conn.execute("DROP TABLE IF EXISTS cancel_requests", [])?;
+        conn.execute("DROP TABLE IF EXISTS crop_detections", [])?;
+        conn.execute("DROP TABLE IF EXISTS processing_history", [])?;
+        conn.execute("DROP TABLE IF EXISTS transcript_edit_locks", [])?;
+        conn.execute("DROP TABLE IF EXISTS search_cache", [])?;
+        conn.execute("DROP TABLE IF EXISTS schema_versions", [])?;
         conn.execute("DROP TABLE IF EXISTS schema_version", [])?;
 
         // Recreate schema_version table
@@ -66,7 +96,8 @@ fn init_database(conn: &Connection) -> SqliteResult<()> {
                 last_generated TEXT,
                 duration TEXT,
                 source TEXT,
-                watch_directory TEXT
+                watch_directory TEXT,
+                atci_version TEXT
             )",
             [],
         )?;
@@ -115,6 +146,83 @@ fn init_database(conn: &Connection) -> SqliteResult<()> {
             )",
             [],
         )?;
+
+        // Create crop_detections table
+        conn.execute(
+            "CREATE TABLE crop_detections (
+                video_path TEXT PRIMARY KEY,
+                modified_at TEXT NOT NULL,
+                crop TEXT NOT NULL
+            )",
+            [],
+        )?;
+
+        // Create processing_history table
+        conn.execute(
+            "CREATE TABLE processing_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                path TEXT NOT NULL,
+                outcome TEXT NOT NULL,
+                finished_at TEXT NOT NULL,
+                warning TEXT
+            )",
+            [],
+        )?;
+
+        // Create transcript_edit_locks table
+        conn.execute(
+            "CREATE TABLE transcript_edit_locks (
+                video_path TEXT PRIMARY KEY,
+                locked_at INTEGER NOT NULL
+            )",
+            [],
+        )?;
+
+        // Create search_cache table
+        conn.execute(
+            "CREATE TABLE search_cache (
+                query_hash TEXT NOT NULL,
+                filter_hash TEXT NOT NULL,
+                result_json TEXT NOT NULL,
+                created_at DATETIME NOT NULL,
+                PRIMARY KEY (query_hash, filter_hash)
+            )",
+            [],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Apply every migration in `MIGRATIONS` that this database hasn't seen yet
+pub fn migrate(conn: &Connection) -> SqliteResult<()> {
+    apply_migrations(conn, MIGRATIONS)
+}
+
+fn apply_migrations(conn: &Connection, migrations: &[(i64, &str)]) -> SqliteResult<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS schema_versions (
+            version INTEGER PRIMARY KEY,
+            applied_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    let applied: i64 = conn.query_row(
+        "SELECT COALESCE(MAX(version), 0) FROM schema_versions",
+        [],
+        |row| row.get(0),
+    )?;
+
+    for (version, sql) in migrations.iter().filter(|(version, _)| *version > applied) {
+        // Each migration and its version row land together or not at all
+        let tx = conn.unchecked_transaction()?;
+        tx.execute_batch(sql)?;
+        tx.execute(
+            "INSERT INTO schema_versions (version, applied_at) VALUES (?1, ?2)",
+            (version, chrono::Utc::now().to_rfc3339()),
+        )?;
+        tx.commit()?;
     }
 
     Ok(())
@@ -127,5 +235,73 @@ pub fn get_connection() -> SqliteResult<Connection> {
     }
     let conn = Connection::open(db_path)?;
     init_database(&conn)?;
+    migrate(&conn)?;
+    Ok(conn)
+}
+
+/// A fresh in-memory database with the full schema, for tests
+#[cfg(test)]
+pub fn open_in_memory() -> SqliteResult<Connection> {
+    let conn = Connection::open_in_memory()?;
+    init_database(&conn)?;
+    migrate(&conn)?;
     Ok(conn)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_MIGRATIONS: &[(i64, &str)] = &[
+        (
+            1,
+            "CREATE TABLE notes (id INTEGER PRIMARY KEY, body TEXT NOT NULL)",
+        ),
+        (
+            2,
+            "ALTER TABLE notes ADD COLUMN pinned BOOLEAN NOT NULL DEFAULT 0",
+        ),
+    ];
+
+    fn applied_versions(conn: &Connection) -> Vec<i64> {
+        let mut stmt = conn
+            .prepare("SELECT version FROM schema_versions ORDER BY version")
+            .unwrap();
+        stmt.query_map([], |row| row.get(0))
+            .unwrap()
+            .map(|v| v.unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_migrations_apply_in_order_and_keep_data() {
+        let conn = Connection::open_in_memory().unwrap();
+        apply_migrations(&conn, &TEST_MIGRATIONS[..1]).unwrap();
+        conn.execute("INSERT INTO notes (body) VALUES ('kept')", [])
+            .unwrap();
+
+        apply_migrations(&conn, TEST_MIGRATIONS).unwrap();
+        assert_eq!(applied_versions(&conn), vec![1, 2]);
+
+        let (body, pinned): (String, bool) = conn
+            .query_row("SELECT body, pinned FROM notes", [], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+        assert_eq!(body, "kept");
+        assert!(!pinned);
+
+        // Running again is a no-op
+        apply_migrations(&conn, TEST_MIGRATIONS).unwrap();
+        assert_eq!(applied_versions(&conn), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_failed_migration_is_not_recorded() {
+        let conn = Connection::open_in_memory().unwrap();
+        let broken: &[(i64, &str)] = &[(1, "CREATE TABLE ok (id INTEGER); NOT VALID SQL")];
+
+        assert!(apply_migrations(&conn, broken).is_err());
+        assert!(applied_versions(&conn).is_empty());
+    }
+}
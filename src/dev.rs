@@ -31,7 +31,7 @@ pub async fn download_test_content(dir: Option<PathBuf>) -> Result<(), Box<dyn s
     let target_str = target_dir.to_string_lossy().to_string();
     if !updated_config.watch_directories.contains(&target_str) {
         updated_config.watch_directories.push(target_str.clone());
-        crate::config::store_config(&updated_config)?;
+        crate::config::store_config_and_warn(&updated_config)?;
         println!("Added {} to watch directories", target_str);
     } else {
         println!("Directory already in watch list");
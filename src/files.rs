@@ -9,10 +9,12 @@ use crate::web::ApiResponse;
 use chrono::{DateTime, Local};
 use globset::{Glob, GlobSetBuilder};
 use rayon::prelude::*;
-use rocket::get;
 use rocket::serde::json::Json;
+use rocket::{get, post};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::fs;
+use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -27,6 +29,43 @@ pub struct VideoInfo {
     pub length: Option<String>,
     pub source: Option<String>,
     pub watch_directory: Option<String>,
+    pub atci_version: Option<String>,
+}
+
+const CSV_HEADER: &str =
+    "name,base_name,created_at,line_count,full_path,transcript,last_generated,length,source";
+
+pub fn csv_field(value: &str, always_quote: bool) -> String {
+    if always_quote || value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Render video info as CSV with one header row followed by one row per file
+pub fn video_infos_to_csv(video_infos: &[VideoInfo]) -> String {
+    let mut output = String::from(CSV_HEADER);
+    output.push('\n');
+
+    for info in video_infos {
+        let row = [
+            csv_field(&info.name, false),
+            csv_field(&info.base_name, false),
+            csv_field(&info.created_at, false),
+            info.line_count.to_string(),
+            csv_field(&info.full_path, false),
+            info.transcript.to_string(),
+            csv_field(info.last_generated.as_deref().unwrap_or_default(), false),
+            csv_field(info.length.as_deref().unwrap_or_default(), false),
+            // source can name several models or subtitle streams, so always quote it
+            csv_field(info.source.as_deref().unwrap_or_default(), true),
+        ];
+        output.push_str(&row.join(","));
+        output.push('\n');
+    }
+
+    output
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -45,14 +84,14 @@ fn format_datetime(timestamp: std::time::SystemTime) -> String {
 
 pub fn get_video_extensions() -> Vec<&'static str> {
     vec![
-        "mp4", "avi", "mov", "mkv", "wmv", "flv", "webm", "m4v", "ts",
+        "mp4", "avi", "mov", "mkv", "wmv", "flv", "webm", "m4v", "ts", "m2ts", "mts", "mxf", "dv",
     ]
 }
 
 pub fn load_cache_data() -> Result<CacheData, Box<dyn std::error::Error>> {
     let conn = db::get_connection()?;
 
-    let mut stmt = conn.prepare("SELECT name, base_name, created_at, line_count, full_path, transcript, last_generated, duration, source, watch_directory FROM video_info ORDER BY created_at DESC")?;
+    let mut stmt = conn.prepare("SELECT name, base_name, created_at, line_count, full_path, transcript, last_generated, duration, source, watch_directory, atci_version FROM video_info ORDER BY created_at DESC")?;
     let video_iter = stmt.query_map([], |row| {
         Ok(VideoInfo {
             name: row.get(0)?,
@@ -65,6 +104,7 @@ pub fn load_cache_data() -> Result<CacheData, Box<dyn std::error::Error>> {
             length: row.get(7)?,
             source: row.get(8)?,
             watch_directory: row.get(9)?,
+            atci_version: row.get(10)?,
         })
     })?;
 
@@ -110,6 +150,7 @@ pub fn load_sorted_paginated_cache_data(
         "last_generated",
         "length",
         "source",
+        "atci_version",
     ];
     let sort_column = if valid_columns.contains(&sort_by) {
         sort_by
@@ -142,7 +183,7 @@ pub fn load_sorted_paginated_cache_data(
 
     // Build the SQL query with filtering, sorting and pagination
     let query = format!(
-        "SELECT name, base_name, created_at, line_count, full_path, transcript, last_generated, duration, source, watch_directory
+        "SELECT name, base_name, created_at, line_count, full_path, transcript, last_generated, duration, source, watch_directory, atci_version
          FROM video_info
          {}
          ORDER BY {} {}
@@ -165,6 +206,7 @@ pub fn load_sorted_paginated_cache_data(
             length: row.get(7)?,
             source: row.get(8)?,
             watch_directory: row.get(9)?,
+            atci_version: row.get(10)?,
         })
     };
 
@@ -223,6 +265,103 @@ pub fn load_sorted_paginated_cache_data(
     })
 }
 
+const FILE_LIST_DEFAULT_LIMIT: u32 = 25;
+const FILE_LIST_MAX_LIMIT: u32 = 200;
+
+#[derive(Serialize, Debug)]
+pub struct FileListPage {
+    pub total: u32,
+    pub limit: u32,
+    pub offset: u32,
+    pub items: Vec<VideoInfo>,
+}
+
+/// Map a /api/files/list `sort_by` value to its video_info column
+fn file_list_sort_column(sort_by: &str) -> Option<&'static str> {
+    match sort_by {
+        "name" => Some("name"),
+        "date" => Some("created_at"),
+        // Stored zero-padded as HH:MM:SS, so it sorts correctly as text
+        "duration" => Some("duration"),
+        "transcript_status" => Some("transcript"),
+        _ => None,
+    }
+}
+
+/// One page of the file cache for /api/files/list. `limit` is clamped to 1..=200.
+pub fn load_file_list_page(
+    limit: Option<u32>,
+    offset: Option<u32>,
+    sort_by: Option<&str>,
+    sort_order: Option<&str>,
+) -> Result<FileListPage, Box<dyn std::error::Error>> {
+    let conn = db::get_connection()?;
+    query_file_list_page(&conn, limit, offset, sort_by, sort_order)
+}
+
+fn query_file_list_page(
+    conn: &rusqlite::Connection,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    sort_by: Option<&str>,
+    sort_order: Option<&str>,
+) -> Result<FileListPage, Box<dyn std::error::Error>> {
+    let limit = limit
+        .unwrap_or(FILE_LIST_DEFAULT_LIMIT)
+        .clamp(1, FILE_LIST_MAX_LIMIT);
+    let offset = offset.unwrap_or(0);
+    let sort_by = sort_by.unwrap_or("date");
+    let sort_column = file_list_sort_column(sort_by).ok_or_else(|| {
+        format!(
+            "Invalid sort_by '{}', expected name, date, duration or transcript_status",
+            sort_by
+        )
+    })?;
+    let sort_direction = match sort_order.unwrap_or("desc") {
+        "asc" => "ASC",
+        "desc" => "DESC",
+        other => {
+            return Err(format!("Invalid sort_order '{}', expected asc or desc", other).into());
+        }
+    };
+
+    let total: u32 = conn.query_row("SELECT COUNT(*) FROM video_info", [], |row| row.get(0))?;
+
+    // full_path breaks ties so pages don't overlap when many rows share a sort value
+    let query = format!(
+        "SELECT name, base_name, created_at, line_count, full_path, transcript, last_generated, duration, source, watch_directory, atci_version
+         FROM video_info
+         ORDER BY {} {}, full_path ASC
+         LIMIT ?1 OFFSET ?2",
+        sort_column, sort_direction
+    );
+    let mut stmt = conn.prepare(&query)?;
+    let items = stmt
+        .query_map([limit, offset], |row| {
+            Ok(VideoInfo {
+                name: row.get(0)?,
+                base_name: row.get(1)?,
+                created_at: row.get(2)?,
+                line_count: row.get(3)?,
+                full_path: row.get(4)?,
+                transcript: row.get(5)?,
+                last_generated: row.get(6)?,
+                length: row.get(7)?,
+                source: row.get(8)?,
+                watch_directory: row.get(9)?,
+                atci_version: row.get(10)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(FileListPage {
+        total,
+        limit,
+        offset,
+        items,
+    })
+}
+
 #[allow(dead_code)]
 pub fn count_cache_records(
     filter: Option<&Vec<String>>,
@@ -265,6 +404,105 @@ pub fn count_cache_records(
     Ok(total_records)
 }
 
+/// Parse a cached `length` such as `01:02:03` into seconds
+pub fn parse_duration_to_seconds(duration_str: &str) -> Option<u64> {
+    let parts: Vec<&str> = duration_str.split(':').collect();
+    if parts.len() != 3 {
+        return None;
+    }
+
+    let hours = parts[0].parse::<u64>().ok()?;
+    let minutes = parts[1].parse::<u64>().ok()?;
+    let seconds = parts[2].parse::<u64>().ok()?;
+
+    Some(hours * 3600 + minutes * 60 + seconds)
+}
+
+/// Format seconds as `HH:MM:SS`, the same shape as a cached `length`
+pub fn format_seconds_to_duration(total_seconds: u64) -> String {
+    let hours = total_seconds / 3600;
+    let minutes = (total_seconds % 3600) / 60;
+    let seconds = total_seconds % 60;
+    format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+}
+
+/// A directory and how many cached videos it holds, for `LibraryStats::top_directories`
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct DirectoryCount {
+    pub directory: String,
+    pub count: usize,
+}
+
+/// Aggregate numbers about the cached library, printed by `atci stats`
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct LibraryStats {
+    pub total_videos: usize,
+    pub transcribed: usize,
+    pub pending: usize,
+    pub total_duration_seconds: u64,
+    pub total_duration: String,
+    pub oldest_video: Option<String>,
+    pub oldest_created_at: Option<String>,
+    pub newest_video: Option<String>,
+    pub newest_created_at: Option<String>,
+    pub top_directories: Vec<DirectoryCount>,
+    /// Mean line count across transcribed videos
+    pub average_line_count: f64,
+}
+
+const STATS_TOP_DIRECTORIES: usize = 5;
+
+pub fn library_stats(video_infos: &[VideoInfo]) -> LibraryStats {
+    let transcribed: Vec<&VideoInfo> = video_infos.iter().filter(|v| v.transcript).collect();
+
+    let total_duration_seconds = video_infos
+        .iter()
+        .filter_map(|v| v.length.as_deref().and_then(parse_duration_to_seconds))
+        .sum();
+
+    // created_at is `%Y-%m-%d %H:%M:%S`, so it orders correctly as a string
+    let dated = || video_infos.iter().filter(|v| v.created_at != "Unknown");
+    let oldest = dated().min_by(|a, b| a.created_at.cmp(&b.created_at));
+    let newest = dated().max_by(|a, b| a.created_at.cmp(&b.created_at));
+
+    let mut directory_counts: std::collections::HashMap<String, usize> =
+        std::collections::HashMap::new();
+    for video in video_infos {
+        let directory = Path::new(&video.full_path)
+            .parent()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_default();
+        *directory_counts.entry(directory).or_default() += 1;
+    }
+    let mut top_directories: Vec<DirectoryCount> = directory_counts
+        .into_iter()
+        .map(|(directory, count)| DirectoryCount { directory, count })
+        .collect();
+    top_directories.sort_by(|a, b| b.count.cmp(&a.count).then(a.directory.cmp(&b.directory)));
+    top_directories.truncate(STATS_TOP_DIRECTORIES);
+
+    let average_line_count = if transcribed.is_empty() {
+        0.0
+    } else {
+        let total_lines: usize = transcribed.iter().map(|v| v.line_count).sum();
+        (total_lines as f64 / transcribed.len() as f64 * 10.0).round() / 10.0
+    };
+
+    LibraryStats {
+        total_videos: video_infos.len(),
+        transcribed: transcribed.len(),
+        pending: video_infos.len() - transcribed.len(),
+        total_duration_seconds,
+        total_duration: format_seconds_to_duration(total_duration_seconds),
+        oldest_video: oldest.map(|v| v.full_path.clone()),
+        oldest_created_at: oldest.map(|v| v.created_at.clone()),
+        newest_video: newest.map(|v| v.full_path.clone()),
+        newest_created_at: newest.map(|v| v.created_at.clone()),
+        top_directories,
+        average_line_count,
+    }
+}
+
 pub fn load_video_info_from_cache(
     filter: Option<&Vec<String>>,
 ) -> Result<Vec<VideoInfo>, Box<dyn std::error::Error>> {
@@ -398,6 +636,7 @@ pub fn regenerate_watch_directory(watch_directory: &str) -> Result<(), Box<dyn s
                 length: None,
                 source: None,
                 watch_directory: Some(watch_directory.clone()),
+                atci_version: None,
             })
         })
         .collect();
@@ -414,7 +653,7 @@ pub fn regenerate_watch_directory(watch_directory: &str) -> Result<(), Box<dyn s
 
     // Insert new data for this watch directory
     {
-        let mut stmt = tx.prepare("INSERT INTO video_info (name, base_name, created_at, line_count, full_path, transcript, last_generated, duration, source, watch_directory) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)")?;
+        let mut stmt = tx.prepare("INSERT INTO video_info (name, base_name, created_at, line_count, full_path, transcript, last_generated, duration, source, watch_directory, atci_version) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)")?;
 
         for video in &video_infos {
             stmt.execute((
@@ -428,6 +667,7 @@ pub fn regenerate_watch_directory(watch_directory: &str) -> Result<(), Box<dyn s
                 &video.length,
                 &video.source,
                 &video.watch_directory,
+                &video.atci_version,
             ))?;
         }
     }
@@ -436,6 +676,101 @@ pub fn regenerate_watch_directory(watch_directory: &str) -> Result<(), Box<dyn s
     Ok(())
 }
 
+/// Whether a .txt file was written by atci: it has a metadata block or cue timing lines.
+/// Other .txt files that happen to live in a watch directory are left alone.
+fn is_atci_transcript(path: &Path) -> bool {
+    if path.to_string_lossy().ends_with(".progress.txt") {
+        return false;
+    }
+
+    crate::transcripts::read_transcript_file(path).is_ok_and(|content| {
+        content.lines().any(|line| {
+            line.trim() == ">>>.atcimetaend" || crate::transcripts::is_timestamp_line(line)
+        })
+    })
+}
+
+/// Finds atci transcripts in the watch directories whose video file no longer exists
+pub fn find_orphaned_transcripts() -> Vec<PathBuf> {
+    let cfg = config::load_config_or_default();
+    let video_extensions = get_video_extensions();
+
+    let mut video_stems = HashSet::new();
+    let mut transcripts = Vec::new();
+
+    for watch_directory in &cfg.watch_directories {
+        for entry in WalkDir::new(watch_directory)
+            .into_iter()
+            .filter_map(|e| e.ok())
+        {
+            let path = entry.path();
+            if !path.is_file() {
+                continue;
+            }
+
+            let Some(ext) = path.extension() else {
+                continue;
+            };
+            let ext_lower = ext.to_string_lossy().to_lowercase();
+            if ext_lower == "txt" {
+                transcripts.push(path.to_path_buf());
+            } else if video_extensions.contains(&ext_lower.as_str()) {
+                video_stems.insert(path.with_extension(""));
+            }
+        }
+    }
+
+    let mut orphans: Vec<PathBuf> = transcripts
+        .into_iter()
+        .filter(|txt| !video_stems.contains(&txt.with_extension("")))
+        .filter(|txt| is_atci_transcript(txt))
+        .collect();
+    orphans.sort();
+    orphans
+}
+
+/// Deletes an orphaned transcript along with its .meta file, returning the paths removed
+pub fn delete_orphaned_transcript(txt_path: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut removed = Vec::new();
+
+    fs::remove_file(txt_path)?;
+    removed.push(txt_path.to_path_buf());
+
+    let meta_path = txt_path.with_extension("meta");
+    if meta_path.exists() {
+        fs::remove_file(&meta_path)?;
+        removed.push(meta_path);
+    }
+
+    Ok(removed)
+}
+
+/// Drops a single video from the cache without rescanning the watch directories, for
+/// scripts that delete videos themselves. Errors if no entry has `path` as its full_path.
+pub fn remove_from_cache(path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let conn = db::get_connection()?;
+    let rows_affected = conn.execute("DELETE FROM video_info WHERE full_path = ?1", [path])?;
+    if rows_affected == 0 {
+        return Err(format!("{} is not in the file cache", path).into());
+    }
+    Ok(())
+}
+
+/// Same as get_and_save_video_info_from_disk, but limits the scan to `parallel` worker threads
+pub fn get_and_save_video_info_from_disk_parallel(
+    parallel: usize,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if parallel == 0 {
+        return Err("--parallel must be at least 1".into());
+    }
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(parallel)
+        .build()?;
+    pool.install(|| get_and_save_video_info_from_disk().map_err(|e| e.to_string()))?;
+    Ok(())
+}
+
 pub fn get_and_save_video_info_from_disk() -> Result<(), Box<dyn std::error::Error>> {
     let cfg = config::load_config_or_default();
     let video_extensions = get_video_extensions();
@@ -452,7 +787,7 @@ pub fn get_and_save_video_info_from_disk() -> Result<(), Box<dyn std::error::Err
         })
         .collect();
 
-    let video_infos: Vec<VideoInfo> = all_entries
+    let mut video_infos: Vec<VideoInfo> = all_entries
         .par_iter()
         .filter_map(|(entry, watch_directory)| {
             let file_path = entry.path();
@@ -496,7 +831,7 @@ pub fn get_and_save_video_info_from_disk() -> Result<(), Box<dyn std::error::Err
             let transcript_exists = txt_path.exists();
 
             let (line_count, last_generated) = if transcript_exists {
-                let line_count = fs::read_to_string(&txt_path)
+                let line_count = crate::transcripts::read_transcript_file(&txt_path)
                     .map(|content| content.lines().count())
                     .unwrap_or(0);
 
@@ -510,14 +845,11 @@ pub fn get_and_save_video_info_from_disk() -> Result<(), Box<dyn std::error::Err
                 (0, None)
             };
 
-            let (length, source) = if transcript_exists {
-                let metadata = metadata::get_metadata_fields(file_path);
-                (
-                    metadata.clone().unwrap().length.clone(),
-                    metadata.clone().unwrap().source.clone(),
-                )
+            let (length, source, atci_version) = if transcript_exists {
+                let metadata = metadata::get_metadata_fields(file_path).unwrap_or_default();
+                (metadata.length, metadata.source, metadata.atci_version)
             } else {
-                (None, None)
+                (None, None, None)
             };
 
             let created_at = metadata
@@ -537,10 +869,13 @@ pub fn get_and_save_video_info_from_disk() -> Result<(), Box<dyn std::error::Err
                 length,
                 source,
                 watch_directory: Some(watch_directory.clone()),
+                atci_version,
             })
         })
         .collect();
 
+    video_infos.sort_by(|a, b| a.full_path.cmp(&b.full_path));
+
     // Save to database in a transaction
     let conn = db::get_connection()?;
     let tx = conn.unchecked_transaction()?;
@@ -550,7 +885,7 @@ pub fn get_and_save_video_info_from_disk() -> Result<(), Box<dyn std::error::Err
 
     // Insert new data
     {
-        let mut stmt = tx.prepare("INSERT INTO video_info (name, base_name, created_at, line_count, full_path, transcript, last_generated, duration, source, watch_directory) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)")?;
+        let mut stmt = tx.prepare("INSERT INTO video_info (name, base_name, created_at, line_count, full_path, transcript, last_generated, duration, source, watch_directory, atci_version) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)")?;
 
         for video in &video_infos {
             stmt.execute((
@@ -564,10 +899,14 @@ pub fn get_and_save_video_info_from_disk() -> Result<(), Box<dyn std::error::Err
                 &video.length,
                 &video.source,
                 &video.watch_directory,
+                &video.atci_version,
             ))?;
         }
     }
 
+    // Transcripts may have changed along with the file list, so cached results are stale
+    tx.execute("DELETE FROM search_cache", [])?;
+
     tx.commit()?;
     Ok(())
 }
@@ -621,6 +960,55 @@ pub fn web_get_files(
     }
 }
 
+#[get("/api/files/list?<limit>&<offset>&<sort_by>&<sort_order>")]
+pub fn web_list_files(
+    _auth: AuthGuard,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    sort_by: Option<String>,
+    sort_order: Option<String>,
+) -> Json<ApiResponse<FileListPage>> {
+    match load_file_list_page(limit, offset, sort_by.as_deref(), sort_order.as_deref()) {
+        Ok(page) => Json(ApiResponse::success(page)),
+        Err(e) => Json(ApiResponse::error(format!("Failed to list files: {}", e))),
+    }
+}
+
+#[derive(Serialize)]
+pub struct RescanStarted {
+    pub status: &'static str,
+    pub task_id: String,
+}
+
+/// Refresh the file cache in the background. When the scan finishes a `rescan_complete`
+/// event carrying the task id and outcome is sent on /api/queue/stream.
+#[post("/api/files/rescan")]
+pub async fn web_rescan_files(_auth: AuthGuard) -> Json<ApiResponse<RescanStarted>> {
+    let task_id = uuid::Uuid::new_v4().to_string();
+
+    let event_task_id = task_id.clone();
+    tokio::spawn(async move {
+        let result = tokio::task::spawn_blocking(|| {
+            get_and_save_video_info_from_disk().map_err(|e| e.to_string())
+        })
+        .await
+        .unwrap_or_else(|e| Err(e.to_string()));
+
+        let payload = match result {
+            Ok(()) => serde_json::json!({ "task_id": event_task_id, "status": "completed" }),
+            Err(e) => {
+                serde_json::json!({ "task_id": event_task_id, "status": "failed", "error": e })
+            }
+        };
+        crate::queue::queue_event("rescan_complete", payload.to_string());
+    });
+
+    Json(ApiResponse::success(RescanStarted {
+        status: "started",
+        task_id,
+    }))
+}
+
 #[get("/api/sources")]
 pub fn web_get_sources(_auth: AuthGuard) -> Json<ApiResponse<serde_json::Value>> {
     match load_cache_data() {
@@ -633,3 +1021,64 @@ pub fn web_get_sources(_auth: AuthGuard) -> Json<ApiResponse<serde_json::Value>>
         ))),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `count` videos that all share a created_at, so only the full_path tie-break orders them
+    fn file_list_fixture(count: usize) -> rusqlite::Connection {
+        let conn = db::open_in_memory().unwrap();
+        for i in 0..count {
+            conn.execute(
+                "INSERT INTO video_info (name, base_name, created_at, line_count, full_path, transcript)
+                 VALUES (?1, ?1, '2025-01-01 00:00:00', 0, ?2, 0)",
+                (format!("video{}.mp4", i), format!("/videos/video{:02}.mp4", i)),
+            )
+            .unwrap();
+        }
+        conn
+    }
+
+    #[test]
+    fn test_file_list_page_clamps_limit() {
+        let conn = file_list_fixture(3);
+
+        let page = query_file_list_page(&conn, Some(0), None, None, None).unwrap();
+        assert_eq!(page.limit, 1);
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.total, 3);
+
+        let page = query_file_list_page(&conn, Some(500), None, None, None).unwrap();
+        assert_eq!(page.limit, FILE_LIST_MAX_LIMIT);
+        assert_eq!(page.items.len(), 3);
+    }
+
+    #[test]
+    fn test_file_list_page_rejects_invalid_sort() {
+        let conn = file_list_fixture(1);
+
+        let err = query_file_list_page(&conn, None, None, Some("size"), None).unwrap_err();
+        assert!(err.to_string().contains("Invalid sort_by 'size'"));
+
+        let err = query_file_list_page(&conn, None, None, None, Some("up")).unwrap_err();
+        assert!(err.to_string().contains("Invalid sort_order 'up'"));
+    }
+
+    #[test]
+    fn test_file_list_pages_do_not_overlap_on_ties() {
+        let conn = file_list_fixture(5);
+
+        let mut seen = Vec::new();
+        for offset in [0, 2, 4] {
+            let page =
+                query_file_list_page(&conn, Some(2), Some(offset), Some("date"), None).unwrap();
+            seen.extend(page.items.into_iter().map(|item| item.full_path));
+        }
+
+        let expected: Vec<String> = (0..5)
+            .map(|i| format!("/videos/video{:02}.mp4", i))
+            .collect();
+        assert_eq!(seen, expected);
+    }
+}
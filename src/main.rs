@@ -14,15 +14,16 @@
 // You should have received a copy of the GNU Affero General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::config::AtciConfig;
+use crate::config::{AtciConfig, Validate};
 use clap::{Parser, Subcommand};
-use dialoguer::{Input, Select};
+use dialoguer::{Confirm, Input, Select};
 use rust_embed::Embed;
 use std::collections::HashSet;
 use std::fs;
 use std::path::Path;
 use std::time::Duration;
 
+mod access_log;
 mod auth;
 mod clipper;
 mod config;
@@ -70,10 +71,14 @@ enum Commands {
     Clip {
         #[arg(help = "Path to the video file")]
         path: String,
-        #[arg(help = "Start time (seconds: 455.5, frames: 300f, timestamp: 01:30:15.5)")]
+        #[arg(
+            help = "Start time (seconds: 455.5, frames: 300f, timestamp: 01:30:15.5), or a whole transcript cue line \"00:05:25.920 --> 00:05:46.060\" to set both start and end"
+        )]
         start: String,
-        #[arg(help = "End time (seconds: 520.5, frames: 600f, timestamp: 01:35:20.0)")]
-        end: String,
+        #[arg(
+            help = "End time (seconds: 520.5, frames: 600f, timestamp: 01:35:20.0); omit it when start is a cue line"
+        )]
+        end: Option<String>,
         #[arg(help = "Optional text to overlay")]
         text: Option<String>,
         #[arg(long, help = "Display text overlay", default_value = "true")]
@@ -82,6 +87,41 @@ enum Commands {
         format: String,
         #[arg(long, help = "Font size for text overlay")]
         font_size: Option<u32>,
+        #[arg(long, help = "Detect and crop black bars from the source video")]
+        auto_crop: bool,
+        #[arg(
+            long,
+            help = "Reformat the clip as 1080x1920 portrait for social media"
+        )]
+        portrait: bool,
+        #[arg(long, help = "Fill style around the video in portrait mode", value_parser = ["blur", "black", "white"], default_value = "blur")]
+        portrait_background: String,
+        #[arg(
+            long,
+            help = "Pad the clip to this aspect ratio, e.g. 16:9, 4:3 or 1:1",
+            value_parser = clipper::AspectRatio::parse,
+            conflicts_with = "portrait"
+        )]
+        aspect_ratio: Option<clipper::AspectRatio>,
+        #[arg(
+            long,
+            help = "Use two-pass encoding to hit --target-size-mb (mp4 only)",
+            requires = "target_size_mb"
+        )]
+        two_pass: bool,
+        #[arg(long, help = "Target file size in megabytes for --two-pass")]
+        target_size_mb: Option<f32>,
+        #[arg(
+            short = 'o',
+            long,
+            help = "Write the clip to this path instead of an auto-generated one"
+        )]
+        output: Option<String>,
+        #[arg(
+            long,
+            help = "Also write a WebVTT chapters file (.vtt) next to the clip"
+        )]
+        chapters_file: bool,
     },
     #[command(about = "Extract a frame from a video with optional text overlay")]
     #[command(arg_required_else_help = true)]
@@ -94,6 +134,20 @@ enum Commands {
         text: Option<String>,
         #[arg(long, help = "Font size for text overlay")]
         font_size: Option<u32>,
+        #[arg(
+            short = 'o',
+            long,
+            help = "Write the frame to this path instead of a temporary file"
+        )]
+        output: Option<String>,
+    },
+    #[command(about = "Join clips end to end without re-encoding")]
+    #[command(arg_required_else_help = true)]
+    Concat {
+        #[arg(required = true, help = "Clips to join, in order")]
+        clips: Vec<String>,
+        #[arg(short, long, help = "Path to write the joined video to")]
+        output: String,
     },
     #[command(about = "Manage external tools and dependencies")]
     Tools {
@@ -142,6 +196,73 @@ enum Commands {
             default_value = "false"
         )]
         gif: bool,
+        #[arg(long, help = "Maximum number of matches to return")]
+        limit: Option<usize>,
+        #[arg(
+            long,
+            help = "Number of matches to skip before returning results",
+            default_value = "0"
+        )]
+        offset: usize,
+        #[arg(
+            long,
+            help = "Show the last 20 search queries instead of searching",
+            default_value = "false"
+        )]
+        history: bool,
+        #[arg(
+            long,
+            help = "Glob pattern for file paths to skip (can be specified multiple times)"
+        )]
+        exclude_pattern: Vec<String>,
+        #[arg(long, help = "Bypass the search result cache")]
+        no_cache: bool,
+        #[arg(
+            long,
+            help = "Comma-separated parts of each record that must all match the query",
+            value_delimiter = ',',
+            value_parser = ["name", "transcript", "metadata"],
+            default_value = "transcript"
+        )]
+        fields: Vec<String>,
+        #[arg(
+            long,
+            help = "Only search the video files listed in this file, one path per line"
+        )]
+        in_file: Option<String>,
+        #[arg(
+            long,
+            help = "Group matches under the file they came from (default)",
+            conflicts_with = "group_by_time"
+        )]
+        group_by_file: bool,
+        #[arg(
+            long,
+            help = "List matches from all files together, ordered by their timestamp in the video"
+        )]
+        group_by_time: bool,
+        #[arg(long, help = "Include at most this many matches from any one file")]
+        max_matches_per_file: Option<usize>,
+        #[arg(
+            long,
+            help = "Match the query's exact case (by default searches ignore case)"
+        )]
+        case_sensitive: bool,
+        #[arg(
+            short = 'C',
+            long,
+            value_name = "N",
+            default_value_t = 0,
+            help = "Show N transcript lines before and after each match"
+        )]
+        context: usize,
+        #[arg(
+            long,
+            value_name = "PATH",
+            conflicts_with_all = ["json", "group_by_time", "history"],
+            help = "Format each file's results with a Handlebars template, or a built-in one: @markdown, @html, @csv"
+        )]
+        template: Option<String>,
     },
     #[command(about = "Create supercut videos")]
     #[command(arg_required_else_help = true)]
@@ -154,6 +275,16 @@ enum Commands {
         #[command(subcommand)]
         transcripts_command: Option<TranscriptsCommands>,
     },
+    #[command(about = "Show aggregate statistics about the cached video library")]
+    Stats {
+        #[arg(long, help = "Output a flat JSON object instead of formatted text")]
+        json: bool,
+    },
+    #[command(about = "Manage web API credentials")]
+    Auth {
+        #[command(subcommand)]
+        auth_command: Option<AuthCommands>,
+    },
     #[command(about = "Launch the web server and watcher")]
     Web {
         #[command(subcommand)]
@@ -206,9 +337,34 @@ enum FilesCommands {
             value_delimiter = ','
         )]
         filter: Option<Vec<String>>,
+        #[arg(long, help = "Output format: json or csv", value_parser = ["json", "csv"], default_value = "json")]
+        format: String,
     },
     #[command(about = "Update file information cache by scanning watch directories")]
-    Update,
+    Update {
+        #[arg(
+            long,
+            help = "Number of files to process concurrently (defaults to one per CPU core)"
+        )]
+        parallel: Option<usize>,
+    },
+    #[command(
+        about = "List .txt transcripts in watch directories whose video file no longer exists"
+    )]
+    Orphans {
+        #[arg(long, help = "Output the list as a JSON array")]
+        json: bool,
+        #[arg(
+            long,
+            help = "Delete the orphaned .txt and .meta files after listing them"
+        )]
+        delete: bool,
+    },
+    #[command(about = "Remove a video from the file cache without rescanning")]
+    Remove {
+        #[arg(help = "Full path of the video, as shown by `files get`")]
+        path: String,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -284,16 +440,45 @@ enum QueueCommands {
     Get,
     #[command(about = "Get current queue processing status")]
     Status,
+    #[command(about = "List recently finished files with their outcome and any warning")]
+    History {
+        #[arg(long, default_value_t = 20, help = "Number of entries to show")]
+        limit: usize,
+    },
     #[command(about = "Add a path to the blocklist")]
     Block {
         #[arg(help = "Path to add to the blocklist")]
         path: String,
     },
+    #[command(about = "Add a path to the end of the queue")]
+    Add {
+        #[arg(help = "Path to the video file")]
+        path: String,
+        #[arg(
+            long,
+            help = "Whisper model to use for this file instead of model_name"
+        )]
+        model: Option<String>,
+    },
     #[command(about = "Set the queue with new paths")]
     Set {
         #[arg(help = "Paths in desired order", num_args = 1..)]
         paths: Vec<String>,
     },
+    #[command(about = "Remove a path from the queue before it is processed")]
+    Remove {
+        #[arg(
+            help = "Path to remove from the queue",
+            required_unless_present = "all"
+        )]
+        path: Option<String>,
+        #[arg(
+            long,
+            help = "Remove every pending path (keeps the file being processed and the blocklist)",
+            conflicts_with = "path"
+        )]
+        all: bool,
+    },
     #[command(about = "Cancel queue processing")]
     Cancel,
 }
@@ -314,7 +499,21 @@ enum ToolsCommands {
     Download {
         #[arg(help = "Name of the tool to download")]
         tool: String,
+        #[arg(
+            long,
+            requires = "sha256",
+            help = "Download from this URL instead of the pinned release (zip, .tar.xz or bare binary)"
+        )]
+        url: Option<String>,
+        #[arg(
+            long,
+            requires = "url",
+            help = "SHA256 of the tool's binary, after extracting it from any archive"
+        )]
+        sha256: Option<String>,
     },
+    #[command(about = "Re-download downloaded tools that have a newer release")]
+    Update,
 }
 
 #[derive(Subcommand, Debug)]
@@ -336,6 +535,13 @@ enum ModelsCommands {
     },
 }
 
+#[derive(Subcommand, Debug)]
+#[command(arg_required_else_help = true)]
+enum AuthCommands {
+    #[command(about = "Print the token API clients can send as `Authorization: Bearer <token>`")]
+    GenerateToken,
+}
+
 #[derive(Subcommand, Debug)]
 enum ConfigCommands {
     #[command(about = "Display current configuration settings")]
@@ -354,6 +560,20 @@ enum ConfigCommands {
         #[arg(help = "Field name to unset")]
         field: String,
     },
+    #[command(about = "Open the configuration file in $EDITOR")]
+    Edit,
+    #[command(about = "Time whisper on the first 60 seconds of a video")]
+    Benchmark {
+        #[arg(help = "Path to the video file to sample")]
+        video_path: String,
+        #[arg(long, help = "Model to benchmark instead of the configured model_name")]
+        model: Option<String>,
+    },
+    #[command(about = "Restore every configuration field to its default value")]
+    Reset {
+        #[arg(short = 'y', long, help = "Skip the confirmation prompt")]
+        yes: bool,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -374,6 +594,12 @@ enum WebCommands {
             default_value = "127.0.0.1"
         )]
         host: String,
+        #[arg(
+            long,
+            help = "Serve on a Unix domain socket at this path instead of a TCP host and port. The socket forwards to a random 127.0.0.1 port that local users can still reach directly",
+            conflicts_with_all = ["host", "port"]
+        )]
+        unix_socket: Option<String>,
     },
     #[command(about = "Launch API-only server")]
     Api {
@@ -417,12 +643,115 @@ enum TranscriptsCommands {
         #[arg(help = "New content for the entire transcript file")]
         content: String,
     },
+    #[command(about = "Append a new cue after the last cue in a transcript file")]
+    Append {
+        #[arg(help = "Path to the video file")]
+        video_path: String,
+        #[arg(help = "Start time of the cue (e.g. 00:01:02.500)")]
+        start: String,
+        #[arg(help = "End time of the cue (e.g. 00:01:04.000)")]
+        end: String,
+        #[arg(help = "Text content of the cue")]
+        text: String,
+    },
+    #[command(about = "Import an .lrc lyrics file as the transcript for a video file")]
+    ImportLrc {
+        #[arg(help = "Path to the video file")]
+        video_path: String,
+        #[arg(help = "Path to the .lrc file")]
+        lrc_path: String,
+    },
+    #[command(about = "Import a faster-whisper .json file as the transcript for a video file")]
+    ImportFasterWhisper {
+        #[arg(help = "Path to the video file")]
+        video_path: String,
+        #[arg(help = "Path to the faster-whisper .json file")]
+        json_path: String,
+    },
+    #[command(about = "Export a transcript as an .lrc lyrics file next to the video file")]
+    ExportLrc {
+        #[arg(help = "Path to the video file")]
+        video_path: String,
+    },
+    #[command(about = "Export a transcript as SRT subtitles, JSON or CSV")]
+    Export {
+        #[arg(help = "Path to the video file")]
+        video_path: String,
+        #[arg(long, help = "Output format", value_parser = transcripts::EXPORT_FORMATS)]
+        format: String,
+        #[arg(
+            short = 'o',
+            long,
+            help = "Where to write the export (defaults to the video path with the format's extension)"
+        )]
+        output: Option<std::path::PathBuf>,
+    },
+    #[command(about = "Offset every cue timestamp in a transcript by a fixed number of seconds")]
+    ShiftTimestamps {
+        #[arg(help = "Path to the video file")]
+        video_path: String,
+        #[arg(
+            long,
+            allow_hyphen_values = true,
+            help = "Seconds to add to each timestamp (negative to shift earlier)"
+        )]
+        delta: f64,
+        #[arg(long, help = "Print the shifted transcript without saving it")]
+        dry_run: bool,
+    },
+    #[command(about = "Merge consecutive cues that continue the same sentence")]
+    Compact {
+        #[arg(help = "Path to the video file")]
+        video_path: String,
+        #[arg(
+            long,
+            help = "Merge cues separated by less than this many milliseconds",
+            default_value = "500"
+        )]
+        max_gap_ms: u64,
+        #[arg(long, help = "Print the compacted transcript without saving it")]
+        dry_run: bool,
+    },
+    #[command(about = "Find and replace text in a transcript, or in every cached transcript")]
+    SearchAndReplace {
+        #[arg(help = "Path to the video file", required_unless_present = "all_files")]
+        video_path: Option<String>,
+        #[arg(long, help = "Text to find (a regular expression with --regex)")]
+        find: String,
+        #[arg(long, help = "Replacement text (may use $1-style groups with --regex)")]
+        replace: String,
+        #[arg(long, help = "Treat --find as a regular expression")]
+        regex: bool,
+        #[arg(long, help = "Print the lines that would change without saving them")]
+        dry_run: bool,
+        #[arg(
+            long,
+            help = "Apply the replacement to every cached video's transcript",
+            conflicts_with = "video_path"
+        )]
+        all_files: bool,
+    },
     #[command(about = "Delete transcript and meta files to force regeneration")]
     Regenerate {
         #[arg(help = "Path to the video file")]
         video_path: String,
         #[arg(short = 'i', long, help = "Interactive mode with processing options")]
         interactive: bool,
+        #[arg(
+            long,
+            conflicts_with = "interactive",
+            help = "Wait for the queue to re-transcribe the video, printing JSON-lines progress events"
+        )]
+        json_progress: bool,
+    },
+    #[command(about = "Copy a video's transcript to another video file")]
+    Copy {
+        #[arg(help = "Path to the video file whose transcript should be copied")]
+        source_video: String,
+        #[arg(help = "Path to the video file to copy the transcript to")]
+        dest_video: String,
+        #[arg(long, help = "Replace the destination's existing transcript")]
+        overwrite: bool,
     },
     #[command(about = "Rename both video file and its corresponding transcript file")]
     Rename {
@@ -460,44 +789,21 @@ fn is_valid_config_field(field: &str) -> bool {
             | "processing_success_command"
             | "processing_failure_command"
             | "stream_chunk_size"
+            | "processing_timeout_secs"
+            | "inter_processing_delay_secs"
+            | "watch_poll_interval_secs"
+            | "min_video_size_bytes"
+            | "session_timeout_mins"
+            | "preview_cache_size_mb"
+            | "exclusive_lock_timeout_secs"
+            | "access_log_path"
+            | "access_log_format"
+            | "max_queue_size"
+            | "max_file_size_gb"
+            | "max_concurrent_transcriptions"
     )
 }
 
-fn set_config_field(cfg: &mut AtciConfig, field: &str, value: &str) -> Result<(), String> {
-    match field {
-        "ffmpeg_path" => cfg.ffmpeg_path = value.to_string(),
-        "ffprobe_path" => cfg.ffprobe_path = value.to_string(),
-        "model_name" => cfg.model_name = value.to_string(),
-        "whispercli_path" => cfg.whispercli_path = value.to_string(),
-        "password" => cfg.password = Some(value.to_string()),
-        "watch_directories" => {
-            // For watch_directories, treat the value as a single directory to add
-            if !cfg.watch_directories.contains(&value.to_string()) {
-                cfg.watch_directories.push(value.to_string());
-            }
-        }
-        "allow_whisper" => {
-            cfg.allow_whisper = value
-                .parse::<bool>()
-                .map_err(|_| format!("Invalid boolean value for allow_whisper: {}", value))?;
-        }
-        "allow_subtitles" => {
-            cfg.allow_subtitles = value
-                .parse::<bool>()
-                .map_err(|_| format!("Invalid boolean value for allow_subtitles: {}", value))?;
-        }
-        "processing_success_command" => cfg.processing_success_command = value.to_string(),
-        "processing_failure_command" => cfg.processing_failure_command = value.to_string(),
-        "stream_chunk_size" => {
-            cfg.stream_chunk_size = value
-                .parse::<u32>()
-                .map_err(|_| format!("Invalid number value for stream_chunk_size: {}", value))?;
-        }
-        _ => return Err(format!("Unknown field: {}", field)),
-    }
-    Ok(())
-}
-
 fn unset_config_field(cfg: &mut AtciConfig, field: &str) -> Result<(), String> {
     match field {
         "ffmpeg_path" => cfg.ffmpeg_path = String::new(),
@@ -511,6 +817,18 @@ fn unset_config_field(cfg: &mut AtciConfig, field: &str) -> Result<(), String> {
         "processing_success_command" => cfg.processing_success_command = String::new(),
         "processing_failure_command" => cfg.processing_failure_command = String::new(),
         "stream_chunk_size" => cfg.stream_chunk_size = 60,
+        "processing_timeout_secs" => cfg.processing_timeout_secs = 3600,
+        "inter_processing_delay_secs" => cfg.inter_processing_delay_secs = 0,
+        "watch_poll_interval_secs" => cfg.watch_poll_interval_secs = 10,
+        "min_video_size_bytes" => cfg.min_video_size_bytes = 1048576,
+        "session_timeout_mins" => cfg.session_timeout_mins = 1440,
+        "preview_cache_size_mb" => cfg.preview_cache_size_mb = 500,
+        "exclusive_lock_timeout_secs" => cfg.exclusive_lock_timeout_secs = 30,
+        "access_log_path" => cfg.access_log_path = String::new(),
+        "access_log_format" => cfg.access_log_format = "json".to_string(),
+        "max_queue_size" => cfg.max_queue_size = None,
+        "max_file_size_gb" => cfg.max_file_size_gb = None,
+        "max_concurrent_transcriptions" => cfg.max_concurrent_transcriptions = 1,
         _ => return Err(format!("Unknown field: {}", field)),
     }
     Ok(())
@@ -1038,18 +1356,77 @@ fn validate_and_prompt_config(
         }
     }
 
+    // Saving already warns about every invalid value, including mismatched FFmpeg builds
     if config_changed {
-        config::store_config(cfg)?;
+        config::store_config_and_warn(cfg)?;
         println!("Configuration updated and saved.");
+    } else if fields_to_verify.contains("ffmpeg_path")
+        && fields_to_verify.contains("ffprobe_path")
+        && let Some(mismatch) = config::ffmpeg_version_mismatch(&cfg.ffmpeg_path, &cfg.ffprobe_path)
+    {
+        eprintln!("Warning: ffprobe_path {}", mismatch);
     }
 
     Ok(())
 }
 
+const PLAYLIST_CONTENT_TYPES: &[&str] = &[
+    "application/vnd.apple.mpegurl",
+    "application/x-mpegurl",
+    "audio/x-mpegurl",
+    "audio/mpegurl",
+];
+
+/// Fetch the playlist before handing it to ffmpeg, whose errors for a bad URL are hard to read.
+/// Servers that send a generic content type are accepted as long as the body is an M3U playlist.
+async fn check_stream_playlist(url: &str) -> Result<(), String> {
+    let response = reqwest::Client::new()
+        .get(url)
+        .timeout(std::time::Duration::from_secs(15))
+        .send()
+        .await
+        .map_err(|e| format!("Could not reach stream URL {}: {}", url, e))?;
+
+    let status = response.status();
+    if status != reqwest::StatusCode::OK {
+        return Err(format!("Stream URL {} returned HTTP {}", url, status));
+    }
+
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.split(';').next().unwrap_or("").trim().to_lowercase())
+        .unwrap_or_default();
+    if PLAYLIST_CONTENT_TYPES.contains(&content_type.as_str()) {
+        return Ok(());
+    }
+
+    let body = response
+        .text()
+        .await
+        .map_err(|e| format!("Could not read stream URL {}: {}", url, e))?;
+    if body.trim_start().starts_with("#EXTM3U") {
+        Ok(())
+    } else {
+        Err(format!(
+            "Stream URL {} is not an M3U8 playlist (Content-Type: {})",
+            url,
+            if content_type.is_empty() {
+                "none"
+            } else {
+                &content_type
+            }
+        ))
+    }
+}
+
 async fn download_stream(url: &str, stream_name: &str) -> Result<(), Box<dyn std::error::Error>> {
     use chrono::Utc;
     use std::fs;
 
+    check_stream_playlist(url).await?;
+
     println!("Starting stream download: {} -> {}", url, stream_name);
 
     // Get or create streams directory structure
@@ -1068,7 +1445,7 @@ async fn download_stream(url: &str, stream_name: &str) -> Result<(), Box<dyn std
 
     if !cfg.watch_directories.contains(&stream_dir_str) {
         cfg.watch_directories.push(stream_dir_str.clone());
-        config::store_config(&cfg)?;
+        config::store_config_and_warn(&cfg)?;
         println!(
             "Added stream directory to watch directories: {}",
             stream_dir_str
@@ -1206,16 +1583,34 @@ fn check_services_status(json: bool) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Print transcript lines around a search match, dimmed when writing to a terminal
+fn print_context_lines(lines: &[String]) {
+    use std::io::IsTerminal;
+
+    let dim = std::io::stdout().is_terminal();
+    for line in lines {
+        if dim {
+            println!("  \x1b[2m|\t{}\x1b[0m", line);
+        } else {
+            println!("  |\t{}", line);
+        }
+    }
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
 
     match args.command {
         Some(Commands::Files { files_command }) => match files_command {
-            Some(FilesCommands::Get { filter }) => {
+            Some(FilesCommands::Get { filter, format }) => {
                 match files::load_video_info_from_cache(filter.as_ref()) {
                     Ok(video_infos) => {
-                        let json_output = serde_json::to_string_pretty(&video_infos)?;
-                        println!("{}", json_output);
+                        if format == "csv" {
+                            print!("{}", files::video_infos_to_csv(&video_infos));
+                        } else {
+                            let json_output = serde_json::to_string_pretty(&video_infos)?;
+                            println!("{}", json_output);
+                        }
                     }
                     Err(e) => {
                         eprintln!("Error reading cache file: {}", e);
@@ -1223,12 +1618,69 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     }
                 }
             }
-            Some(FilesCommands::Update) => {
-                files::get_and_save_video_info_from_disk()?;
+            Some(FilesCommands::Update { parallel }) => {
+                match parallel {
+                    Some(parallel) => files::get_and_save_video_info_from_disk_parallel(parallel)?,
+                    None => files::get_and_save_video_info_from_disk()?,
+                }
                 let cache_data = files::load_cache_data()?;
                 let json_output = serde_json::to_string_pretty(&cache_data.files)?;
                 println!("{}", json_output);
             }
+            Some(FilesCommands::Orphans { json, delete }) => {
+                let orphans = files::find_orphaned_transcripts();
+
+                if json {
+                    println!("{}", serde_json::to_string_pretty(&orphans)?);
+                } else if orphans.is_empty() {
+                    println!("No orphaned transcripts found");
+                } else {
+                    for orphan in &orphans {
+                        println!("{}", orphan.display());
+                    }
+                }
+
+                if delete && !orphans.is_empty() {
+                    let mut failed = false;
+                    for orphan in &orphans {
+                        match files::delete_orphaned_transcript(orphan) {
+                            Ok(removed) => {
+                                for path in removed {
+                                    eprintln!("Deleted {}", path.display());
+                                }
+                            }
+                            Err(e) => {
+                                eprintln!("Error deleting {}: {}", orphan.display(), e);
+                                failed = true;
+                            }
+                        }
+                    }
+
+                    if let Err(e) = files::get_and_save_video_info_from_disk() {
+                        eprintln!("Warning: could not refresh file cache: {}", e);
+                    }
+
+                    if failed {
+                        std::process::exit(1);
+                    }
+                }
+            }
+            Some(FilesCommands::Remove { path }) => {
+                // Resolve relative paths the same way `queue remove` does; fall back to the
+                // path as given for files that have since been moved or deleted
+                let full_path = std::fs::canonicalize(&path)
+                    .map(|p| p.to_string_lossy().to_string())
+                    .unwrap_or(path);
+                match files::remove_from_cache(&full_path) {
+                    Ok(()) => {
+                        println!("Removed from file cache: {}", full_path);
+                    }
+                    Err(e) => {
+                        eprintln!("Error removing from file cache: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
             None => {}
         },
         Some(Commands::Queue { queue_command }) => match queue_command {
@@ -1242,11 +1694,25 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     std::process::exit(1);
                 }
             },
+            Some(QueueCommands::History { limit }) => match queue::processing_history(limit) {
+                Ok(history) => {
+                    let json_output = serde_json::to_string_pretty(&history)?;
+                    println!("{}", json_output);
+                }
+                Err(e) => {
+                    eprintln!("Error reading processing history: {}", e);
+                    std::process::exit(1);
+                }
+            },
             Some(QueueCommands::Status) => match queue::get_queue_status(None) {
                 Ok((path, age)) => {
+                    let progress_percent = path.as_deref().and_then(queue::get_progress_percent);
+                    let cooling_down_secs = queue::cooldown_remaining_secs(None).unwrap_or(None);
                     let result = serde_json::json!({
                         "currently_processing": path.unwrap_or_else(|| "".to_string()),
-                        "age_in_seconds": age
+                        "age_in_seconds": age,
+                        "progress_percent": progress_percent,
+                        "cooling_down_secs": cooling_down_secs
                     });
                     println!("{}", result);
                 }
@@ -1264,6 +1730,35 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     std::process::exit(1);
                 }
             },
+            Some(QueueCommands::Add { path, model }) => {
+                let full_path = match std::fs::canonicalize(&path) {
+                    Ok(full_path) => full_path.to_string_lossy().to_string(),
+                    Err(e) => {
+                        eprintln!("Error adding to queue: {}: {}", path, e);
+                        std::process::exit(1);
+                    }
+                };
+                if let Some(model) = &model
+                    && !model_manager::models_directory()
+                        .join(format!("{}.bin", model))
+                        .exists()
+                {
+                    eprintln!(
+                        "Error: model {} is not downloaded, run `atci models download {}` first",
+                        model, model
+                    );
+                    std::process::exit(1);
+                }
+                match queue::add_to_queue(&full_path, model, None) {
+                    Ok(()) => {
+                        println!("Added to queue: {}", full_path);
+                    }
+                    Err(e) => {
+                        eprintln!("Error adding to queue: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
             Some(QueueCommands::Set { paths }) => match queue::set_queue(paths) {
                 Ok(()) => {
                     println!("Queue set successfully");
@@ -1273,6 +1768,32 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     std::process::exit(1);
                 }
             },
+            Some(QueueCommands::Remove { all: true, .. }) => match queue::clear_pending_queue() {
+                Ok(removed) => {
+                    println!("Removed {} pending path(s) from the queue", removed);
+                }
+                Err(e) => {
+                    eprintln!("Error clearing queue: {}", e);
+                    std::process::exit(1);
+                }
+            },
+            Some(QueueCommands::Remove { path, .. }) => {
+                let path = path.unwrap_or_default();
+                // Queued paths are stored canonicalized by `queue add`; fall back to the
+                // path as given for files that have since been moved or deleted
+                let full_path = std::fs::canonicalize(&path)
+                    .map(|p| p.to_string_lossy().to_string())
+                    .unwrap_or(path);
+                match queue::remove_from_queue(&full_path) {
+                    Ok(()) => {
+                        println!("Removed from queue: {}", full_path);
+                    }
+                    Err(e) => {
+                        eprintln!("Error removing from queue: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
             Some(QueueCommands::Cancel) => match queue::cancel_queue() {
                 Ok(message) => {
                     println!("{}", message);
@@ -1292,6 +1813,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             display_text,
             format,
             font_size,
+            auto_crop,
+            portrait,
+            portrait_background,
+            aspect_ratio,
+            two_pass,
+            target_size_mb,
+            output,
+            chapters_file,
         }) => {
             let mut cfg: AtciConfig = config::load_config()?;
 
@@ -1300,17 +1829,51 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             required_fields.insert("ffmpeg_path".to_string());
             required_fields.insert("ffprobe_path".to_string());
 
+            // A cue line fills in both times, so the next positional argument is the overlay text
+            let (start, end, text) = match clipper::parse_cue_range(&start) {
+                Some((cue_start, cue_end)) => {
+                    if end.is_some() && text.is_some() {
+                        eprintln!(
+                            "Error: an end time can't be given when the start is a cue line ({})",
+                            start
+                        );
+                        std::process::exit(1);
+                    }
+                    (cue_start, cue_end, text.or(end))
+                }
+                None => match end {
+                    Some(end) => (start, end, text),
+                    None => {
+                        eprintln!(
+                            "Error: missing end time; pass one or give a cue line such as \"00:05:25.920 --> 00:05:46.060\""
+                        );
+                        std::process::exit(1);
+                    }
+                },
+            };
+
             // Validate and prompt for missing configuration
             validate_and_prompt_config(&mut cfg, &required_fields)?;
 
-            let output_path = clipper::clip(
+            let options = clipper::ClipOptions {
+                font_size,
+                auto_crop,
+                output: output.map(std::path::PathBuf::from),
+                portrait: portrait
+                    .then(|| clipper::PortraitBackground::from_name(&portrait_background))
+                    .flatten(),
+                aspect_ratio,
+                two_pass_target_mb: target_size_mb.filter(|_| two_pass),
+                chapters_file,
+            };
+            let output_path = clipper::clip_with_options(
                 Path::new(&path),
                 &start,
                 &end,
                 text.as_deref(),
                 display_text,
                 &format,
-                font_size,
+                &options,
             )?;
             println!("{}", output_path.display());
         }
@@ -1319,6 +1882,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             time,
             text,
             font_size,
+            output,
         }) => {
             let mut cfg: AtciConfig = config::load_config()?;
 
@@ -1336,9 +1900,27 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 text.as_deref(),
                 font_size,
                 Some(360),
+                output.as_deref().map(Path::new),
             )?;
             println!("{}", output_path.display());
         }
+        Some(Commands::Concat { clips, output }) => {
+            let mut cfg: AtciConfig = config::load_config()?;
+
+            let mut required_fields = HashSet::new();
+            required_fields.insert("ffmpeg_path".to_string());
+            validate_and_prompt_config(&mut cfg, &required_fields)?;
+
+            let clips: Vec<std::path::PathBuf> =
+                clips.iter().map(std::path::PathBuf::from).collect();
+            match clipper::concatenate_clips(&clips, std::path::PathBuf::from(output)) {
+                Ok(output_path) => println!("{}", output_path.display()),
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
         Some(Commands::Tools { tools_command }) => match tools_command {
             Some(ToolsCommands::List { json }) => {
                 let tools = tools_manager::list_tools();
@@ -1369,20 +1951,63 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     }
                 }
             }
-            Some(ToolsCommands::Download { tool }) => match tools_manager::download_tool(&tool) {
-                Ok(path) => {
-                    println!("Successfully downloaded {} to: {}", tool, path);
+            Some(ToolsCommands::Download { tool, url, sha256 }) => {
+                let result = match (url, sha256) {
+                    (Some(url), Some(sha256)) => {
+                        tools_manager::download_tool_from_url(&tool, &url, &sha256)
+                    }
+                    _ => tools_manager::download_tool(&tool),
+                };
+                match result {
+                    Ok(path) => {
+                        println!("Successfully downloaded {} to: {}", tool, path);
+                    }
+                    Err(e) => {
+                        eprintln!("Error downloading {}: {}", tool, e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            Some(ToolsCommands::Update) => {
+                let updates = tools_manager::update_tools();
+                if updates.is_empty() {
+                    println!("No downloaded tools to update");
+                    return Ok(());
                 }
-                Err(e) => {
-                    eprintln!("Error downloading {}: {}", tool, e);
+
+                let mut failed = false;
+                println!("\nUpdate Summary:");
+                println!("{}", "=".repeat(50));
+                for (tool, update) in updates {
+                    match update {
+                        tools_manager::ToolUpdate::Updated { from, to } => {
+                            println!("{}: updated", tool);
+                            if let Some(from) = from {
+                                println!("   From: {}", from);
+                            }
+                            println!("   To: {}", to);
+                        }
+                        tools_manager::ToolUpdate::UpToDate { url } => {
+                            println!("{}: already up to date ({})", tool, url);
+                        }
+                        tools_manager::ToolUpdate::Skipped { reason } => {
+                            println!("{}: skipped, {}", tool, reason);
+                        }
+                        tools_manager::ToolUpdate::Failed { error } => {
+                            failed = true;
+                            eprintln!("{}: failed, {}", tool, error);
+                        }
+                    }
+                }
+                if failed {
                     std::process::exit(1);
                 }
-            },
+            }
             None => {}
         },
         Some(Commands::Models { models_command }) => match models_command {
             Some(ModelsCommands::List { json }) => {
-                let models = model_manager::list_models();
+                let models = model_manager::list_models_with_sizes();
                 if json {
                     let json_output = serde_json::to_string_pretty(&models)?;
                     println!("{}", json_output);
@@ -1400,6 +2025,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 println!("   Status: Currently configured");
                             }
                             println!("   Path: {}", model.path);
+                            if let Some(size_mb) = model.size_mb {
+                                println!("   Size: {}", model_manager::format_size_mb(size_mb));
+                            }
                             println!();
                         }
                     }
@@ -1408,7 +2036,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         println!("AVAILABLE MODELS");
                         println!("{}", "=".repeat(50));
                         for model in available {
-                            println!("  {}", model.name);
+                            match model.size_mb {
+                                Some(size_mb) => println!(
+                                    "  {} {}",
+                                    model.name,
+                                    model_manager::format_size_mb(size_mb)
+                                ),
+                                None => println!("  {}", model.name),
+                            }
                         }
                     }
                 }
@@ -1481,7 +2116,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             Some(ConfigCommands::Set { field, value }) => {
                 if !is_valid_config_field(&field) {
                     eprintln!(
-                        "Error: Unknown field '{}'. Valid fields are: ffmpeg_path, ffprobe_path, model_name, whispercli_path, watch_directories, password, allow_whisper, allow_subtitles, processing_success_command, processing_failure_command, stream_chunk_size",
+                        "Error: Unknown field '{}'. Valid fields are: ffmpeg_path, ffprobe_path, model_name, whispercli_path, watch_directories, password, allow_whisper, allow_subtitles, processing_success_command, processing_failure_command, stream_chunk_size, processing_timeout_secs, inter_processing_delay_secs, watch_poll_interval_secs, min_video_size_bytes, session_timeout_mins, max_queue_size, preview_cache_size_mb, exclusive_lock_timeout_secs, access_log_path, access_log_format, max_file_size_gb, max_concurrent_transcriptions",
                         field
                     );
                     std::process::exit(1);
@@ -1489,18 +2124,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                 let mut cfg: AtciConfig = config::load_config()?;
 
-                if let Err(e) = set_config_field(&mut cfg, &field, &value) {
+                if let Err(e) = config::set_config_field(&mut cfg, &field, &value) {
                     eprintln!("Error setting field: {}", e);
                     std::process::exit(1);
                 }
 
-                config::store_config(&cfg)?;
+                config::store_config_and_warn(&cfg)?;
                 println!("Set {} = {}", field, value);
             }
             Some(ConfigCommands::Unset { field }) => {
                 if !is_valid_config_field(&field) {
                     eprintln!(
-                        "Error: Unknown field '{}'. Valid fields are: ffmpeg_path, ffprobe_path, model_name, whispercli_path, watch_directories, password, allow_whisper, allow_subtitles, processing_success_command, processing_failure_command, stream_chunk_size",
+                        "Error: Unknown field '{}'. Valid fields are: ffmpeg_path, ffprobe_path, model_name, whispercli_path, watch_directories, password, allow_whisper, allow_subtitles, processing_success_command, processing_failure_command, stream_chunk_size, processing_timeout_secs, inter_processing_delay_secs, watch_poll_interval_secs, min_video_size_bytes, session_timeout_mins, max_queue_size, preview_cache_size_mb, exclusive_lock_timeout_secs, access_log_path, access_log_format, max_file_size_gb, max_concurrent_transcriptions",
                         field
                     );
                     std::process::exit(1);
@@ -1513,9 +2148,131 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     std::process::exit(1);
                 }
 
-                config::store_config(&cfg)?;
+                config::store_config_and_warn(&cfg)?;
                 println!("Unset {}", field);
             }
+            Some(ConfigCommands::Edit) => {
+                let config_path = match std::env::var("ATCI_CONFIG_PATH") {
+                    Ok(path) => std::path::PathBuf::from(path),
+                    Err(_) => confy::get_configuration_file_path("atci", "config")?,
+                };
+
+                if !config_path.exists() {
+                    config::store_config_and_warn(&config::load_config()?)?;
+                }
+
+                // $EDITOR may include arguments, e.g. "code --wait"
+                let editor = std::env::var("EDITOR")
+                    .ok()
+                    .filter(|editor| !editor.trim().is_empty())
+                    .unwrap_or_else(|| "vi".to_string());
+                let mut editor_parts = editor.split_whitespace();
+                let program = editor_parts.next().unwrap_or("vi");
+
+                let status = std::process::Command::new(program)
+                    .args(editor_parts)
+                    .arg(&config_path)
+                    .status()
+                    .map_err(|e| format!("Failed to launch editor '{}': {}", editor, e))?;
+                if !status.success() {
+                    eprintln!("Editor exited with {}, configuration not reloaded", status);
+                    std::process::exit(1);
+                }
+
+                let cfg = match config::load_config() {
+                    Ok(cfg) => cfg,
+                    Err(e) => {
+                        eprintln!(
+                            "Error: {} is not a valid configuration: {}",
+                            config_path.display(),
+                            e
+                        );
+                        std::process::exit(1);
+                    }
+                };
+
+                let errors = cfg.validate();
+                for error in &errors {
+                    eprintln!("Warning: invalid config value {}", error);
+                }
+                let _ = files::get_and_save_video_info_from_disk();
+
+                if errors.is_empty() {
+                    println!("Saved configuration at {}", config_path.display());
+                }
+            }
+            Some(ConfigCommands::Benchmark { video_path, model }) => {
+                let mut cfg: AtciConfig = config::load_config()?;
+
+                let mut required_fields = HashSet::new();
+                required_fields.insert("ffmpeg_path".to_string());
+                required_fields.insert("ffprobe_path".to_string());
+                required_fields.insert("whispercli_path".to_string());
+                validate_and_prompt_config(&mut cfg, &required_fields)?;
+
+                let rt = tokio::runtime::Runtime::new()?;
+                match rt.block_on(video_processor::benchmark_transcription(
+                    Path::new(&video_path),
+                    model.as_deref(),
+                )) {
+                    Ok(result) => {
+                        println!("Model: {}", result.model);
+                        println!("Audio processed: {:.1}s", result.audio_secs);
+                        println!("Transcription time: {:.1}s", result.wall_secs);
+                        println!("Speed: {:.2}x realtime", result.speed_ratio);
+
+                        let mut cfg: AtciConfig = config::load_config()?;
+                        cfg.last_benchmark_speed_ratio = Some(result.speed_ratio);
+                        config::store_config_and_warn(&cfg)?;
+                    }
+                    Err(e) => {
+                        eprintln!("Error running benchmark: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            Some(ConfigCommands::Reset { yes }) => {
+                let config_path = match std::env::var("ATCI_CONFIG_PATH") {
+                    Ok(path) => std::path::PathBuf::from(path),
+                    Err(_) => confy::get_configuration_file_path("atci", "config")?,
+                };
+
+                let confirmed = yes
+                    || Confirm::new()
+                        .with_prompt(format!(
+                            "Reset all settings in {} to their defaults?",
+                            config_path.display()
+                        ))
+                        .default(false)
+                        .interact()?;
+
+                if !confirmed {
+                    println!("Reset cancelled");
+                } else {
+                    let old_cfg: AtciConfig = config::load_config()?;
+                    let defaults = AtciConfig::default();
+                    config::store_config_and_warn(&defaults)?;
+
+                    println!("Reset configuration at {}", config_path.display());
+                    let old_values = serde_json::to_value(&old_cfg)?;
+                    let default_values = serde_json::to_value(&defaults)?;
+                    if let (Some(old_values), Some(default_values)) =
+                        (old_values.as_object(), default_values.as_object())
+                    {
+                        for (field, old_value) in old_values {
+                            if default_values.get(field) == Some(old_value) {
+                                continue;
+                            }
+                            // Don't leave secrets in the terminal's scrollback
+                            if config::SECRET_FIELDS.contains(&field.as_str()) {
+                                println!("  cleared {} (was set)", field);
+                            } else {
+                                println!("  cleared {} (was {})", field, old_value);
+                            }
+                        }
+                    }
+                }
+            }
             None => {
                 let cfg: AtciConfig = config::load_config()?;
                 let json_output = serde_json::to_string_pretty(&cfg)?;
@@ -1528,50 +2285,174 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             filter,
             clip,
             gif,
+            limit,
+            offset,
+            history,
+            exclude_pattern,
+            no_cache,
+            fields,
+            in_file,
+            group_by_file: _,
+            group_by_time,
+            max_matches_per_file,
+            case_sensitive,
+            context,
+            template,
         }) => {
-            let search_query = query.join(" ");
-            let rt = tokio::runtime::Runtime::new()?;
+            if history {
+                let entries: Vec<search::SearchHistoryEntry> = search::load_search_history()?
+                    .into_iter()
+                    .rev()
+                    .take(20)
+                    .collect();
+                if json {
+                    println!("{}", serde_json::to_string_pretty(&entries)?);
+                } else {
+                    for entry in entries {
+                        println!(
+                            "{}  {} ({} matches)",
+                            entry.timestamp, entry.query, entry.result_count
+                        );
+                    }
+                }
+            } else {
+                let search_query = query.join(" ");
+                let only_paths = match in_file {
+                    Some(list_path) => match search::read_file_list(Path::new(&list_path)) {
+                        Ok(paths) => Some(paths),
+                        Err(e) => {
+                            eprintln!("Error reading file list {}: {}", list_path, e);
+                            std::process::exit(1);
+                        }
+                    },
+                    None => None,
+                };
+                let rt = tokio::runtime::Runtime::new()?;
 
-            match rt.block_on(search::search(&search_query, filter.as_ref(), clip, gif)) {
-                Ok(results) => {
-                    if json {
-                        let json_output = serde_json::to_string_pretty(&results)?;
-                        println!("{}", json_output);
-                    } else {
-                        for result in results {
-                            println!("File: {}", result.file_path);
-                            for search_match in result.matches {
-                                if let Some(timestamp) = search_match.timestamp {
-                                    println!("  {}: {}", search_match.line_number, timestamp);
-                                    println!(
-                                        "  {}:\t{}",
-                                        search_match.line_number + 1,
-                                        search_match.line_text
-                                    );
-                                } else {
-                                    println!(
-                                        "  {}: \"{}\"",
-                                        search_match.line_number, search_match.line_text
-                                    );
+                // Pagination applies to the interleaved list, so fetch everything first
+                let (page_limit, page_offset) = if group_by_time {
+                    (None, 0)
+                } else {
+                    (limit, offset)
+                };
+
+                match rt.block_on(search::search_paginated(
+                    &search_query,
+                    filter.as_ref(),
+                    page_limit,
+                    page_offset,
+                    &search::SearchOptions {
+                        generate_clips: clip,
+                        generate_gifs: gif,
+                        exclude_patterns: exclude_pattern,
+                        no_cache,
+                        fields: fields
+                            .iter()
+                            .filter_map(|f| search::SearchField::from_name(f))
+                            .collect(),
+                        only_paths,
+                        max_matches_per_file,
+                        case_sensitive,
+                        context_lines: context,
+                    },
+                )) {
+                    Ok(response) => {
+                        if let Err(e) =
+                            search::record_search_history(&search_query, response.total_matches)
+                        {
+                            eprintln!("Warning: could not save search history: {}", e);
+                        }
+                        if let Some(template) = template {
+                            match search::render_search_template(
+                                &template,
+                                &search_query,
+                                &response.results,
+                            ) {
+                                Ok(output) => print!("{}", output),
+                                Err(e) => {
+                                    eprintln!("Error rendering template: {}", e);
+                                    std::process::exit(1);
                                 }
-
-                                // Display clip information if available
-                                if let Some(clip_path) = &search_match.clip_path {
-                                    println!("Clip: {}", clip_path);
+                            }
+                        } else if group_by_time {
+                            let matches: Vec<search::TimedSearchMatch> =
+                                search::group_by_time(response.results)
+                                    .into_iter()
+                                    .skip(offset)
+                                    .take(limit.unwrap_or(usize::MAX))
+                                    .collect();
+                            if json {
+                                println!("{}", serde_json::to_string_pretty(&matches)?);
+                            } else {
+                                for timed in matches {
+                                    let search_match = timed.search_match;
+                                    print_context_lines(&search_match.context_before);
+                                    match search_match.timestamp {
+                                        Some(timestamp) => println!(
+                                            "{} @ {}\n  {}:\t{}",
+                                            timed.file_path,
+                                            timestamp,
+                                            search_match.line_number + 1,
+                                            search_match.line_text
+                                        ),
+                                        None => println!(
+                                            "{}\n  {}: \"{}\"",
+                                            timed.file_path,
+                                            search_match.line_number,
+                                            search_match.line_text
+                                        ),
+                                    }
+                                    print_context_lines(&search_match.context_after);
+                                    if let Some(clip_path) = &search_match.clip_path {
+                                        println!("Clip: {}", clip_path);
+                                    }
+                                    if let Some(clip_command) = &search_match.clip_command {
+                                        println!("Command: {}", clip_command);
+                                    }
+                                    println!();
                                 }
-                                if let Some(clip_command) = &search_match.clip_command {
-                                    println!("Command: {}", clip_command);
+                            }
+                        } else if json {
+                            let json_output = serde_json::to_string_pretty(&response)?;
+                            println!("{}", json_output);
+                        } else {
+                            for result in response.results {
+                                println!("File: {}", result.file_path);
+                                for search_match in result.matches {
+                                    print_context_lines(&search_match.context_before);
+                                    if let Some(timestamp) = search_match.timestamp {
+                                        println!("  {}: {}", search_match.line_number, timestamp);
+                                        println!(
+                                            "  {}:\t{}",
+                                            search_match.line_number + 1,
+                                            search_match.line_text
+                                        );
+                                    } else {
+                                        println!(
+                                            "  {}: \"{}\"",
+                                            search_match.line_number, search_match.line_text
+                                        );
+                                    }
+                                    print_context_lines(&search_match.context_after);
+
+                                    // Display clip information if available
+                                    if let Some(clip_path) = &search_match.clip_path {
+                                        println!("Clip: {}", clip_path);
+                                    }
+                                    if let Some(clip_command) = &search_match.clip_command {
+                                        println!("Command: {}", clip_command);
+                                    }
+
+                                    println!();
                                 }
-
                                 println!();
                             }
-                            println!();
                         }
                     }
-                }
-                Err(e) => {
-                    eprintln!("Error searching: {}", e);
-                    std::process::exit(1);
+                    Err(e) => {
+                        eprintln!("Error searching: {}", e);
+                        std::process::exit(1);
+                    }
                 }
             }
         }
@@ -1702,9 +2583,172 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     std::process::exit(1);
                 }
             },
+            Some(TranscriptsCommands::Append {
+                video_path,
+                start,
+                end,
+                text,
+            }) => match transcripts::append(&video_path, &start, &end, &text) {
+                Ok(()) => {
+                    println!("Successfully appended cue to transcript for {}", video_path);
+                }
+                Err(e) => {
+                    eprintln!("Error appending cue: {}", e);
+                    std::process::exit(1);
+                }
+            },
+            Some(TranscriptsCommands::ImportLrc {
+                video_path,
+                lrc_path,
+            }) => match transcripts::import_lrc(&video_path, &lrc_path) {
+                Ok(()) => {
+                    println!("Successfully imported {} for {}", lrc_path, video_path);
+                }
+                Err(e) => {
+                    eprintln!("Error importing LRC: {}", e);
+                    std::process::exit(1);
+                }
+            },
+            Some(TranscriptsCommands::ImportFasterWhisper {
+                video_path,
+                json_path,
+            }) => match transcripts::import_faster_whisper(&video_path, &json_path) {
+                Ok(()) => {
+                    println!("Successfully imported {} for {}", json_path, video_path);
+                }
+                Err(e) => {
+                    eprintln!("Error importing faster-whisper JSON: {}", e);
+                    std::process::exit(1);
+                }
+            },
+            Some(TranscriptsCommands::ExportLrc { video_path }) => {
+                match transcripts::export_lrc(&video_path) {
+                    Ok(lrc_path) => {
+                        println!("Successfully exported LRC to {}", lrc_path.display());
+                    }
+                    Err(e) => {
+                        eprintln!("Error exporting LRC: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            Some(TranscriptsCommands::Export {
+                video_path,
+                format,
+                output,
+            }) => match transcripts::export(&video_path, &format, output.as_deref()) {
+                Ok(output_path) => {
+                    println!(
+                        "Successfully exported {} to {}",
+                        format,
+                        output_path.display()
+                    );
+                }
+                Err(e) => {
+                    eprintln!("Error exporting transcript: {}", e);
+                    std::process::exit(1);
+                }
+            },
+            Some(TranscriptsCommands::ShiftTimestamps {
+                video_path,
+                delta,
+                dry_run,
+            }) => match transcripts::shift_timestamps(&video_path, delta, dry_run) {
+                Ok(shifted) => {
+                    if dry_run {
+                        println!("{}", shifted);
+                    } else {
+                        println!(
+                            "Successfully shifted timestamps by {}s for {}",
+                            delta, video_path
+                        );
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Error shifting timestamps: {}", e);
+                    std::process::exit(1);
+                }
+            },
+            Some(TranscriptsCommands::Compact {
+                video_path,
+                max_gap_ms,
+                dry_run,
+            }) => match transcripts::compact(&video_path, max_gap_ms, dry_run) {
+                Ok((compacted, merged)) => {
+                    if dry_run {
+                        println!("{}", compacted);
+                    } else {
+                        println!("Merged {} cues in {}", merged, video_path);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Error compacting transcript: {}", e);
+                    std::process::exit(1);
+                }
+            },
+            Some(TranscriptsCommands::SearchAndReplace {
+                video_path,
+                find,
+                replace,
+                regex,
+                dry_run,
+                all_files,
+            }) => {
+                let pattern = match transcripts::FindPattern::new(&find, regex) {
+                    Ok(pattern) => pattern,
+                    Err(e) => {
+                        eprintln!("Error: invalid --find pattern: {}", e);
+                        std::process::exit(1);
+                    }
+                };
+
+                let video_paths: Vec<String> = if all_files {
+                    files::load_cache_data()?
+                        .files
+                        .into_iter()
+                        .filter(|info| info.transcript)
+                        .map(|info| info.full_path)
+                        .collect()
+                } else {
+                    video_path.into_iter().collect()
+                };
+
+                let mut total = 0;
+                let mut failed = false;
+                for video_path in &video_paths {
+                    match transcripts::search_and_replace(video_path, &pattern, &replace, dry_run) {
+                        Ok(replacements) => {
+                            let count: usize = replacements.iter().map(|r| r.count).sum();
+                            if dry_run {
+                                for replacement in &replacements {
+                                    println!("{}:{}", video_path, replacement.line_number);
+                                    println!("- {}", replacement.before);
+                                    println!("+ {}", replacement.after);
+                                }
+                            } else if count > 0 || !all_files {
+                                println!("Made {} replacement(s) in {}", count, video_path);
+                            }
+                            total += count;
+                        }
+                        Err(e) => {
+                            eprintln!("Error replacing in {}: {}", video_path, e);
+                            failed = true;
+                        }
+                    }
+                }
+
+                if all_files || dry_run {
+                    let verb = if dry_run { "Would make" } else { "Made" };
+                    println!("{} {} replacement(s) in total", verb, total);
+                }
+                if failed {
+                    std::process::exit(1);
+                }
+            }
             Some(TranscriptsCommands::Regenerate {
                 video_path,
                 interactive,
+                json_progress,
             }) => {
                 if interactive {
                     let rt = tokio::runtime::Runtime::new()?;
@@ -1721,6 +2765,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     });
                 } else {
                     match transcripts::regenerate(&video_path, None, None) {
+                        Ok(()) if json_progress => {
+                            let outcome =
+                                transcripts::follow_regenerate_progress(&video_path, |event| {
+                                    println!("{}", event);
+                                })?;
+                            if outcome.as_deref() != Some("success") {
+                                std::process::exit(1);
+                            }
+                        }
                         Ok(()) => {
                             println!("Successfully deleted transcript files for {}", video_path);
                         }
@@ -1731,6 +2784,21 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     }
                 }
             }
+            Some(TranscriptsCommands::Copy {
+                source_video,
+                dest_video,
+                overwrite,
+            }) => match transcripts::copy(&source_video, &dest_video, overwrite) {
+                Ok(copied) => {
+                    for path in copied {
+                        println!("Copied {}", path.display());
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Error copying transcript: {}", e);
+                    std::process::exit(1);
+                }
+            },
             Some(TranscriptsCommands::Rename {
                 video_path,
                 new_path,
@@ -1745,9 +2813,66 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             },
             None => {}
         },
+        Some(Commands::Stats { json }) => {
+            let video_infos = match files::load_video_info_from_cache(None) {
+                Ok(video_infos) => video_infos,
+                Err(e) => {
+                    eprintln!("Error loading file cache: {}", e);
+                    std::process::exit(1);
+                }
+            };
+            let stats = files::library_stats(&video_infos);
+
+            if json {
+                println!("{}", serde_json::to_string_pretty(&stats)?);
+            } else {
+                println!("Library Stats:");
+                println!("{}", "=".repeat(50));
+                println!("Videos:         {}", stats.total_videos);
+                println!("Transcribed:    {}", stats.transcribed);
+                println!("Pending:        {}", stats.pending);
+                println!("Total duration: {}", stats.total_duration);
+                println!("Avg lines:      {}", stats.average_line_count);
+                if let (Some(video), Some(created_at)) =
+                    (&stats.oldest_video, &stats.oldest_created_at)
+                {
+                    println!("Oldest:         {} ({})", video, created_at);
+                }
+                if let (Some(video), Some(created_at)) =
+                    (&stats.newest_video, &stats.newest_created_at)
+                {
+                    println!("Newest:         {} ({})", video, created_at);
+                }
+                if !stats.top_directories.is_empty() {
+                    println!("\nTop directories:");
+                    for entry in &stats.top_directories {
+                        println!("  {:>6}  {}", entry.count, entry.directory);
+                    }
+                }
+            }
+        }
+        Some(Commands::Auth { auth_command }) => match auth_command {
+            Some(AuthCommands::GenerateToken) => {
+                let cfg = config::load_config()?;
+                match cfg.password.as_deref() {
+                    Some(password) => println!("{}", auth::api_token(password)),
+                    None => {
+                        eprintln!(
+                            "Error: no password is set, so the web API needs no token. Set one with `atci config set password <password>`"
+                        );
+                        std::process::exit(1);
+                    }
+                }
+            }
+            None => {}
+        },
         Some(Commands::Web { web_command }) => {
             match web_command {
-                Some(WebCommands::All { host, port }) => {
+                Some(WebCommands::All {
+                    host,
+                    port,
+                    unix_socket,
+                }) => {
                     let mut cfg: AtciConfig = config::load_config()?;
                     let mut required_fields = HashSet::new();
                     required_fields.insert("ffmpeg_path".to_string());
@@ -1766,19 +2891,30 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     setup_pid_file_for_service("web")?;
 
                     // Set up cleanup handler for both watcher and web PID files
+                    let socket_to_cleanup = unix_socket.clone();
                     ctrlc::set_handler(move || {
                         println!("\nReceived interrupt signal, cleaning up pid files");
                         cleanup_pid_file("watcher");
                         cleanup_pid_file("web");
+                        if let Some(socket_path) = &socket_to_cleanup {
+                            let _ = fs::remove_file(socket_path);
+                        }
                         std::process::exit(0);
                     })?;
 
                     files::get_and_save_video_info_from_disk()?;
 
-                    println!(
-                        "Starting atci \x1b[1mAPI\x1b[0m and \x1b[1mWeb UI\x1b[0m server on \x1b]8;;http://{}:{}\x1b\\http://{}:{}\x1b]8;;\x1b\\",
-                        host, port, host, port
-                    );
+                    if let Some(socket_path) = &unix_socket {
+                        println!(
+                            "Starting atci \x1b[1mAPI\x1b[0m and \x1b[1mWeb UI\x1b[0m server on unix socket {}",
+                            socket_path
+                        );
+                    } else {
+                        println!(
+                            "Starting atci \x1b[1mAPI\x1b[0m and \x1b[1mWeb UI\x1b[0m server on \x1b]8;;http://{}:{}\x1b\\http://{}:{}\x1b]8;;\x1b\\",
+                            host, port, host, port
+                        );
+                    }
 
                     let rt = tokio::runtime::Runtime::new()?;
                     rt.block_on(async {
@@ -1802,7 +2938,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                             }
                         }
 
-                        if let Err(e) = web::launch_server(&host, port).await {
+                        if let Some(socket_path) = &unix_socket {
+                            let result =
+                                web::launch_server_on_unix_socket(Path::new(socket_path)).await;
+                            let _ = fs::remove_file(socket_path);
+                            if let Err(e) = result {
+                                eprintln!("Error starting web server: {}", e);
+                                std::process::exit(1);
+                            }
+                        } else if let Err(e) = web::launch_server(&host, port).await {
                             eprintln!("Error starting web server: {}", e);
                             std::process::exit(1);
                         }
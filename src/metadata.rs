@@ -5,12 +5,27 @@ use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
 
-pub const META_FIELDS: &[&str] = &["length", "source"];
+pub const META_FIELDS: &[&str] = &["length", "source", "atci_version", "confidence"];
 
 #[derive(Debug, Clone, Default)]
 pub struct Metadata {
     pub length: Option<String>,
     pub source: Option<String>,
+    pub atci_version: Option<String>,
+    /// Average whisper segment log probability, closer to 0 is more confident
+    pub confidence: Option<String>,
+}
+
+/// Parse a `length:` value such as `01:02:03.5` into seconds
+pub fn parse_length_secs(length: &str) -> Option<f64> {
+    let parts: Vec<&str> = length.trim().split(':').collect();
+    if parts.len() != 3 {
+        return None;
+    }
+    let hours: f64 = parts[0].parse().ok()?;
+    let minutes: f64 = parts[1].parse().ok()?;
+    let seconds: f64 = parts[2].parse().ok()?;
+    Some(hours * 3600.0 + minutes * 60.0 + seconds)
 }
 
 pub fn get_metadata_fields(video_path: &Path) -> Option<Metadata> {
@@ -30,6 +45,8 @@ pub fn get_metadata_fields(video_path: &Path) -> Option<Metadata> {
     let mut metadata = Metadata {
         length: None,
         source: None,
+        atci_version: None,
+        confidence: None,
     };
 
     for line in lines {
@@ -37,6 +54,11 @@ pub fn get_metadata_fields(video_path: &Path) -> Option<Metadata> {
             metadata.length = Some(line.trim_start_matches("length:").trim().to_string());
         } else if line.starts_with("source:") {
             metadata.source = Some(line.trim_start_matches("source:").trim().to_string());
+        } else if line.starts_with("atci_version:") {
+            metadata.atci_version =
+                Some(line.trim_start_matches("atci_version:").trim().to_string());
+        } else if line.starts_with("confidence:") {
+            metadata.confidence = Some(line.trim_start_matches("confidence:").trim().to_string());
         }
     }
 
@@ -45,6 +45,86 @@ pub struct ModelInfo {
     pub downloaded: bool,
     pub path: String,
     pub configured: bool,
+    /// File size in megabytes (10^6 bytes), from disk once downloaded or from the
+    /// download server's Content-Length before that
+    pub size_mb: Option<u64>,
+}
+
+/// Remote sizes already looked up this session, keyed by model name. A failed lookup is
+/// stored as None so an offline machine doesn't retry on every listing.
+static REMOTE_SIZES: std::sync::OnceLock<
+    std::sync::Mutex<std::collections::HashMap<String, Option<u64>>>,
+> = std::sync::OnceLock::new();
+
+fn remote_sizes() -> &'static std::sync::Mutex<std::collections::HashMap<String, Option<u64>>> {
+    REMOTE_SIZES.get_or_init(Default::default)
+}
+
+fn bytes_to_mb(bytes: u64) -> u64 {
+    (bytes + 500_000) / 1_000_000
+}
+
+/// Format a model size for display, e.g. `(1.5 GB)` or `(142 MB)`
+pub fn format_size_mb(size_mb: u64) -> String {
+    if size_mb >= 1000 {
+        format!("({:.1} GB)", size_mb as f64 / 1000.0)
+    } else {
+        format!("({} MB)", size_mb)
+    }
+}
+
+fn fetch_remote_size_mb(client: &reqwest::blocking::Client, model_name: &str) -> Option<u64> {
+    let url = format!("{}/{}.bin", HUGGINGFACE_BASE_URL, model_name);
+    let response = client.head(&url).send().ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    // content_length() reports the (empty) HEAD body, so read the header itself
+    response
+        .headers()
+        .get(reqwest::header::CONTENT_LENGTH)?
+        .to_str()
+        .ok()?
+        .parse::<u64>()
+        .ok()
+        .map(bytes_to_mb)
+}
+
+/// Same as list_models, but also looks up the download size of models that aren't on disk.
+/// Lookups run in parallel and are cached for the rest of the session.
+pub fn list_models_with_sizes() -> Vec<ModelInfo> {
+    let mut models = list_models();
+
+    let missing: Vec<String> = models
+        .iter()
+        .filter(|m| m.size_mb.is_none() && !m.downloaded)
+        .map(|m| m.name.clone())
+        .filter(|name| !remote_sizes().lock().unwrap().contains_key(name))
+        .collect();
+    if !missing.is_empty()
+        && let Ok(client) = reqwest::blocking::Client::builder()
+            .connect_timeout(std::time::Duration::from_secs(2))
+            .timeout(std::time::Duration::from_secs(5))
+            .build()
+    {
+        let client = &client;
+        let fetched: Vec<(String, Option<u64>)> = std::thread::scope(|scope| {
+            let handles: Vec<_> = missing
+                .iter()
+                .map(|name| scope.spawn(move || (name.clone(), fetch_remote_size_mb(client, name))))
+                .collect();
+            handles.into_iter().filter_map(|h| h.join().ok()).collect()
+        });
+        remote_sizes().lock().unwrap().extend(fetched);
+    }
+
+    {
+        let sizes = remote_sizes().lock().unwrap();
+        for model in models.iter_mut().filter(|m| m.size_mb.is_none()) {
+            model.size_mb = sizes.get(&model.name).copied().flatten();
+        }
+    }
+    models
 }
 
 pub fn models_directory() -> std::path::PathBuf {
@@ -63,12 +143,22 @@ pub fn list_models() -> Vec<ModelInfo> {
         .iter()
         .map(|&model_name| {
             let model_path = models_dir.join(format!("{}.bin", model_name));
+            let size_mb = match std::fs::metadata(&model_path) {
+                Ok(metadata) => Some(bytes_to_mb(metadata.len())),
+                Err(_) => remote_sizes()
+                    .lock()
+                    .unwrap()
+                    .get(model_name)
+                    .copied()
+                    .flatten(),
+            };
 
             ModelInfo {
                 name: model_name.to_string(),
                 downloaded: model_path.exists(),
                 path: model_path.to_string_lossy().to_string(),
                 configured: model_name == configured_model,
+                size_mb,
             }
         })
         .collect()
@@ -142,19 +232,47 @@ pub struct DownloadModelRequest {
     model: String,
 }
 
+// Both handlers make blocking HTTP requests, so they run off the async executor
+
 #[get("/api/models/list")]
-pub fn web_list_models(_auth: AuthGuard) -> Json<ApiResponse<Vec<ModelInfo>>> {
-    let models = list_models();
-    Json(ApiResponse::success(models))
+pub async fn web_list_models(_auth: AuthGuard) -> Json<ApiResponse<Vec<ModelInfo>>> {
+    match tokio::task::spawn_blocking(list_models_with_sizes).await {
+        Ok(models) => Json(ApiResponse::success(models)),
+        Err(e) => Json(ApiResponse::error(e.to_string())),
+    }
 }
 
 #[post("/api/models/download", data = "<request>")]
-pub fn web_download_model(
+pub async fn web_download_model(
     _auth: AuthGuard,
     request: Json<DownloadModelRequest>,
 ) -> Json<ApiResponse<String>> {
-    match download_model(&request.model) {
+    let model = request.into_inner().model;
+    let result =
+        tokio::task::spawn_blocking(move || download_model(&model).map_err(|e| e.to_string()))
+            .await
+            .unwrap_or_else(|e| Err(e.to_string()));
+
+    match result {
         Ok(path) => Json(ApiResponse::success(path)),
-        Err(e) => Json(ApiResponse::error(e.to_string())),
+        Err(e) => Json(ApiResponse::error(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_size_mb() {
+        assert_eq!(format_size_mb(142), "(142 MB)");
+        assert_eq!(format_size_mb(1500), "(1.5 GB)");
+        assert_eq!(format_size_mb(3095), "(3.1 GB)");
+    }
+
+    #[test]
+    fn test_bytes_to_mb_rounds() {
+        assert_eq!(bytes_to_mb(147_951_465), 148);
+        assert_eq!(bytes_to_mb(1_000), 0);
     }
 }
@@ -3,9 +3,11 @@
 
 use crate::config::AtciConfig;
 use crate::video_processor;
+use std::collections::HashSet;
 use std::fs;
 use std::io::Write;
 use std::path::Path;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tokio::time::sleep;
 use walkdir::WalkDir;
@@ -14,20 +16,109 @@ use crate::auth::AuthGuard;
 use crate::config;
 use crate::db;
 use crate::files;
+use crate::metadata;
 use crate::web::ApiResponse;
+use rocket::Shutdown;
+use rocket::http::Status;
+use rocket::response::status::Custom;
+use rocket::response::stream::{Event, EventStream};
 use rocket::serde::Deserialize;
 use rocket::serde::json::Json;
 use rocket::{get, post};
 use rusqlite::Connection;
+use std::sync::OnceLock;
+use tokio::sync::broadcast;
+
+/// A message on /api/queue/stream: either a plain log line or a named event
+/// (e.g. `rescan_complete`) that the frontend can listen for with addEventListener
+#[derive(Clone)]
+struct QueueStreamMessage {
+    event: Option<&'static str>,
+    data: String,
+}
+
+static QUEUE_LOG: OnceLock<broadcast::Sender<QueueStreamMessage>> = OnceLock::new();
+
+fn queue_log_sender() -> &'static broadcast::Sender<QueueStreamMessage> {
+    QUEUE_LOG.get_or_init(|| broadcast::channel(1024).0)
+}
+
+// Print a queue processor log line and forward it to any /api/queue/stream subscribers
+fn queue_log(line: String) {
+    eprintln!("{}", line);
+    let _ = queue_log_sender().send(QueueStreamMessage {
+        event: None,
+        data: line,
+    });
+}
+
+/// Send a named event to any /api/queue/stream subscribers
+pub fn queue_event(event: &'static str, data: String) {
+    let _ = queue_log_sender().send(QueueStreamMessage {
+        event: Some(event),
+        data,
+    });
+}
 
 #[get("/api/queue")]
 pub fn web_get_queue(_auth: AuthGuard) -> Json<ApiResponse<serde_json::Value>> {
     match get_queue(None) {
-        Ok(queue_data) => Json(ApiResponse::success(queue_data.into())),
+        Ok(queue_data) => Json(ApiResponse::success(
+            queue_with_video_info(queue_data).into(),
+        )),
         Err(e) => Json(ApiResponse::error(format!("Failed to get queue: {}", e))),
     }
 }
 
+/// Look up each queued path in the file cache, falling back to just the path and name
+fn queue_with_video_info(queue_data: Vec<String>) -> Vec<serde_json::Value> {
+    let cached: std::collections::HashMap<String, files::VideoInfo> = files::load_cache_data()
+        .map(|cache_data| {
+            cache_data
+                .files
+                .into_iter()
+                .map(|info| (info.full_path.clone(), info))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    queue_data
+        .into_iter()
+        .map(|path| match cached.get(&path) {
+            Some(info) => serde_json::to_value(info).unwrap_or_default(),
+            None => {
+                let name = Path::new(&path)
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_string();
+                serde_json::json!({ "full_path": path, "name": name })
+            }
+        })
+        .collect()
+}
+
+#[get("/api/queue/stream")]
+pub fn web_stream_queue(_auth: AuthGuard, mut shutdown: Shutdown) -> EventStream![] {
+    let mut receiver = queue_log_sender().subscribe();
+    EventStream! {
+        loop {
+            let message = tokio::select! {
+                message = receiver.recv() => match message {
+                    Ok(message) => message,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+                _ = &mut shutdown => break,
+            };
+            match message.event {
+                Some(event) => yield Event::data(message.data).event(event),
+                None => yield Event::data(message.data),
+            }
+        }
+    }
+}
+
 pub fn get_queue(conn: Option<&Connection>) -> Result<Vec<String>, Box<dyn std::error::Error>> {
     let owned_conn;
     let conn = match conn {
@@ -51,7 +142,7 @@ pub fn get_queue(conn: Option<&Connection>) -> Result<Vec<String>, Box<dyn std::
 
 pub fn set_queue(paths: Vec<String>) -> Result<(), Box<dyn std::error::Error>> {
     let conn = db::get_connection()?;
-    let tx = conn.unchecked_transaction()?;
+    let tx = lock_queue_exclusive(&conn, queue_lock_timeout())?;
 
     // Get all existing paths from the queue table
     let mut existing_paths = std::collections::HashMap::new();
@@ -100,15 +191,126 @@ pub fn set_queue(paths: Vec<String>) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Removes a single pending entry from the queue. Errors if `path` isn't queued; a file
+/// that is already being processed has left the queue and has to be cancelled instead.
+pub fn remove_from_queue(path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let conn = db::get_connection()?;
+    let tx = lock_queue_exclusive(&conn, queue_lock_timeout())?;
+
+    let rows_affected = tx.execute("DELETE FROM queue WHERE path = ?1", [path])?;
+    if rows_affected == 0 {
+        return Err(format!("{} is not in the queue", path).into());
+    }
+
+    tx.commit()?;
+    Ok(())
+}
+
+/// Removes every pending entry from the queue, leaving the file currently being processed
+/// and the blocklist alone. Returns how many entries were removed.
+pub fn clear_pending_queue() -> Result<usize, Box<dyn std::error::Error>> {
+    let conn = db::get_connection()?;
+    let tx = lock_queue_exclusive(&conn, queue_lock_timeout())?;
+
+    let rows_affected = tx.execute("DELETE FROM queue", [])?;
+
+    tx.commit()?;
+    Ok(rows_affected)
+}
+
+/// Returned by `add_to_queue` when the queue already holds `max_queue_size` files
+#[derive(Debug)]
+pub struct QueueFullError {
+    pub max_queue_size: u32,
+}
+
+impl std::fmt::Display for QueueFullError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Queue is full ({} files, the max_queue_size limit)",
+            self.max_queue_size
+        )
+    }
+}
+
+impl std::error::Error for QueueFullError {}
+
+/// Returned when the queue's exclusive lock is still held by another writer after
+/// `exclusive_lock_timeout_secs`
+#[derive(Debug)]
+pub struct LockTimeout {
+    pub timeout_secs: u64,
+}
+
+impl std::fmt::Display for LockTimeout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Timed out after {}s waiting for the queue lock (exclusive_lock_timeout_secs)",
+            self.timeout_secs
+        )
+    }
+}
+
+impl std::error::Error for LockTimeout {}
+
+const LOCK_INITIAL_BACKOFF: Duration = Duration::from_millis(10);
+const LOCK_MAX_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Takes SQLite's write lock for the queue tables with `BEGIN IMMEDIATE`, retrying with
+/// exponential backoff until `timeout` has elapsed. SQLite's own busy handler is disabled
+/// while waiting so the backoff here is the only thing deciding how long we block.
+fn lock_queue_exclusive(
+    conn: &Connection,
+    timeout: Duration,
+) -> Result<rusqlite::Transaction<'_>, Box<dyn std::error::Error>> {
+    conn.busy_timeout(Duration::ZERO)?;
+    let start = std::time::Instant::now();
+    let mut backoff = LOCK_INITIAL_BACKOFF;
+
+    let result = loop {
+        match rusqlite::Transaction::new_unchecked(conn, rusqlite::TransactionBehavior::Immediate) {
+            Ok(tx) => break Ok(tx),
+            Err(rusqlite::Error::SqliteFailure(e, _))
+                if matches!(
+                    e.code,
+                    rusqlite::ErrorCode::DatabaseBusy | rusqlite::ErrorCode::DatabaseLocked
+                ) =>
+            {
+                let elapsed = start.elapsed();
+                if elapsed >= timeout {
+                    break Err(Box::new(LockTimeout {
+                        timeout_secs: timeout.as_secs(),
+                    }) as Box<dyn std::error::Error>);
+                }
+                std::thread::sleep(backoff.min(timeout - elapsed));
+                backoff = (backoff * 2).min(LOCK_MAX_BACKOFF);
+            }
+            Err(e) => break Err(e.into()),
+        }
+    };
+
+    // Restore rusqlite's default busy handler for the statements run under the lock
+    conn.busy_timeout(Duration::from_secs(5))?;
+    result
+}
+
+fn queue_lock_timeout() -> Duration {
+    Duration::from_secs(config::load_config_or_default().exclusive_lock_timeout_secs)
+}
+
 pub fn add_to_queue(
     path: &str,
     model: Option<String>,
     subtitle_stream_index: Option<i32>,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    let cfg = config::load_config_or_default();
     let conn = db::get_connection()?;
+    let tx = lock_queue_exclusive(&conn, Duration::from_secs(cfg.exclusive_lock_timeout_secs))?;
 
     // Check if this path is currently being processed
-    let is_currently_processing: bool = conn
+    let is_currently_processing: bool = tx
         .query_row(
             "SELECT COUNT(*) > 0 FROM currently_processing WHERE path = ?1",
             [path],
@@ -120,20 +322,26 @@ pub fn add_to_queue(
         return Ok(());
     }
 
-    let existing_queue = get_queue(Some(&conn))?;
+    let existing_queue = get_queue(Some(&tx))?;
 
     if existing_queue.contains(&path.to_string()) {
         return Ok(());
     }
 
+    if let Some(max_queue_size) = cfg.max_queue_size
+        && existing_queue.len() >= max_queue_size as usize
+    {
+        return Err(Box::new(QueueFullError { max_queue_size }));
+    }
+
     // Get the next position (max position + 1, or 0 if empty)
-    let next_position: i64 = conn.query_row(
+    let next_position: i64 = tx.query_row(
         "SELECT COALESCE(MAX(position), -1) + 1 FROM queue",
         [],
         |row| row.get(0),
     )?;
 
-    conn.execute(
+    tx.execute(
         "INSERT INTO queue (position, path, model, subtitle_stream_index) VALUES (?1, ?2, ?3, ?4)",
         (
             next_position,
@@ -143,12 +351,38 @@ pub fn add_to_queue(
         ),
     )?;
 
+    tx.commit()?;
     Ok(())
 }
 
+/// Which blocklist a path is recorded in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlocklistKind {
+    /// Blocked by the user
+    Manual,
+    /// Whisper ran past `processing_timeout_secs` and was killed
+    TimedOut,
+}
+
+impl BlocklistKind {
+    fn file_name(&self) -> &'static str {
+        match self {
+            BlocklistKind::Manual => ".blocklist",
+            BlocklistKind::TimedOut => ".blocklist_timed_out",
+        }
+    }
+}
+
 pub fn add_to_blocklist(path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    add_to_blocklist_kind(path, BlocklistKind::Manual)
+}
+
+pub fn add_to_blocklist_kind(
+    path: &str,
+    kind: BlocklistKind,
+) -> Result<(), Box<dyn std::error::Error>> {
     let home_dir = dirs::home_dir().ok_or("Could not find home directory")?;
-    let blocklist_path = home_dir.join(".atci/.blocklist");
+    let blocklist_path = home_dir.join(".atci").join(kind.file_name());
 
     let mut file = std::fs::OpenOptions::new()
         .create(true)
@@ -173,9 +407,13 @@ pub fn web_get_queue_status(_auth: AuthGuard) -> Json<ApiResponse<serde_json::Va
     match get_queue_status(Some(&conn)) {
         Ok((path, age)) => {
             let queue = get_queue(Some(&conn)).unwrap_or_else(|_| Vec::new());
+            let progress_percent = path.as_deref().and_then(get_progress_percent);
+            let cooling_down_secs = cooldown_remaining_secs(Some(&conn)).unwrap_or(None);
             let result = serde_json::json!({
                 "currently_processing": path.unwrap_or_else(|| "".to_string()),
                 "age_in_seconds": age,
+                "progress_percent": progress_percent,
+                "cooling_down_secs": cooling_down_secs,
                 "queue": queue
             });
             Json(ApiResponse::success(result))
@@ -198,6 +436,34 @@ pub fn web_block_path(_auth: AuthGuard, path: String) -> Json<ApiResponse<&'stat
     }
 }
 
+#[derive(Deserialize)]
+pub struct AddRequest {
+    path: String,
+    model: Option<String>,
+}
+
+#[post("/api/queue/add", data = "<request>")]
+pub fn web_add_to_queue(
+    _auth: AuthGuard,
+    request: Json<AddRequest>,
+) -> Result<Json<ApiResponse<&'static str>>, Custom<Json<ApiResponse<&'static str>>>> {
+    match add_to_queue(&request.path, request.model.clone(), None) {
+        Ok(()) => Ok(Json(ApiResponse::success("Path added to queue"))),
+        Err(e) if e.is::<QueueFullError>() => Err(Custom(
+            Status::TooManyRequests,
+            Json(ApiResponse::error(e.to_string())),
+        )),
+        Err(e) if e.is::<LockTimeout>() => Err(Custom(
+            Status::ServiceUnavailable,
+            Json(ApiResponse::error(e.to_string())),
+        )),
+        Err(e) => Ok(Json(ApiResponse::error(format!(
+            "Failed to add path to queue: {}",
+            e
+        )))),
+    }
+}
+
 #[derive(Deserialize)]
 pub struct SetRequest {
     paths: Vec<String>,
@@ -222,6 +488,98 @@ pub fn web_cancel_queue(_auth: AuthGuard) -> Json<ApiResponse<String>> {
     }
 }
 
+// Rough number of cues whisper emits per second of audio, used to estimate progress
+const AVERAGE_CUES_PER_SECOND: f32 = 0.25;
+
+/// Estimate how far along the transcript for `path` is by comparing the cues whisper
+/// has written so far against the number expected for the video's duration
+pub fn get_progress_percent(path: &str) -> Option<f32> {
+    let conn = db::get_connection().ok()?;
+    let cfg = config::load_config_or_default();
+    progress_percent(&conn, path, &cfg.ffprobe_path)
+}
+
+fn progress_percent(conn: &Connection, path: &str, ffprobe_path: &str) -> Option<f32> {
+    let progress_path = video_processor::progress_output_path(Path::new(path));
+    let output = fs::read_to_string(progress_path).ok()?;
+    let cue_count = output.lines().filter(|line| line.contains("-->")).count();
+
+    let duration = processing_duration_secs(conn, path, ffprobe_path)?;
+    let expected_cues = duration * AVERAGE_CUES_PER_SECOND;
+    if expected_cues <= 0.0 {
+        return None;
+    }
+
+    // Never report completion until the transcript has actually been written
+    Some((cue_count as f32 / expected_cues * 100.0).min(99.0))
+}
+
+/// Duration of an in-flight video in seconds. Progress is polled every few seconds, so it's
+/// worked out once, from the cached `video_info` length when there is one and ffprobe
+/// otherwise, and kept on the file's currently_processing row.
+fn processing_duration_secs(conn: &Connection, path: &str, ffprobe_path: &str) -> Option<f32> {
+    let stored: Option<f64> = conn
+        .query_row(
+            "SELECT duration_secs FROM currently_processing WHERE path = ?1",
+            [path],
+            |row| row.get(0),
+        )
+        .ok()
+        .flatten();
+    if let Some(duration) = stored {
+        return Some(duration as f32);
+    }
+
+    let cached_length: Option<String> = conn
+        .query_row(
+            "SELECT duration FROM video_info WHERE full_path = ?1",
+            [path],
+            |row| row.get(0),
+        )
+        .ok()
+        .flatten();
+    let duration = match cached_length
+        .as_deref()
+        .and_then(files::parse_duration_to_seconds)
+    {
+        Some(seconds) => seconds as f32,
+        None => {
+            let duration_output = std::process::Command::new(ffprobe_path)
+                .args([
+                    "-v",
+                    "error",
+                    "-show_entries",
+                    "format=duration",
+                    "-of",
+                    "default=noprint_wrappers=1:nokey=1",
+                    path,
+                ])
+                .output()
+                .ok()?;
+            String::from_utf8_lossy(&duration_output.stdout)
+                .trim()
+                .parse()
+                .ok()?
+        }
+    };
+
+    let _ = conn.execute(
+        "UPDATE currently_processing SET duration_secs = ?2 WHERE path = ?1",
+        (path, duration as f64),
+    );
+    Some(duration)
+}
+
+/// Whether `path` has been moved out of the queue and is being transcribed right now
+pub fn is_currently_processing(path: &str) -> Result<bool, Box<dyn std::error::Error>> {
+    let conn = db::get_connection()?;
+    Ok(conn.query_row(
+        "SELECT COUNT(*) > 0 FROM currently_processing WHERE path = ?1",
+        [path],
+        |row| row.get(0),
+    )?)
+}
+
 pub fn get_queue_status(
     conn: Option<&Connection>,
 ) -> Result<(Option<String>, u64), Box<dyn std::error::Error>> {
@@ -237,7 +595,8 @@ pub fn get_queue_status(
     // Get the currently processing item with its starting time
     let result: Option<(String, Option<String>)> = conn
         .query_row(
-            "SELECT path, starting_time FROM currently_processing LIMIT 1",
+            "SELECT path, starting_time FROM currently_processing
+             WHERE cooling_down_until IS NULL ORDER BY id LIMIT 1",
             [],
             |row| Ok((row.get::<_, String>(0)?, row.get::<_, Option<String>>(1)?)),
         )
@@ -266,6 +625,195 @@ pub fn get_queue_status(
     }
 }
 
+/// Mark `path`'s currently_processing row as cooling down for `delay_secs`
+fn start_cooldown(conn: &Connection, path: &str, delay_secs: u32) -> rusqlite::Result<()> {
+    let until = chrono::Utc::now() + chrono::Duration::seconds(delay_secs as i64);
+    conn.execute(
+        "UPDATE currently_processing SET cooling_down_until = ?2 WHERE path = ?1",
+        (path, until.to_rfc3339()),
+    )?;
+    Ok(())
+}
+
+/// Seconds left before the queue picks up its next file, if it's cooling down after one
+pub fn cooldown_remaining_secs(
+    conn: Option<&Connection>,
+) -> Result<Option<u64>, Box<dyn std::error::Error>> {
+    let owned_conn;
+    let conn = match conn {
+        Some(c) => c,
+        None => {
+            owned_conn = db::get_connection()?;
+            &owned_conn
+        }
+    };
+
+    let until: Option<String> = conn.query_row(
+        "SELECT MAX(cooling_down_until) FROM currently_processing",
+        [],
+        |row| row.get(0),
+    )?;
+    Ok(until
+        .and_then(|until| chrono::DateTime::parse_from_rfc3339(&until).ok())
+        .map(|until| {
+            until
+                .with_timezone(&chrono::Utc)
+                .signed_duration_since(chrono::Utc::now())
+                .num_seconds()
+                .max(0) as u64
+        }))
+}
+
+/// Final result of a queue item, recorded in processing_history
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessingOutcome {
+    Success,
+    Failed,
+    /// The file disappeared from disk before processing started
+    Skipped,
+    /// The file was smaller than `min_video_size_bytes`
+    SkippedTooSmall,
+    /// The file was larger than `max_file_size_gb`
+    SkippedTooLarge,
+}
+
+impl ProcessingOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProcessingOutcome::Success => "success",
+            ProcessingOutcome::Failed => "failed",
+            ProcessingOutcome::Skipped => "skipped",
+            ProcessingOutcome::SkippedTooSmall => "skipped_too_small",
+            ProcessingOutcome::SkippedTooLarge => "skipped_too_large",
+        }
+    }
+}
+
+pub fn record_processing_outcome(
+    conn: &Connection,
+    path: &str,
+    outcome: ProcessingOutcome,
+) -> Result<(), Box<dyn std::error::Error>> {
+    record_processing_outcome_with_warning(conn, path, outcome, None)
+}
+
+pub fn record_processing_outcome_with_warning(
+    conn: &Connection,
+    path: &str,
+    outcome: ProcessingOutcome,
+    warning: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    conn.execute(
+        "INSERT INTO processing_history (path, outcome, finished_at, warning) VALUES (?1, ?2, ?3, ?4)",
+        (path, outcome.as_str(), chrono::Utc::now().to_rfc3339(), warning),
+    )?;
+    Ok(())
+}
+
+/// Warning for transcripts whisper wasn't confident about, based on the
+/// `confidence` metadata written from its JSON sidecar
+fn low_confidence_warning(video_path: &Path) -> Option<String> {
+    let confidence: f64 = metadata::get_metadata_fields(video_path)?
+        .confidence?
+        .parse()
+        .ok()?;
+    (confidence < video_processor::LOW_CONFIDENCE_THRESHOLD)
+        .then(|| format!("Low transcription confidence ({:.3})", confidence))
+}
+
+/// A row of processing_history
+#[derive(Debug, serde::Serialize)]
+pub struct ProcessingHistoryEntry {
+    pub path: String,
+    pub outcome: String,
+    pub finished_at: String,
+    pub warning: Option<String>,
+}
+
+/// The `limit` most recently finished queue items, newest first
+pub fn processing_history(
+    limit: usize,
+) -> Result<Vec<ProcessingHistoryEntry>, Box<dyn std::error::Error>> {
+    query_processing_history(&db::get_connection()?, limit)
+}
+
+fn query_processing_history(
+    conn: &Connection,
+    limit: usize,
+) -> Result<Vec<ProcessingHistoryEntry>, Box<dyn std::error::Error>> {
+    let mut stmt = conn.prepare(
+        "SELECT path, outcome, finished_at, warning FROM processing_history ORDER BY id DESC LIMIT ?1",
+    )?;
+    let entries = stmt
+        .query_map([limit as i64], |row| {
+            Ok(ProcessingHistoryEntry {
+                path: row.get(0)?,
+                outcome: row.get(1)?,
+                finished_at: row.get(2)?,
+                warning: row.get(3)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(entries)
+}
+
+/// The most recent recorded outcome for a file, as stored by `record_processing_outcome`
+pub fn last_processing_outcome(path: &str) -> Option<String> {
+    db::get_connection()
+        .and_then(|conn| {
+            conn.query_row(
+                "SELECT outcome FROM processing_history WHERE path = ?1 ORDER BY id DESC LIMIT 1",
+                [path],
+                |row| row.get(0),
+            )
+        })
+        .ok()
+}
+
+/// `max_file_size_gb` counts decimal gigabytes, matching how drives and file managers report sizes
+fn exceeds_max_file_size(file_size: u64, max_file_size_gb: Option<f32>) -> bool {
+    max_file_size_gb.is_some_and(|max_gb| file_size as f64 > max_gb as f64 * 1e9)
+}
+
+/// Whether a file is still outside the configured size limits and was already skipped for it
+fn was_skipped_for_size(path: &str, file_size: u64) -> bool {
+    let cfg = crate::config::load_config_or_default();
+    let outcome = if file_size < cfg.min_video_size_bytes {
+        ProcessingOutcome::SkippedTooSmall
+    } else if exceeds_max_file_size(file_size, cfg.max_file_size_gb) {
+        ProcessingOutcome::SkippedTooLarge
+    } else {
+        return false;
+    };
+    db::get_connection()
+        .and_then(|conn| {
+            conn.query_row(
+                "SELECT COUNT(*) > 0 FROM processing_history WHERE path = ?1 AND outcome = ?2",
+                (path, outcome.as_str()),
+                |row| row.get(0),
+            )
+        })
+        .unwrap_or(false)
+}
+
+/// A queued file with its model and subtitle stream overrides
+type QueueItem = (String, Option<String>, Option<i64>);
+
+fn first_queue_entry(conn: &Connection) -> Option<QueueItem> {
+    conn.query_row(
+        "SELECT path, model, subtitle_stream_index FROM queue ORDER BY position LIMIT 1",
+        [],
+        |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, Option<String>>(1)?,
+                row.get::<_, Option<i64>>(2)?,
+            ))
+        },
+    )
+    .ok()
+}
+
 fn remove_first_line_from_queue(
     conn: Option<&Connection>,
 ) -> Result<(), Box<dyn std::error::Error>> {
@@ -278,7 +826,7 @@ fn remove_first_line_from_queue(
             &owned_conn
         }
     };
-    let tx = conn.unchecked_transaction()?;
+    let tx = lock_queue_exclusive(conn, queue_lock_timeout())?;
 
     tx.execute(
         "DELETE FROM queue WHERE position = (SELECT MIN(position) FROM queue)",
@@ -292,24 +840,84 @@ fn remove_first_line_from_queue(
     Ok(())
 }
 
+/// Items the watcher has moved into currently_processing, oldest first
+fn currently_processing_items(
+    conn: &Connection,
+) -> Result<Vec<QueueItem>, Box<dyn std::error::Error>> {
+    let mut stmt = conn.prepare(
+        "SELECT path, model, subtitle_stream_index FROM currently_processing ORDER BY id",
+    )?;
+    let items = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, Option<String>>(1)?,
+                row.get::<_, Option<i64>>(2)?,
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(items)
+}
+
+/// Which of `items` to start now: those not already running, oldest first, until
+/// `max_concurrent` jobs are in flight
+fn items_to_start(
+    items: Vec<QueueItem>,
+    in_flight: &HashSet<String>,
+    max_concurrent: usize,
+) -> Vec<QueueItem> {
+    let free = max_concurrent.max(1).saturating_sub(in_flight.len());
+    items
+        .into_iter()
+        .filter(|(path, _, _)| !in_flight.contains(path))
+        .take(free)
+        .collect()
+}
+
 pub async fn process_queue() -> Result<(), Box<dyn std::error::Error>> {
-    tokio::spawn(async {
-        eprintln!("[QUEUE] Queue processor started");
+    let in_flight: Arc<Mutex<HashSet<String>>> = Arc::new(Mutex::new(HashSet::new()));
+
+    tokio::spawn(async move {
+        queue_log("[QUEUE] Queue processor started".to_string());
         loop {
-            match process_queue_iteration().await {
-                Ok(processed) => {
-                    if processed {
-                        eprintln!("[QUEUE] Successfully processed queue item");
+            let items = match db::get_connection() {
+                Ok(conn) => currently_processing_items(&conn).unwrap_or_default(),
+                Err(_) => Vec::new(),
+            };
+
+            // Read the limit every pass, like the watcher does, so a config change applies
+            // without a restart. Lowering it lets running jobs finish rather than stopping them.
+            let max_concurrent =
+                config::load_config_or_default().max_concurrent_transcriptions as usize;
+            let to_start = items_to_start(items, &in_flight.lock().unwrap(), max_concurrent);
+
+            // Start items in the order the watcher moved them over
+            for (path, model, subtitle_stream_index) in to_start {
+                in_flight.lock().unwrap().insert(path.clone());
+
+                let in_flight = in_flight.clone();
+                tokio::spawn(async move {
+                    match process_queue_item(&path, model, subtitle_stream_index).await {
+                        Ok(processed) => {
+                            if processed {
+                                queue_log("[QUEUE] Successfully processed queue item".to_string());
+                            }
+                        }
+                        Err(e) => {
+                            queue_log(format!("[QUEUE] Error processing queue: {}", e));
+                        }
                     }
-                }
-                Err(e) => {
-                    eprintln!("[QUEUE] Error processing queue: {}", e);
-                }
-            }
 
-            // Clear the currently_processing table after each iteration
-            if let Ok(conn) = db::get_connection() {
-                let _ = conn.execute("DELETE FROM currently_processing", []);
+                    // Free the slot so the watcher can move the next queued file in, and drop
+                    // a cancel request that arrived after the job's last check
+                    if let Ok(conn) = db::get_connection() {
+                        let _ = conn
+                            .execute("DELETE FROM currently_processing WHERE path = ?1", [&path]);
+                        let _ =
+                            conn.execute("DELETE FROM cancel_requests WHERE path = ?1", [&path]);
+                    }
+                    in_flight.lock().unwrap().remove(&path);
+                });
             }
 
             sleep(Duration::from_secs(2)).await;
@@ -318,125 +926,198 @@ pub async fn process_queue() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-pub async fn process_queue_iteration() -> Result<bool, Box<dyn std::error::Error>> {
+async fn process_queue_item(
+    video_path_str: &str,
+    model: Option<String>,
+    subtitle_stream_index: Option<i64>,
+) -> Result<bool, Box<dyn std::error::Error>> {
     let conn = db::get_connection()?;
 
-    // Get the currently processing item with model and subtitle stream info
-    let current_item: Option<(String, Option<String>, Option<i64>)> = conn
-        .query_row(
-            "SELECT path, model, subtitle_stream_index FROM currently_processing LIMIT 1",
-            [],
-            |row| {
-                Ok((
-                    row.get::<_, String>(0)?,
-                    row.get::<_, Option<String>>(1)?,
-                    row.get::<_, Option<i64>>(2)?,
-                ))
-            },
-        )
-        .ok();
+    queue_log(format!("[QUEUE] Processing queue item: {}", video_path_str));
+    queue_log(format!(
+        "[QUEUE] Model: {:?}, Subtitle stream: {:?}",
+        model, subtitle_stream_index
+    ));
+    let video_path_str = video_path_str.trim();
+    if video_path_str.is_empty() {
+        return Ok(false);
+    }
 
-    if let Some((video_path_str, model, subtitle_stream_index)) = current_item {
-        eprintln!("[QUEUE] Processing queue item: {}", video_path_str);
-        eprintln!(
-            "[QUEUE] Model: {:?}, Subtitle stream: {:?}",
-            model, subtitle_stream_index
-        );
-        let video_path_str = video_path_str.trim();
-        if video_path_str.is_empty() {
-            return Ok(false);
-        }
+    let video_path = Path::new(video_path_str);
 
-        let video_path = Path::new(video_path_str);
+    if !video_path.exists() {
+        queue_log(format!("Video file does not exist: {}", video_path_str));
+        record_processing_outcome(&conn, video_path_str, ProcessingOutcome::Skipped)?;
+        return Ok(true);
+    }
 
-        if !video_path.exists() {
-            eprintln!("Video file does not exist: {}", video_path_str);
-            return Ok(true);
-        }
+    let video_extensions = crate::files::get_video_extensions();
+    let has_valid_extension = video_path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| video_extensions.contains(&ext.to_lowercase().as_str()))
+        .unwrap_or(false);
 
-        let video_extensions = crate::files::get_video_extensions();
-        let has_valid_extension = video_path
-            .extension()
-            .and_then(|ext| ext.to_str())
-            .map(|ext| video_extensions.contains(&ext.to_lowercase().as_str()))
-            .unwrap_or(false);
-
-        if !has_valid_extension {
-            eprintln!(
-                "File does not have a valid video extension: {}",
-                video_path_str
-            );
+    if !has_valid_extension {
+        queue_log(format!(
+            "File does not have a valid video extension: {}",
+            video_path_str
+        ));
+        return Ok(true);
+    }
+
+    let size_cfg = crate::config::load_config_or_default();
+    let min_size = size_cfg.min_video_size_bytes;
+    let file_size = fs::metadata(video_path)?.len();
+    if file_size < min_size {
+        queue_log(format!(
+            "Skipping {}: {} bytes is below the minimum size of {} bytes",
+            video_path_str, file_size, min_size
+        ));
+        record_processing_outcome(&conn, video_path_str, ProcessingOutcome::SkippedTooSmall)?;
+        return Ok(true);
+    }
+    if exceeds_max_file_size(file_size, size_cfg.max_file_size_gb) {
+        queue_log(format!(
+            "Skipping {}: {:.2} GB is above the max_file_size_gb limit of {} GB",
+            video_path_str,
+            file_size as f64 / 1e9,
+            size_cfg.max_file_size_gb.unwrap_or_default()
+        ));
+        record_processing_outcome(&conn, video_path_str, ProcessingOutcome::SkippedTooLarge)?;
+        return Ok(true);
+    }
+
+    let mut processing_successful = true;
+    let mut error_message = String::new();
+
+    // Create transcript with cancellation support
+    let subtitle_index_i32 = subtitle_stream_index.map(|i| i as i32);
+    match video_processor::cancellable_create_transcript(video_path, model, subtitle_index_i32)
+        .await
+    {
+        Ok(true) => {
+            // Successfully created transcript, continue
+        }
+        Ok(false) => {
+            // Cancelled, exit early
+            queue_log(format!("Processing cancelled for: {}", video_path_str));
             return Ok(true);
         }
+        Err(e) => {
+            processing_successful = false;
+            error_message = format!("Error creating transcript: {}", e);
+            queue_log(format!(
+                "Error creating transcript for {}: {}",
+                video_path_str, e
+            ));
+        }
+    }
 
-        let mut processing_successful = true;
-        let mut error_message = String::new();
-
-        // Create transcript with cancellation support
-        let subtitle_index_i32 = subtitle_stream_index.map(|i| i as i32);
-        match video_processor::cancellable_create_transcript(video_path, model, subtitle_index_i32)
-            .await
-        {
+    // Update metadata with length and cancellation support (only if transcript creation succeeded)
+    if processing_successful {
+        match video_processor::cancellable_add_length_to_metadata(video_path).await {
             Ok(true) => {
-                // Successfully created transcript, continue
+                // Successfully added metadata, continue
             }
             Ok(false) => {
                 // Cancelled, exit early
-                println!("Processing cancelled for: {}", video_path_str);
+                queue_log(format!("Processing cancelled for: {}", video_path_str));
                 return Ok(true);
             }
             Err(e) => {
                 processing_successful = false;
-                error_message = format!("Error creating transcript: {}", e);
-                eprintln!("Error creating transcript for {}: {}", video_path_str, e);
+                error_message = format!("Error adding length metadata: {}", e);
+                queue_log(format!(
+                    "Error adding length metadata for {}: {}",
+                    video_path_str, e
+                ));
             }
         }
+    }
 
-        // Update metadata with length and cancellation support (only if transcript creation succeeded)
-        if processing_successful {
-            match video_processor::cancellable_add_length_to_metadata(video_path).await {
-                Ok(true) => {
-                    // Successfully added metadata, continue
-                }
-                Ok(false) => {
-                    // Cancelled, exit early
-                    println!("Processing cancelled for: {}", video_path_str);
-                    return Ok(true);
-                }
-                Err(e) => {
-                    processing_successful = false;
-                    error_message = format!("Error adding length metadata: {}", e);
-                    eprintln!("Error adding length metadata for {}: {}", video_path_str, e);
-                }
-            }
+    // Note: Success/failure commands are now handled directly by the transcript creation functions
+    // to avoid duplicate execution, especially for video parts
+    if processing_successful {
+        queue_log(format!(
+            "Processed queue item successfully: {}",
+            video_path_str
+        ));
+        let warning = low_confidence_warning(video_path);
+        if let Some(warning) = &warning {
+            queue_log(format!("Warning for {}: {}", video_path_str, warning));
         }
-
-        // Note: Success/failure commands are now handled directly by the transcript creation functions
-        // to avoid duplicate execution, especially for video parts
-        if processing_successful {
-            println!("Processed queue item successfully: {}", video_path_str);
-        } else {
-            eprintln!(
-                "Processing failed for {}: {}",
-                video_path_str, error_message
-            );
+        record_processing_outcome_with_warning(
+            &conn,
+            video_path_str,
+            ProcessingOutcome::Success,
+            warning.as_deref(),
+        )?;
+        if let Err(e) = video_processor::clear_error_file(video_path) {
+            queue_log(format!(
+                "Could not remove error file for {}: {}",
+                video_path_str, e
+            ));
+        }
+    } else {
+        queue_log(format!(
+            "Processing failed for {}: {}",
+            video_path_str, error_message
+        ));
+        record_processing_outcome(&conn, video_path_str, ProcessingOutcome::Failed)?;
+        if let Err(e) = video_processor::write_error_file(video_path, &error_message) {
+            queue_log(format!(
+                "Could not write error file for {}: {}",
+                video_path_str, e
+            ));
         }
-
-        // Update file info regardless of processing result
-        files::get_and_save_video_info_from_disk()?;
-        return Ok(true);
     }
 
-    Ok(false)
+    // Update file info regardless of processing result
+    files::get_and_save_video_info_from_disk()?;
+
+    // Give the machine a break before picking up the next file. The finished file keeps
+    // its slot meanwhile so the watcher doesn't move the next one in, and status reports
+    // the cooldown instead of a file being processed.
+    if processing_successful {
+        let delay_secs = crate::config::load_config_or_default().inter_processing_delay_secs;
+        if delay_secs > 0 {
+            start_cooldown(&conn, video_path_str, delay_secs)?;
+            queue_log(format!(
+                "[QUEUE] Cooling down for {} seconds before next file",
+                delay_secs
+            ));
+            queue_event("cooling_down", delay_secs.to_string());
+            sleep(Duration::from_secs(delay_secs as u64)).await;
+        }
+    }
+    Ok(true)
 }
 
 pub fn cancel_queue() -> Result<String, Box<dyn std::error::Error>> {
     let conn = crate::db::get_connection()?;
+    request_cancel(&conn)
+}
 
-    // Check if a cancel request already exists
-    let existing: Option<String> = conn
+/// Ask every job in currently_processing to stop. Each job gets its own request, which
+/// it removes once it has cancelled, so one job finishing doesn't clear the others'.
+fn request_cancel(conn: &Connection) -> Result<String, Box<dyn std::error::Error>> {
+    let tx = lock_queue_exclusive(conn, queue_lock_timeout())?;
+
+    let processing: Vec<String> = tx
+        .prepare("SELECT path FROM currently_processing ORDER BY id")?
+        .query_map([], |row| row.get(0))?
+        .collect::<Result<_, _>>()?;
+    if processing.is_empty() {
+        return Ok("Nothing is currently processing".to_string());
+    }
+
+    // Check if every job already has a cancel request
+    let existing: Option<String> = tx
         .query_row(
-            "SELECT created_at FROM cancel_requests LIMIT 1",
+            "SELECT MIN(c.created_at) FROM cancel_requests c
+             WHERE c.path IN (SELECT path FROM currently_processing)
+             HAVING COUNT(DISTINCT c.path) = (SELECT COUNT(DISTINCT path) FROM currently_processing)",
             [],
             |row| row.get(0),
         )
@@ -451,124 +1132,375 @@ pub fn cancel_queue() -> Result<String, Box<dyn std::error::Error>> {
         let now = chrono::Utc::now()
             .format("%Y-%m-%d %H:%M:%S UTC")
             .to_string();
-        let rows_affected = conn.execute(
-            "INSERT INTO cancel_requests (created_at) VALUES (?1)",
+        let rows_affected = tx.execute(
+            "INSERT INTO cancel_requests (created_at, path)
+             SELECT DISTINCT ?1, path FROM currently_processing
+             WHERE path NOT IN (SELECT path FROM cancel_requests)",
             [&now],
         )?;
+        tx.commit()?;
         if rows_affected > 0 {
-            Ok("Created cancel request".to_string())
+            Ok(format!(
+                "Created cancel request for {} in-flight job(s)",
+                processing.len()
+            ))
         } else {
             Err("Failed to create cancel request".into())
         }
     }
 }
 
+/// How often the watcher moves queued files into currently_processing. Scanning the watch
+/// directories for new files only happens every `watch_poll_interval_secs`.
+const WATCHER_TICK: Duration = Duration::from_millis(500);
+
 pub async fn watch_for_missing_metadata() -> Result<(), Box<dyn std::error::Error>> {
     tokio::spawn(async move {
         eprintln!("[WATCHER] Metadata watcher started");
+        let mut last_scan: Option<std::time::Instant> = None;
         loop {
             let cfg: AtciConfig = config::load_config().expect("Failed to load config");
 
-            let video_extensions = crate::files::get_video_extensions();
-
             if cfg.watch_directories.is_empty() {
                 eprintln!("No watch directories configured");
                 return;
             }
 
-            // Scan watch directories and add files to queue
-            let files_to_add: Vec<_> = cfg
-                .watch_directories
-                .iter()
-                .flat_map(|wd| {
-                    let mut files: Vec<_> = WalkDir::new(wd)
-                        .into_iter()
-                        .filter_map(|e| e.ok())
-                        .filter_map(|entry| {
-                            let file_path = entry.path();
-
-                            // skip directories
-                            if !file_path.is_file() {
-                                return None;
-                            }
+            let scan_interval = Duration::from_secs(cfg.watch_poll_interval_secs.max(1));
+            if last_scan.is_none_or(|scanned_at| scanned_at.elapsed() >= scan_interval) {
+                queue_new_files(&cfg);
+                last_scan = Some(std::time::Instant::now());
+            }
 
-                            if let Some(extension) = file_path.extension() {
-                                let ext_str = extension.to_string_lossy().to_lowercase();
+            fill_currently_processing(&cfg);
+            sleep(WATCHER_TICK).await;
+        }
+    });
+    Ok(())
+}
 
-                                // we're only interested in video files
-                                if !video_extensions.contains(&ext_str.as_str()) {
-                                    return None;
-                                }
+/// Scan the watch directories and add files without a transcript to the queue
+fn queue_new_files(cfg: &AtciConfig) {
+    let video_extensions = crate::files::get_video_extensions();
+
+    let files_to_add: Vec<_> = cfg
+        .watch_directories
+        .iter()
+        .flat_map(|wd| {
+            let mut files: Vec<_> = WalkDir::new(wd)
+                .into_iter()
+                .filter_map(|e| e.ok())
+                .filter_map(|entry| {
+                    let file_path = entry.path();
+
+                    // skip directories
+                    if !file_path.is_file() {
+                        return None;
+                    }
 
-                                // we want to make sure the file isn't in the process of currently being copied over to our watch directory
-                                // since there isn't any way to actually tell for sure via an api call, a useful proxy for this is that the file hasn't been modified in the last 3 seconds
-                                if let Ok(metadata) = fs::metadata(file_path)
-                                    && let Ok(modified) = metadata.modified()
+                    if let Some(extension) = file_path.extension() {
+                        let ext_str = extension.to_string_lossy().to_lowercase();
+
+                        // we're only interested in video files
+                        if !video_extensions.contains(&ext_str.as_str()) {
+                            return None;
+                        }
+
+                        // we want to make sure the file isn't in the process of currently being copied over to our watch directory
+                        // since there isn't any way to actually tell for sure via an api call, a useful proxy for this is that the file hasn't been modified in the last 3 seconds
+                        if let Ok(metadata) = fs::metadata(file_path)
+                            && let Ok(modified) = metadata.modified()
+                        {
+                            let now = std::time::SystemTime::now();
+                            if let Ok(duration) = now.duration_since(modified)
+                                && duration.as_secs() >= 3
+                            {
+                                let txt_path = file_path.with_extension("txt");
+                                let path_str = file_path.to_string_lossy().to_string();
+
+                                // files that already failed or were skipped for their size would otherwise be re-queued on every scan
+                                if !txt_path.exists()
+                                    && !video_processor::error_file_path(file_path).exists()
+                                    && !was_skipped_for_size(&path_str, metadata.len())
                                 {
-                                    let now = std::time::SystemTime::now();
-                                    if let Ok(duration) = now.duration_since(modified)
-                                        && duration.as_secs() >= 3
-                                    {
-                                        let txt_path = file_path.with_extension("txt");
-
-                                        if !txt_path.exists() {
-                                            return Some(file_path.to_string_lossy().to_string());
-                                        }
-                                    }
+                                    return Some(path_str);
                                 }
                             }
-                            None
-                        })
-                        .collect::<Vec<_>>();
-                    files.sort();
-                    files
+                        }
+                    }
+                    None
                 })
-                .collect();
-
-            for file_to_add in files_to_add {
-                if let Err(e) = add_to_queue(&file_to_add, None, None) {
-                    eprintln!("[WATCHER] Error adding to queue: {}", e);
-                } else {
-                    eprintln!("[WATCHER] Added to queue: {}", file_to_add);
-                }
+                .collect::<Vec<_>>();
+            files.sort();
+            files
+        })
+        .collect();
+
+    for file_to_add in files_to_add {
+        if let Err(e) = add_to_queue(&file_to_add, None, None) {
+            eprintln!("[WATCHER] Error adding to queue: {}", e);
+            // The rest are picked up on a later scan once the queue has room
+            if e.is::<QueueFullError>() {
+                break;
             }
+        } else {
+            eprintln!("[WATCHER] Added to queue: {}", file_to_add);
+        }
+    }
+}
 
-            // after we've added all the files to the queue, check if there's anything currently processing
-            // if not, take the first item from the queue and add it to currently_processing table
-            if let Ok(conn) = db::get_connection() {
-                let has_current_processing: bool = conn
-                    .query_row("SELECT COUNT(*) > 0 FROM currently_processing", [], |row| {
-                        row.get(0)
-                    })
-                    .unwrap_or(false);
-
-                if !has_current_processing {
-                    // Get the first item from queue with all its data
-                    if let Ok(mut stmt) = conn.prepare("SELECT path, model, subtitle_stream_index FROM queue ORDER BY position LIMIT 1")
-                        && let Ok(mut rows) = stmt.query_map([], |row| {
-                            Ok((
-                                row.get::<_, String>(0)?,
-                                row.get::<_, Option<String>>(1)?,
-                                row.get::<_, Option<i64>>(2)?
-                            ))
-                        })
-                            && let Some(Ok((path, model, subtitle_stream_index))) = rows.next() {
-                                eprintln!("[WATCHER] Moving to currently_processing: {}", path);
-                                // Add to currently_processing table with current timestamp
-                                let now = chrono::Utc::now().to_rfc3339();
-                                let _ = conn.execute(
-                                    "INSERT INTO currently_processing (starting_time, path, model, subtitle_stream_index) VALUES (?1, ?2, ?3, ?4)",
-                                    (now, &path, model, subtitle_stream_index),
-                                );
-
-                                // Remove from queue
-                                let _ = remove_first_line_from_queue(Some(&conn));
-                                eprintln!("[WATCHER] Moved item to currently_processing and removed from queue");
-                            }
-                }
+/// Move items from the front of the queue into currently_processing until
+/// max_concurrent_transcriptions are in flight
+fn fill_currently_processing(cfg: &AtciConfig) {
+    if let Ok(conn) = db::get_connection() {
+        let mut processing_count: u32 = conn
+            .query_row("SELECT COUNT(*) FROM currently_processing", [], |row| {
+                row.get(0)
+            })
+            .unwrap_or(u32::MAX);
+
+        // Take items from the front of the queue, skipping files deleted while they waited
+        while processing_count < cfg.max_concurrent_transcriptions.max(1)
+            && let Some((path, model, subtitle_stream_index)) = first_queue_entry(&conn)
+        {
+            if !Path::new(&path).exists() {
+                eprintln!("[WATCHER] Skipping deleted file: {}", path);
+                let _ = record_processing_outcome(&conn, &path, ProcessingOutcome::Skipped);
+                let _ = remove_first_line_from_queue(Some(&conn));
+                continue;
             }
-            sleep(Duration::from_millis(500)).await;
+
+            eprintln!("[WATCHER] Moving to currently_processing: {}", path);
+            // Add to currently_processing table with current timestamp
+            let now = chrono::Utc::now().to_rfc3339();
+            let _ = conn.execute(
+                "INSERT INTO currently_processing (starting_time, path, model, subtitle_stream_index) VALUES (?1, ?2, ?3, ?4)",
+                (now, &path, model, subtitle_stream_index),
+            );
+
+            // Remove from queue
+            let _ = remove_first_line_from_queue(Some(&conn));
+            eprintln!("[WATCHER] Moved item to currently_processing and removed from queue");
+            processing_count += 1;
         }
-    });
-    Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exceeds_max_file_size() {
+        assert!(!exceeds_max_file_size(50_000_000_000, None));
+        assert!(!exceeds_max_file_size(2_000_000_000, Some(2.0)));
+        assert!(exceeds_max_file_size(2_000_000_001, Some(2.0)));
+        assert!(exceeds_max_file_size(600_000_000, Some(0.5)));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_progress_probes_duration_once_per_file() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let video = dir.path().join("a.mp4");
+        let path = video.to_string_lossy().to_string();
+        // 40s of video is expected to produce 10 cues; 5 have been written
+        fs::write(
+            video_processor::progress_output_path(&video),
+            "00:00.000 --> 00:01.000\n".repeat(5),
+        )
+        .unwrap();
+
+        let conn = db::open_in_memory().unwrap();
+        conn.execute(
+            "INSERT INTO currently_processing (starting_time, path) VALUES ('now', ?1)",
+            [&path],
+        )
+        .unwrap();
+
+        // ffprobe stand-in that reports 40 seconds and counts its runs
+        let probes = dir.path().join("probes");
+        let ffprobe = dir.path().join("ffprobe");
+        fs::write(
+            &ffprobe,
+            format!("#!/bin/sh\necho run >> '{}'\necho 40.0\n", probes.display()),
+        )
+        .unwrap();
+        fs::set_permissions(&ffprobe, fs::Permissions::from_mode(0o755)).unwrap();
+
+        for _ in 0..3 {
+            let percent = progress_percent(&conn, &path, &ffprobe.to_string_lossy()).unwrap();
+            assert!((percent - 50.0).abs() < 0.01);
+        }
+        assert_eq!(fs::read_to_string(&probes).unwrap().lines().count(), 1);
+    }
+
+    #[test]
+    fn test_progress_uses_cached_video_length() {
+        let dir = tempfile::tempdir().unwrap();
+        let video = dir.path().join("b.mp4");
+        let path = video.to_string_lossy().to_string();
+        fs::write(
+            video_processor::progress_output_path(&video),
+            "00:00.000 --> 00:01.000\n",
+        )
+        .unwrap();
+
+        let conn = db::open_in_memory().unwrap();
+        conn.execute(
+            "INSERT INTO currently_processing (starting_time, path) VALUES ('now', ?1)",
+            [&path],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO video_info (name, base_name, created_at, line_count, full_path, transcript, duration)
+             VALUES ('b.mp4', 'b', 'now', 0, ?1, 0, '00:00:40')",
+            [&path],
+        )
+        .unwrap();
+
+        // No ffprobe is needed when the length is already cached
+        let percent = progress_percent(&conn, &path, "/nonexistent/ffprobe").unwrap();
+        assert!((percent - 10.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_cooldown_is_reported_instead_of_processing() {
+        let conn = db::open_in_memory().unwrap();
+        conn.execute(
+            "INSERT INTO currently_processing (starting_time, path) VALUES (?1, '/videos/a.mp4')",
+            [chrono::Utc::now().to_rfc3339()],
+        )
+        .unwrap();
+        assert_eq!(
+            get_queue_status(Some(&conn)).unwrap().0.as_deref(),
+            Some("/videos/a.mp4")
+        );
+        assert_eq!(cooldown_remaining_secs(Some(&conn)).unwrap(), None);
+
+        start_cooldown(&conn, "/videos/a.mp4", 30).unwrap();
+        assert_eq!(get_queue_status(Some(&conn)).unwrap().0, None);
+        let remaining = cooldown_remaining_secs(Some(&conn)).unwrap().unwrap();
+        assert!((28..=30).contains(&remaining));
+        // The row keeps the slot so the watcher doesn't fill it during the cooldown
+        let rows: i64 = conn
+            .query_row("SELECT COUNT(*) FROM currently_processing", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(rows, 1);
+    }
+
+    #[test]
+    fn test_items_to_start_fills_free_slots_in_order() {
+        let item = |path: &str| (path.to_string(), None, None);
+        let items = vec![item("/a.mp4"), item("/b.mp4"), item("/c.mp4")];
+
+        let started: Vec<String> = items_to_start(items.clone(), &HashSet::new(), 2)
+            .into_iter()
+            .map(|(path, _, _)| path)
+            .collect();
+        assert_eq!(started, vec!["/a.mp4", "/b.mp4"]);
+
+        // One job is running; raising the limit to 3 starts the other two
+        let running = HashSet::from(["/a.mp4".to_string()]);
+        let started: Vec<String> = items_to_start(items.clone(), &running, 3)
+            .into_iter()
+            .map(|(path, _, _)| path)
+            .collect();
+        assert_eq!(started, vec!["/b.mp4", "/c.mp4"]);
+
+        // Lowering the limit below what's running starts nothing
+        let running = HashSet::from(["/a.mp4".to_string(), "/b.mp4".to_string()]);
+        assert!(items_to_start(items, &running, 1).is_empty());
+    }
+
+    #[test]
+    fn test_cancel_applies_to_every_in_flight_job() {
+        let conn = db::open_in_memory().unwrap();
+        assert_eq!(
+            request_cancel(&conn).unwrap(),
+            "Nothing is currently processing"
+        );
+
+        for path in ["/videos/a.mp4", "/videos/b.mp4"] {
+            conn.execute(
+                "INSERT INTO currently_processing (starting_time, path) VALUES ('now', ?1)",
+                [path],
+            )
+            .unwrap();
+        }
+        assert_eq!(
+            request_cancel(&conn).unwrap(),
+            "Created cancel request for 2 in-flight job(s)"
+        );
+        assert!(
+            request_cancel(&conn)
+                .unwrap()
+                .starts_with("Cancel request already exists")
+        );
+
+        // The first job cancelling removes only its own request
+        conn.execute(
+            "DELETE FROM cancel_requests WHERE path = '/videos/a.mp4'",
+            [],
+        )
+        .unwrap();
+        let remaining: Vec<String> = conn
+            .prepare("SELECT path FROM cancel_requests")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .map(|path| path.unwrap())
+            .collect();
+        assert_eq!(remaining, vec!["/videos/b.mp4"]);
+    }
+
+    #[test]
+    fn test_processing_history_includes_warnings_newest_first() {
+        let conn = db::open_in_memory().unwrap();
+        record_processing_outcome(&conn, "/videos/a.mp4", ProcessingOutcome::Success).unwrap();
+        record_processing_outcome_with_warning(
+            &conn,
+            "/videos/b.mp4",
+            ProcessingOutcome::Success,
+            Some("Low transcription confidence (0.312)"),
+        )
+        .unwrap();
+
+        let history = query_processing_history(&conn, 10).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].path, "/videos/b.mp4");
+        assert_eq!(
+            history[0].warning.as_deref(),
+            Some("Low transcription confidence (0.312)")
+        );
+        assert_eq!(history[1].warning, None);
+
+        assert_eq!(query_processing_history(&conn, 1).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_lock_queue_exclusive_times_out_while_another_writer_holds_lock() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("queue.db");
+        let holder = Connection::open(&db_path).unwrap();
+        holder
+            .execute_batch("CREATE TABLE queue (position INTEGER, path TEXT); BEGIN IMMEDIATE;")
+            .unwrap();
+
+        let waiter = Connection::open(&db_path).unwrap();
+        let start = std::time::Instant::now();
+        let err = lock_queue_exclusive(&waiter, Duration::from_millis(200)).unwrap_err();
+        assert!(err.is::<LockTimeout>());
+        assert!(start.elapsed() >= Duration::from_millis(200));
+
+        holder.execute_batch("COMMIT;").unwrap();
+        let tx = lock_queue_exclusive(&waiter, Duration::from_millis(200)).unwrap();
+        tx.execute("INSERT INTO queue VALUES (0, 'a.mp4')", [])
+            .unwrap();
+        tx.commit().unwrap();
+    }
 }
@@ -9,15 +9,17 @@ use crate::web::ApiResponse;
 use crate::{config, config::AtciConfig};
 use chrono::{DateTime, Local};
 use futures::stream::{self, StreamExt};
+use globset::{Glob, GlobSetBuilder};
 use rocket::get;
 use rocket::serde::json::Json;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::fs;
 use std::io::{self, Read};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct SearchMatch {
     pub line_number: usize,
     pub line_text: String,
@@ -25,12 +27,174 @@ pub struct SearchMatch {
     pub video_info: VideoInfo,
     pub clip_path: Option<String>,
     pub clip_command: Option<String>,
+    /// Transcript text from the cues just before and after the match, when context was requested
+    #[serde(default)]
+    pub context_before: Vec<String>,
+    #[serde(default)]
+    pub context_after: Vec<String>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct SearchResult {
     pub file_path: String,
     pub matches: Vec<SearchMatch>,
+    #[serde(default)]
+    pub file_metadata: VideoInfoSummary,
+}
+
+/// File-level details shown next to each result, taken from the file cache
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VideoInfoSummary {
+    pub duration_secs: Option<f64>,
+    pub model: Option<String>,
+    pub created_at: String,
+    pub transcript_line_count: usize,
+}
+
+impl VideoInfoSummary {
+    pub fn from_video_info(info: &VideoInfo) -> Self {
+        VideoInfoSummary {
+            duration_secs: info.length.as_deref().and_then(metadata::parse_length_secs),
+            model: info.source.clone(),
+            created_at: info.created_at.clone(),
+            transcript_line_count: info.line_count,
+        }
+    }
+}
+
+/// Search results along with the total match count across all of them
+#[derive(Debug, Serialize)]
+pub struct SearchResponse {
+    pub total_matches: usize,
+    pub results: Vec<SearchResult>,
+}
+
+/// Options controlling how a search is run
+#[derive(Debug, Default, Clone)]
+pub struct SearchOptions {
+    pub generate_clips: bool,
+    pub generate_gifs: bool,
+    /// Glob patterns for file paths to skip
+    pub exclude_patterns: Vec<String>,
+    /// Ignore and don't populate the search result cache
+    pub no_cache: bool,
+    /// Parts of each record the query must match; empty means the transcript only
+    pub fields: Vec<SearchField>,
+    /// Search only these video files instead of walking the watch directories
+    pub only_paths: Option<Vec<PathBuf>>,
+    /// Keep at most this many of each file's earliest matches
+    pub max_matches_per_file: Option<usize>,
+    /// Match the query's exact case instead of ignoring it
+    pub case_sensitive: bool,
+    /// Include this many transcript lines before and after each match
+    pub context_lines: usize,
+}
+
+impl SearchOptions {
+    fn search_fields(&self) -> Vec<SearchField> {
+        if self.fields.is_empty() {
+            vec![SearchField::Transcript]
+        } else {
+            self.fields.clone()
+        }
+    }
+}
+
+handlebars::handlebars_helper!(csv_helper: |value: Json| {
+    let text = match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(text) => text.clone(),
+        other => other.to_string(),
+    };
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text
+    }
+});
+
+/// Render search results through a Handlebars template, once per file. `template` is
+/// either a path or one of the built-in aliases `@markdown`, `@html` and `@csv`.
+/// Each render gets the `SearchResult` fields plus `query` and `is_first`, so a template
+/// can print a header only once. Output is HTML-escaped only for HTML templates.
+pub fn render_search_template(
+    template: &str,
+    query: &str,
+    results: &[SearchResult],
+) -> Result<String, Box<dyn std::error::Error>> {
+    let (source, is_html) = match template.strip_prefix('@') {
+        Some(alias) => {
+            let asset =
+                crate::Asset::get(&format!("search_templates/{}.hbs", alias)).ok_or(format!(
+                    "Unknown template {}; built-in templates are @markdown, @html and @csv",
+                    template
+                ))?;
+            (String::from_utf8(asset.data.into_owned())?, alias == "html")
+        }
+        None => (
+            fs::read_to_string(template)
+                .map_err(|e| format!("Could not read template {}: {}", template, e))?,
+            template.contains(".html"),
+        ),
+    };
+
+    let mut handlebars = handlebars::Handlebars::new();
+    if !is_html {
+        handlebars.register_escape_fn(handlebars::no_escape);
+    }
+    handlebars.register_helper("csv", Box::new(csv_helper));
+    handlebars.register_template_string("search", source)?;
+
+    let mut output = String::new();
+    for (index, result) in results.iter().enumerate() {
+        let mut context = serde_json::to_value(result)?;
+        if let Some(fields) = context.as_object_mut() {
+            fields.insert("query".to_string(), query.into());
+            fields.insert("is_first".to_string(), (index == 0).into());
+        }
+        output.push_str(&handlebars.render("search", &context)?);
+    }
+    Ok(output)
+}
+
+/// Read a list of video paths, one per line, skipping blank lines and `#` comments
+pub fn read_file_list(list_path: &std::path::Path) -> io::Result<Vec<PathBuf>> {
+    Ok(fs::read_to_string(list_path)?
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(PathBuf::from)
+        .collect())
+}
+
+/// A part of a transcript record that can be searched
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchField {
+    /// The video's path within its watch directory
+    Name,
+    /// The cue text after the metadata block
+    Transcript,
+    /// The metadata block at the top of the transcript
+    Metadata,
+}
+
+impl SearchField {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "name" => Some(SearchField::Name),
+            "transcript" => Some(SearchField::Transcript),
+            "metadata" => Some(SearchField::Metadata),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchField::Name => "name",
+            SearchField::Transcript => "transcript",
+            SearchField::Metadata => "metadata",
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -41,6 +205,53 @@ pub struct SupercutClipData {
     pub text: String,
 }
 
+const SEARCH_HISTORY_MAX_ENTRIES: usize = 200;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchHistoryEntry {
+    pub query: String,
+    pub result_count: usize,
+    pub timestamp: String,
+}
+
+fn search_history_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let home_dir = dirs::home_dir().ok_or("Could not find home directory")?;
+    Ok(home_dir.join(".atci").join("search_history.json"))
+}
+
+/// Load the search history, oldest entry first
+pub fn load_search_history() -> Result<Vec<SearchHistoryEntry>, Box<dyn std::error::Error>> {
+    let history_path = search_history_path()?;
+    if !history_path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(history_path)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+/// Append a query to the search history, rotating out the oldest entries
+pub fn record_search_history(
+    query: &str,
+    result_count: usize,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut history = load_search_history().unwrap_or_default();
+    history.push(SearchHistoryEntry {
+        query: query.to_string(),
+        result_count,
+        timestamp: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+    });
+    if history.len() > SEARCH_HISTORY_MAX_ENTRIES {
+        history.drain(..history.len() - SEARCH_HISTORY_MAX_ENTRIES);
+    }
+
+    let history_path = search_history_path()?;
+    if let Some(parent) = history_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(history_path, serde_json::to_string_pretty(&history)?)?;
+    Ok(())
+}
+
 fn format_datetime(timestamp: std::time::SystemTime) -> String {
     let datetime: DateTime<Local> = timestamp.into();
     datetime.format("%Y-%m-%d %H:%M:%S").to_string()
@@ -52,6 +263,15 @@ fn normalize_apostrophes(text: &str) -> String {
         .replace(['\u{2019}', '\u{2018}', '\u{00B4}', '`'], "'")
 }
 
+/// Normalize text for comparison against a query, lowercasing it unless the search is case-sensitive
+fn normalize_for_match(text: &str, case_sensitive: bool) -> String {
+    if case_sensitive {
+        normalize_apostrophes(text)
+    } else {
+        normalize_apostrophes(&text.to_lowercase())
+    }
+}
+
 fn generate_clip_for_match(
     file_path: &std::path::Path,
     timestamp_line: &str,
@@ -135,38 +355,256 @@ fn parse_timestamp_range(timestamp_line: &str) -> Option<(String, String)> {
     None
 }
 
+/// How long a cached search result stays valid
+const SEARCH_CACHE_TTL: &str = "-1 hour";
+
+fn sha256_hex(input: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(input.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Hash the normalized query and the options that affect which files are searched
+fn search_cache_keys(
+    query: &str,
+    filter: Option<&Vec<String>>,
+    options: &SearchOptions,
+    watch_directories: &[String],
+) -> (String, String) {
+    let normalized_query = normalize_for_match(query, options.case_sensitive);
+
+    let mut filters: Vec<String> = filter
+        .map(|f| f.iter().map(|s| s.trim().to_lowercase()).collect())
+        .unwrap_or_default();
+    filters.sort();
+    let mut excludes = options.exclude_patterns.clone();
+    excludes.sort();
+    let fields: Vec<&str> = options.search_fields().iter().map(|f| f.as_str()).collect();
+    let mut only_paths: Vec<String> = options
+        .only_paths
+        .iter()
+        .flatten()
+        .map(|p| p.to_string_lossy().to_string())
+        .collect();
+    only_paths.sort();
+
+    (
+        sha256_hex(&normalized_query),
+        sha256_hex(&format!(
+            "{}\n{}\n{}\n{}\n{}\n{}",
+            filters.join(","),
+            excludes.join(","),
+            watch_directories.join(","),
+            fields.join(","),
+            only_paths.join(","),
+            options.case_sensitive
+        )),
+    )
+}
+
+fn load_cached_search(query_hash: &str, filter_hash: &str) -> Option<Vec<SearchResult>> {
+    let conn = crate::db::get_connection().ok()?;
+    let result_json: String = conn
+        .query_row(
+            "SELECT result_json FROM search_cache
+             WHERE query_hash = ?1 AND filter_hash = ?2 AND created_at > datetime('now', ?3)",
+            rusqlite::params![query_hash, filter_hash, SEARCH_CACHE_TTL],
+            |row| row.get(0),
+        )
+        .ok()?;
+    serde_json::from_str(&result_json).ok()
+}
+
+fn store_cached_search(
+    query_hash: &str,
+    filter_hash: &str,
+    results: &[SearchResult],
+) -> Result<(), Box<dyn std::error::Error>> {
+    let conn = crate::db::get_connection()?;
+    conn.execute(
+        "DELETE FROM search_cache WHERE created_at <= datetime('now', ?1)",
+        [SEARCH_CACHE_TTL],
+    )?;
+    conn.execute(
+        "INSERT OR REPLACE INTO search_cache (query_hash, filter_hash, result_json, created_at)
+         VALUES (?1, ?2, ?3, datetime('now'))",
+        rusqlite::params![query_hash, filter_hash, serde_json::to_string(results)?],
+    )?;
+    Ok(())
+}
+
+/// Drop all cached search results, e.g. after the file list has been rebuilt
+pub fn clear_search_cache() -> Result<(), Box<dyn std::error::Error>> {
+    let conn = crate::db::get_connection()?;
+    conn.execute("DELETE FROM search_cache", [])?;
+    Ok(())
+}
+
+/// Search all transcripts, reusing a cached result for identical queries within the last hour.
+/// Searches that generate clips or gifs are never cached.
 pub async fn search(
     query: &str,
     filter: Option<&Vec<String>>,
-    generate_clips: bool,
-    generate_gifs: bool,
+    options: &SearchOptions,
 ) -> Result<Vec<SearchResult>, Box<dyn std::error::Error>> {
     let cfg: AtciConfig = config::load_config()?;
-    let video_extensions = crate::files::get_video_extensions();
+    let cacheable = !options.no_cache && !options.generate_clips && !options.generate_gifs;
+    let (query_hash, filter_hash) =
+        search_cache_keys(query, filter, options, &cfg.watch_directories);
+
+    // The cache holds every match without context, so the per-file cap and the
+    // surrounding lines are applied on the way out
+    if cacheable && let Some(results) = load_cached_search(&query_hash, &filter_hash) {
+        let results = cap_matches_per_file(results, options.max_matches_per_file);
+        return Ok(add_context_lines(results, options.context_lines));
+    }
+
+    let results = search_uncached(query, filter, options, &cfg).await?;
+
+    if cacheable && let Err(e) = store_cached_search(&query_hash, &filter_hash, &results) {
+        eprintln!("Warning: could not cache search results: {}", e);
+    }
+
+    let results = cap_matches_per_file(results, options.max_matches_per_file);
+    Ok(add_context_lines(results, options.context_lines))
+}
+
+/// Fill in up to `count` transcript text lines on each side of every match. Timestamps,
+/// blank lines and the metadata block are skipped so the context reads as the dialogue around it.
+fn add_context_lines(mut results: Vec<SearchResult>, count: usize) -> Vec<SearchResult> {
+    if count == 0 {
+        return results;
+    }
+
+    for result in &mut results {
+        let Ok(content) = crate::transcripts::read_transcript_file(
+            &Path::new(&result.file_path).with_extension("txt"),
+        ) else {
+            continue;
+        };
+        let lines: Vec<&str> = content.lines().collect();
+        let transcript_start = lines
+            .iter()
+            .position(|line| line.trim() == ">>>.atcimetaend")
+            .map_or(0, |index| index + 1);
+
+        let is_text = |index: &usize| {
+            let line = lines[*index].trim();
+            !line.is_empty() && !crate::transcripts::is_timestamp_line(line)
+        };
+
+        for search_match in &mut result.matches {
+            // line_number is 1-based; 0 marks a name-only match with no transcript line
+            let Some(index) = search_match.line_number.checked_sub(1) else {
+                continue;
+            };
+            if index < transcript_start || index >= lines.len() {
+                continue;
+            }
+
+            let mut before: Vec<String> = (transcript_start..index)
+                .rev()
+                .filter(is_text)
+                .take(count)
+                .map(|i| lines[i].to_string())
+                .collect();
+            before.reverse();
+            search_match.context_before = before;
+            search_match.context_after = (index + 1..lines.len())
+                .filter(is_text)
+                .take(count)
+                .map(|i| lines[i].to_string())
+                .collect();
+        }
+    }
 
-    let filtered_directories = cfg.watch_directories.clone();
+    results
+}
+
+/// Matches are collected in line order, so truncating keeps each file's earliest ones
+fn cap_matches_per_file(mut results: Vec<SearchResult>, max: Option<usize>) -> Vec<SearchResult> {
+    if let Some(max) = max {
+        for result in &mut results {
+            result.matches.truncate(max);
+        }
+    }
+    results
+}
 
-    let all_entries: Vec<_> = filtered_directories
+/// The part of a video's path that `--fields name` matches against: its path relative to the
+/// watch directory it's in, or just its file name when it's outside all of them
+fn searchable_name(file_path: &Path, watch_directories: &[String]) -> String {
+    watch_directories
         .iter()
-        .flat_map(|watch_directory| {
-            WalkDir::new(watch_directory)
-                .into_iter()
-                .filter_map(|e| e.ok())
-                .collect::<Vec<_>>()
+        .find_map(|watch_directory| file_path.strip_prefix(watch_directory).ok())
+        .map(|relative| relative.to_string_lossy().to_string())
+        .or_else(|| {
+            file_path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
         })
-        .collect();
+        .unwrap_or_default()
+}
+
+async fn search_uncached(
+    query: &str,
+    filter: Option<&Vec<String>>,
+    options: &SearchOptions,
+    cfg: &AtciConfig,
+) -> Result<Vec<SearchResult>, Box<dyn std::error::Error>> {
+    let video_extensions = crate::files::get_video_extensions();
+
+    let mut exclude_builder = GlobSetBuilder::new();
+    for pattern in &options.exclude_patterns {
+        exclude_builder.add(Glob::new(pattern)?);
+    }
+    let exclude_set = exclude_builder.build()?;
+
+    let all_entries: Vec<PathBuf> = match &options.only_paths {
+        Some(paths) => paths
+            .iter()
+            .filter(|path| !exclude_set.is_match(path))
+            .cloned()
+            .collect(),
+        None => cfg
+            .watch_directories
+            .iter()
+            .flat_map(|watch_directory| {
+                WalkDir::new(watch_directory)
+                    .into_iter()
+                    .filter_map(|e| e.ok())
+                    // Excluded paths are dropped before their transcripts are ever read
+                    .filter(|e| !exclude_set.is_match(e.path()))
+                    .map(|e| e.into_path())
+                    .collect::<Vec<_>>()
+            })
+            .collect(),
+    };
 
     let query_string = query.to_string();
-    let normalized_query = normalize_apostrophes(&query_string.to_lowercase());
+    let normalized_query = normalize_for_match(&query_string, options.case_sensitive);
+    let fields = options.search_fields();
+    let case_sensitive = options.case_sensitive;
+
+    // Files that aren't in the cache yet fall back to what's read from disk below
+    let cached_infos: std::collections::HashMap<String, VideoInfo> =
+        crate::files::load_video_info_from_cache(None)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|info| (info.full_path.clone(), info))
+            .collect();
+    let cached_infos = &cached_infos;
+    let watch_directories = &cfg.watch_directories;
 
     let results_stream = stream::iter(all_entries)
         .map(|entry| {
             let video_extensions = video_extensions.clone();
             let filter = filter.cloned();
             let normalized_query = normalized_query.clone();
+            let fields = fields.clone();
 
             async move {
-                let file_path = entry.path();
+                let file_path = entry.as_path();
 
                 if !file_path.is_file() {
                     return None;
@@ -192,15 +630,32 @@ pub async fn search(
                     }
                 }
 
+                if fields.contains(&SearchField::Name)
+                    && !normalize_for_match(
+                        &searchable_name(file_path, watch_directories),
+                        case_sensitive,
+                    )
+                    .contains(&normalized_query)
+                {
+                    return None;
+                }
+
                 let txt_path = file_path.with_extension("txt");
 
                 if !txt_path.exists() {
                     return None;
                 }
 
-                let content = tokio::fs::read_to_string(&txt_path).await.ok()?;
+                let bytes = tokio::fs::read(&txt_path).await.ok()?;
+                let content = crate::transcripts::decode_transcript_bytes(&bytes);
                 let lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
 
+                // Lines before this index belong to the metadata block (including its end marker)
+                let transcript_start = lines
+                    .iter()
+                    .position(|line| line.trim() == ">>>.atcimetaend")
+                    .map_or(0, |index| index + 1);
+
                 // Create VideoInfo for this file
                 let metadata = tokio::fs::metadata(file_path).await.ok()?;
                 let filename = file_path
@@ -218,10 +673,14 @@ pub async fn search(
                     .and_then(|meta| meta.modified().ok())
                     .map(format_datetime);
 
-                let (length, model) = {
+                let (length, model, atci_version) = {
                     let metadata_fields =
                         metadata::get_metadata_fields(file_path).unwrap_or_default();
-                    (metadata_fields.length, metadata_fields.source)
+                    (
+                        metadata_fields.length,
+                        metadata_fields.source,
+                        metadata_fields.atci_version,
+                    )
                 };
 
                 let created_at = metadata
@@ -245,21 +704,27 @@ pub async fn search(
                     length,
                     source: model,
                     watch_directory: None,
+                    atci_version,
                 };
 
-                let matches: Vec<SearchMatch> = lines
+                let in_metadata = |line_num: usize| line_num + 1 < transcript_start;
+                let in_transcript = |line_num: usize| line_num >= transcript_start;
+
+                let mut matches: Vec<SearchMatch> = lines
                     .iter()
                     .enumerate()
+                    .filter(|(line_num, _)| {
+                        (fields.contains(&SearchField::Transcript) && in_transcript(*line_num))
+                            || (fields.contains(&SearchField::Metadata) && in_metadata(*line_num))
+                    })
                     .filter_map(|(line_num, line)| {
-                        let normalized_line = normalize_apostrophes(&line.to_lowercase());
+                        let normalized_line = normalize_for_match(line, case_sensitive);
                         if normalized_line.contains(&normalized_query) {
                             // Check if the previous line contains a timestamp
-                            let timestamp = if line_num > 0 {
+                            let timestamp = if in_transcript(line_num) && line_num > 0 {
                                 let prev_line = &lines[line_num - 1];
-                                // Check if the previous line looks like a timestamp (contains digits and colons)
-                                if prev_line.contains(':')
-                                    && prev_line.chars().any(|c| c.is_ascii_digit())
-                                {
+                                // The previous line is the cue timing, in VTT or SRT form
+                                if crate::transcripts::is_timestamp_line(prev_line) {
                                     Some(prev_line.to_string())
                                 } else {
                                     None
@@ -270,9 +735,9 @@ pub async fn search(
 
                             // Generate clip if requested and timestamp is available
                             let (clip_path, clip_command) = if let Some(ts) = &timestamp {
-                                if generate_clips || generate_gifs {
-                                    let format = if generate_gifs { "gif" } else { "mp4" };
-                                    let text_for_clip = if generate_gifs {
+                                if options.generate_clips || options.generate_gifs {
+                                    let format = if options.generate_gifs { "gif" } else { "mp4" };
+                                    let text_for_clip = if options.generate_gifs {
                                         Some(line.as_str())
                                     } else {
                                         None
@@ -292,6 +757,8 @@ pub async fn search(
                                 video_info: video_info.clone(),
                                 clip_path,
                                 clip_command,
+                                context_before: Vec::new(),
+                                context_after: Vec::new(),
                             })
                         } else {
                             None
@@ -299,12 +766,43 @@ pub async fn search(
                     })
                     .collect();
 
+                // Every selected field has to match
+                if fields.contains(&SearchField::Transcript)
+                    && !matches.iter().any(|m| in_transcript(m.line_number - 1))
+                {
+                    return None;
+                }
+                if fields.contains(&SearchField::Metadata)
+                    && !matches.iter().any(|m| in_metadata(m.line_number - 1))
+                {
+                    return None;
+                }
+
+                // A name-only search has no matching lines, so report the file itself
+                if matches.is_empty() && fields.contains(&SearchField::Name) {
+                    matches.push(SearchMatch {
+                        line_number: 0,
+                        line_text: file_path.to_string_lossy().to_string(),
+                        timestamp: None,
+                        video_info: video_info.clone(),
+                        clip_path: None,
+                        clip_command: None,
+                        context_before: Vec::new(),
+                        context_after: Vec::new(),
+                    });
+                }
+
                 if matches.is_empty() {
                     None
                 } else {
+                    let file_metadata = cached_infos
+                        .get(video_info.full_path.as_str())
+                        .map(VideoInfoSummary::from_video_info)
+                        .unwrap_or_else(|| VideoInfoSummary::from_video_info(&video_info));
                     Some(SearchResult {
                         file_path: file_path.to_string_lossy().to_string(),
                         matches,
+                        file_metadata,
                     })
                 }
             }
@@ -321,6 +819,95 @@ pub async fn search(
     Ok(results)
 }
 
+/// Run a search and return one page of matches along with the total match count
+pub async fn search_paginated(
+    query: &str,
+    filter: Option<&Vec<String>>,
+    limit: Option<usize>,
+    offset: usize,
+    options: &SearchOptions,
+) -> Result<SearchResponse, Box<dyn std::error::Error>> {
+    let results = search(query, filter, options).await?;
+    Ok(paginate_results(results, limit, offset))
+}
+
+/// Keep only the matches in the requested window, dropping files left without matches
+fn paginate_results(
+    results: Vec<SearchResult>,
+    limit: Option<usize>,
+    offset: usize,
+) -> SearchResponse {
+    let total_matches = results.iter().map(|r| r.matches.len()).sum();
+    let mut to_skip = offset;
+    let mut remaining = limit.unwrap_or(usize::MAX);
+
+    let results = results
+        .into_iter()
+        .filter_map(|result| {
+            let match_count = result.matches.len();
+            let matches: Vec<SearchMatch> = result
+                .matches
+                .into_iter()
+                .skip(to_skip)
+                .take(remaining)
+                .collect();
+            to_skip = to_skip.saturating_sub(match_count);
+            remaining -= matches.len();
+            if matches.is_empty() {
+                None
+            } else {
+                Some(SearchResult {
+                    file_path: result.file_path,
+                    matches,
+                    file_metadata: result.file_metadata,
+                })
+            }
+        })
+        .collect();
+
+    SearchResponse {
+        total_matches,
+        results,
+    }
+}
+
+/// A search match tagged with the video it came from, used when results from several
+/// files are interleaved instead of grouped per file
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TimedSearchMatch {
+    pub file_path: String,
+    #[serde(flatten)]
+    pub search_match: SearchMatch,
+}
+
+/// Flatten per-file results into a single list ordered by where each match starts in its
+/// video. Matches without a timestamp (e.g. name or metadata matches) go last, and ties
+/// keep their original file order.
+pub fn group_by_time(results: Vec<SearchResult>) -> Vec<TimedSearchMatch> {
+    let mut matches: Vec<(Option<u64>, TimedSearchMatch)> = results
+        .into_iter()
+        .flat_map(|result| {
+            let file_path = result.file_path;
+            result.matches.into_iter().map(move |search_match| {
+                let start_ms = search_match.timestamp.as_deref().and_then(|ts| {
+                    let start = ts.split("-->").next().unwrap_or(ts);
+                    crate::transcripts::parse_cue_timestamp(start).ok()
+                });
+                (
+                    start_ms,
+                    TimedSearchMatch {
+                        file_path: file_path.clone(),
+                        search_match,
+                    },
+                )
+            })
+        })
+        .collect();
+
+    matches.sort_by_key(|(start_ms, _)| start_ms.unwrap_or(u64::MAX));
+    matches.into_iter().map(|(_, m)| m).collect()
+}
+
 pub async fn get_supercut_clip_data(
     query: &str,
     filter: Option<&Vec<String>>,
@@ -328,7 +915,7 @@ pub async fn get_supercut_clip_data(
     randomize: bool,
 ) -> Result<Vec<SupercutClipData>, Box<dyn std::error::Error>> {
     // Get all search results without generating clips
-    let results = search(query, filter, false, false).await?;
+    let results = search(query, filter, &SearchOptions::default()).await?;
 
     if results.is_empty() {
         return Err("No search results found".into());
@@ -480,7 +1067,15 @@ pub async fn search_and_supercut(
     } else {
         // Original sentence-level approach
         // First, get all search results with clips generated
-        let results = search(query, filter, true, false).await?;
+        let results = search(
+            query,
+            filter,
+            &SearchOptions {
+                generate_clips: true,
+                ..Default::default()
+            },
+        )
+        .await?;
 
         if results.is_empty() {
             return Err("No search results found".into());
@@ -605,11 +1200,13 @@ pub fn supercut_from_input(
     Ok(supercut_path.to_string_lossy().to_string())
 }
 
-#[get("/api/search?<query>&<filter>")]
+#[get("/api/search?<query>&<filter>&<limit>&<offset>")]
 pub async fn web_search_transcripts(
     _auth: AuthGuard,
     query: String,
     filter: Option<String>,
+    limit: Option<usize>,
+    offset: Option<usize>,
 ) -> Json<ApiResponse<serde_json::Value>> {
     let parsed_filter = filter.map(|f| {
         f.split(',')
@@ -618,9 +1215,17 @@ pub async fn web_search_transcripts(
             .collect::<Vec<String>>()
     });
 
-    match search(&query, parsed_filter.as_ref(), false, false).await {
-        Ok(results) => Json(ApiResponse::success(
-            serde_json::to_value(results).unwrap_or_default(),
+    match search_paginated(
+        &query,
+        parsed_filter.as_ref(),
+        limit,
+        offset.unwrap_or(0),
+        &SearchOptions::default(),
+    )
+    .await
+    {
+        Ok(response) => Json(ApiResponse::success(
+            serde_json::to_value(response).unwrap_or_default(),
         )),
         Err(e) => Json(ApiResponse::error(format!("Search failed: {}", e))),
     }
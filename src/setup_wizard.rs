@@ -27,6 +27,25 @@ pub enum SetupWizardScreen {
     Password,
 }
 
+impl SetupWizardScreen {
+    /// Optional screens can be skipped with `s` (Esc on the password prompt,
+    /// where `s` is part of the input) without saving anything
+    pub fn is_optional(&self) -> bool {
+        matches!(
+            self,
+            SetupWizardScreen::WatchDirectories | SetupWizardScreen::Password
+        )
+    }
+
+    fn skip_hint(&self) -> Option<&'static str> {
+        match self {
+            SetupWizardScreen::Password => Some("[Esc] Skip"),
+            screen if screen.is_optional() => Some("[s] Skip"),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct ToolOption {
     pub display_text: String,
@@ -37,6 +56,7 @@ pub struct ToolOption {
 pub enum ToolAction {
     UseDownloaded(String),
     UseSystem(String),
+    SystemInstall(tools_manager::PackageManager),
     Download,
     CustomPath,
 }
@@ -76,12 +96,13 @@ impl SetupWizard {
         Ok(wizard)
     }
 
+    /// Whether a required field is still missing. Watch directories are optional
+    /// here; if they were skipped the TUI offers the wizard again on next launch.
     pub fn check_if_setup_needed(&self) -> bool {
         self.config_data.ffmpeg_path.is_empty()
             || self.config_data.ffprobe_path.is_empty()
             || self.config_data.whispercli_path.is_empty()
             || self.config_data.model_name.is_empty()
-            || self.config_data.watch_directories.is_empty()
     }
 
     pub fn start_wizard(&mut self) {
@@ -101,6 +122,7 @@ impl SetupWizard {
 
     pub fn load_tool_options(&mut self, tool: &str) {
         self.setup_wizard_options.clear();
+        self.setup_wizard_selected_index = 0;
 
         let tools = tools_manager::list_tools();
         if let Some(tool_info) = tools.iter().find(|t| t.name == tool) {
@@ -125,6 +147,21 @@ impl SetupWizard {
                 });
             }
 
+            // Offer the system package manager when one is installed and packages the tool
+            if let Some(manager) = tools_manager::detect_package_manager()
+                && let Some(package) = manager.package_for(tool)
+            {
+                self.setup_wizard_options.push(ToolOption {
+                    display_text: format!(
+                        "Install {} with {} ({})",
+                        tool,
+                        manager.binary(),
+                        manager.install_command(package).join(" ")
+                    ),
+                    action: ToolAction::SystemInstall(manager),
+                });
+            }
+
             // Always offer download option
             self.setup_wizard_options.push(ToolOption {
                 display_text: format!("Download and use {}", tool),
@@ -136,15 +173,23 @@ impl SetupWizard {
                 display_text: "Enter custom path".to_string(),
                 action: ToolAction::CustomPath,
             });
-        }
 
-        self.setup_wizard_selected_index = 0;
+            // Without a downloaded copy, a tool already on PATH is the most likely choice
+            if !tool_info.downloaded
+                && let Some(index) = self
+                    .setup_wizard_options
+                    .iter()
+                    .position(|option| matches!(option.action, ToolAction::UseSystem(_)))
+            {
+                self.setup_wizard_selected_index = index;
+            }
+        }
     }
 
     pub fn load_model_options(&mut self) {
         self.setup_wizard_options.clear();
 
-        let models = model_manager::list_models();
+        let models = model_manager::list_models_with_sizes();
 
         // Add downloaded models first
         for model in models.iter().filter(|m| m.downloaded) {
@@ -161,8 +206,12 @@ impl SetupWizard {
 
         // Add available models for download
         for model in models.iter().filter(|m| !m.downloaded) {
+            let size = model
+                .size_mb
+                .map(|size_mb| format!(" {}", model_manager::format_size_mb(size_mb)))
+                .unwrap_or_default();
             self.setup_wizard_options.push(ToolOption {
-                display_text: format!("Download and use {}", model.name),
+                display_text: format!("Download and use {}{}", model.name, size),
                 action: ToolAction::Download,
             });
         }
@@ -183,12 +232,18 @@ impl SetupWizard {
                 self.save_config()?;
                 Ok(())
             }
+            ToolAction::SystemInstall(manager) => {
+                let tool_name = tool_name_for_field(field);
+
+                // Exit TUI temporarily so the package manager (and sudo) can use the terminal
+                let installed_path = install_tool_with_tui_pause(tool_name, *manager)
+                    .map_err(|e| format!("Failed to install {}: {}", tool_name, e))?;
+                config::set_config_field(&mut self.config_data, field, &installed_path)?;
+                self.save_config()?;
+                Ok(())
+            }
             ToolAction::Download => {
-                // Extract tool name from field (e.g., "ffmpeg_path" -> "ffmpeg", "whispercli_path" -> "whisper-cli")
-                let tool_name = match field {
-                    "whispercli_path" => "whisper-cli",
-                    _ => field.strip_suffix("_path").unwrap_or(field),
-                };
+                let tool_name = tool_name_for_field(field);
 
                 // Exit TUI temporarily for download
                 if let Err(e) = download_tool_with_tui_pause(tool_name) {
@@ -223,7 +278,11 @@ impl SetupWizard {
                     .setup_wizard_options
                     .get(self.setup_wizard_selected_index)
                 {
-                    if let Some(model_name) = option.display_text.strip_prefix("Download and use ")
+                    // The display text may end with the download size, e.g. "ggml-base (142 MB)"
+                    if let Some(model_name) = option
+                        .display_text
+                        .strip_prefix("Download and use ")
+                        .and_then(|rest| rest.split(" (").next())
                     {
                         // Exit TUI temporarily for download
                         if let Err(e) = download_model_with_tui_pause(model_name) {
@@ -322,11 +381,9 @@ impl SetupWizard {
             FFprobe => self.load_tool_options("ffprobe"),
             WhisperCli => self.load_tool_options("whisper-cli"),
             Model => self.load_model_options(),
-            WatchDirectories => {
+            WatchDirectories if self.directory_picker.is_none() => {
                 // Initialize directory picker if not already open
-                if self.directory_picker.is_none() {
-                    let _ = self.open_directory_picker();
-                }
+                let _ = self.open_directory_picker();
             }
             _ => {}
         }
@@ -358,16 +415,32 @@ impl SetupWizard {
             FFprobe => self.load_tool_options("ffprobe"),
             WhisperCli => self.load_tool_options("whisper-cli"),
             Model => self.load_model_options(),
-            WatchDirectories => {
+            WatchDirectories if self.directory_picker.is_none() => {
                 // Initialize directory picker if not already open
-                if self.directory_picker.is_none() {
-                    let _ = self.open_directory_picker();
-                }
+                let _ = self.open_directory_picker();
             }
             _ => {}
         }
     }
 
+    /// Leave an optional screen without saving what was entered on it
+    pub fn skip_wizard_screen(&mut self) -> Result<(), String> {
+        use SetupWizardScreen::*;
+
+        match self.setup_wizard_screen {
+            WatchDirectories => {
+                self.setup_wizard_watch_dirs.clear();
+                self.next_wizard_screen();
+                Ok(())
+            }
+            Password => {
+                self.setup_wizard_input_buffer.clear();
+                self.complete_wizard()
+            }
+            _ => Ok(()),
+        }
+    }
+
     pub fn complete_wizard(&mut self) -> Result<(), String> {
         // Save any pending watch directories
         if !self.setup_wizard_watch_dirs.is_empty() {
@@ -393,7 +466,11 @@ impl SetupWizard {
     }
 
     pub fn save_config(&mut self) -> Result<(), String> {
-        config::store_config(&self.config_data).map_err(|e| format!("Failed to save config: {}", e))
+        // Each screen fills in one field, so the config is expected to be invalid until the wizard
+        // completes, and check_if_setup_needed reports what's still missing
+        config::store_config(&self.config_data)
+            .map(|_errors| ())
+            .map_err(|e| format!("Failed to save config: {}", e))
     }
 
     pub fn open_directory_picker(&mut self) -> Result<(), Box<dyn std::error::Error>> {
@@ -410,6 +487,14 @@ impl SetupWizard {
     }
 }
 
+/// Extract tool name from a config field (e.g., "ffmpeg_path" -> "ffmpeg", "whispercli_path" -> "whisper-cli")
+fn tool_name_for_field(field: &str) -> &str {
+    match field {
+        "whispercli_path" => "whisper-cli",
+        _ => field.strip_suffix("_path").unwrap_or(field),
+    }
+}
+
 /// Temporarily exits TUI mode, downloads a tool, and waits for user to press Enter
 fn download_tool_with_tui_pause(tool_name: &str) -> Result<(), Box<dyn Error>> {
     // Exit TUI mode
@@ -452,6 +537,50 @@ fn download_tool_with_tui_pause(tool_name: &str) -> Result<(), Box<dyn Error>> {
     result.map(|_| ())
 }
 
+/// Temporarily exits TUI mode, installs a tool with the system package manager,
+/// and waits for user to press Enter. Returns the installed binary's path.
+fn install_tool_with_tui_pause(
+    tool_name: &str,
+    manager: tools_manager::PackageManager,
+) -> Result<String, Box<dyn Error>> {
+    // Exit TUI mode
+    disable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, LeaveAlternateScreen, DisableMouseCapture)?;
+
+    // Clear screen and show install
+    println!("\n\n");
+    println!("Installing {} with {}...", tool_name, manager.binary());
+    println!();
+
+    // Perform the actual install
+    let result = tools_manager::install_with_package_manager(tool_name, manager);
+
+    // Wait for user to acknowledge
+    println!();
+    match &result {
+        Ok(path) => println!("Installed {} at {}", tool_name, path),
+        Err(e) => println!("Install failed: {}", e),
+    }
+    println!();
+    println!("Press Enter to continue...");
+
+    // Wait for Enter key
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    // Clear the screen before re-entering TUI
+    print!("\x1B[2J\x1B[1;1H");
+    use std::io::Write;
+    stdout.flush()?;
+
+    // Re-enter TUI mode
+    enable_raw_mode()?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+
+    result
+}
+
 /// Temporarily exits TUI mode, downloads a model, and waits for user to press Enter
 fn download_model_with_tui_pause(model_name: &str) -> Result<(), Box<dyn Error>> {
     // Exit TUI mode
@@ -624,17 +753,14 @@ fn handle_setup_wizard_input(
             } else {
                 // In selection mode
                 match key.code {
-                    KeyCode::Up | KeyCode::Char('k') => {
-                        if wizard.setup_wizard_selected_index > 0 {
-                            wizard.setup_wizard_selected_index -= 1;
-                        }
+                    KeyCode::Up | KeyCode::Char('k') if wizard.setup_wizard_selected_index > 0 => {
+                        wizard.setup_wizard_selected_index -= 1;
                     }
-                    KeyCode::Down | KeyCode::Char('j') => {
+                    KeyCode::Down | KeyCode::Char('j')
                         if wizard.setup_wizard_selected_index
-                            < wizard.setup_wizard_options.len().saturating_sub(1)
-                        {
-                            wizard.setup_wizard_selected_index += 1;
-                        }
+                            < wizard.setup_wizard_options.len().saturating_sub(1) =>
+                    {
+                        wizard.setup_wizard_selected_index += 1;
                     }
                     KeyCode::Enter => {
                         if let Some(option) = wizard
@@ -661,10 +787,8 @@ fn handle_setup_wizard_input(
                             }
                         }
                     }
-                    KeyCode::Esc => {
-                        if wizard.setup_wizard_screen != Welcome {
-                            wizard.previous_wizard_screen();
-                        }
+                    KeyCode::Esc if wizard.setup_wizard_screen != Welcome => {
+                        wizard.previous_wizard_screen();
                     }
                     _ => {}
                 }
@@ -711,17 +835,14 @@ fn handle_setup_wizard_input(
             } else {
                 // In selection mode
                 match key.code {
-                    KeyCode::Up | KeyCode::Char('k') => {
-                        if wizard.setup_wizard_selected_index > 0 {
-                            wizard.setup_wizard_selected_index -= 1;
-                        }
+                    KeyCode::Up | KeyCode::Char('k') if wizard.setup_wizard_selected_index > 0 => {
+                        wizard.setup_wizard_selected_index -= 1;
                     }
-                    KeyCode::Down | KeyCode::Char('j') => {
+                    KeyCode::Down | KeyCode::Char('j')
                         if wizard.setup_wizard_selected_index
-                            < wizard.setup_wizard_options.len().saturating_sub(1)
-                        {
-                            wizard.setup_wizard_selected_index += 1;
-                        }
+                            < wizard.setup_wizard_options.len().saturating_sub(1) =>
+                    {
+                        wizard.setup_wizard_selected_index += 1;
                     }
                     KeyCode::Enter => {
                         if let Some(option) = wizard
@@ -806,6 +927,11 @@ fn handle_setup_wizard_input(
                         }
                     }
                 }
+                KeyCode::Char('s') => {
+                    if let Err(e) = wizard.skip_wizard_screen() {
+                        eprintln!("Error completing wizard: {}", e);
+                    }
+                }
                 KeyCode::Esc => {
                     wizard.previous_wizard_screen();
                 }
@@ -823,12 +949,8 @@ fn handle_setup_wizard_input(
             match key.code {
                 KeyCode::Esc => {
                     // Skip password (optional) and complete wizard
-                    wizard.setup_wizard_input_buffer.clear();
-                    match wizard.complete_wizard() {
-                        Ok(()) => {}
-                        Err(e) => {
-                            eprintln!("Error completing wizard: {}", e);
-                        }
+                    if let Err(e) = wizard.skip_wizard_screen() {
+                        eprintln!("Error completing wizard: {}", e);
                     }
                 }
                 KeyCode::Enter => {
@@ -946,6 +1068,21 @@ fn render_setup_wizard_modal(f: &mut Frame, wizard: &SetupWizard) {
 
         f.render_widget(progress_paragraph, progress_area);
     }
+
+    if let Some(hint) = wizard.setup_wizard_screen.skip_hint() {
+        let hint_paragraph = Paragraph::new(hint)
+            .style(Style::default().fg(wizard.colors.disabled))
+            .alignment(Alignment::Right);
+
+        let hint_area = ratatui::layout::Rect {
+            x: area.x + 1,
+            y: area.y + area.height.saturating_sub(2),
+            width: area.width.saturating_sub(3),
+            height: 1,
+        };
+
+        f.render_widget(hint_paragraph, hint_area);
+    }
 }
 
 fn render_wizard_welcome(f: &mut Frame, wizard: &SetupWizard, area: ratatui::layout::Rect) {
@@ -966,7 +1103,7 @@ fn render_wizard_welcome(f: &mut Frame, wizard: &SetupWizard, area: ratatui::lay
         Line::from("  2. FFprobe - for video metadata"),
         Line::from("  3. Whisper CLI - for transcription"),
         Line::from("  4. Model - whisper model for transcription"),
-        Line::from("  5. Watch Directories - folders to monitor"),
+        Line::from("  5. Watch Directories - (optional) folders to monitor"),
         Line::from("  6. Password - (optional) for web interface"),
         Line::from(""),
         Line::from(""),
@@ -1161,6 +1298,10 @@ fn render_wizard_watch_directories(
             "Press 'c' to create ~/atci_videos and continue",
             Style::default().fg(wizard.colors.success),
         )));
+        lines.push(Line::from(Span::styled(
+            "Press 's' to skip and add directories later",
+            Style::default().fg(wizard.colors.disabled),
+        )));
     } else {
         lines.push(Line::from(Span::styled(
             "Press 'n' to add current directory, 'c' to continue",
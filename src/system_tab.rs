@@ -1,7 +1,9 @@
 use crate::config;
+use crate::files;
 use ratatui::Frame;
 use ratatui::layout::{Alignment, Constraint, Direction, Layout};
 use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph};
 use std::{error::Error, fs, time::Duration};
 use tui_big_text::BigText;
@@ -28,6 +30,21 @@ impl App {
         }
     }
 
+    pub fn system_first(&mut self) {
+        if self.config_data.watch_directories.is_empty() {
+            self.system_section = SystemSection::Config;
+            self.config_selected_field = 0;
+        } else {
+            self.system_section = SystemSection::WatchDirectories;
+            self.watch_directories_selected_index = 0;
+        }
+    }
+
+    pub fn system_last(&mut self) {
+        self.system_section = SystemSection::Config;
+        self.config_selected_field = self.get_config_field_count() - 1;
+    }
+
     pub fn system_previous(&mut self) {
         match self.system_section {
             SystemSection::Config => {
@@ -231,10 +248,17 @@ pub fn render_system_tab(
         )
         .split(area);
 
-    // Create main block
-    let main_block = Block::default()
+    // Create main block, badged with the queue's activity while it isn't idle
+    let mut main_block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::new().fg(app.colors.footer_border_color));
+    if let Some((badge, color)) = app.queue_badge() {
+        main_block = main_block.title(Line::from(vec![
+            Span::raw(" "),
+            Span::styled(badge, Style::new().fg(color).add_modifier(Modifier::BOLD)),
+            Span::raw(" "),
+        ]));
+    }
 
     f.render_widget(main_block, area);
 
@@ -413,9 +437,9 @@ fn render_watch_directories_section<'a>(
     let widths = [Constraint::Min(30)];
 
     let watch_dirs_title = if app.system_section == SystemSection::WatchDirectories {
-        "Watch Directories (↑↓/jk: Navigate, n: Add, d: Delete, r: Regenerate) [ACTIVE]"
+        "Watch Directories (↑↓/jk: Navigate, gg/G: Top/Bottom, n: Add, d: Delete, r: Regenerate) [ACTIVE]"
     } else {
-        "Watch Directories (↑↓/jk: Navigate, n: Add, d: Delete, r: Regenerate)"
+        "Watch Directories (↑↓/jk: Navigate, gg/G: Top/Bottom, n: Add, d: Delete, r: Regenerate)"
     };
     let watch_dirs_border_color = if app.system_section == SystemSection::WatchDirectories {
         app.colors.selection
@@ -715,13 +739,13 @@ fn render_stats_section<'a>(
     let mut total_seconds = 0u64;
     for duration_result in durations {
         if let Ok(duration_str) = duration_result
-            && let Some(seconds) = parse_duration_to_seconds(&duration_str)
+            && let Some(seconds) = files::parse_duration_to_seconds(&duration_str)
         {
             total_seconds += seconds;
         }
     }
 
-    let total_runtime = format_seconds_to_duration(total_seconds);
+    let total_runtime = files::format_seconds_to_duration(total_seconds);
 
     // Add total transcripts row with a divider style
     let total_row = Row::new(vec![
@@ -842,7 +866,7 @@ fn get_directory_stats(
         let mut total_seconds = 0u64;
         for duration_result in durations {
             if let Ok(duration_str) = duration_result
-                && let Some(seconds) = parse_duration_to_seconds(&duration_str)
+                && let Some(seconds) = files::parse_duration_to_seconds(&duration_str)
             {
                 total_seconds += seconds;
             }
@@ -858,7 +882,7 @@ fn get_directory_stats(
         results.push((
             dir.clone(),
             count,
-            format_seconds_to_duration(total_seconds),
+            files::format_seconds_to_duration(total_seconds),
         ));
     }
 
@@ -878,23 +902,3 @@ fn format_age(seconds: u64) -> String {
         format!("{}h {}m", hours, minutes)
     }
 }
-
-fn parse_duration_to_seconds(duration_str: &str) -> Option<u64> {
-    let parts: Vec<&str> = duration_str.split(':').collect();
-    if parts.len() != 3 {
-        return None;
-    }
-
-    let hours = parts[0].parse::<u64>().ok()?;
-    let minutes = parts[1].parse::<u64>().ok()?;
-    let seconds = parts[2].parse::<u64>().ok()?;
-
-    Some(hours * 3600 + minutes * 60 + seconds)
-}
-
-fn format_seconds_to_duration(total_seconds: u64) -> String {
-    let hours = total_seconds / 3600;
-    let minutes = (total_seconds % 3600) / 60;
-    let seconds = total_seconds % 60;
-    format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
-}
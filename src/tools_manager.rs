@@ -161,26 +161,80 @@ fn get_current_path(tool: &str, cfg: &crate::AtciConfig) -> String {
     }
 }
 
+/// Whether a zip entry is the tool's binary. Windows builds nest the binary under
+/// a directory such as `ffmpeg-N-essentials_build/bin/`, next to docs and licenses
+/// whose paths also contain the tool name, so only the final path component counts.
+fn is_tool_archive_entry(entry_name: &str, tool: &str) -> bool {
+    if entry_name.ends_with('/') {
+        return false;
+    }
+    let filename = entry_name.rsplit('/').next().unwrap_or(entry_name);
+    filename == tool || filename.eq_ignore_ascii_case(&format!("{}.exe", tool))
+}
+
 pub fn download_tool(tool: &str) -> Result<String, Box<dyn std::error::Error>> {
     let platform = detect_platform();
 
-    let url = match tool {
-        "ffmpeg" => get_ffmpeg_url(&platform),
-        "ffprobe" => get_ffprobe_url(&platform),
-        "whisper-cli" => get_whisper_cli_url(&platform),
-        _ => return Err(format!("Unknown tool: {}", tool).into()),
-    };
-    println!("Downloading tool: {} from {}", tool, url.unwrap());
-
-    let url = url.ok_or(format!(
-        "No download URL available for {} on {}",
+    if !matches!(tool, "ffmpeg" | "ffprobe" | "whisper-cli") {
+        return Err(format!("Unknown tool: {}", tool).into());
+    }
+    let url = pinned_url(tool, &platform).ok_or(format!(
+        "No download URL available for {} on {}; pass --url and --sha256 to install a build you've verified",
         tool, platform
     ))?;
 
+    download_tool_from(
+        tool,
+        url,
+        get_tool_sha256(tool, &platform).filter(|hash| !hash.is_empty()),
+    )
+}
+
+/// Downloads `tool` from a release the user picked, such as a static Linux build, checked
+/// against the SHA256 they supply for its binary
+pub fn download_tool_from_url(
+    tool: &str,
+    url: &str,
+    sha256: &str,
+) -> Result<String, Box<dyn std::error::Error>> {
+    if !matches!(tool, "ffmpeg" | "ffprobe" | "whisper-cli") {
+        return Err(format!("Unknown tool: {}", tool).into());
+    }
+    download_tool_from(tool, url, Some(sha256))
+}
+
+/// Downloads `tool` from `url` and records the url next to the binary so that
+/// `tools update` can tell which release is installed. Without `expected_hash`
+/// the download is installed unverified, with a warning; `tools update` never
+/// does that and skips any release without a pinned hash instead.
+fn download_tool_from(
+    tool: &str,
+    url: &str,
+    expected_hash: Option<&str>,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let path = install_tool(tool, url, expected_hash)?;
+    std::fs::write(binaries_directory(tool).join(SOURCE_URL_FILE), url)?;
+    Ok(path)
+}
+
+fn install_tool(
+    tool: &str,
+    url: &str,
+    expected_hash: Option<&str>,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let platform = detect_platform();
+    println!("Downloading tool: {} from {}", tool, url);
+
     let binaries_dir = binaries_directory(tool);
     std::fs::create_dir_all(&binaries_dir)?;
 
-    let response = reqwest::blocking::get(url)?;
+    let bytes = fetch_tool_bytes(tool, url)?;
+    install_tool_bytes(tool, &bytes, expected_hash, &binaries_dir, &platform)
+}
+
+/// Downloads `url` into memory behind a progress bar
+fn fetch_tool_bytes(tool: &str, url: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let response = reqwest::blocking::get(url)?.error_for_status()?;
     let total_size = response.content_length().unwrap_or(0);
 
     // Create progress bar
@@ -210,8 +264,50 @@ pub fn download_tool(tool: &str) -> Result<String, Box<dyn std::error::Error>> {
 
     pb.finish_with_message(format!("Downloaded {} successfully!", tool));
 
-    // Handle whisper-cli for macOS ARM as a direct binary download
-    if tool == "whisper-cli" && platform == "macos-arm" {
+    Ok(bytes)
+}
+
+/// Zip archives start with a local file header
+fn is_zip_archive(bytes: &[u8]) -> bool {
+    bytes.starts_with(b"PK\x03\x04")
+}
+
+/// Releases published as the binary itself rather than a zip archive. Anything else that
+/// isn't a zip (an error page, or an archive format atci can't extract) is rejected.
+fn ships_bare_binary(tool: &str, platform: &str) -> bool {
+    matches!((tool, platform), ("whisper-cli", "macos-arm"))
+}
+
+/// Installs a downloaded tool into `binaries_dir`, either straight from a bare binary
+/// (see `ships_bare_binary`) or by extracting it from a zip or `.tar.xz` archive.
+/// `expected_hash` is checked against the installed binary, except for the Windows
+/// whisper-cli zip, whose hash covers the whole archive.
+fn install_tool_bytes(
+    tool: &str,
+    bytes: &[u8],
+    expected_hash: Option<&str>,
+    binaries_dir: &std::path::Path,
+    platform: &str,
+) -> Result<String, Box<dyn std::error::Error>> {
+    // Once out of the tarball the binary installs like a bare download
+    let extracted;
+    let (bytes, from_tarball) = if is_tar_xz(bytes) {
+        extracted = extract_from_tar_xz(bytes, tool)?;
+        (extracted.as_slice(), true)
+    } else {
+        (bytes, false)
+    };
+
+    if !from_tarball && !is_zip_archive(bytes) && !ships_bare_binary(tool, platform) {
+        return Err(format!(
+            "Download for {} on {} is neither a zip nor a .tar.xz archive, which atci can't install",
+            tool, platform
+        )
+        .into());
+    }
+
+    // Handle bare binaries as a direct download
+    if from_tarball || !is_zip_archive(bytes) {
         let extension = if cfg!(target_os = "windows") {
             ".exe"
         } else {
@@ -219,10 +315,10 @@ pub fn download_tool(tool: &str) -> Result<String, Box<dyn std::error::Error>> {
         };
         let output_path = binaries_dir.join(format!("{}{}", tool, extension));
 
-        std::fs::write(&output_path, &bytes)?;
+        std::fs::write(&output_path, bytes)?;
 
         // Verify SHA256 hash
-        if let Some(expected_hash) = get_tool_sha256(tool, &platform) {
+        if let Some(expected_hash) = expected_hash {
             match verify_sha256(&output_path.to_string_lossy(), expected_hash) {
                 Ok(true) => println!("SHA256 verification successful for {}", tool),
                 Ok(false) => {
@@ -256,11 +352,15 @@ pub fn download_tool(tool: &str) -> Result<String, Box<dyn std::error::Error>> {
             }
         }
 
+        if from_tarball && (tool == "ffmpeg" || tool == "ffprobe") {
+            install_gpl_notice(tool, binaries_dir)?;
+        }
+
         return Ok(output_path.to_string_lossy().to_string());
     }
 
     // Handle zip archives for other tools
-    let cursor = std::io::Cursor::new(bytes.clone());
+    let cursor = std::io::Cursor::new(bytes);
     let mut archive = zip::ZipArchive::new(cursor)?;
 
     // Special handling for whisper-cli on Windows to extract exe and dll files
@@ -268,9 +368,9 @@ pub fn download_tool(tool: &str) -> Result<String, Box<dyn std::error::Error>> {
         let mut main_executable_path = None;
 
         // Verify SHA256 hash of the original zip file
-        if let Some(expected_hash) = get_tool_sha256(tool, &platform) {
+        if let Some(expected_hash) = expected_hash {
             let temp_zip_path = binaries_dir.join("temp_whisper.zip");
-            std::fs::write(&temp_zip_path, &bytes)?;
+            std::fs::write(&temp_zip_path, bytes)?;
             match verify_sha256(&temp_zip_path.to_string_lossy(), expected_hash) {
                 Ok(true) => {
                     println!("SHA256 verification successful for {} zip file", tool);
@@ -330,7 +430,7 @@ pub fn download_tool(tool: &str) -> Result<String, Box<dyn std::error::Error>> {
         let mut file = archive.by_index(i)?;
         let file_name = file.name();
 
-        if file_name.contains(tool) && !file_name.ends_with('/') {
+        if is_tool_archive_entry(file_name, tool) {
             let extension = if cfg!(target_os = "windows") {
                 ".exe"
             } else {
@@ -342,7 +442,7 @@ pub fn download_tool(tool: &str) -> Result<String, Box<dyn std::error::Error>> {
             std::io::copy(&mut file, &mut output_file)?;
 
             // Verify SHA256 hash
-            if let Some(expected_hash) = get_tool_sha256(tool, &platform) {
+            if let Some(expected_hash) = expected_hash {
                 match verify_sha256(&output_path.to_string_lossy(), expected_hash) {
                     Ok(true) => println!("SHA256 verification successful for {}", tool),
                     Ok(false) => {
@@ -376,26 +476,8 @@ pub fn download_tool(tool: &str) -> Result<String, Box<dyn std::error::Error>> {
                 }
             }
 
-            // Create GPL license file for ffmpeg and ffprobe
             if tool == "ffmpeg" || tool == "ffprobe" {
-                create_gpl_license_file(&binaries_dir)?;
-                create_compiling_file(&binaries_dir)?;
-
-                println!();
-                println!("FREE SOFTWARE NOTICE:");
-                println!(
-                    "   {} is free software licensed under the GNU General Public License v2.",
-                    tool
-                );
-                println!("   You are free to use, modify, and distribute this software under the");
-                println!(
-                    "   terms of the GPL v2. See COPYING.GPLv2 in the install location for full license terms."
-                );
-                println!(
-                    "   Source code locations and build instructions are available in the COMPILING file."
-                );
-                println!("Install location: {}", binaries_dir.display());
-                println!();
+                install_gpl_notice(tool, binaries_dir)?;
             }
 
             return Ok(output_path.to_string_lossy().to_string());
@@ -405,6 +487,365 @@ pub fn download_tool(tool: &str) -> Result<String, Box<dyn std::error::Error>> {
     Err(format!("Could not find {} binary in the downloaded archive", tool).into())
 }
 
+/// ffmpeg and ffprobe are GPL, so their license and build notes go next to the binary
+fn install_gpl_notice(
+    tool: &str,
+    binaries_dir: &std::path::Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    create_gpl_license_file(binaries_dir)?;
+    create_compiling_file(binaries_dir)?;
+
+    println!();
+    println!("FREE SOFTWARE NOTICE:");
+    println!(
+        "   {} is free software licensed under the GNU General Public License v2.",
+        tool
+    );
+    println!("   You are free to use, modify, and distribute this software under the");
+    println!(
+        "   terms of the GPL v2. See COPYING.GPLv2 in the install location for full license terms."
+    );
+    println!(
+        "   Source code locations and build instructions are available in the COMPILING file."
+    );
+    println!("Install location: {}", binaries_dir.display());
+    println!();
+    Ok(())
+}
+
+/// xz streams start with this magic number
+fn is_tar_xz(bytes: &[u8]) -> bool {
+    bytes.starts_with(b"\xFD7zXZ\x00")
+}
+
+/// Pull `tool`'s binary out of a `.tar.xz` archive, the format static Linux builds such as
+/// johnvansickle.com's ship in. Only plain files count; GNU long names are followed.
+fn extract_from_tar_xz(bytes: &[u8], tool: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let mut tar = Vec::new();
+    xz2::read::XzDecoder::new(bytes).read_to_end(&mut tar)?;
+
+    let mut offset = 0;
+    let mut long_name: Option<String> = None;
+    while let Some(header) = tar.get(offset..offset + 512) {
+        // The archive ends with zeroed blocks
+        if header.iter().all(|&b| b == 0) {
+            break;
+        }
+        let field = |range: std::ops::Range<usize>| {
+            let raw = &header[range];
+            let end = raw.iter().position(|&b| b == 0).unwrap_or(raw.len());
+            String::from_utf8_lossy(&raw[..end]).to_string()
+        };
+        let size = usize::from_str_radix(field(124..136).trim(), 8)
+            .map_err(|_| "Invalid entry size in the downloaded archive")?;
+        let data_start = offset + 512;
+        let data = tar
+            .get(data_start..data_start + size)
+            .ok_or("The downloaded archive is truncated")?;
+
+        let name = long_name.take().unwrap_or_else(|| {
+            let prefix = field(345..500);
+            if prefix.is_empty() {
+                field(0..100)
+            } else {
+                format!("{}/{}", prefix, field(0..100))
+            }
+        });
+        match header[156] {
+            b'L' => {
+                long_name = Some(
+                    String::from_utf8_lossy(data)
+                        .trim_end_matches('\0')
+                        .to_string(),
+                )
+            }
+            b'0' | 0 if is_tool_archive_entry(&name, tool) => return Ok(data.to_vec()),
+            _ => {}
+        }
+        offset = data_start + size.div_ceil(512) * 512;
+    }
+
+    Err(format!("Could not find {} binary in the downloaded archive", tool).into())
+}
+
+const SOURCE_URL_FILE: &str = ".source_url";
+
+/// Where to look for newer releases of a tool: the page is scanned for
+/// `prefix<version>suffix` and the newest match is appended to `download_base`.
+struct ReleaseSource {
+    page_url: &'static str,
+    prefix: &'static str,
+    suffix: &'static str,
+    download_base: &'static str,
+}
+
+fn get_release_source(tool: &str, platform: &str) -> Option<ReleaseSource> {
+    const S3_LISTING: &str =
+        "https://autotranscript.s3.us-east-1.amazonaws.com/?list-type=2&prefix=binaries/";
+    const S3_BASE: &str = "https://autotranscript.s3.us-east-1.amazonaws.com/";
+
+    match (tool, platform) {
+        ("ffmpeg", "windows") => Some(ReleaseSource {
+            page_url: S3_LISTING,
+            prefix: "binaries/ffmpeg",
+            suffix: "win64.zip",
+            download_base: S3_BASE,
+        }),
+        ("ffmpeg", "macos-arm") => Some(ReleaseSource {
+            page_url: S3_LISTING,
+            prefix: "binaries/ffmpeg",
+            suffix: "arm.zip",
+            download_base: S3_BASE,
+        }),
+        ("ffprobe", "windows") => Some(ReleaseSource {
+            page_url: S3_LISTING,
+            prefix: "binaries/ffprobe",
+            suffix: "win64.zip",
+            download_base: S3_BASE,
+        }),
+        ("ffprobe", "macos-arm") => Some(ReleaseSource {
+            page_url: S3_LISTING,
+            prefix: "binaries/ffprobe",
+            suffix: "arm.zip",
+            download_base: S3_BASE,
+        }),
+        ("whisper-cli", "windows") => Some(ReleaseSource {
+            page_url: "https://api.github.com/repos/ggml-org/whisper.cpp/releases/latest",
+            prefix: "https://github.com/ggml-org/whisper.cpp/releases/download/v",
+            suffix: "/whisper-bin-x64.zip",
+            download_base: "",
+        }),
+        _ => None,
+    }
+}
+
+fn parse_version(version: &str) -> Vec<u64> {
+    version
+        .split('.')
+        .map(|part| part.parse().unwrap_or(0))
+        .collect()
+}
+
+/// Finds every `prefix<version>suffix` in `page`, where the version is made of
+/// digits and dots, and returns the full name with the highest version.
+fn find_newest_release(page: &str, prefix: &str, suffix: &str) -> Option<String> {
+    let mut newest: Option<(Vec<u64>, String)> = None;
+
+    for (start, _) in page.match_indices(prefix) {
+        let rest = &page[start + prefix.len()..];
+        let version_len = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(rest.len());
+        let version = &rest[..version_len];
+        if version.is_empty() || !rest[version_len..].starts_with(suffix) {
+            continue;
+        }
+
+        let parsed = parse_version(version);
+        if newest.as_ref().is_none_or(|(best, _)| parsed > *best) {
+            newest = Some((parsed, format!("{}{}{}", prefix, version, suffix)));
+        }
+    }
+
+    newest.map(|(_, name)| name)
+}
+
+fn pinned_url(tool: &str, platform: &str) -> Option<&'static str> {
+    match tool {
+        "ffmpeg" => get_ffmpeg_url(platform),
+        "ffprobe" => get_ffprobe_url(platform),
+        "whisper-cli" => get_whisper_cli_url(platform),
+        _ => None,
+    }
+    .filter(|url| !url.is_empty())
+}
+
+fn installed_source_url(tool: &str, platform: &str) -> Option<String> {
+    let recorded = std::fs::read_to_string(binaries_directory(tool).join(SOURCE_URL_FILE)).ok();
+    match recorded {
+        Some(url) if !url.trim().is_empty() => Some(url.trim().to_string()),
+        // Tools downloaded before the url was recorded came from the pinned release.
+        _ => pinned_url(tool, platform).map(str::to_string),
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ToolUpdate {
+    Updated { from: Option<String>, to: String },
+    UpToDate { url: String },
+    Skipped { reason: String },
+    Failed { error: String },
+}
+
+fn update_tool(tool: &str, platform: &str) -> ToolUpdate {
+    let Some(source) = get_release_source(tool, platform) else {
+        return ToolUpdate::Skipped {
+            reason: format!("no release source for {} on {}", tool, platform),
+        };
+    };
+
+    let page = match reqwest::blocking::Client::new()
+        .get(source.page_url)
+        .header(reqwest::header::USER_AGENT, "atci")
+        .send()
+        .and_then(|response| response.error_for_status())
+        .and_then(|response| response.text())
+    {
+        Ok(page) => page,
+        Err(e) => {
+            return ToolUpdate::Failed {
+                error: format!("could not fetch {}: {}", source.page_url, e),
+            };
+        }
+    };
+
+    let Some(name) = find_newest_release(&page, source.prefix, source.suffix) else {
+        return ToolUpdate::Failed {
+            error: format!("no release found at {}", source.page_url),
+        };
+    };
+    let latest_url = format!("{}{}", source.download_base, name);
+
+    let installed = installed_source_url(tool, platform);
+    if installed.as_deref() == Some(latest_url.as_str()) {
+        return ToolUpdate::UpToDate { url: latest_url };
+    }
+
+    // The pinned hash only applies if the newest release happens to be the pinned one, and
+    // nothing is installed without a hash to check it against.
+    let expected_hash = if pinned_url(tool, platform) == Some(latest_url.as_str()) {
+        get_tool_sha256(tool, platform).filter(|hash| !hash.is_empty())
+    } else {
+        None
+    };
+    let Some(expected_hash) = expected_hash else {
+        return ToolUpdate::Skipped {
+            reason: format!(
+                "no SHA256 is known for {}, so it can't be verified; a newer atci will pin it",
+                latest_url
+            ),
+        };
+    };
+
+    match download_tool_from(tool, &latest_url, Some(expected_hash)) {
+        Ok(_) => ToolUpdate::Updated {
+            from: installed,
+            to: latest_url,
+        },
+        Err(e) => ToolUpdate::Failed {
+            error: e.to_string(),
+        },
+    }
+}
+
+/// Re-downloads every downloaded tool whose newest release differs from the
+/// installed one. Tools that were never downloaded are left alone.
+pub fn update_tools() -> Vec<(String, ToolUpdate)> {
+    let platform = detect_platform();
+
+    list_tools()
+        .into_iter()
+        .filter(|tool| tool.downloaded)
+        .map(|tool| {
+            let update = update_tool(&tool.name, &platform);
+            (tool.name, update)
+        })
+        .collect()
+}
+
+/// A system package manager that can install the tools instead of downloading
+/// the pre-built binaries into ~/.atci
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PackageManager {
+    Brew,
+    AptGet,
+    Apt,
+    Pacman,
+}
+
+impl PackageManager {
+    /// Checked in this order, so apt-get wins over apt when both are present
+    const ALL: [PackageManager; 4] = [
+        PackageManager::Brew,
+        PackageManager::AptGet,
+        PackageManager::Apt,
+        PackageManager::Pacman,
+    ];
+
+    pub fn binary(&self) -> &'static str {
+        match self {
+            PackageManager::Brew => "brew",
+            PackageManager::AptGet => "apt-get",
+            PackageManager::Apt => "apt",
+            PackageManager::Pacman => "pacman",
+        }
+    }
+
+    /// Name of the package that provides `tool`, if this package manager has one.
+    /// ffprobe ships in the ffmpeg package; whisper.cpp is only packaged by Homebrew.
+    pub fn package_for(&self, tool: &str) -> Option<&'static str> {
+        match (self, tool) {
+            (_, "ffmpeg" | "ffprobe") => Some("ffmpeg"),
+            (PackageManager::Brew, "whisper-cli") => Some("whisper-cpp"),
+            _ => None,
+        }
+    }
+
+    /// The full command line, starting with the program to run
+    pub fn install_command(&self, package: &str) -> Vec<String> {
+        let args: &[&str] = match self {
+            PackageManager::Brew => &["brew", "install"],
+            PackageManager::AptGet => &["sudo", "apt-get", "install", "-y"],
+            PackageManager::Apt => &["sudo", "apt", "install", "-y"],
+            PackageManager::Pacman => &["sudo", "pacman", "-S", "--noconfirm"],
+        };
+        args.iter()
+            .map(|arg| arg.to_string())
+            .chain(std::iter::once(package.to_string()))
+            .collect()
+    }
+}
+
+/// The first supported package manager found on PATH
+pub fn detect_package_manager() -> Option<PackageManager> {
+    PackageManager::ALL
+        .into_iter()
+        .find(|manager| find_in_system_path(manager.binary()).is_some())
+}
+
+/// Installs `tool` with the package manager, inheriting the terminal so sudo can
+/// prompt for a password, and returns the path of the installed binary
+pub fn install_with_package_manager(
+    tool: &str,
+    manager: PackageManager,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let package = manager.package_for(tool).ok_or(format!(
+        "{} has no package for {}",
+        manager.binary(),
+        tool
+    ))?;
+
+    let command = manager.install_command(package);
+    println!("Running: {}", command.join(" "));
+    let status = std::process::Command::new(&command[0])
+        .args(&command[1..])
+        .status()?;
+    if !status.success() {
+        return Err(format!("{} exited with {}", command.join(" "), status).into());
+    }
+
+    find_in_system_path(tool).ok_or_else(|| {
+        format!(
+            "{} installed {} but {} is still not on PATH",
+            manager.binary(),
+            package,
+            tool
+        )
+        .into()
+    })
+}
+
 fn create_gpl_license_file(
     binaries_dir: &std::path::Path,
 ) -> Result<(), Box<dyn std::error::Error>> {
@@ -1732,3 +2173,101 @@ fn codesign_executable(executable_path: &str) -> Result<(), Box<dyn std::error::
         Err(format!("Failed to code sign executable: {}", error_msg).into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_newest_versioned_release() {
+        let listing = "<Key>binaries/ffmpeg71win64.zip</Key>\
+            <Key>binaries/ffmpeg80win64.zip</Key>\
+            <Key>binaries/ffmpeg81arm.zip</Key>\
+            <Key>binaries/ffprobe90win64.zip</Key>";
+        assert_eq!(
+            find_newest_release(listing, "binaries/ffmpeg", "win64.zip"),
+            Some("binaries/ffmpeg80win64.zip".to_string())
+        );
+    }
+
+    #[test]
+    fn compares_dotted_versions_numerically() {
+        let page = r#""https://x/download/v1.7.6/whisper-bin-x64.zip",
+            "https://x/download/v1.10.0/whisper-bin-x64.zip""#;
+        assert_eq!(
+            find_newest_release(page, "https://x/download/v", "/whisper-bin-x64.zip"),
+            Some("https://x/download/v1.10.0/whisper-bin-x64.zip".to_string())
+        );
+    }
+
+    #[test]
+    fn builds_package_manager_install_commands() {
+        assert_eq!(
+            PackageManager::AptGet.install_command("ffmpeg"),
+            ["sudo", "apt-get", "install", "-y", "ffmpeg"]
+        );
+        assert_eq!(
+            PackageManager::Brew.install_command("whisper-cpp"),
+            ["brew", "install", "whisper-cpp"]
+        );
+        assert_eq!(
+            PackageManager::Pacman.package_for("ffprobe"),
+            Some("ffmpeg")
+        );
+        assert_eq!(PackageManager::Pacman.package_for("whisper-cli"), None);
+    }
+
+    #[test]
+    fn ignores_names_without_a_version() {
+        assert_eq!(
+            find_newest_release("binaries/ffmpegwin64.zip", "binaries/ffmpeg", "win64.zip"),
+            None
+        );
+    }
+
+    /// Serves `body` once over HTTP on a local port, returning the url to fetch it from
+    fn serve_once(body: Vec<u8>) -> String {
+        use std::io::Write;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/tool", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 1024];
+            let _ = stream.read(&mut request);
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                body.len()
+            )
+            .unwrap();
+            stream.write_all(&body).unwrap();
+        });
+        url
+    }
+
+    #[test]
+    fn installs_bare_binary_when_hash_matches() {
+        let body = b"#!/bin/sh\necho whisper\n".to_vec();
+        let expected_hash = format!("{:x}", Sha256::digest(&body));
+        let dir = tempfile::tempdir().unwrap();
+
+        let bytes = fetch_tool_bytes("whisper-cli", &serve_once(body)).unwrap();
+        let path = install_tool_bytes(
+            "whisper-cli",
+            &bytes,
+            Some(&expected_hash),
+            dir.path(),
+            "macos-arm",
+        )
+        .unwrap();
+
+        assert_eq!(std::path::Path::new(&path), dir.path().join("whisper-cli"));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o755);
+        }
+    }
+}
@@ -9,11 +9,46 @@ use crate::queue;
 use crate::video_processor;
 use crate::web::ApiResponse;
 use dialoguer::Select;
-use rocket::serde::{Deserialize, json::Json};
+use rocket::serde::{Deserialize, Serialize, json::Json};
 use rocket::{get, post};
+use std::collections::HashMap;
 use std::fs;
+use std::io::{BufRead, BufReader};
 use std::path::Path;
 
+/// Read a transcript file as UTF-8. Files written by other tools may carry a
+/// UTF-8 or UTF-16 BOM, or not be UTF-8 at all, in which case they're read as
+/// Latin-1. Anything written back out is plain UTF-8.
+pub fn read_transcript_file(path: &Path) -> std::io::Result<String> {
+    let bytes = fs::read(path)?;
+    Ok(decode_transcript_bytes(&bytes))
+}
+
+/// Write a transcript file and drop cached search results, which may now be stale
+fn write_transcript_file(path: impl AsRef<Path>, content: impl AsRef<[u8]>) -> std::io::Result<()> {
+    fs::write(path, content)?;
+    if let Err(e) = crate::search::clear_search_cache() {
+        eprintln!("Warning: could not clear search cache: {}", e);
+    }
+    Ok(())
+}
+
+/// Decode transcript bytes the way `read_transcript_file` does, for callers reading them asynchronously
+pub fn decode_transcript_bytes(bytes: &[u8]) -> String {
+    if let Some((encoding, bom_len)) = encoding_rs::Encoding::for_bom(bytes) {
+        return encoding
+            .decode_without_bom_handling(&bytes[bom_len..])
+            .0
+            .into_owned();
+    }
+
+    match std::str::from_utf8(bytes) {
+        Ok(text) => text.to_string(),
+        // Every byte is a valid Latin-1 code point, so this never fails
+        Err(_) => bytes.iter().map(|&b| b as char).collect(),
+    }
+}
+
 pub fn get_transcript(video_path: &str) -> Result<String, Box<dyn std::error::Error>> {
     let video_path_obj = Path::new(video_path);
     let txt_path = video_path_obj.with_extension("txt");
@@ -22,7 +57,7 @@ pub fn get_transcript(video_path: &str) -> Result<String, Box<dyn std::error::Er
         return Err(format!("Transcript file does not exist: {}", txt_path.display()).into());
     }
 
-    let content = fs::read_to_string(&txt_path)?;
+    let content = read_transcript_file(&txt_path)?;
     Ok(content)
 }
 
@@ -42,7 +77,7 @@ pub fn set_line(
         return Err(format!("Transcript file does not exist: {}", txt_path.display()).into());
     }
 
-    let content = fs::read_to_string(&txt_path)?;
+    let content = read_transcript_file(&txt_path)?;
     let mut lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
 
     let line_index = line_number - 1; // Convert to 0-based index
@@ -66,11 +101,139 @@ pub fn set_line(
     };
 
     let updated_content = lines.join(line_ending);
-    fs::write(&txt_path, updated_content)?;
+    write_transcript_file(&txt_path, updated_content)?;
 
     Ok(())
 }
 
+/// Read a single 1-based line from a transcript without loading the whole file
+pub fn get_line(
+    video_path: &str,
+    line_number: usize,
+) -> Result<String, Box<dyn std::error::Error>> {
+    if line_number == 0 {
+        return Err("Line number must be greater than 0".into());
+    }
+
+    let txt_path = Path::new(video_path).with_extension("txt");
+
+    if !txt_path.exists() {
+        return Err(format!("Transcript file does not exist: {}", txt_path.display()).into());
+    }
+
+    let beyond_end = || format!("Line number {} is beyond the end of the file", line_number);
+
+    let mut reader = BufReader::new(fs::File::open(&txt_path)?);
+    let has_bom = encoding_rs::Encoding::for_bom(reader.fill_buf()?).is_some();
+
+    if !has_bom {
+        match reader.lines().nth(line_number - 1) {
+            Some(Ok(line)) => return Ok(line),
+            Some(Err(e)) if e.kind() != std::io::ErrorKind::InvalidData => return Err(e.into()),
+            None => return Err(beyond_end().into()),
+            // Not UTF-8, so decode the whole file instead
+            Some(Err(_)) => {}
+        }
+    }
+
+    read_transcript_file(&txt_path)?
+        .lines()
+        .nth(line_number - 1)
+        .map(str::to_string)
+        .ok_or_else(|| beyond_end().into())
+}
+
+/// Replace the text of the cue spanning `cue_start --> cue_end`.
+/// Returns the 1-based line number of the cue's (new) text line.
+pub fn set_cue_text(
+    video_path: &str,
+    cue_start: &str,
+    cue_end: &str,
+    new_text: &str,
+) -> Result<usize, Box<dyn std::error::Error>> {
+    let start_ms = parse_cue_timestamp(cue_start)?;
+    let end_ms = parse_cue_timestamp(cue_end)?;
+
+    let txt_path = Path::new(video_path).with_extension("txt");
+
+    if !txt_path.exists() {
+        return Err(format!("Transcript file does not exist: {}", txt_path.display()).into());
+    }
+
+    let content = read_transcript_file(&txt_path)?;
+    let mut lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
+
+    let header_index = lines
+        .iter()
+        .position(|line| {
+            line.trim().split_once(" --> ").is_some_and(|(start, end)| {
+                parse_cue_timestamp(start).ok() == Some(start_ms)
+                    && parse_cue_timestamp(end).ok() == Some(end_ms)
+            })
+        })
+        .ok_or_else(|| format!("No cue found for {} --> {}", cue_start, cue_end))?;
+
+    // The cue's text runs from the line after the header up to the next blank line
+    let text_start = header_index + 1;
+    let text_end = lines[text_start..]
+        .iter()
+        .position(|line| line.trim().is_empty())
+        .map_or(lines.len(), |offset| text_start + offset);
+    lines.splice(text_start..text_end, [new_text.to_string()]);
+
+    let line_ending = if content.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+
+    write_transcript_file(&txt_path, lines.join(line_ending))?;
+
+    Ok(text_start + 1)
+}
+
+/// Edits older than this are assumed to have been abandoned by a crashed process
+const TRANSCRIPT_EDIT_LOCK_STALE_SECS: i64 = 60;
+
+/// Claim the edit lock row for a transcript. Returns false if another edit holds it.
+fn acquire_edit_lock(
+    conn: &rusqlite::Connection,
+    video_path: &str,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    let now = chrono::Utc::now().timestamp();
+    let changed = conn.execute(
+        "INSERT INTO transcript_edit_locks (video_path, locked_at) VALUES (?1, ?2)
+         ON CONFLICT(video_path) DO UPDATE SET locked_at = excluded.locked_at
+         WHERE transcript_edit_locks.locked_at < ?3",
+        rusqlite::params![video_path, now, now - TRANSCRIPT_EDIT_LOCK_STALE_SECS],
+    )?;
+    Ok(changed == 1)
+}
+
+fn release_edit_lock(conn: &rusqlite::Connection, video_path: &str) {
+    let _ = conn.execute(
+        "DELETE FROM transcript_edit_locks WHERE video_path = ?1",
+        [video_path],
+    );
+}
+
+/// Run an edit against a transcript while holding its edit lock row
+fn with_edit_lock<T>(
+    video_path: &str,
+    edit: impl FnOnce() -> Result<T, Box<dyn std::error::Error>>,
+) -> Result<T, Box<dyn std::error::Error>> {
+    let conn = crate::db::get_connection()?;
+    conn.busy_timeout(std::time::Duration::from_secs(5))?;
+
+    if !acquire_edit_lock(&conn, video_path)? {
+        return Err(format!("Transcript for {} is being edited elsewhere", video_path).into());
+    }
+
+    let result = edit();
+    release_edit_lock(&conn, video_path);
+    result
+}
+
 fn set_with_config(
     video_path: &str,
     new_content: &str,
@@ -111,7 +274,7 @@ fn set_with_config(
     }
 
     let txt_path = video_path_obj.with_extension("txt");
-    fs::write(txt_path, new_content)?;
+    write_transcript_file(txt_path, new_content)?;
     Ok(())
 }
 
@@ -128,13 +291,18 @@ pub fn regenerate(
     let video_path_obj = Path::new(video_path);
     let txt_path = video_path_obj.with_extension("txt");
 
+    // A failed video has no transcript, only the .error sentinel that keeps it out of the queue
+    let had_error_file = video_processor::clear_error_file(video_path_obj)?;
+
     // Check if transcript file exists
-    if !txt_path.exists() {
+    if !txt_path.exists() && !had_error_file {
         return Err("No transcript files found to delete".into());
     }
 
     // Delete the transcript file
-    std::fs::remove_file(&txt_path)?;
+    if txt_path.exists() {
+        std::fs::remove_file(&txt_path)?;
+    }
 
     // Add video back to the queue for re-processing
     queue::add_to_queue(video_path, model, subtitle_stream_index)?;
@@ -142,69 +310,1057 @@ pub fn regenerate(
     Ok(())
 }
 
-pub fn rename(video_path: &str, new_path: &str) -> Result<(), Box<dyn std::error::Error>> {
-    let video_path_obj = Path::new(video_path);
-    let new_path_obj = Path::new(new_path);
+const REGENERATE_PROGRESS_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Where a regenerated video is on its way back through the queue, as reported by
+/// `transcripts regenerate --json-progress`
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegenerateStage {
+    /// Waiting in the queue at this 1-based position
+    Queued(usize),
+    /// Being transcribed, with the estimated fraction done when whisper has written any cues
+    Transcribing(Option<f64>),
+    /// Neither queued nor processing any more
+    Finished,
+}
+
+impl RegenerateStage {
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            RegenerateStage::Queued(position) => {
+                serde_json::json!({ "stage": "queued", "position": position })
+            }
+            RegenerateStage::Transcribing(progress) => {
+                serde_json::json!({ "stage": "transcribing", "progress": progress.unwrap_or(0.0) })
+            }
+            RegenerateStage::Finished => serde_json::json!({ "stage": "finished" }),
+        }
+    }
+}
+
+pub fn regenerate_stage(video_path: &str) -> Result<RegenerateStage, Box<dyn std::error::Error>> {
+    if queue::is_currently_processing(video_path)? {
+        // Whole percents, so polling doesn't report the same estimate with float noise
+        let progress = queue::get_progress_percent(video_path).map(|p| p.round() as f64 / 100.0);
+        return Ok(RegenerateStage::Transcribing(progress));
+    }
+
+    let queue = queue::get_queue(None)?;
+    Ok(match queue.iter().position(|path| path == video_path) {
+        Some(index) => RegenerateStage::Queued(index + 1),
+        None => RegenerateStage::Finished,
+    })
+}
+
+/// Poll a regenerated video until the queue processor is done with it, passing each change
+/// of stage to `emit`. The final event carries the processing outcome, which is also returned.
+/// Only returns once `atci watch` (or the web server) has picked the video up and finished it.
+pub fn follow_regenerate_progress(
+    video_path: &str,
+    mut emit: impl FnMut(&serde_json::Value),
+) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    let mut last_event = None;
+    loop {
+        let stage = regenerate_stage(video_path)?;
+        if stage == RegenerateStage::Finished {
+            let outcome = queue::last_processing_outcome(video_path);
+            emit(&serde_json::json!({ "stage": "finished", "outcome": outcome }));
+            return Ok(outcome);
+        }
+
+        let event = stage.to_json();
+        if last_event.as_ref() != Some(&event) {
+            emit(&event);
+            last_event = Some(event);
+        }
+        std::thread::sleep(REGENERATE_PROGRESS_POLL_INTERVAL);
+    }
+}
+
+pub fn rename(video_path: &str, new_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let video_path_obj = Path::new(video_path);
+    let new_path_obj = Path::new(new_path);
+
+    // Validate that the video path exists
+    if !video_path_obj.exists() {
+        return Err(format!("Video file does not exist: {}", video_path_obj.display()).into());
+    }
+
+    // Validate that it's a video file by checking common video extensions
+    let is_video = video_path_obj
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| files::get_video_extensions().contains(&ext.to_lowercase().as_str()))
+        .unwrap_or(false);
+
+    if !is_video {
+        return Err(format!(
+            "File is not a supported video format: {}",
+            video_path_obj.display()
+        )
+        .into());
+    }
+
+    // Check if corresponding txt file exists
+    let txt_path = video_path_obj.with_extension("txt");
+    if !txt_path.exists() {
+        return Err(format!("Transcript file does not exist: {}", txt_path.display()).into());
+    }
+
+    // Validate new path has same extension as original
+    let original_ext = video_path_obj.extension();
+    let new_ext = new_path_obj.extension();
+    if original_ext != new_ext {
+        return Err("New path must have the same file extension as the original".into());
+    }
+
+    // Check if new paths already exist
+    if new_path_obj.exists() {
+        return Err(format!(
+            "Target video file already exists: {}",
+            new_path_obj.display()
+        )
+        .into());
+    }
+
+    let new_txt_path = new_path_obj.with_extension("txt");
+    if new_txt_path.exists() {
+        return Err(format!(
+            "Target transcript file already exists: {}",
+            new_txt_path.display()
+        )
+        .into());
+    }
+
+    // Rename both files
+    fs::rename(video_path_obj, new_path_obj)?;
+    fs::rename(&txt_path, &new_txt_path)?;
+
+    // Update cache
+    files::get_and_save_video_info_from_disk()?;
+
+    Ok(())
+}
+
+/// Copy a video's transcript (and a legacy .meta file, if there is one) so it also belongs to
+/// `dest_video`, e.g. a re-encoded copy of the same recording. The metadata block only holds
+/// length/source/version/confidence values, none of which refer to the video's path, so the
+/// copy is byte for byte. Returns the files that were written.
+pub fn copy(
+    source_video: &str,
+    dest_video: &str,
+    overwrite: bool,
+) -> Result<Vec<std::path::PathBuf>, Box<dyn std::error::Error>> {
+    let source_path = Path::new(source_video);
+    let dest_path = Path::new(dest_video);
+
+    let is_video = dest_path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| files::get_video_extensions().contains(&ext.to_lowercase().as_str()))
+        .unwrap_or(false);
+    if !is_video {
+        return Err(format!(
+            "Destination is not a supported video format: {}",
+            dest_path.display()
+        )
+        .into());
+    }
+
+    let source_txt = source_path.with_extension("txt");
+    if !source_txt.exists() {
+        return Err(format!("Transcript file does not exist: {}", source_txt.display()).into());
+    }
+
+    if source_txt == dest_path.with_extension("txt") {
+        return Err("Source and destination videos share the same transcript file".into());
+    }
+
+    let pairs: Vec<(std::path::PathBuf, std::path::PathBuf)> = ["txt", "meta"]
+        .iter()
+        .map(|ext| {
+            (
+                source_path.with_extension(ext),
+                dest_path.with_extension(ext),
+            )
+        })
+        .filter(|(source, _)| source.exists())
+        .collect();
+
+    if !overwrite {
+        for (_, dest) in &pairs {
+            if dest.exists() {
+                return Err(format!(
+                    "Target file already exists: {} (use --overwrite to replace it)",
+                    dest.display()
+                )
+                .into());
+            }
+        }
+    }
+
+    let mut copied = Vec::new();
+    for (source, dest) in pairs {
+        fs::copy(&source, &dest)?;
+        copied.push(dest);
+    }
+
+    files::get_and_save_video_info_from_disk()?;
+
+    Ok(copied)
+}
+
+/// Whether `time` is HH:MM:SS or MM:SS followed by `separator` and milliseconds
+fn is_cue_time(time: &str, separator: char) -> bool {
+    let parts: Vec<&str> = time.split(':').collect();
+    if !(2..=3).contains(&parts.len()) {
+        return false;
+    }
+    let (fields, last) = parts.split_at(parts.len() - 1);
+    let all_digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+    fields.iter().all(|field| all_digits(field))
+        && last[0]
+            .split_once(separator)
+            .is_some_and(|(seconds, millis)| all_digits(seconds) && all_digits(millis))
+}
+
+fn is_cue_timing_line(line: &str, separator: char) -> bool {
+    line.trim().split_once(" --> ").is_some_and(|(start, end)| {
+        // VTT allows cue settings such as `align:start` after the end time
+        let end = end.split_whitespace().next().unwrap_or_default();
+        is_cue_time(start, separator) && is_cue_time(end, separator)
+    })
+}
+
+/// A VTT cue timing line: `00:00:01.000 --> 00:00:02.000`
+pub fn is_vtt_timestamp_line(line: &str) -> bool {
+    is_cue_timing_line(line, '.')
+}
+
+/// An SRT cue timing line, which uses a comma before the milliseconds: `00:00:01,000 --> 00:00:02,000`
+pub fn is_srt_timestamp_line(line: &str) -> bool {
+    is_cue_timing_line(line, ',')
+}
+
+pub fn is_timestamp_line(line: &str) -> bool {
+    is_vtt_timestamp_line(line) || is_srt_timestamp_line(line)
+}
+
+/// Parse a cue timestamp (HH:MM:SS.mmm, MM:SS.mmm or plain seconds) into milliseconds.
+/// SRT's comma millisecond separator (HH:MM:SS,mmm) is accepted too.
+pub fn parse_cue_timestamp(timestamp: &str) -> Result<u64, Box<dyn std::error::Error>> {
+    let timestamp = timestamp.trim();
+    let normalized = timestamp.replace(',', ".");
+    let parts: Vec<&str> = normalized.split(':').collect();
+
+    let seconds: f64 = match parts.len() {
+        1 => parts[0].parse()?,
+        2 => {
+            let minutes: f64 = parts[0].parse()?;
+            let seconds: f64 = parts[1].parse()?;
+            minutes * 60.0 + seconds
+        }
+        3 => {
+            let hours: f64 = parts[0].parse()?;
+            let minutes: f64 = parts[1].parse()?;
+            let seconds: f64 = parts[2].parse()?;
+            hours * 3600.0 + minutes * 60.0 + seconds
+        }
+        _ => return Err(format!("Invalid timestamp format: {}", timestamp).into()),
+    };
+
+    if seconds < 0.0 {
+        return Err(format!("Timestamp cannot be negative: {}", timestamp).into());
+    }
+
+    Ok((seconds * 1000.0).round() as u64)
+}
+
+/// Format milliseconds as a cue timestamp (HH:MM:SS.mmm)
+pub fn format_cue_timestamp(total_ms: u64) -> String {
+    let hours = total_ms / 3_600_000;
+    let minutes = (total_ms % 3_600_000) / 60_000;
+    let seconds = (total_ms % 60_000) / 1000;
+    let milliseconds = total_ms % 1000;
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        hours, minutes, seconds, milliseconds
+    )
+}
+
+/// A single timed cue from a transcript
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cue {
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub text: String,
+}
+
+/// A blank-line separated chunk of a transcript body. Anything that isn't a cue
+/// (such as a missing or failed part notice) is kept verbatim.
+#[derive(Debug, Clone)]
+pub enum TranscriptBlock {
+    Cue(Cue),
+    Other(String),
+}
+
+/// Split a transcript into its metadata lines and its body
+pub fn split_metadata(content: &str) -> (Vec<&str>, &str) {
+    match content.split_once(">>>.atcimetaend") {
+        Some((meta, body)) => (
+            meta.lines().filter(|l| !l.trim().is_empty()).collect(),
+            body,
+        ),
+        None => (Vec::new(), content),
+    }
+}
+
+/// Split a transcript body into blank-line separated cues and other blocks
+pub fn parse_blocks(body: &str) -> Vec<TranscriptBlock> {
+    body.replace("\r\n", "\n")
+        .split("\n\n")
+        .map(|block| block.trim())
+        .filter(|block| !block.is_empty())
+        .map(|block| {
+            let (first_line, text) = block.split_once('\n').unwrap_or((block, ""));
+            if let Some((start, end)) = first_line.split_once(" --> ")
+                && let Ok(start_ms) = parse_cue_timestamp(start)
+                && let Ok(end_ms) = parse_cue_timestamp(end)
+            {
+                TranscriptBlock::Cue(Cue {
+                    start_ms,
+                    end_ms,
+                    text: text.to_string(),
+                })
+            } else {
+                TranscriptBlock::Other(block.to_string())
+            }
+        })
+        .collect()
+}
+
+/// Join metadata lines and blocks back into transcript file content
+pub fn render_transcript(metadata: &[&str], blocks: &[TranscriptBlock]) -> String {
+    let body = blocks
+        .iter()
+        .map(|block| match block {
+            TranscriptBlock::Cue(cue) => format!(
+                "{} --> {}\n{}",
+                format_cue_timestamp(cue.start_ms),
+                format_cue_timestamp(cue.end_ms),
+                cue.text
+            ),
+            TranscriptBlock::Other(text) => text.clone(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    if metadata.is_empty() {
+        format!("\n{}\n", body)
+    } else {
+        format!("{}\n>>>.atcimetaend\n\n{}\n", metadata.join("\n"), body)
+    }
+}
+
+pub fn append(
+    video_path: &str,
+    start: &str,
+    end: &str,
+    text: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let video_path_obj = Path::new(video_path);
+    let txt_path = video_path_obj.with_extension("txt");
+
+    if !txt_path.exists() {
+        return Err(format!("Transcript file does not exist: {}", txt_path.display()).into());
+    }
+
+    if text.trim().is_empty() {
+        return Err("Cue text cannot be empty".into());
+    }
+
+    let start_ms = parse_cue_timestamp(start)?;
+    let end_ms = parse_cue_timestamp(end)?;
+
+    if end_ms <= start_ms {
+        return Err("End time must be greater than start time".into());
+    }
+
+    let content = read_transcript_file(&txt_path)?;
+
+    // Find the end time of the last existing cue
+    let last_end_ms = content
+        .lines()
+        .filter_map(|line| line.split_once(" --> "))
+        .filter_map(|(_, cue_end)| parse_cue_timestamp(cue_end).ok())
+        .max();
+
+    if let Some(last_end_ms) = last_end_ms
+        && start_ms < last_end_ms
+    {
+        return Err(format!(
+            "New cue starting at {} overlaps with existing cues ending at {}",
+            format_cue_timestamp(start_ms),
+            format_cue_timestamp(last_end_ms)
+        )
+        .into());
+    }
+
+    let mut updated_content = content.trim_end().to_string();
+    if !updated_content.is_empty() {
+        updated_content.push_str("\n\n");
+    }
+    updated_content.push_str(&format!(
+        "{} --> {}\n{}\n",
+        format_cue_timestamp(start_ms),
+        format_cue_timestamp(end_ms),
+        text.trim()
+    ));
+
+    write_transcript_file(&txt_path, updated_content)?;
+
+    Ok(())
+}
+
+/// How long the final lyric line is shown, since LRC only records start times
+const LRC_LAST_LINE_DURATION_MS: u64 = 5000;
+
+/// Convert LRC lyrics (`[MM:SS.xx]text`) into atci cues
+pub fn lrc_to_transcript(lrc: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let mut lyrics: Vec<(u64, String)> = Vec::new();
+
+    for line in lrc.lines() {
+        let mut rest = line.trim();
+        let mut timestamps = Vec::new();
+
+        // A line may carry several timestamps when the same lyric repeats
+        while let Some(tag) = rest.strip_prefix('[') {
+            let Some((tag, after)) = tag.split_once(']') else {
+                break;
+            };
+            // Non-numeric tags like [ar:Artist] are metadata, not timestamps
+            match parse_cue_timestamp(tag) {
+                Ok(ms) if tag.contains(':') => timestamps.push(ms),
+                _ => break,
+            }
+            rest = after;
+        }
+
+        for ms in timestamps {
+            lyrics.push((ms, rest.trim().to_string()));
+        }
+    }
+
+    if lyrics.is_empty() {
+        return Err("No timestamped lyric lines found in LRC file".into());
+    }
+
+    lyrics.sort_by_key(|(ms, _)| *ms);
+
+    let cues: Vec<String> = lyrics
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, text))| !text.is_empty())
+        .map(|(i, (start_ms, text))| {
+            let end_ms = lyrics
+                .get(i + 1)
+                .map(|(next_ms, _)| *next_ms)
+                .unwrap_or(start_ms + LRC_LAST_LINE_DURATION_MS);
+            format!(
+                "{} --> {}\n{}",
+                format_cue_timestamp(*start_ms),
+                format_cue_timestamp(end_ms),
+                text
+            )
+        })
+        .collect();
+
+    Ok(format!("\n{}\n", cues.join("\n\n")))
+}
+
+#[derive(Deserialize)]
+struct FasterWhisperOutput {
+    segments: Vec<FasterWhisperSegment>,
+}
+
+#[derive(Deserialize)]
+struct FasterWhisperSegment {
+    start: f64,
+    end: f64,
+    text: String,
+}
+
+/// Convert faster-whisper's `{"segments": [{"start", "end", "text"}]}` JSON into atci cues
+pub fn faster_whisper_to_transcript(json: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let output: FasterWhisperOutput = serde_json::from_str(json)?;
+
+    let cues: Vec<String> = output
+        .segments
+        .iter()
+        .filter(|segment| !segment.text.trim().is_empty())
+        .map(|segment| {
+            let start_ms = (segment.start.max(0.0) * 1000.0).round() as u64;
+            let end_ms = (segment.end.max(0.0) * 1000.0).round() as u64;
+            format!(
+                "{} --> {}\n{}",
+                format_cue_timestamp(start_ms),
+                format_cue_timestamp(end_ms.max(start_ms)),
+                segment.text.trim()
+            )
+        })
+        .collect();
+
+    if cues.is_empty() {
+        return Err("No segments with text found in faster-whisper JSON".into());
+    }
+
+    Ok(format!("\n{}\n", cues.join("\n\n")))
+}
+
+/// Convert atci cues into LRC lyrics, one `[MM:SS.xx]` line per cue
+pub fn transcript_to_lrc(content: &str) -> String {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut lrc_lines = Vec::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        let Some((start, _)) = line.split_once(" --> ") else {
+            continue;
+        };
+        let Ok(start_ms) = parse_cue_timestamp(start) else {
+            continue;
+        };
+
+        let text: Vec<&str> = lines[i + 1..]
+            .iter()
+            .take_while(|l| !l.trim().is_empty() && !l.contains(" --> "))
+            .map(|l| l.trim())
+            .collect();
+
+        let minutes = start_ms / 60_000;
+        let centiseconds = (start_ms % 60_000) / 10;
+        lrc_lines.push(format!(
+            "[{:02}:{:02}.{:02}]{}",
+            minutes,
+            centiseconds / 100,
+            centiseconds % 100,
+            text.join(" ")
+        ));
+    }
+
+    lrc_lines.join("\n") + "\n"
+}
+
+pub fn import_lrc(video_path: &str, lrc_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let video_path_obj = Path::new(video_path);
+    if !video_path_obj.exists() {
+        return Err(format!("Video file does not exist: {}", video_path).into());
+    }
+
+    let lrc_content = read_transcript_file(Path::new(lrc_path))?;
+    let transcript = lrc_to_transcript(&lrc_content)?;
+
+    fs::write(video_path_obj.with_extension("txt"), transcript)?;
+    video_processor::add_key_to_metadata_block(video_path_obj, "source", "lrc")
+        .map_err(|e| e.to_string())?;
+
+    // Update cache
+    files::get_and_save_video_info_from_disk()?;
+
+    Ok(())
+}
+
+pub fn import_faster_whisper(
+    video_path: &str,
+    json_path: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let video_path_obj = Path::new(video_path);
+    if !video_path_obj.exists() {
+        return Err(format!("Video file does not exist: {}", video_path).into());
+    }
+
+    let json = read_transcript_file(Path::new(json_path))?;
+    let transcript = faster_whisper_to_transcript(&json)?;
+
+    fs::write(video_path_obj.with_extension("txt"), transcript)?;
+    video_processor::add_key_to_metadata_block(video_path_obj, "source", "faster-whisper")
+        .map_err(|e| e.to_string())?;
+    video_processor::add_key_to_metadata_block(
+        video_path_obj,
+        "atci_version",
+        env!("CARGO_PKG_VERSION"),
+    )
+    .map_err(|e| e.to_string())?;
+
+    // Update cache
+    files::get_and_save_video_info_from_disk()?;
+
+    Ok(())
+}
+
+pub fn export_lrc(video_path: &str) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+    let content = get_transcript(video_path)?;
+    let lrc_path = Path::new(video_path).with_extension("lrc");
+    fs::write(&lrc_path, transcript_to_lrc(&content))?;
+    Ok(lrc_path)
+}
+
+/// Formats `transcripts export` can write
+pub const EXPORT_FORMATS: [&str; 3] = ["srt", "json", "csv"];
+
+#[derive(Serialize)]
+struct ExportedCue<'a> {
+    index: usize,
+    start: String,
+    end: String,
+    text: &'a str,
+}
+
+/// The cues of a transcript in order, skipping metadata and non-cue blocks
+fn transcript_cues(content: &str) -> Vec<Cue> {
+    let (_, body) = split_metadata(content);
+    parse_blocks(body)
+        .into_iter()
+        .filter_map(|block| match block {
+            TranscriptBlock::Cue(cue) => Some(cue),
+            TranscriptBlock::Other(_) => None,
+        })
+        .collect()
+}
+
+/// Convert atci cues into SRT subtitles, numbered from 1 with comma millisecond separators
+pub fn transcript_to_srt(content: &str) -> String {
+    transcript_cues(content)
+        .iter()
+        .enumerate()
+        .map(|(i, cue)| {
+            format!(
+                "{}\n{} --> {}\n{}\n",
+                i + 1,
+                format_cue_timestamp(cue.start_ms).replace('.', ","),
+                format_cue_timestamp(cue.end_ms).replace('.', ","),
+                cue.text
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Convert atci cues into a JSON array of `{index, start, end, text}` objects
+pub fn transcript_to_json(content: &str) -> Result<String, serde_json::Error> {
+    let cues = transcript_cues(content);
+    let exported: Vec<ExportedCue> = cues
+        .iter()
+        .enumerate()
+        .map(|(i, cue)| ExportedCue {
+            index: i + 1,
+            start: format_cue_timestamp(cue.start_ms),
+            end: format_cue_timestamp(cue.end_ms),
+            text: &cue.text,
+        })
+        .collect();
+    serde_json::to_string_pretty(&exported)
+}
+
+/// Convert atci cues into CSV rows of `index,start_seconds,end_seconds,text`
+pub fn transcript_to_csv(content: &str) -> String {
+    let mut output = String::from("index,start_seconds,end_seconds,text\n");
+    for (i, cue) in transcript_cues(content).iter().enumerate() {
+        output.push_str(&format!(
+            "{},{:.3},{:.3},{}\n",
+            i + 1,
+            cue.start_ms as f64 / 1000.0,
+            cue.end_ms as f64 / 1000.0,
+            files::csv_field(&cue.text, false)
+        ));
+    }
+    output
+}
+
+/// Export a transcript as SRT, JSON or CSV, writing next to the video file unless `output` is given
+pub fn export(
+    video_path: &str,
+    format: &str,
+    output: Option<&Path>,
+) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+    let content = get_transcript(video_path)?;
+    let exported = match format {
+        "srt" => transcript_to_srt(&content),
+        "json" => transcript_to_json(&content)? + "\n",
+        "csv" => transcript_to_csv(&content),
+        _ => {
+            return Err(format!(
+                "Unknown export format {}; use one of {}",
+                format,
+                EXPORT_FORMATS.join(", ")
+            )
+            .into());
+        }
+    };
+
+    let output_path = output
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| Path::new(video_path).with_extension(format));
+    fs::write(&output_path, exported)?;
+    Ok(output_path)
+}
+
+/// Offset every cue timestamp by `delta_ms`, clamping to `[0, max_ms]`
+pub fn shift_timestamps_in_content(content: &str, delta_ms: i64, max_ms: Option<u64>) -> String {
+    let shift = |ms: u64| -> u64 {
+        let shifted = (ms as i64 + delta_ms).max(0) as u64;
+        max_ms.map_or(shifted, |max_ms| shifted.min(max_ms))
+    };
+
+    let shifted_lines: Vec<String> = content
+        .lines()
+        .map(|line| {
+            if let Some((start, end)) = line.split_once(" --> ")
+                && let Ok(start_ms) = parse_cue_timestamp(start)
+                && let Ok(end_ms) = parse_cue_timestamp(end)
+            {
+                format!(
+                    "{} --> {}",
+                    format_cue_timestamp(shift(start_ms)),
+                    format_cue_timestamp(shift(end_ms))
+                )
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+
+    let mut shifted = shifted_lines.join("\n");
+    if content.ends_with('\n') {
+        shifted.push('\n');
+    }
+    shifted
+}
+
+/// Shift all cues in a transcript by `delta` seconds and return the new content.
+/// With `dry_run` the transcript file is left untouched.
+pub fn shift_timestamps(
+    video_path: &str,
+    delta: f64,
+    dry_run: bool,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let txt_path = Path::new(video_path).with_extension("txt");
+    let content = get_transcript(video_path)?;
+
+    let cfg = load_config_or_default();
+    let max_ms = match crate::clipper::get_video_duration_seconds(
+        Path::new(video_path),
+        Path::new(&cfg.ffprobe_path),
+    ) {
+        Ok(duration) => Some((duration * 1000.0).round() as u64),
+        Err(e) => {
+            eprintln!(
+                "Warning: could not read video duration, timestamps will not be clamped to it: {}",
+                e
+            );
+            None
+        }
+    };
+
+    let shifted = shift_timestamps_in_content(&content, (delta * 1000.0).round() as i64, max_ms);
+
+    if !dry_run {
+        write_transcript_file(&txt_path, &shifted)?;
+    }
+
+    Ok(shifted)
+}
+
+/// Whether cue text opens with a speaker marker such as `>> `, `- `, `[SPEAKER_01]` or `Name: `
+fn starts_with_speaker_tag(text: &str) -> bool {
+    let text = text.trim_start();
+    if text.starts_with(">>") || text.starts_with("- ") || text.starts_with('[') {
+        return true;
+    }
+    text.split_once(": ").is_some_and(|(name, _)| {
+        !name.is_empty()
+            && name.len() <= 30
+            && name.chars().next().is_some_and(|c| c.is_uppercase())
+            && name
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == ' ' || c == '_')
+    })
+}
+
+/// Merge runs of cues separated by less than `max_gap_ms` into a single cue spanning the run.
+/// A cue starting with a speaker tag always starts a new run, and so does a cue following one
+/// that finished its sentence. Returns the new content and how many cues were merged away.
+pub fn compact_cues_in_content(content: &str, max_gap_ms: u64) -> (String, usize) {
+    let (metadata, body) = split_metadata(content);
+    let mut compacted: Vec<TranscriptBlock> = Vec::new();
+    let mut merged = 0;
+
+    for block in parse_blocks(body) {
+        if let (Some(TranscriptBlock::Cue(previous)), TranscriptBlock::Cue(cue)) =
+            (compacted.last_mut(), &block)
+        {
+            let gap = cue.start_ms.saturating_sub(previous.end_ms);
+            let sentence_finished = previous.text.trim_end().ends_with(['.', '?', '!']);
+            if gap < max_gap_ms && !sentence_finished && !starts_with_speaker_tag(&cue.text) {
+                previous.end_ms = previous.end_ms.max(cue.end_ms);
+                previous.text = format!("{} {}", previous.text.trim_end(), cue.text.trim_start());
+                merged += 1;
+                continue;
+            }
+        }
+        compacted.push(block);
+    }
+
+    (render_transcript(&metadata, &compacted), merged)
+}
+
+/// Merge consecutive cues of the same sentence in a transcript and return the new content
+/// along with the number of cues merged away. With `dry_run` the file is left untouched.
+pub fn compact(
+    video_path: &str,
+    max_gap_ms: u64,
+    dry_run: bool,
+) -> Result<(String, usize), Box<dyn std::error::Error>> {
+    let txt_path = Path::new(video_path).with_extension("txt");
+    let content = get_transcript(video_path)?;
+
+    let (compacted, merged) = compact_cues_in_content(&content, max_gap_ms);
+
+    if !dry_run {
+        write_transcript_file(&txt_path, &compacted)?;
+    }
+
+    Ok((compacted, merged))
+}
+
+/// What `search_and_replace` looks for: a plain string, or a regex whose replacement may use
+/// `$1`-style capture group references
+pub enum FindPattern {
+    Literal(String),
+    Regex(regex::Regex),
+}
+
+impl FindPattern {
+    pub fn new(find: &str, is_regex: bool) -> Result<Self, Box<dyn std::error::Error>> {
+        if find.is_empty() {
+            return Err("The pattern to find cannot be empty".into());
+        }
+        if is_regex {
+            Ok(FindPattern::Regex(regex::Regex::new(find)?))
+        } else {
+            Ok(FindPattern::Literal(find.to_string()))
+        }
+    }
+
+    /// The line with every match replaced, along with how many matches there were
+    fn replace_line(&self, line: &str, replacement: &str) -> (String, usize) {
+        match self {
+            FindPattern::Literal(find) => (
+                line.replace(find.as_str(), replacement),
+                line.matches(find.as_str()).count(),
+            ),
+            FindPattern::Regex(re) => (
+                re.replace_all(line, replacement).into_owned(),
+                re.find_iter(line).count(),
+            ),
+        }
+    }
+}
+
+/// A transcript line changed by `search_and_replace`
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineReplacement {
+    /// 1-based line number in the transcript file
+    pub line_number: usize,
+    pub before: String,
+    pub after: String,
+    pub count: usize,
+}
 
-    // Validate that the video path exists
-    if !video_path_obj.exists() {
-        return Err(format!("Video file does not exist: {}", video_path_obj.display()).into());
-    }
+/// Replace `pattern` in the cue text of a transcript, leaving metadata and timestamp lines
+/// alone. Returns the new content along with every line that changed.
+pub fn replace_in_content(
+    content: &str,
+    pattern: &FindPattern,
+    replacement: &str,
+) -> (String, Vec<LineReplacement>) {
+    let mut in_metadata = content.contains(">>>.atcimetaend");
+    let mut replacements = Vec::new();
+
+    let replaced_lines: Vec<String> = content
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            if in_metadata {
+                in_metadata = line.trim() != ">>>.atcimetaend";
+                return line.to_string();
+            }
+            if is_timestamp_line(line) {
+                return line.to_string();
+            }
 
-    // Validate that it's a video file by checking common video extensions
-    let is_video = video_path_obj
-        .extension()
-        .and_then(|ext| ext.to_str())
-        .map(|ext| files::get_video_extensions().contains(&ext.to_lowercase().as_str()))
-        .unwrap_or(false);
+            let (replaced, count) = pattern.replace_line(line, replacement);
+            if count > 0 && replaced != line {
+                replacements.push(LineReplacement {
+                    line_number: index + 1,
+                    before: line.to_string(),
+                    after: replaced.clone(),
+                    count,
+                });
+            }
+            replaced
+        })
+        .collect();
 
-    if !is_video {
-        return Err(format!(
-            "File is not a supported video format: {}",
-            video_path_obj.display()
-        )
-        .into());
+    let mut replaced = replaced_lines.join("\n");
+    if content.ends_with('\n') {
+        replaced.push('\n');
     }
+    (replaced, replacements)
+}
 
-    // Check if corresponding txt file exists
-    let txt_path = video_path_obj.with_extension("txt");
-    if !txt_path.exists() {
-        return Err(format!("Transcript file does not exist: {}", txt_path.display()).into());
+/// Replace `pattern` throughout a video's transcript and save it with `set`, returning the
+/// lines that changed. With `dry_run` the transcript file is left untouched.
+pub fn search_and_replace(
+    video_path: &str,
+    pattern: &FindPattern,
+    replacement: &str,
+    dry_run: bool,
+) -> Result<Vec<LineReplacement>, Box<dyn std::error::Error>> {
+    let content = get_transcript(video_path)?;
+    let (replaced, replacements) = replace_in_content(&content, pattern, replacement);
+
+    if !dry_run && !replacements.is_empty() {
+        set(video_path, &replaced)?;
     }
 
-    // Validate new path has same extension as original
-    let original_ext = video_path_obj.extension();
-    let new_ext = new_path_obj.extension();
-    if original_ext != new_ext {
-        return Err("New path must have the same file extension as the original".into());
+    Ok(replacements)
+}
+
+/// Words too common to say anything about what a video is about
+const STOP_WORDS: &[&str] = &[
+    "the", "and", "for", "are", "but", "not", "you", "all", "any", "can", "had", "her", "was",
+    "one", "our", "out", "has", "him", "his", "how", "its", "let", "she", "that", "this", "with",
+    "have", "from", "they", "will", "would", "there", "their", "what", "about", "which", "when",
+    "were", "been", "just", "like", "know", "yeah", "okay", "gonna", "really", "right", "going",
+    "then", "them", "than", "into", "your", "some", "because", "could", "think", "well",
+];
+
+/// Term counts for the spoken text of a transcript, ignoring metadata, timestamps and
+/// short or very common words
+fn transcript_terms(content: &str) -> HashMap<String, usize> {
+    let (_, body) = split_metadata(content);
+    let mut terms = HashMap::new();
+
+    for block in parse_blocks(body) {
+        let TranscriptBlock::Cue(cue) = block else {
+            continue;
+        };
+        for word in cue
+            .text
+            .split(|c: char| !c.is_alphanumeric() && c != '\'')
+            .map(|word| word.trim_matches('\'').to_lowercase())
+            .filter(|word| word.chars().count() >= 3 && !STOP_WORDS.contains(&word.as_str()))
+        {
+            *terms.entry(word).or_insert(0) += 1;
+        }
     }
 
-    // Check if new paths already exist
-    if new_path_obj.exists() {
-        return Err(format!(
-            "Target video file already exists: {}",
-            new_path_obj.display()
-        )
-        .into());
+    terms
+}
+
+/// Rank `candidates` (path, transcript) by the cosine similarity of their TF-IDF
+/// vectors to `target`'s, best first. The IDF is taken over the target and all
+/// candidates, so it's computed per query rather than stored.
+fn rank_similar(target: &str, candidates: &[(String, String)], limit: usize) -> Vec<(String, f64)> {
+    let target_terms = transcript_terms(target);
+    let candidate_terms: Vec<HashMap<String, usize>> = candidates
+        .iter()
+        .map(|(_, content)| transcript_terms(content))
+        .collect();
+
+    let document_count = (candidates.len() + 1) as f64;
+    let mut document_frequency: HashMap<&str, usize> = HashMap::new();
+    for terms in std::iter::once(&target_terms).chain(&candidate_terms) {
+        for term in terms.keys() {
+            *document_frequency.entry(term.as_str()).or_insert(0) += 1;
+        }
     }
 
-    let new_txt_path = new_path_obj.with_extension("txt");
-    if new_txt_path.exists() {
-        return Err(format!(
-            "Target transcript file already exists: {}",
-            new_txt_path.display()
-        )
-        .into());
+    let tf_idf = |terms: &HashMap<String, usize>| -> HashMap<String, f64> {
+        terms
+            .iter()
+            .map(|(term, &count)| {
+                let df = document_frequency[term.as_str()] as f64;
+                (term.clone(), count as f64 * (document_count / df).ln())
+            })
+            .collect()
+    };
+    let norm = |vector: &HashMap<String, f64>| vector.values().map(|w| w * w).sum::<f64>().sqrt();
+
+    let target_vector = tf_idf(&target_terms);
+    let target_norm = norm(&target_vector);
+    if target_norm == 0.0 {
+        return Vec::new();
     }
 
-    // Rename both files
-    fs::rename(video_path_obj, new_path_obj)?;
-    fs::rename(&txt_path, &new_txt_path)?;
+    let mut ranked: Vec<(String, f64)> = candidates
+        .iter()
+        .zip(&candidate_terms)
+        .filter_map(|((path, _), terms)| {
+            let vector = tf_idf(terms);
+            let vector_norm = norm(&vector);
+            if vector_norm == 0.0 {
+                return None;
+            }
+            let dot: f64 = target_vector
+                .iter()
+                .filter_map(|(term, weight)| vector.get(term).map(|other| weight * other))
+                .sum();
+            let score = dot / (target_norm * vector_norm);
+            (score > 0.0).then(|| (path.clone(), score))
+        })
+        .collect();
+
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranked.truncate(limit);
+    ranked
+}
 
-    // Update cache
-    files::get_and_save_video_info_from_disk()?;
+#[derive(Debug, Serialize)]
+pub struct SimilarVideo {
+    pub path: String,
+    pub name: String,
+    pub score: f64,
+}
 
-    Ok(())
+/// The videos in the file cache whose transcripts are most similar to this one's
+pub fn find_similar(
+    video_path: &str,
+    limit: usize,
+) -> Result<Vec<SimilarVideo>, Box<dyn std::error::Error>> {
+    let target = get_transcript(video_path)?;
+
+    let candidates: Vec<(String, String)> = files::load_video_info_from_cache(None)?
+        .into_iter()
+        .filter(|info| info.transcript && info.full_path != video_path)
+        .filter_map(|info| {
+            let txt_path = Path::new(&info.full_path).with_extension("txt");
+            let content = read_transcript_file(&txt_path).ok()?;
+            Some((info.full_path, content))
+        })
+        .collect();
+
+    Ok(rank_similar(&target, &candidates, limit)
+        .into_iter()
+        .map(|(path, score)| SimilarVideo {
+            name: Path::new(&path)
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            path,
+            score,
+        })
+        .collect())
 }
 
 pub async fn regenerate_interactive(video_path: &str) -> Result<(), Box<dyn std::error::Error>> {
@@ -216,6 +1372,7 @@ pub async fn regenerate_interactive(video_path: &str) -> Result<(), Box<dyn std:
     }
 
     let cfg = crate::config::load_config()?;
+    video_processor::clear_error_file(video_path_obj)?;
 
     // Display file information
     println!("\n=== File Information ===");
@@ -363,6 +1520,20 @@ pub struct RenameTranscriptRequest {
     pub new_path: String,
 }
 
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum EditTranscriptRequest {
+    Line {
+        line_number: usize,
+        new_text: String,
+    },
+    Cue {
+        cue_start: String,
+        cue_end: String,
+        new_text: String,
+    },
+}
+
 #[get("/api/transcripts?<video_path>")]
 pub fn web_get_transcript_by_path(
     _auth: AuthGuard,
@@ -377,6 +1548,78 @@ pub fn web_get_transcript_by_path(
     }
 }
 
+#[derive(Serialize)]
+pub struct TranscriptLine {
+    pub line_number: usize,
+    pub text: String,
+}
+
+#[get("/api/videos/<path>/transcript/line/<n>")]
+pub fn web_get_transcript_line(
+    _auth: AuthGuard,
+    path: String,
+    n: usize,
+) -> Json<ApiResponse<TranscriptLine>> {
+    match get_line(&path, n) {
+        Ok(text) => Json(ApiResponse::success(TranscriptLine {
+            line_number: n,
+            text,
+        })),
+        Err(e) => Json(ApiResponse::error(format!(
+            "Failed to get transcript line: {}",
+            e
+        ))),
+    }
+}
+
+#[get("/api/transcripts/<path>/similar")]
+pub fn web_similar_transcripts(
+    _auth: AuthGuard,
+    path: String,
+) -> Json<ApiResponse<Vec<SimilarVideo>>> {
+    match find_similar(&path, 10) {
+        Ok(similar) => Json(ApiResponse::success(similar)),
+        Err(e) => Json(ApiResponse::error(format!(
+            "Failed to find similar videos: {}",
+            e
+        ))),
+    }
+}
+
+#[post("/api/transcripts/<path>/edit", data = "<request>")]
+pub fn web_edit_transcript(
+    _auth: AuthGuard,
+    path: String,
+    request: Json<EditTranscriptRequest>,
+) -> Json<ApiResponse<TranscriptLine>> {
+    let result = with_edit_lock(&path, || {
+        let line_number = match &*request {
+            EditTranscriptRequest::Line {
+                line_number,
+                new_text,
+            } => {
+                set_line(&path, *line_number, new_text)?;
+                *line_number
+            }
+            EditTranscriptRequest::Cue {
+                cue_start,
+                cue_end,
+                new_text,
+            } => set_cue_text(&path, cue_start, cue_end, new_text)?,
+        };
+        let text = get_line(&path, line_number)?;
+        Ok(TranscriptLine { line_number, text })
+    });
+
+    match result {
+        Ok(line) => Json(ApiResponse::success(line)),
+        Err(e) => Json(ApiResponse::error(format!(
+            "Failed to edit transcript: {}",
+            e
+        ))),
+    }
+}
+
 #[post("/api/transcripts/replace", data = "<request>")]
 pub fn web_replace_transcript(
     _auth: AuthGuard,
@@ -459,6 +1702,74 @@ mod tests {
         assert_eq!(result.unwrap(), transcript_content);
     }
 
+    #[test]
+    fn test_get_transcript_utf16_bom() {
+        let temp_dir = TempDir::new().unwrap();
+        let video_path = temp_dir.path().join("test_video.mp4");
+        let mut bytes = vec![0xFF, 0xFE];
+        bytes.extend(
+            "Café\nLine 2"
+                .encode_utf16()
+                .flat_map(|unit| unit.to_le_bytes()),
+        );
+        fs::write(temp_dir.path().join("test_video.txt"), bytes).unwrap();
+
+        let video_path = video_path.to_str().unwrap();
+        assert_eq!(get_transcript(video_path).unwrap(), "Café\nLine 2");
+        assert_eq!(get_line(video_path, 1).unwrap(), "Café");
+    }
+
+    #[test]
+    fn test_get_transcript_latin1_fallback() {
+        let temp_dir = TempDir::new().unwrap();
+        let video_path = temp_dir.path().join("test_video.mp4");
+        fs::write(temp_dir.path().join("test_video.txt"), b"Caf\xe9\nLine 2").unwrap();
+
+        let video_path = video_path.to_str().unwrap();
+        assert_eq!(get_transcript(video_path).unwrap(), "Café\nLine 2");
+        assert_eq!(get_line(video_path, 1).unwrap(), "Café");
+
+        set_line(video_path, 2, "Naïve").unwrap();
+        let written = fs::read(temp_dir.path().join("test_video.txt")).unwrap();
+        assert_eq!(String::from_utf8(written).unwrap(), "Café\nNaïve");
+    }
+
+    #[test]
+    fn test_rank_similar_prefers_shared_rare_terms() {
+        let cue = |text: &str| format!("00:00:01.000 --> 00:00:02.000\n{}\n", text);
+        let target = cue("the volcano erupted and lava reached the village");
+        let candidates = vec![
+            (
+                "cooking.mp4".to_string(),
+                cue("stir the pasta and add the sauce"),
+            ),
+            (
+                "geology.mp4".to_string(),
+                cue("lava flows from the volcano"),
+            ),
+            ("village.mp4".to_string(), cue("a quiet village market")),
+        ];
+
+        let ranked = rank_similar(&target, &candidates, 10);
+        let paths: Vec<&str> = ranked.iter().map(|(p, _)| p.as_str()).collect();
+        assert_eq!(paths, vec!["geology.mp4", "village.mp4"]);
+        assert!(ranked[0].1 > ranked[1].1);
+
+        assert_eq!(rank_similar(&target, &candidates, 1).len(), 1);
+    }
+
+    #[test]
+    fn test_get_line() {
+        let temp_dir = TempDir::new().unwrap();
+        let video_path = temp_dir.path().join("test_video.mp4");
+        create_test_file(temp_dir.path(), "test_video.txt", "Line 1\nLine 2\nLine 3");
+
+        let video_path = video_path.to_str().unwrap();
+        assert_eq!(get_line(video_path, 2).unwrap(), "Line 2");
+        assert!(get_line(video_path, 0).is_err());
+        assert!(get_line(video_path, 4).is_err());
+    }
+
     #[test]
     fn test_get_transcript_file_not_exists() {
         let temp_dir = TempDir::new().unwrap();
@@ -489,6 +1800,107 @@ mod tests {
         assert_eq!(updated_content, "Line 1\nModified Line 2\nLine 3");
     }
 
+    #[test]
+    fn test_compact_cues_in_content() {
+        let content = "source: whisper\n>>>.atcimetaend\n\n00:00:01.000 --> 00:00:02.000\nso what I\n\n00:00:02.000 --> 00:00:03.000\nwanted to say is\n\n00:00:03.100 --> 00:00:04.000\nthis works.\n\n00:00:04.000 --> 00:00:05.000\nand then\n\n00:00:05.000 --> 00:00:06.000\n>> Next speaker\n\n00:00:09.000 --> 00:00:10.000\nlater on\n";
+
+        let (compacted, merged) = compact_cues_in_content(content, 500);
+        assert_eq!(merged, 2);
+        assert_eq!(
+            compacted,
+            "source: whisper\n>>>.atcimetaend\n\n00:00:01.000 --> 00:00:04.000\nso what I wanted to say is this works.\n\n00:00:04.000 --> 00:00:05.000\nand then\n\n00:00:05.000 --> 00:00:06.000\n>> Next speaker\n\n00:00:09.000 --> 00:00:10.000\nlater on\n"
+        );
+    }
+
+    #[test]
+    fn test_replace_in_content_skips_metadata_and_timestamps() {
+        let content = "source: john\n>>>.atcimetaend\n\n00:00:01.000 --> 00:00:02.000\njon said hi to jon\n\n00:00:02.000 --> 00:00:03.000\nbye\n";
+
+        let pattern = FindPattern::new("jon", false).unwrap();
+        let (replaced, replacements) = replace_in_content(content, &pattern, "John");
+        assert_eq!(
+            replaced,
+            "source: john\n>>>.atcimetaend\n\n00:00:01.000 --> 00:00:02.000\nJohn said hi to John\n\n00:00:02.000 --> 00:00:03.000\nbye\n"
+        );
+        assert_eq!(
+            replacements,
+            vec![LineReplacement {
+                line_number: 5,
+                before: "jon said hi to jon".to_string(),
+                after: "John said hi to John".to_string(),
+                count: 2,
+            }]
+        );
+
+        // A regex that would also match the timestamps only touches cue text
+        let pattern = FindPattern::new(r"(\d+)", true).unwrap();
+        let (replaced, _) =
+            replace_in_content("00:00:01.000 --> 00:00:02.000\nroom 101\n", &pattern, "#$1");
+        assert_eq!(replaced, "00:00:01.000 --> 00:00:02.000\nroom #101\n");
+
+        assert!(FindPattern::new("", false).is_err());
+        assert!(FindPattern::new("(unclosed", true).is_err());
+    }
+
+    #[test]
+    fn test_regenerate_stage_to_json() {
+        assert_eq!(
+            RegenerateStage::Queued(3).to_json().to_string(),
+            r#"{"position":3,"stage":"queued"}"#
+        );
+        assert_eq!(
+            RegenerateStage::Transcribing(Some(0.42))
+                .to_json()
+                .to_string(),
+            r#"{"progress":0.42,"stage":"transcribing"}"#
+        );
+        assert_eq!(
+            RegenerateStage::Transcribing(None).to_json()["progress"],
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_starts_with_speaker_tag() {
+        assert!(starts_with_speaker_tag(">> hello"));
+        assert!(starts_with_speaker_tag("[SPEAKER_01] hello"));
+        assert!(starts_with_speaker_tag("John Smith: hello"));
+        assert!(!starts_with_speaker_tag("and then: we left"));
+        assert!(!starts_with_speaker_tag("hello there"));
+    }
+
+    #[test]
+    fn test_set_cue_text_by_timestamp() {
+        let temp_dir = TempDir::new().unwrap();
+        let video_path = temp_dir.path().join("test_video.mp4");
+        let original_content = "00:00:01.000 --> 00:00:02.000\nfirst\n\n00:00:03.000 --> 00:00:04.000\nsecond\nwrapped\n\n00:00:05.000 --> 00:00:06.000\nthird";
+
+        create_test_file(temp_dir.path(), "test_video.txt", original_content);
+
+        let line_number = set_cue_text(
+            video_path.to_str().unwrap(),
+            "00:00:03.000",
+            "00:00:04.000",
+            "replaced",
+        )
+        .unwrap();
+        assert_eq!(line_number, 5);
+
+        let updated_content = get_transcript(video_path.to_str().unwrap()).unwrap();
+        assert_eq!(
+            updated_content,
+            "00:00:01.000 --> 00:00:02.000\nfirst\n\n00:00:03.000 --> 00:00:04.000\nreplaced\n\n00:00:05.000 --> 00:00:06.000\nthird"
+        );
+
+        let missing = set_cue_text(
+            video_path.to_str().unwrap(),
+            "00:00:07.000",
+            "00:00:08.000",
+            "nope",
+        );
+        assert!(missing.is_err());
+    }
+
     #[test]
     fn test_set_line_zero_line_number() {
         let temp_dir = TempDir::new().unwrap();
@@ -551,6 +1963,197 @@ mod tests {
         assert_eq!(updated_content, "Line 1\r\nModified Line 2\r\nLine 3");
     }
 
+    #[test]
+    fn test_lrc_to_transcript() {
+        let lrc =
+            "[ar:Someone]\n[00:01.50]First line\n[00:04.00][01:10.25]Chorus\n[00:07.00]Second line";
+        let transcript = lrc_to_transcript(lrc).unwrap();
+        assert_eq!(
+            transcript,
+            "\n00:00:01.500 --> 00:00:04.000\nFirst line\n\n00:00:04.000 --> 00:00:07.000\nChorus\n\n00:00:07.000 --> 00:01:10.250\nSecond line\n\n00:01:10.250 --> 00:01:15.250\nChorus\n"
+        );
+    }
+
+    #[test]
+    fn test_faster_whisper_to_transcript() {
+        let json = r#"{"language": "en", "segments": [
+            {"id": 0, "start": 0.0, "end": 1.5, "text": " Hello there."},
+            {"id": 1, "start": 1.5, "end": 2.0, "text": "  "},
+            {"id": 2, "start": 61.25, "end": 63.0, "text": " General Kenobi."}
+        ]}"#;
+        assert_eq!(
+            faster_whisper_to_transcript(json).unwrap(),
+            "\n00:00:00.000 --> 00:00:01.500\nHello there.\n\n00:01:01.250 --> 00:01:03.000\nGeneral Kenobi.\n"
+        );
+        assert!(faster_whisper_to_transcript(r#"{"segments": []}"#).is_err());
+    }
+
+    #[test]
+    fn test_transcript_to_lrc() {
+        let transcript = "source: lrc\n>>>.atcimetaend\n\n00:00:01.500 --> 00:00:04.000\nFirst line\n\n01:02:03.456 --> 01:02:05.000\nLate line";
+        assert_eq!(
+            transcript_to_lrc(transcript),
+            "[00:01.50]First line\n[62:03.45]Late line\n"
+        );
+    }
+
+    #[test]
+    fn test_timestamp_line_detects_vtt_and_srt() {
+        assert!(is_vtt_timestamp_line("00:00:01.000 --> 00:00:02.500"));
+        assert!(is_vtt_timestamp_line("01:02.000 --> 01:04.000 align:start"));
+        assert!(!is_vtt_timestamp_line("00:00:01,000 --> 00:00:02,500"));
+
+        assert!(is_srt_timestamp_line("00:00:01,000 --> 00:00:02,500"));
+        assert!(!is_srt_timestamp_line("00:00:01.000 --> 00:00:02.500"));
+
+        assert!(is_timestamp_line("00:00:01.000 --> 00:00:02.500"));
+        assert!(is_timestamp_line("00:00:01,000 --> 00:00:02,500"));
+        assert!(!is_timestamp_line("we met at 10:30 --> then left"));
+        assert!(!is_timestamp_line("length: 00:01:00"));
+    }
+
+    #[test]
+    fn test_parse_cue_timestamp_accepts_srt_comma() {
+        assert_eq!(parse_cue_timestamp("00:01:02,345").unwrap(), 62_345);
+        assert_eq!(parse_cue_timestamp("00:01:02.345").unwrap(), 62_345);
+    }
+
+    const EXPORT_FIXTURE: &str = "source: whisper\n>>>.atcimetaend\n\n00:00:01.250 --> 00:00:04.000\nHello, \"world\"\n\n01:02:03.456 --> 01:02:05.000\nSecond cue\nwraps here\n";
+
+    fn fixture_timings() -> Vec<(u64, u64)> {
+        transcript_cues(EXPORT_FIXTURE)
+            .iter()
+            .map(|cue| (cue.start_ms, cue.end_ms))
+            .collect()
+    }
+
+    #[test]
+    fn test_transcript_to_srt_round_trips_timestamps() {
+        let srt = transcript_to_srt(EXPORT_FIXTURE);
+        assert!(srt.starts_with("1\n00:00:01,250 --> 00:00:04,000\nHello, \"world\"\n\n2\n"));
+
+        // Dropping the sequence numbers leaves blocks parse_blocks reads back as cues
+        let body: String = srt
+            .split("\n\n")
+            .map(|block| block.split_once('\n').map_or(block, |(_, rest)| rest))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        let timings: Vec<(u64, u64)> = transcript_cues(&body)
+            .iter()
+            .map(|cue| (cue.start_ms, cue.end_ms))
+            .collect();
+        assert_eq!(timings, fixture_timings());
+    }
+
+    #[test]
+    fn test_transcript_to_json_round_trips_timestamps() {
+        let json: serde_json::Value =
+            serde_json::from_str(&transcript_to_json(EXPORT_FIXTURE).unwrap()).unwrap();
+        let cues = json.as_array().unwrap();
+        assert_eq!(cues[1]["index"], 2);
+        assert_eq!(cues[1]["text"], "Second cue\nwraps here");
+
+        let timings: Vec<(u64, u64)> = cues
+            .iter()
+            .map(|cue| {
+                (
+                    parse_cue_timestamp(cue["start"].as_str().unwrap()).unwrap(),
+                    parse_cue_timestamp(cue["end"].as_str().unwrap()).unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(timings, fixture_timings());
+    }
+
+    #[test]
+    fn test_transcript_to_csv_round_trips_timestamps() {
+        let csv = transcript_to_csv(EXPORT_FIXTURE);
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("index,start_seconds,end_seconds,text"));
+        assert_eq!(lines.next(), Some("1,1.250,4.000,\"Hello, \"\"world\"\"\""));
+
+        let timings: Vec<(u64, u64)> = csv
+            .lines()
+            .skip(1)
+            .filter_map(|line| {
+                let mut fields = line.split(',');
+                let _index = fields.next()?;
+                let start = parse_cue_timestamp(fields.next()?).ok()?;
+                let end = parse_cue_timestamp(fields.next()?).ok()?;
+                Some((start, end))
+            })
+            .collect();
+        assert_eq!(timings, fixture_timings());
+    }
+
+    #[test]
+    fn test_shift_timestamps_in_content() {
+        let content = "source: whisper\n>>>.atcimetaend\n\n00:00:01.000 --> 00:00:02.500\nFirst\n\n00:00:09.000 --> 00:00:11.000\nLast\n";
+        assert_eq!(
+            shift_timestamps_in_content(content, -1500, Some(10_000)),
+            "source: whisper\n>>>.atcimetaend\n\n00:00:00.000 --> 00:00:01.000\nFirst\n\n00:00:07.500 --> 00:00:09.500\nLast\n"
+        );
+        assert_eq!(
+            shift_timestamps_in_content(content, 1500, Some(10_000)),
+            "source: whisper\n>>>.atcimetaend\n\n00:00:02.500 --> 00:00:04.000\nFirst\n\n00:00:10.000 --> 00:00:10.000\nLast\n"
+        );
+    }
+
+    #[test]
+    fn test_append_cue_after_last() {
+        let temp_dir = TempDir::new().unwrap();
+        let video_path = temp_dir.path().join("test_video.mp4");
+        let original_content =
+            "length: 00:01:00\n>>>.atcimetaend\n00:00:01.000 --> 00:00:04.000\nHello there\n";
+
+        create_test_file(temp_dir.path(), "test_video.txt", original_content);
+
+        let result = append(
+            video_path.to_str().unwrap(),
+            "00:00:05.000",
+            "00:00:07.500",
+            "Manual note",
+        );
+        assert!(result.is_ok());
+
+        let updated_content = get_transcript(video_path.to_str().unwrap()).unwrap();
+        assert_eq!(
+            updated_content,
+            "length: 00:01:00\n>>>.atcimetaend\n00:00:01.000 --> 00:00:04.000\nHello there\n\n00:00:05.000 --> 00:00:07.500\nManual note\n"
+        );
+    }
+
+    #[test]
+    fn test_append_cue_overlapping() {
+        let temp_dir = TempDir::new().unwrap();
+        let video_path = temp_dir.path().join("test_video.mp4");
+
+        create_test_file(
+            temp_dir.path(),
+            "test_video.txt",
+            "00:00:01.000 --> 00:00:04.000\nHello there\n",
+        );
+
+        let result = append(video_path.to_str().unwrap(), "00:00:03", "00:00:06", "Note");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("overlaps"));
+    }
+
+    #[test]
+    fn test_append_cue_end_before_start() {
+        let temp_dir = TempDir::new().unwrap();
+        let video_path = temp_dir.path().join("test_video.mp4");
+
+        create_test_file(temp_dir.path(), "test_video.txt", "");
+
+        let result = append(video_path.to_str().unwrap(), "00:00:06", "00:00:05", "Note");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "End time must be greater than start time"
+        );
+    }
+
     #[test]
     fn test_set_success() {
         let temp_dir = TempDir::new().unwrap();
@@ -678,6 +2281,32 @@ mod tests {
         assert!(!txt_path.exists());
     }
 
+    #[test]
+    fn test_regenerate_clears_error_file_of_failed_video() {
+        let _guard = REGENERATE_TEST_MUTEX.lock().unwrap();
+
+        // Clear queue to avoid race conditions in parallel tests
+        if let Ok(conn) = crate::db::get_connection() {
+            let _ = conn.execute("DELETE FROM queue", []);
+            let _ = conn.execute("DELETE FROM currently_processing", []);
+        }
+
+        let temp_dir = TempDir::new().unwrap();
+        let video_path = temp_dir.path().join("test_video.mp4");
+
+        create_test_file(
+            temp_dir.path(),
+            "test_video.error",
+            "whisper exited with status 1",
+        );
+
+        let result = regenerate(video_path.to_str().unwrap(), None, None);
+        assert!(result.is_ok());
+
+        let error_path = temp_dir.path().join("test_video.error");
+        assert!(!error_path.exists());
+    }
+
     #[test]
     fn test_regenerate_no_files_to_delete() {
         let _guard = REGENERATE_TEST_MUTEX.lock().unwrap();
@@ -13,8 +13,9 @@ use ratatui::{
     Frame, Terminal,
     backend::{Backend, CrosstermBackend},
     layout::{Alignment, Constraint, Direction, Layout},
-    style::{Color, Style},
-    widgets::{Block, Borders, Clear, Paragraph},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
 };
 use std::{
     env,
@@ -22,6 +23,7 @@ use std::{
     fs::{self, OpenOptions},
     io,
     process::{Command, Stdio},
+    sync::{Arc, Mutex},
     time::{Duration, Instant},
 };
 
@@ -102,6 +104,26 @@ pub enum SystemSection {
     WatchDirectories,
 }
 
+/// Multi-key vim-style sequences recognised in normal mode
+const KEY_SEQUENCES: &[&str] = &["gg"];
+const KEY_SEQUENCE_TIMEOUT: Duration = Duration::from_secs(1);
+const QUEUE_STATUS_POLL_INTERVAL: Duration = Duration::from_secs(5);
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+const TITLE_FILENAME_MAX_CHARS: usize = 30;
+const NOTIFICATION_DURATION: Duration = Duration::from_secs(3);
+const SAVED_INDICATOR_DURATION: Duration = Duration::from_secs(2);
+
+/// Processing takes precedence over the queue length since it's what's happening right now
+fn queue_badge_for(processing: bool, queued: usize) -> Option<(String, Color)> {
+    if processing {
+        Some(("[⚙ processing]".to_string(), Color::Green))
+    } else if queued > 0 {
+        Some((format!("[{} in queue]", queued), Color::Yellow))
+    } else {
+        None
+    }
+}
+
 pub struct App {
     pub colors: TableColors,
     pub current_tab: TabState,
@@ -119,6 +141,22 @@ pub struct App {
     pub watch_directories_selected_index: usize,
     pub show_directory_picker: bool,
     pub directory_picker: Option<ratatui_explorer::FileExplorer>,
+    pub key_buffer: Vec<char>,
+    pub last_key_press: Instant,
+    pub show_about: bool,
+    pub about_scroll: usize,
+    pub latest_version: Arc<Mutex<Option<String>>>,
+    pub queue_status: Arc<Mutex<Option<String>>>,
+    /// Number of files waiting in the queue, refreshed by the same poller as queue_status
+    pub queue_length: Arc<Mutex<usize>>,
+    pub spinner_frame: usize,
+    pub terminal_title: String,
+    /// Last file the queue was seen processing, to notice when it finishes
+    pub last_processing: Option<String>,
+    /// Banner shown when a file finishes: (shown at, message, succeeded)
+    pub notification: Option<(Instant, String, bool)>,
+    /// When the field being edited was last saved with Ctrl+S, for the [saved] indicator
+    pub config_saved_at: Option<Instant>,
 }
 
 #[derive(Clone)]
@@ -154,6 +192,18 @@ impl Default for App {
             watch_directories_selected_index: 0,
             show_directory_picker: false,
             directory_picker: None,
+            key_buffer: Vec::new(),
+            last_key_press: Instant::now(),
+            show_about: false,
+            about_scroll: 0,
+            latest_version: Arc::new(Mutex::new(None)),
+            queue_status: Arc::new(Mutex::new(None)),
+            queue_length: Arc::new(Mutex::new(0)),
+            spinner_frame: 0,
+            terminal_title: String::new(),
+            last_processing: None,
+            notification: None,
+            config_saved_at: None,
         }
     }
 }
@@ -179,6 +229,18 @@ impl App {
             watch_directories_selected_index: 0,
             show_directory_picker: false,
             directory_picker: None,
+            key_buffer: Vec::new(),
+            last_key_press: Instant::now(),
+            show_about: false,
+            about_scroll: 0,
+            latest_version: Arc::new(Mutex::new(None)),
+            queue_status: Arc::new(Mutex::new(None)),
+            queue_length: Arc::new(Mutex::new(0)),
+            spinner_frame: 0,
+            terminal_title: String::new(),
+            last_processing: None,
+            notification: None,
+            config_saved_at: None,
         };
 
         // Initialize system services
@@ -187,9 +249,158 @@ impl App {
         // Initialize queue
         app.refresh_queue();
 
+        app.fetch_latest_version();
+        app.start_queue_status_poller();
+
         Ok(app)
     }
 
+    /// Poll which file the queue is processing and how many are waiting in the background
+    /// for the title bar and the queue badge
+    pub fn start_queue_status_poller(&self) {
+        let queue_status = Arc::clone(&self.queue_status);
+        let queue_length = Arc::clone(&self.queue_length);
+        std::thread::spawn(move || {
+            loop {
+                if let Ok((path, _)) = crate::queue::get_queue_status(None)
+                    && let Ok(mut guard) = queue_status.lock()
+                {
+                    *guard = path;
+                }
+                if let Ok(queue) = crate::queue::get_queue(None)
+                    && let Ok(mut guard) = queue_length.lock()
+                {
+                    *guard = queue.len();
+                }
+                std::thread::sleep(QUEUE_STATUS_POLL_INTERVAL);
+            }
+        });
+    }
+
+    /// Badge text and color for the queue's activity, or None while it's idle
+    pub fn queue_badge(&self) -> Option<(String, Color)> {
+        let processing = self
+            .queue_status
+            .lock()
+            .ok()
+            .is_some_and(|guard| guard.as_deref().is_some_and(|path| !path.is_empty()));
+        let queued = self.queue_length.lock().map(|guard| *guard).unwrap_or(0);
+        queue_badge_for(processing, queued)
+    }
+
+    /// Terminal title showing a spinner and the abbreviated filename while the queue is processing
+    pub fn next_terminal_title(&mut self) -> String {
+        let processing = self
+            .queue_status
+            .lock()
+            .ok()
+            .and_then(|guard| guard.clone());
+        match processing {
+            Some(path) if !path.is_empty() => {
+                self.spinner_frame = (self.spinner_frame + 1) % SPINNER_FRAMES.len();
+                let name = std::path::Path::new(&path)
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or(path);
+                let name = if name.chars().count() > TITLE_FILENAME_MAX_CHARS {
+                    let truncated: String =
+                        name.chars().take(TITLE_FILENAME_MAX_CHARS - 3).collect();
+                    format!("{}...", truncated)
+                } else {
+                    name
+                };
+                format!("atci {} {}", SPINNER_FRAMES[self.spinner_frame], name)
+            }
+            _ => "atci".to_string(),
+        }
+    }
+
+    /// Show a banner once the file the queue was processing is no longer being processed
+    pub fn check_processing_finished(&mut self) {
+        let processing = self
+            .queue_status
+            .lock()
+            .ok()
+            .and_then(|guard| guard.clone())
+            .filter(|path| !path.is_empty());
+
+        if processing == self.last_processing {
+            return;
+        }
+
+        if let Some(finished) = self.last_processing.take() {
+            let succeeded =
+                crate::queue::last_processing_outcome(&finished).is_some_and(|outcome| {
+                    outcome == crate::queue::ProcessingOutcome::Success.as_str()
+                });
+            let name = std::path::Path::new(&finished)
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or(finished);
+            let message = if succeeded {
+                format!("✓ Finished: {}", name)
+            } else {
+                format!("✗ Failed: {}", name)
+            };
+            self.notification = Some((Instant::now(), message, succeeded));
+        }
+        self.last_processing = processing;
+    }
+
+    /// The banner to show, dropping it once it has been up long enough
+    pub fn active_notification(&mut self) -> Option<&(Instant, String, bool)> {
+        if self
+            .notification
+            .as_ref()
+            .is_some_and(|(shown_at, _, _)| shown_at.elapsed() >= NOTIFICATION_DURATION)
+        {
+            self.notification = None;
+        }
+        self.notification.as_ref()
+    }
+
+    /// Look up the latest GitHub release in the background for the About screen
+    pub fn fetch_latest_version(&self) {
+        let latest_version = Arc::clone(&self.latest_version);
+        std::thread::spawn(move || {
+            let version = self_update::backends::github::ReleaseList::configure()
+                .repo_owner("adnissen")
+                .repo_name("atci")
+                .build()
+                .and_then(|r| r.fetch())
+                .ok()
+                .and_then(|releases| releases.first().map(|r| r.version.clone()))
+                .unwrap_or_else(|| "unknown".to_string());
+            if let Ok(mut guard) = latest_version.lock() {
+                *guard = Some(version);
+            }
+        });
+    }
+
+    /// Record a keypress and return the key sequence it completes, if any
+    pub fn push_key(&mut self, c: char) -> Option<&'static str> {
+        if self.last_key_press.elapsed() > KEY_SEQUENCE_TIMEOUT {
+            self.key_buffer.clear();
+        }
+        self.last_key_press = Instant::now();
+        self.key_buffer.push(c);
+
+        let buffered: String = self.key_buffer.iter().collect();
+        if let Some(sequence) = KEY_SEQUENCES.iter().find(|seq| **seq == buffered) {
+            self.key_buffer.clear();
+            return Some(sequence);
+        }
+
+        // Start over if the buffer can no longer become a known sequence
+        if !KEY_SEQUENCES.iter().any(|seq| seq.starts_with(&buffered)) {
+            self.key_buffer.clear();
+            if KEY_SEQUENCES.iter().any(|seq| seq.starts_with(c)) {
+                self.key_buffer.push(c);
+            }
+        }
+        None
+    }
+
     pub fn config_next_field(&mut self) {
         let total_fields = self.get_config_field_count();
         if self.config_selected_field < total_fields - 1 {
@@ -221,7 +432,7 @@ impl App {
     }
 
     pub fn get_config_field_count(&self) -> usize {
-        21 // Total number of config fields (excluding watch_directories)
+        33 // Total number of config fields (excluding watch_directories)
     }
 
     pub fn get_config_field_names(&self) -> Vec<&'static str> {
@@ -236,6 +447,18 @@ impl App {
             "allow_whisper",
             "allow_subtitles",
             "stream_chunk_size",
+            "processing_timeout_secs",
+            "inter_processing_delay_secs",
+            "watch_poll_interval_secs",
+            "min_video_size_bytes",
+            "session_timeout_mins",
+            "preview_cache_size_mb",
+            "exclusive_lock_timeout_secs",
+            "access_log_path",
+            "access_log_format",
+            "max_queue_size",
+            "max_file_size_gb",
+            "max_concurrent_transcriptions",
             "hostname",
             "color_buffer_bg",
             "color_header_bg",
@@ -262,17 +485,37 @@ impl App {
             7 => self.config_data.allow_whisper.to_string(),
             8 => self.config_data.allow_subtitles.to_string(),
             9 => self.config_data.stream_chunk_size.to_string(),
-            10 => self.config_data.hostname.clone(),
-            11 => self.config_data.color_buffer_bg.clone(),
-            12 => self.config_data.color_header_bg.clone(),
-            13 => self.config_data.color_text_primary.clone(),
-            14 => self.config_data.color_border_primary.clone(),
-            15 => self.config_data.color_selection.clone(),
-            16 => self.config_data.color_success.clone(),
-            17 => self.config_data.color_disabled.clone(),
-            18 => self.config_data.color_info.clone(),
-            19 => self.config_data.color_error.clone(),
-            20 => self.config_data.color_text_highlight.clone(),
+            10 => self.config_data.processing_timeout_secs.to_string(),
+            11 => self.config_data.inter_processing_delay_secs.to_string(),
+            12 => self.config_data.watch_poll_interval_secs.to_string(),
+            13 => self.config_data.min_video_size_bytes.to_string(),
+            14 => self.config_data.session_timeout_mins.to_string(),
+            15 => self.config_data.preview_cache_size_mb.to_string(),
+            16 => self.config_data.exclusive_lock_timeout_secs.to_string(),
+            17 => self.config_data.access_log_path.clone(),
+            18 => self.config_data.access_log_format.clone(),
+            19 => self
+                .config_data
+                .max_queue_size
+                .map(|n| n.to_string())
+                .unwrap_or_default(),
+            20 => self
+                .config_data
+                .max_file_size_gb
+                .map(|n| n.to_string())
+                .unwrap_or_default(),
+            21 => self.config_data.max_concurrent_transcriptions.to_string(),
+            22 => self.config_data.hostname.clone(),
+            23 => self.config_data.color_buffer_bg.clone(),
+            24 => self.config_data.color_header_bg.clone(),
+            25 => self.config_data.color_text_primary.clone(),
+            26 => self.config_data.color_border_primary.clone(),
+            27 => self.config_data.color_selection.clone(),
+            28 => self.config_data.color_success.clone(),
+            29 => self.config_data.color_disabled.clone(),
+            30 => self.config_data.color_info.clone(),
+            31 => self.config_data.color_error.clone(),
+            32 => self.config_data.color_text_highlight.clone(),
             _ => String::new(),
         }
     }
@@ -314,6 +557,7 @@ impl App {
     pub fn stop_config_editing(&mut self) {
         self.config_editing_mode = false;
         self.config_input_buffer.clear();
+        self.config_saved_at = None;
     }
 
     pub fn cancel_config_edit(&mut self) {
@@ -322,6 +566,19 @@ impl App {
     }
 
     pub fn apply_config_edit(&mut self) -> Result<(), String> {
+        self.write_config_edit()?;
+        self.stop_config_editing();
+        Ok(())
+    }
+
+    /// Save the field being edited (Ctrl+S) while staying in edit mode
+    pub fn save_config_edit(&mut self) -> Result<(), String> {
+        self.write_config_edit()?;
+        self.config_saved_at = Some(Instant::now());
+        Ok(())
+    }
+
+    fn write_config_edit(&mut self) -> Result<(), String> {
         let field_names = self.get_config_field_names();
         if self.config_selected_field < field_names.len() {
             let field_name = field_names[self.config_selected_field];
@@ -331,12 +588,39 @@ impl App {
             // Automatically reload config from disk to ensure consistency
             self.reload_config();
         }
-        self.stop_config_editing();
         Ok(())
     }
 
+    /// `[modified]` while the edit differs from the stored value, then `[saved]` briefly after Ctrl+S
+    pub fn config_edit_indicator(&self) -> Option<(&'static str, Color)> {
+        if !self.config_editing_mode {
+            return None;
+        }
+        if self.config_input_buffer != self.get_config_field_value(self.config_selected_field) {
+            Some(("[modified]", Color::Yellow))
+        } else if self
+            .config_saved_at
+            .is_some_and(|saved_at| saved_at.elapsed() < SAVED_INDICATOR_DURATION)
+        {
+            Some(("[saved]", Color::Green))
+        } else {
+            None
+        }
+    }
+
+    /// Invalid values are still saved, and shown in the notification banner
     pub fn save_config(&mut self) -> Result<(), String> {
-        config::store_config(&self.config_data).map_err(|e| format!("Failed to save config: {}", e))
+        let errors = config::store_config(&self.config_data)
+            .map_err(|e| format!("Failed to save config: {}", e))?;
+        if !errors.is_empty() {
+            let errors: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+            self.notification = Some((
+                Instant::now(),
+                format!("⚠ Invalid config value {}", errors.join("; ")),
+                false,
+            ));
+        }
+        Ok(())
     }
 
     pub fn reload_config(&mut self) {
@@ -504,6 +788,7 @@ pub fn run() -> Result<(), Box<dyn Error>> {
         disable_raw_mode()?;
         execute!(
             terminal.backend_mut(),
+            SetTitle("atci"),
             LeaveAlternateScreen,
             DisableMouseCapture
         )?;
@@ -526,6 +811,11 @@ fn handle_key_event(
         return Ok(None);
     }
 
+    // Esc dismisses the finished-processing banner before anything else sees it
+    if key.code == KeyCode::Esc && app.notification.take().is_some() {
+        return Ok(None);
+    }
+
     // Handle directory picker modal
     if app.show_directory_picker {
         match key.code {
@@ -556,6 +846,24 @@ fn handle_key_event(
         return Ok(None);
     }
 
+    // Handle About modal
+    if app.show_about {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('?') => app.show_about = false,
+            KeyCode::Down | KeyCode::Char('j') => {
+                app.about_scroll = (app.about_scroll + 1).min(KEY_BINDINGS.len().saturating_sub(1));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                app.about_scroll = app.about_scroll.saturating_sub(1);
+            }
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return Ok(Some(true));
+            }
+            _ => {}
+        }
+        return Ok(None);
+    }
+
     // Handle config editing mode
     if app.current_tab == TabState::System && app.config_editing_mode {
         match key.code {
@@ -565,6 +873,11 @@ fn handle_key_event(
                     eprintln!("Failed to apply config edit: {}", e);
                 }
             }
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if let Err(e) = app.save_config_edit() {
+                    eprintln!("Failed to save config edit: {}", e);
+                }
+            }
             KeyCode::Backspace => app.remove_char_from_config(),
             KeyCode::Char(c) => app.add_char_to_config(c),
             _ => {}
@@ -572,116 +885,123 @@ fn handle_key_event(
         return Ok(None);
     }
 
+    if key.code == KeyCode::Char('?') {
+        app.show_about = true;
+        app.about_scroll = 0;
+        return Ok(None);
+    }
+
+    // Handle multi-key sequences before single keys
+    if let KeyCode::Char(c) = key.code
+        && !key.modifiers.contains(KeyModifiers::CONTROL)
+        && let Some(sequence) = app.push_key(c)
+    {
+        if sequence == "gg" && app.current_tab == TabState::System {
+            app.system_first();
+        }
+        return Ok(None);
+    }
+
     // Handle normal mode key events
     match key.code {
         KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
             return Ok(Some(true));
         } // Signal to quit
-        KeyCode::Char('n') => {
+        KeyCode::Char('n')
             if app.current_tab == TabState::System
                 && app.system_section == SystemSection::WatchDirectories
-                && !app.config_editing_mode
-            {
-                // Open directory picker
-                if let Err(e) = app.open_directory_picker() {
-                    eprintln!("Failed to open directory picker: {}", e);
-                }
+                && !app.config_editing_mode =>
+        {
+            // Open directory picker
+            if let Err(e) = app.open_directory_picker() {
+                eprintln!("Failed to open directory picker: {}", e);
             }
         }
-        KeyCode::Char('o') => {
-            if app.current_tab == TabState::System && !app.config_editing_mode {
-                // Open web server in browser (unless editing config)
-                if let Err(e) = app.open_web_server_in_browser() {
-                    eprintln!("Failed to open web server: {}", e);
-                }
+        KeyCode::Char('o') if app.current_tab == TabState::System && !app.config_editing_mode => {
+            // Open web server in browser (unless editing config)
+            if let Err(e) = app.open_web_server_in_browser() {
+                eprintln!("Failed to open web server: {}", e);
             }
         }
-        KeyCode::Down | KeyCode::Char('j') => {
-            if app.current_tab == TabState::System {
-                app.system_next();
-            }
+        KeyCode::Down | KeyCode::Char('j') if app.current_tab == TabState::System => {
+            app.system_next();
         }
-        KeyCode::Up | KeyCode::Char('k') => {
-            if app.current_tab == TabState::System {
-                app.system_previous();
-            }
+        KeyCode::Up | KeyCode::Char('k') if app.current_tab == TabState::System => {
+            app.system_previous();
         }
-        KeyCode::Char('d') => {
+        KeyCode::Char('G') if app.current_tab == TabState::System => {
+            app.system_last();
+        }
+        KeyCode::Char('d')
             if app.current_tab == TabState::System
                 && app.system_section == SystemSection::WatchDirectories
                 && !app.config_editing_mode
+                && !app.config_data.watch_directories.is_empty()
+                && app.watch_directories_selected_index
+                    < app.config_data.watch_directories.len() =>
+        {
+            // Remove selected watch directory
+            app.config_data
+                .watch_directories
+                .remove(app.watch_directories_selected_index);
+            // Adjust selected index if needed
+            if app.watch_directories_selected_index >= app.config_data.watch_directories.len()
+                && app.watch_directories_selected_index > 0
             {
-                // Remove selected watch directory
-                if !app.config_data.watch_directories.is_empty()
-                    && app.watch_directories_selected_index
-                        < app.config_data.watch_directories.len()
-                {
-                    app.config_data
-                        .watch_directories
-                        .remove(app.watch_directories_selected_index);
-                    // Adjust selected index if needed
-                    if app.watch_directories_selected_index
-                        >= app.config_data.watch_directories.len()
-                        && app.watch_directories_selected_index > 0
-                    {
-                        app.watch_directories_selected_index -= 1;
-                    }
-                    // Save config after removing
-                    if let Err(e) = app.save_config() {
-                        eprintln!(
-                            "Failed to save config after removing watch directory: {}",
-                            e
-                        );
-                    }
-                }
+                app.watch_directories_selected_index -= 1;
+            }
+            // Save config after removing
+            if let Err(e) = app.save_config() {
+                eprintln!(
+                    "Failed to save config after removing watch directory: {}",
+                    e
+                );
             }
         }
-        KeyCode::Char('r') => {
+        KeyCode::Char('r')
             if app.current_tab == TabState::System
                 && app.system_section == SystemSection::WatchDirectories
                 && !app.config_editing_mode
-            {
-                // Regenerate selected watch directory
-                if !app.config_data.watch_directories.is_empty()
-                    && app.watch_directories_selected_index
-                        < app.config_data.watch_directories.len()
-                {
-                    let watch_dir =
-                        &app.config_data.watch_directories[app.watch_directories_selected_index];
-                    if let Err(e) = files::regenerate_watch_directory(watch_dir) {
-                        eprintln!("Failed to regenerate watch directory: {}", e);
-                    }
-                }
+                && !app.config_data.watch_directories.is_empty()
+                && app.watch_directories_selected_index
+                    < app.config_data.watch_directories.len() =>
+        {
+            // Regenerate selected watch directory
+            let watch_dir =
+                &app.config_data.watch_directories[app.watch_directories_selected_index];
+            if let Err(e) = files::regenerate_watch_directory(watch_dir) {
+                eprintln!("Failed to regenerate watch directory: {}", e);
             }
         }
-        KeyCode::Enter => {
-            if app.current_tab == TabState::System {
-                // Only handle config editing, services are not selectable
-                if app.system_section == SystemSection::Config {
-                    // For boolean fields, toggle the value instead of entering edit mode
-                    if app.is_selected_field_boolean() {
-                        if let Err(e) = app.toggle_boolean_field() {
-                            eprintln!("Failed to toggle boolean field: {}", e);
-                        }
-                    } else {
-                        app.start_config_editing();
-                    }
+        KeyCode::Enter
+            if app.current_tab == TabState::System
+                && app.system_section == SystemSection::Config =>
+        {
+            // Only handle config editing, services are not selectable
+            // For boolean fields, toggle the value instead of entering edit mode
+            if app.is_selected_field_boolean() {
+                if let Err(e) = app.toggle_boolean_field() {
+                    eprintln!("Failed to toggle boolean field: {}", e);
                 }
+            } else {
+                app.start_config_editing();
             }
         }
-        KeyCode::Char('S') => {
-            if app.current_tab == TabState::System && key.modifiers.contains(KeyModifiers::SHIFT) {
-                // Save config
-                if let Err(e) = app.save_config() {
-                    eprintln!("Failed to save config: {}", e);
-                }
+        KeyCode::Char('S')
+            if app.current_tab == TabState::System
+                && key.modifiers.contains(KeyModifiers::SHIFT) =>
+        {
+            // Save config
+            if let Err(e) = app.save_config() {
+                eprintln!("Failed to save config: {}", e);
             }
         }
-        KeyCode::Char('R') => {
-            if app.current_tab == TabState::System && key.modifiers.contains(KeyModifiers::SHIFT) {
-                // Reload config
-                app.reload_config();
-            }
+        KeyCode::Char('R')
+            if app.current_tab == TabState::System
+                && key.modifiers.contains(KeyModifiers::SHIFT) =>
+        {
+            // Reload config
+            app.reload_config();
         }
         _ => {}
     }
@@ -705,6 +1025,8 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<(),
             app.refresh_system_services();
         }
         app.refresh_queue();
+        app.check_processing_finished();
+        update_terminal_title(app)?;
         if event::poll(Duration::from_millis(200))?
             && let Event::Key(key) = event::read()?
             && let Some(should_quit) = handle_key_event(app, key)?
@@ -717,6 +1039,15 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<(),
     }
 }
 
+fn update_terminal_title(app: &mut App) -> Result<(), Box<dyn Error>> {
+    let title = app.next_terminal_title();
+    if title != app.terminal_title {
+        execute!(io::stdout(), SetTitle(&title))?;
+        app.terminal_title = title;
+    }
+    Ok(())
+}
+
 fn update_cursor_visibility<B: Backend>(
     terminal: &mut Terminal<B>,
     app: &App,
@@ -759,16 +1090,25 @@ fn ui(f: &mut Frame, app: &mut App, conn: &rusqlite::Connection) {
     let controls_text = if app.show_directory_picker {
         "↑↓/jk: Navigate  Enter: Open Directory  n: Select Directory  h/l: Parent/Child  Esc: Cancel".to_string()
     } else if app.config_editing_mode {
-        "Enter: Save & Exit  Esc: Cancel  Type to edit...".to_string()
+        "Enter: Save & Exit  Ctrl+S: Save  Esc: Cancel  Type to edit...".to_string()
     } else {
-        "↑↓/jk: Navigate  Enter: Edit  o: Open Browser App  Ctrl+C: Quit".to_string()
+        "↑↓/jk: Navigate  Enter: Edit  o: Open Browser App  ?: About  Ctrl+C: Quit".to_string()
     };
     let controls_block = Block::default()
         .title("Controls")
         .borders(Borders::ALL)
         .border_style(Style::new().fg(app.colors.footer_border_color));
 
-    let controls_paragraph = Paragraph::new(controls_text.as_str())
+    let mut controls_line = Line::from(controls_text);
+    if let Some((indicator, color)) = app.config_edit_indicator() {
+        controls_line.push_span(Span::raw("  "));
+        controls_line.push_span(Span::styled(
+            indicator,
+            Style::new().fg(color).add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    let controls_paragraph = Paragraph::new(controls_line)
         .block(controls_block)
         .style(Style::new().fg(app.colors.row_fg))
         .alignment(Alignment::Center);
@@ -814,4 +1154,144 @@ fn ui(f: &mut Frame, app: &mut App, conn: &rusqlite::Connection) {
         };
         f.render_widget_ref(explorer.widget(), inner_area);
     }
+
+    if app.show_about {
+        render_about_modal(f, app);
+    }
+
+    render_notification_banner(f, app);
+}
+
+fn render_notification_banner(f: &mut Frame, app: &mut App) {
+    let success_color = app.colors.success;
+    let error_color = app.colors.error;
+    let bg = app.colors.buffer_bg;
+    let Some((_, message, succeeded)) = app.active_notification() else {
+        return;
+    };
+
+    let area = f.area();
+    let text = format!("{}  (Esc to dismiss)", message);
+    let width = (text.chars().count() as u16 + 4).min(area.width);
+    let banner_area = ratatui::layout::Rect {
+        x: area.x + (area.width.saturating_sub(width)) / 2,
+        y: area.y,
+        width,
+        height: 3.min(area.height),
+    };
+    let color = if *succeeded {
+        success_color
+    } else {
+        error_color
+    };
+
+    f.render_widget(Clear, banner_area);
+    f.render_widget(
+        Paragraph::new(text)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::new().fg(color))
+                    .style(Style::new().bg(bg)),
+            )
+            .style(Style::new().fg(color).add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center),
+        banner_area,
+    );
+}
+
+/// Key bindings shown on the About screen, as (section, key, action)
+const KEY_BINDINGS: &[(&str, &str, &str)] = &[
+    ("Global", "?", "Show or hide this screen"),
+    ("Global", "Ctrl+C", "Quit"),
+    ("Global", "Esc", "Dismiss the finished-processing banner"),
+    ("System", "↑↓ / jk", "Navigate"),
+    ("System", "gg / G", "Jump to top / bottom"),
+    ("System", "Enter", "Edit config field or toggle boolean"),
+    ("System", "o", "Open browser app"),
+    ("System", "Shift+S", "Save config"),
+    ("System", "Shift+R", "Reload config"),
+    ("Watch Directories", "n", "Add a directory"),
+    ("Watch Directories", "d", "Delete the selected directory"),
+    (
+        "Watch Directories",
+        "r",
+        "Regenerate the selected directory",
+    ),
+    ("Config Editing", "Enter", "Save and exit"),
+    ("Config Editing", "Ctrl+S", "Save and stay in edit mode"),
+    ("Config Editing", "Esc", "Cancel"),
+    ("Directory Picker", "Enter", "Open directory"),
+    ("Directory Picker", "n", "Select directory"),
+    ("Directory Picker", "h / l", "Parent / child directory"),
+    ("Directory Picker", "Esc", "Cancel"),
+];
+
+fn render_about_modal(f: &mut Frame, app: &App) {
+    let area = f.area();
+    let popup_width = area.width.saturating_sub(10).min(80);
+    let popup_height = area.height.saturating_sub(6).min(28);
+    let popup_area = ratatui::layout::Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    f.render_widget(Clear, popup_area);
+    let block = Block::default()
+        .title("About (↑↓/jk: Scroll, ?/Esc: Close)")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(app.colors.selection))
+        .style(Style::default().bg(app.colors.buffer_bg));
+    let inner_area = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(1)].as_ref())
+        .split(inner_area);
+
+    let latest_version = app
+        .latest_version
+        .lock()
+        .ok()
+        .and_then(|guard| guard.clone())
+        .unwrap_or_else(|| "checking...".to_string());
+    let version_text = format!(
+        "atci v{}\nLatest release: {}",
+        env!("CARGO_PKG_VERSION"),
+        latest_version
+    );
+    f.render_widget(
+        Paragraph::new(version_text)
+            .style(Style::new().fg(app.colors.row_fg))
+            .alignment(Alignment::Center),
+        chunks[0],
+    );
+
+    // Start a new group with a section heading whenever the section changes
+    let mut rows = Vec::new();
+    let mut current_section = "";
+    for (section, key, action) in KEY_BINDINGS.iter().skip(app.about_scroll) {
+        if *section != current_section {
+            current_section = section;
+            rows.push(
+                Row::new(vec![Cell::from(*section), Cell::from("")])
+                    .style(Style::new().fg(app.colors.text_highlight)),
+            );
+        }
+        rows.push(
+            Row::new(vec![Cell::from(format!("  {}", key)), Cell::from(*action)])
+                .style(Style::new().fg(app.colors.row_fg)),
+        );
+    }
+    let table = Table::new(rows, [Constraint::Length(20), Constraint::Min(10)]).header(
+        Row::new(vec!["Key", "Action"]).style(
+            Style::new()
+                .fg(app.colors.header_fg)
+                .bg(app.colors.header_bg),
+        ),
+    );
+    f.render_widget(table, chunks[1]);
 }
@@ -1,6 +1,7 @@
 // atci (andrew's transcript and clipping interface)
 // Copyright (C) 2025 Andrew Nissen
 
+use crate::transcripts::{Cue, TranscriptBlock, parse_blocks, render_transcript, split_metadata};
 use regex::Regex;
 use rusqlite::Connection;
 use std::path::Path;
@@ -169,6 +170,51 @@ pub fn create_missing_part_placeholder(
     Ok(())
 }
 
+/// Merge a part's transcript into the master transcript.
+/// The part's cues are shifted by `offset_ms` (the combined duration of all preceding
+/// parts) and the merged cues are sorted chronologically. The master's metadata is kept
+/// and the part's own metadata block is dropped, unless there is no master yet, in which
+/// case the first part's metadata (source, atci_version, ...) becomes the master's.
+pub fn assemble_parts(
+    master_content: Option<&str>,
+    part_transcript: &str,
+    offset_ms: u64,
+) -> String {
+    let (part_meta, part_body) = split_metadata(part_transcript);
+    let (master_meta, master_body) = match master_content {
+        Some(master_content) => split_metadata(master_content),
+        None => (part_meta, ""),
+    };
+
+    let part_blocks = parse_blocks(part_body)
+        .into_iter()
+        .map(|block| match block {
+            TranscriptBlock::Cue(cue) => TranscriptBlock::Cue(Cue {
+                start_ms: cue.start_ms + offset_ms,
+                end_ms: cue.end_ms + offset_ms,
+                text: cue.text,
+            }),
+            other => other,
+        });
+
+    // Non-cue blocks sort with the cue before them so they keep their position
+    let mut sort_key = 0;
+    let mut keyed_blocks: Vec<(u64, TranscriptBlock)> = parse_blocks(master_body)
+        .into_iter()
+        .chain(part_blocks)
+        .map(|block| {
+            if let TranscriptBlock::Cue(cue) = &block {
+                sort_key = cue.start_ms;
+            }
+            (sort_key, block)
+        })
+        .collect();
+    keyed_blocks.sort_by_key(|(key, _)| *key);
+
+    let blocks: Vec<TranscriptBlock> = keyed_blocks.into_iter().map(|(_, block)| block).collect();
+    render_transcript(&master_meta, &blocks)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -278,4 +324,25 @@ mod tests {
         assert_eq!(master_video_path, "/videos/episode01.mkv");
         assert_eq!(master_transcript_path, "/videos/episode01.txt");
     }
+
+    #[test]
+    fn test_assemble_parts_offsets_and_sorts_cues() {
+        let master = "source: whisper\n>>>.atcimetaend\n\n00:00:01.000 --> 00:00:02.000\nfirst part\n\n00:00:58.000 --> 00:01:00.000\nend of first part";
+        let part = "\n00:00:00.500 --> 00:00:01.500\nsecond part\n";
+
+        let merged = assemble_parts(Some(master), part, 60_000);
+        assert_eq!(
+            merged,
+            "source: whisper\n>>>.atcimetaend\n\n00:00:01.000 --> 00:00:02.000\nfirst part\n\n00:00:58.000 --> 00:01:00.000\nend of first part\n\n00:01:00.500 --> 00:01:01.500\nsecond part\n"
+        );
+    }
+
+    #[test]
+    fn test_assemble_parts_without_master() {
+        let part = "source: whisper\n>>>.atcimetaend\n\n00:00:01.000 --> 00:00:02.000\nhello";
+        assert_eq!(
+            assemble_parts(None, part, 0),
+            "source: whisper\n>>>.atcimetaend\n\n00:00:01.000 --> 00:00:02.000\nhello\n"
+        );
+    }
 }
@@ -49,11 +49,13 @@ fn execute_failure_command(video_path: &Path) {
     }
 }
 
-fn check_cancel_request() -> bool {
+fn check_cancel_request(video_path: &Path) -> bool {
     if let Ok(conn) = crate::db::get_connection() {
-        conn.query_row("SELECT created_at FROM cancel_requests LIMIT 1", [], |_| {
-            Ok(())
-        })
+        conn.query_row(
+            "SELECT created_at FROM cancel_requests WHERE path = ?1 LIMIT 1",
+            [video_path.to_string_lossy().as_ref()],
+            |_| Ok(()),
+        )
         .is_ok()
     } else {
         false
@@ -65,11 +67,13 @@ fn cleanup_cancel_and_processing(
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let mp3_path = video_path.with_extension("mp3");
 
-    // Remove cancel requests and currently processing entry from database
+    // Remove this job's cancel request and currently processing entry from database
     if let Ok(conn) = crate::db::get_connection() {
-        let _ = conn.execute("DELETE FROM cancel_requests", []);
-
         let video_path_str = video_path.to_string_lossy();
+        let _ = conn.execute(
+            "DELETE FROM cancel_requests WHERE path = ?1",
+            [video_path_str.as_ref()],
+        );
         let _ = conn.execute(
             "DELETE FROM currently_processing WHERE path = ?1",
             [video_path_str.as_ref()],
@@ -81,9 +85,37 @@ fn cleanup_cancel_and_processing(
         let _ = fs::remove_file(&mp3_path);
     }
 
+    let _ = fs::remove_file(progress_output_path(video_path));
+
     Ok(())
 }
 
+/// Path whisper's stdout is written to while a transcript is being generated,
+/// used to estimate progress of the currently processing video
+pub fn progress_output_path(video_path: &Path) -> std::path::PathBuf {
+    video_path.with_extension("progress.txt")
+}
+
+/// Sentinel written next to a video whose processing failed, holding the error message,
+/// so the watcher doesn't queue the same broken file again on every scan
+pub fn error_file_path(video_path: &Path) -> std::path::PathBuf {
+    video_path.with_extension("error")
+}
+
+pub fn write_error_file(video_path: &Path, message: &str) -> std::io::Result<()> {
+    std::fs::write(error_file_path(video_path), message)
+}
+
+/// Remove the failure sentinel so the video can be processed again, returning whether one existed
+pub fn clear_error_file(video_path: &Path) -> std::io::Result<bool> {
+    let error_path = error_file_path(video_path);
+    if !error_path.exists() {
+        return Ok(false);
+    }
+    std::fs::remove_file(error_path)?;
+    Ok(true)
+}
+
 pub fn add_key_to_metadata_block(
     video_path: &Path,
     key: &str,
@@ -630,25 +662,18 @@ pub async fn cancellable_create_transcript_for_part(
         }
     }
 
-    // Adjust timestamps in the transcript
-    let adjusted_transcript = adjust_transcript_timestamps(&part_transcript, total_duration_ms)?;
-
-    // Append or create master transcript
-    let final_content = if !Path::new(&master_transcript_path).exists() {
-        // New master file - start with this part's content
-        adjusted_transcript.clone()
-    } else {
-        // Append to existing master transcript
-        let existing_content = std::fs::read_to_string(&master_transcript_path).unwrap_or_default();
-        let trimmed_existing = existing_content.trim_end();
-        let trimmed_new = adjusted_transcript.trim_start();
-        format!("{}\n\n{}", trimmed_existing, trimmed_new)
-    };
+    // Shift this part's cues past the previous parts and merge them into the master transcript
+    let existing_content = std::fs::read_to_string(&master_transcript_path).ok();
+    let final_content = crate::video_parts::assemble_parts(
+        existing_content.as_deref(),
+        &part_transcript,
+        total_duration_ms,
+    );
 
     std::fs::write(&master_transcript_path, final_content)?;
 
     // Record this part as processed
-    let transcript_lines = adjusted_transcript.lines().count() as i32;
+    let transcript_lines = part_transcript.lines().count() as i32;
     crate::video_parts::record_processed_part(&conn, &video_part, transcript_lines)?;
 
     // Clean up part transcript (but keep part video for concatenation)
@@ -721,6 +746,7 @@ pub async fn cancellable_create_transcript_single(
                     None => format!("subtitles: Unknown ({})", stream_index),
                 };
                 add_key_to_metadata_block(video_path, "source", &source_info)?;
+                add_key_to_metadata_block(video_path, "atci_version", env!("CARGO_PKG_VERSION"))?;
                 println!("Created transcript file: {}", txt_path.display());
                 return Ok(true);
             } else {
@@ -752,6 +778,11 @@ pub async fn cancellable_create_transcript_single(
                         streams[0].index
                     );
                     add_key_to_metadata_block(video_path, "source", &source_info)?;
+                    add_key_to_metadata_block(
+                        video_path,
+                        "atci_version",
+                        env!("CARGO_PKG_VERSION"),
+                    )?;
                     println!("Created transcript file: {}", txt_path.display());
                     return Ok(true);
                 } else {
@@ -762,7 +793,7 @@ pub async fn cancellable_create_transcript_single(
     }
 
     // Check if we should cancel before proceeding with audio extraction
-    if check_cancel_request() {
+    if check_cancel_request(video_path) {
         cleanup_cancel_and_processing(video_path)?;
         return Ok(false);
     }
@@ -790,11 +821,126 @@ pub async fn cancellable_create_transcript_single(
         return Ok(true);
     }
 
-    // Extract audio with cancellation check
-    println!("Extracting audio");
     let audio_path = video_path.with_extension("mp3");
+    let home_dir = dirs::home_dir().unwrap_or_else(|| std::path::PathBuf::from("."));
+    let model_name = model.as_ref().unwrap_or(&cfg.model_name);
+    let model_path = home_dir
+        .join(".atci/models")
+        .join(format!("{}.bin", model_name));
 
-    // Start audio extraction in background
+    if !transcribe_audio(video_path, &audio_path, &model_path, &cfg).await? {
+        return Ok(false);
+    }
+    finish_whisper_transcript(video_path, &audio_path, model_name)?;
+
+    Ok(true)
+}
+
+/// Run whisper over the video's first audio stream, leaving its `.mp3.vtt` and `.mp3.json`
+/// output next to `audio_path`. Returns false if the job was cancelled.
+async fn transcribe_audio(
+    video_path: &Path,
+    audio_path: &Path,
+    model_path: &Path,
+    cfg: &crate::AtciConfig,
+) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+    let timeout = Duration::from_secs(cfg.processing_timeout_secs);
+    let progress_path = progress_output_path(video_path);
+    let whisper_log_path = audio_path.with_extension("mp3.log");
+
+    // Stream the audio straight into whisper so transcription starts while ffmpeg is still decoding
+    println!("Transcribing audio");
+    let mut ffmpeg = Command::new(&cfg.ffmpeg_path)
+        .args([
+            "-i",
+            video_path.to_str().unwrap(),
+            "-map",
+            "0:a:0",
+            "-ac",
+            "1",
+            "-ar",
+            "16000",
+            "-c:a",
+            "pcm_s16le",
+            "-f",
+            "wav",
+            "-",
+        ])
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::null())
+        .spawn()?;
+    let ffmpeg_stdout: std::process::Stdio = ffmpeg
+        .stdout
+        .take()
+        .ok_or("Failed to capture ffmpeg output")?
+        .try_into()?;
+
+    let progress_file = fs::File::create(&progress_path)?;
+    let whisper_log = fs::File::create(&whisper_log_path)?;
+    let mut whisper = Command::new(&cfg.whispercli_path)
+        .args([
+            "-m",
+            model_path.to_str().unwrap(),
+            "-np",
+            "--max-context",
+            "0",
+            "-ovtt",
+            // Full JSON includes the per-token probabilities the confidence is based on
+            "-ojf",
+            "-of",
+            audio_path.to_str().unwrap(),
+            "-f",
+            "-",
+        ])
+        .stdin(ffmpeg_stdout)
+        .stdout(std::process::Stdio::from(progress_file))
+        .stderr(std::process::Stdio::from(whisper_log))
+        .spawn()?;
+
+    let streamed = wait_for_whisper(&mut whisper, video_path, timeout).await;
+    let _ = fs::remove_file(&progress_path);
+    let whisper_log = fs::read_to_string(&whisper_log_path).unwrap_or_default();
+    let _ = fs::remove_file(&whisper_log_path);
+
+    match streamed {
+        WhisperOutcome::Completed => {
+            // whisper also exits cleanly at EOF when ffmpeg dies partway through the audio,
+            // so only ffmpeg's status says whether the transcript covers all of it
+            let decoded = ffmpeg.wait().await?;
+            if !decoded.success() {
+                remove_whisper_output(audio_path);
+                return Err(format!("Audio decoding failed (ffmpeg {})", decoded).into());
+            }
+            return Ok(true);
+        }
+        WhisperOutcome::Cancelled => {
+            let _ = ffmpeg.kill().await;
+            cleanup_cancel_and_processing(video_path)?;
+            return Ok(false);
+        }
+        WhisperOutcome::TimedOut => {
+            let _ = ffmpeg.kill().await;
+            return Err(handle_whisper_timeout(video_path, cfg).into());
+        }
+        WhisperOutcome::Failed(e) => {
+            let _ = ffmpeg.kill().await;
+            if !whisper_rejected_stdin(&whisper_log) {
+                let detail = whisper_log
+                    .lines()
+                    .rev()
+                    .find(|line| !line.trim().is_empty());
+                return Err(match detail {
+                    Some(detail) => format!("{}: {}", e, detail.trim()),
+                    None => e,
+                }
+                .into());
+            }
+            // Older whisper builds can't read audio from stdin, so extract to a file instead
+            println!("whisper can't read audio from stdin, extracting audio first");
+        }
+    }
+
+    println!("Extracting audio");
     let mut child = Command::new(&cfg.ffmpeg_path)
         .args([
             "-i",
@@ -828,7 +974,7 @@ pub async fn cancellable_create_transcript_single(
                 }
             }
             _ = sleep(Duration::from_millis(500)) => {
-                if check_cancel_request() {
+                if check_cancel_request(video_path) {
                     let _ = child.kill().await;
                     cleanup_cancel_and_processing(video_path)?;
                     return Ok(false);
@@ -838,19 +984,13 @@ pub async fn cancellable_create_transcript_single(
     }
 
     // Check for cancellation before transcription
-    if check_cancel_request() {
+    if check_cancel_request(video_path) {
         cleanup_cancel_and_processing(video_path)?;
         return Ok(false);
     }
 
-    // Transcribe audio with cancellation check
     println!("Transcribing audio");
-    let home_dir = dirs::home_dir().unwrap_or_else(|| std::path::PathBuf::from("."));
-    let model_name = model.as_ref().unwrap_or(&cfg.model_name);
-    let model_path = home_dir
-        .join(".atci/models")
-        .join(format!("{}.bin", model_name));
-
+    let progress_file = fs::File::create(&progress_path)?;
     let mut child = Command::new(&cfg.whispercli_path)
         .args([
             "-m",
@@ -859,36 +999,37 @@ pub async fn cancellable_create_transcript_single(
             "--max-context",
             "0",
             "-ovtt",
+            // Full JSON includes the per-token probabilities the confidence is based on
+            "-ojf",
             "-f",
             audio_path.to_str().unwrap(),
         ])
-        .stdout(std::process::Stdio::null())
+        .stdout(std::process::Stdio::from(progress_file))
         .spawn()?;
 
-    // Wait for completion while checking for cancellation
-    loop {
-        tokio::select! {
-            result = child.wait() => {
-                match result {
-                    Ok(status) => {
-                        if !status.success() {
-                            return Err("Whisper transcription failed".into());
-                        }
-                        break;
-                    }
-                    Err(e) => return Err(format!("Failed to execute whisper: {}", e).into()),
-                }
-            }
-            _ = sleep(Duration::from_millis(500)) => {
-                if check_cancel_request() {
-                    let _ = child.kill().await;
-                    cleanup_cancel_and_processing(video_path)?;
-                    return Ok(false);
-                }
-            }
+    let outcome = wait_for_whisper(&mut child, video_path, timeout).await;
+    let _ = fs::remove_file(&progress_path);
+    match outcome {
+        WhisperOutcome::Completed => {}
+        WhisperOutcome::Cancelled => {
+            cleanup_cancel_and_processing(video_path)?;
+            return Ok(false);
+        }
+        WhisperOutcome::Failed(e) => return Err(e.into()),
+        WhisperOutcome::TimedOut => {
+            return Err(handle_whisper_timeout(video_path, cfg).into());
         }
     }
 
+    Ok(true)
+}
+
+/// Turn whisper's output into the video's transcript and record where it came from
+fn finish_whisper_transcript(
+    video_path: &Path,
+    audio_path: &Path,
+    model_name: &str,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     // Post-process the whisper output
     let vtt_path = audio_path.with_extension("mp3.vtt");
     if vtt_path.exists() {
@@ -902,13 +1043,132 @@ pub async fn cancellable_create_transcript_single(
 
         let txt_path = audio_path.with_extension("txt");
         fs::rename(&vtt_path, &txt_path)?;
-        let _ = fs::remove_file(&audio_path);
+        let _ = fs::remove_file(audio_path);
 
         add_key_to_metadata_block(video_path, "source", model_name)?;
+        add_key_to_metadata_block(video_path, "atci_version", env!("CARGO_PKG_VERSION"))?;
+
+        let json_path = audio_path.with_extension("mp3.json");
+        if let Ok(json) = fs::read_to_string(&json_path) {
+            if let Some(confidence) = parse_whisper_confidence(&json) {
+                add_key_to_metadata_block(video_path, "confidence", &format!("{:.3}", confidence))?;
+            }
+            let _ = fs::remove_file(&json_path);
+        }
         println!("Successfully created transcript: {}", txt_path.display());
     }
 
-    Ok(true)
+    Ok(())
+}
+
+/// Remove whatever whisper wrote before its transcript was rejected
+fn remove_whisper_output(audio_path: &Path) {
+    let _ = fs::remove_file(audio_path.with_extension("mp3.vtt"));
+    let _ = fs::remove_file(audio_path.with_extension("mp3.json"));
+}
+
+/// Whether whisper failed because it couldn't open `-` as its input, which is how builds
+/// without stdin support react to the streamed audio
+fn whisper_rejected_stdin(stderr: &str) -> bool {
+    stderr
+        .lines()
+        .any(|line| line.contains("error") && line.contains("'-'"))
+}
+
+/// Transcripts whose average token probability falls below this get a
+/// warning in the processing history. whisper.cpp's JSON has no per-segment
+/// `avg_logprob` like openai-whisper's, so the cutoff is on the mean token
+/// probability instead; 0.5 is roughly where openai-whisper's -0.5 logprob lands.
+pub const LOW_CONFIDENCE_THRESHOLD: f64 = 0.5;
+
+/// Average `transcription[].tokens[].p` from whisper's full JSON output (`-ojf`).
+/// Special tokens such as `[_BEG_]` and `[_TT_150]` aren't speech, so they're skipped.
+fn parse_whisper_confidence(json: &str) -> Option<f64> {
+    let value: serde_json::Value = serde_json::from_str(json).ok()?;
+    let probabilities: Vec<f64> = value
+        .get("transcription")?
+        .as_array()?
+        .iter()
+        .filter_map(|segment| segment.get("tokens")?.as_array())
+        .flatten()
+        .filter(|token| {
+            !token
+                .get("text")
+                .and_then(|text| text.as_str())
+                .is_some_and(|text| text.starts_with("[_"))
+        })
+        .filter_map(|token| token.get("p")?.as_f64())
+        .collect();
+
+    if probabilities.is_empty() {
+        return None;
+    }
+    Some(probabilities.iter().sum::<f64>() / probabilities.len() as f64)
+}
+
+enum WhisperOutcome {
+    Completed,
+    Cancelled,
+    Failed(String),
+    TimedOut,
+}
+
+/// Wait for whisper to exit while checking for cancellation, bounded by the processing timeout.
+/// The process is killed if it is cancelled or times out.
+async fn wait_for_whisper(
+    child: &mut tokio::process::Child,
+    video_path: &Path,
+    timeout: Duration,
+) -> WhisperOutcome {
+    let wait_result = tokio::time::timeout(timeout, async {
+        loop {
+            tokio::select! {
+                result = child.wait() => {
+                    return match result {
+                        Ok(status) if status.success() => WhisperOutcome::Completed,
+                        Ok(_) => WhisperOutcome::Failed("Whisper transcription failed".to_string()),
+                        Err(e) => WhisperOutcome::Failed(format!("Failed to execute whisper: {}", e)),
+                    };
+                }
+                _ = sleep(Duration::from_millis(500)) => {
+                    if check_cancel_request(video_path) {
+                        let _ = child.kill().await;
+                        return WhisperOutcome::Cancelled;
+                    }
+                }
+            }
+        }
+    })
+    .await;
+
+    match wait_result {
+        Ok(outcome) => outcome,
+        Err(_) => {
+            // kill() sends SIGKILL on unix
+            let _ = child.kill().await;
+            WhisperOutcome::TimedOut
+        }
+    }
+}
+
+/// Clean up after whisper timed out and blocklist the video so it isn't retried
+fn handle_whisper_timeout(video_path: &Path, cfg: &crate::AtciConfig) -> String {
+    let _ = fs::remove_file(video_path.with_extension("mp3"));
+    eprintln!(
+        "Whisper timed out after {} seconds on {}",
+        cfg.processing_timeout_secs,
+        video_path.display()
+    );
+    if let Err(e) = crate::queue::add_to_blocklist_kind(
+        &video_path.to_string_lossy(),
+        crate::queue::BlocklistKind::TimedOut,
+    ) {
+        eprintln!("Error adding {} to blocklist: {}", video_path.display(), e);
+    }
+    format!(
+        "Whisper timed out after {} seconds",
+        cfg.processing_timeout_secs
+    )
 }
 
 #[get("/api/video/subtitle-streams?<path>")]
@@ -949,7 +1209,7 @@ pub async fn web_get_subtitle_streams(
 pub async fn cancellable_add_length_to_metadata(
     video_path: &Path,
 ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
-    if check_cancel_request() {
+    if check_cancel_request(video_path) {
         cleanup_cancel_and_processing(video_path)?;
         return Ok(false);
     }
@@ -1032,40 +1292,93 @@ fn parse_duration_to_ms(
     Ok(total_ms)
 }
 
-/// Adjust timestamps in transcript by adding an offset
-fn adjust_transcript_timestamps(
-    transcript: &str,
-    offset_ms: u64,
-) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
-    use regex::Regex;
+/// How much audio `benchmark_transcription` feeds to whisper
+const BENCHMARK_SAMPLE_SECS: f64 = 60.0;
 
-    let timestamp_regex =
-        Regex::new(r"(\d{2}:\d{2}:\d{2})\.(\d{3}) --> (\d{2}:\d{2}:\d{2})\.(\d{3})")?;
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct BenchmarkResult {
+    pub model: String,
+    pub audio_secs: f64,
+    pub wall_secs: f64,
+    /// Seconds of audio transcribed per second of wall time (above 1.0 is faster than realtime)
+    pub speed_ratio: f64,
+}
 
-    let adjusted = timestamp_regex.replace_all(transcript, |caps: &regex::Captures| {
-        let start_time = &caps[1];
-        let start_ms = &caps[2];
-        let end_time = &caps[3];
-        let end_ms = &caps[4];
+fn benchmark_speed_ratio(audio_secs: f64, wall_secs: f64) -> f64 {
+    if wall_secs > 0.0 {
+        audio_secs / wall_secs
+    } else {
+        0.0
+    }
+}
 
-        // Parse start timestamp
-        if let Ok(start_total_ms) = parse_timestamp_to_ms(start_time, start_ms)
-            && let Ok(end_total_ms) = parse_timestamp_to_ms(end_time, end_ms)
-        {
-            let adjusted_start = start_total_ms + offset_ms;
-            let adjusted_end = end_total_ms + offset_ms;
+/// Transcribe the first minute of `video_path` with whisper and time it. The sample is
+/// extracted to a temporary mp3 first so only whisper's own speed is measured.
+pub async fn benchmark_transcription(
+    video_path: &Path,
+    model: Option<&str>,
+) -> Result<BenchmarkResult, Box<dyn std::error::Error + Send + Sync>> {
+    let cfg: crate::AtciConfig = crate::config::load_config()?;
+    let model_name = model.unwrap_or(&cfg.model_name).to_string();
+    let model_path = crate::model_manager::models_directory().join(format!("{}.bin", model_name));
+    if !model_path.exists() {
+        return Err(format!("Model not found: {}", model_path.display()).into());
+    }
 
-            let adjusted_start_str = format_ms_to_timestamp(adjusted_start);
-            let adjusted_end_str = format_ms_to_timestamp(adjusted_end);
+    if !has_audio_stream(video_path, Path::new(&cfg.ffprobe_path)).await? {
+        return Err(format!("{} has no audio stream", video_path.display()).into());
+    }
+    let duration = get_video_duration(video_path, Path::new(&cfg.ffprobe_path)).await?;
+    let audio_secs = parse_time_to_seconds(&duration)?.min(BENCHMARK_SAMPLE_SECS);
 
-            return format!("{} --> {}", adjusted_start_str, adjusted_end_str);
-        }
+    let sample_path = env::temp_dir().join(format!("benchmark_{}.mp3", uuid::Uuid::new_v4()));
+    let extract_output = Command::new(&cfg.ffmpeg_path)
+        .args([
+            "-i",
+            video_path.to_str().unwrap(),
+            "-t",
+            &audio_secs.to_string(),
+            "-vn",
+            "-acodec",
+            "libmp3lame",
+            "-ar",
+            "16000",
+            "-ac",
+            "1",
+            "-y",
+            sample_path.to_str().unwrap(),
+        ])
+        .output()
+        .await?;
+    if !extract_output.status.success() {
+        let _ = fs::remove_file(&sample_path);
+        return Err("Failed to extract benchmark audio sample".into());
+    }
 
-        // Fallback: return original if parsing fails
-        caps.get(0).unwrap().as_str().to_string()
-    });
+    let start = std::time::Instant::now();
+    let transcribe_output = Command::new(&cfg.whispercli_path)
+        .args([
+            "-m",
+            model_path.to_str().unwrap(),
+            "-np",
+            "-f",
+            sample_path.to_str().unwrap(),
+        ])
+        .output()
+        .await;
+    let wall_secs = start.elapsed().as_secs_f64();
+    let _ = fs::remove_file(&sample_path);
 
-    Ok(adjusted.to_string())
+    if !transcribe_output?.status.success() {
+        return Err("Whisper transcription failed".into());
+    }
+
+    Ok(BenchmarkResult {
+        model: model_name,
+        audio_secs,
+        wall_secs,
+        speed_ratio: benchmark_speed_ratio(audio_secs, wall_secs),
+    })
 }
 
 /// Extract word-level timestamps for a specific video clip
@@ -1168,8 +1481,9 @@ pub async fn extract_word_timestamps(
     Ok(result)
 }
 
-/// Parse time string (HH:MM:SS.mmm or MM:SS.mmm) to seconds
+/// Parse time string (HH:MM:SS.mmm or MM:SS.mmm, or SRT's HH:MM:SS,mmm) to seconds
 fn parse_time_to_seconds(time_str: &str) -> Result<f64, Box<dyn std::error::Error + Send + Sync>> {
+    let time_str = time_str.replace(',', ".");
     let parts: Vec<&str> = time_str.split(':').collect();
 
     match parts.len() {
@@ -1264,38 +1578,6 @@ fn find_word_in_vtt(
     Ok(None)
 }
 
-/// Parse timestamp (HH:MM:SS.mmm) to milliseconds
-fn parse_timestamp_to_ms(
-    time_str: &str,
-    ms_str: &str,
-) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
-    let time_parts: Vec<&str> = time_str.split(':').collect();
-    if time_parts.len() != 3 {
-        return Err("Invalid time format".into());
-    }
-
-    let hours: u64 = time_parts[0].parse()?;
-    let minutes: u64 = time_parts[1].parse()?;
-    let seconds: u64 = time_parts[2].parse()?;
-    let milliseconds: u64 = ms_str.parse()?;
-
-    let total_ms = (hours * 3600 + minutes * 60 + seconds) * 1000 + milliseconds;
-    Ok(total_ms)
-}
-
-/// Format milliseconds back to timestamp string (HH:MM:SS.mmm)
-fn format_ms_to_timestamp(total_ms: u64) -> String {
-    let hours = total_ms / (3600 * 1000);
-    let minutes = (total_ms % (3600 * 1000)) / (60 * 1000);
-    let seconds = (total_ms % (60 * 1000)) / 1000;
-    let milliseconds = total_ms % 1000;
-
-    format!(
-        "{:02}:{:02}:{:02}.{:03}",
-        hours, minutes, seconds, milliseconds
-    )
-}
-
 /// Update or create the master video file by appending the current part
 async fn update_master_video(
     video_part: &crate::video_parts::VideoPart,
@@ -1390,3 +1672,171 @@ async fn update_master_video(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_benchmark_speed_ratio() {
+        assert_eq!(benchmark_speed_ratio(60.0, 20.0), 3.0);
+        assert_eq!(benchmark_speed_ratio(60.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_parse_whisper_confidence() {
+        let json = r#"{"transcription": [
+            {"text": " Hello", "tokens": [
+                {"text": "[_BEG_]", "p": 0.1},
+                {"text": " Hello", "p": 0.9}
+            ]},
+            {"text": " world", "tokens": [{"text": " world", "p": 0.5}, {"text": "!"}]},
+            {"text": ""}
+        ]}"#;
+        let confidence = parse_whisper_confidence(json).unwrap();
+        assert!((confidence - 0.7).abs() < 1e-9);
+
+        assert_eq!(parse_whisper_confidence(r#"{"transcription": []}"#), None);
+        assert_eq!(parse_whisper_confidence("not json"), None);
+    }
+
+    /// Drive the whisper pipeline with shell-script stand-ins for ffmpeg and whisper-cli
+    #[cfg(unix)]
+    mod stubbed_tools {
+        use super::*;
+
+        /// Write an executable shell script into `dir`
+        fn stub_binary(dir: &Path, name: &str, script: &str) -> String {
+            use std::os::unix::fs::PermissionsExt;
+            let path = dir.join(name);
+            fs::write(&path, format!("#!/bin/sh\n{}", script)).unwrap();
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+            path.to_string_lossy().to_string()
+        }
+
+        /// ffmpeg that logs its arguments and produces a few bytes of "audio", exiting with `status`
+        fn stub_ffmpeg(dir: &Path, status: i32) -> String {
+            let calls = dir.join("ffmpeg.calls");
+            stub_binary(
+                dir,
+                "ffmpeg",
+                &format!(
+                    "echo \"$@\" >> '{}'\nfor last; do :; done\nif [ \"$last\" = \"-\" ]; then printf RIFF; else touch \"$last\"; fi\nexit {}\n",
+                    calls.display(),
+                    status
+                ),
+            )
+        }
+
+        /// whisper that writes a transcript, plus full JSON when given `-ojf`, for `-of` (or its input file).
+        /// With `reject_stdin` it fails the way builds without stdin support do.
+        fn stub_whisper(dir: &Path, reject_stdin: bool) -> String {
+            let reject = if reject_stdin {
+                "if [ \"$input\" = \"-\" ]; then echo \"error: failed to read audio file '-'\" >&2; exit 2; fi\n"
+            } else {
+                ""
+            };
+            stub_binary(
+                dir,
+                "whisper-cli",
+                &format!(
+                    r#"while [ $# -gt 0 ]; do case "$1" in -of) of="$2"; shift;; -f) input="$2"; shift;; -ojf) json=1;; esac; shift; done
+    {reject}if [ "$input" = "-" ]; then cat > /dev/null; fi
+    out="${{of:-$input}}"
+    printf 'WEBVTT\n\n00:00:00.000 --> 00:00:01.000\nhello\n' > "$out.vtt"
+    if [ -n "$json" ]; then echo '{{"transcription": [{{"tokens": [{{"text": " hello", "p": 0.25}}]}}]}}' > "$out.json"; fi
+    "#
+                ),
+            )
+        }
+
+        fn stub_config(ffmpeg: String, whisper: String) -> crate::AtciConfig {
+            crate::AtciConfig {
+                ffmpeg_path: ffmpeg,
+                whispercli_path: whisper,
+                processing_timeout_secs: 30,
+                ..Default::default()
+            }
+        }
+
+        #[tokio::test]
+        async fn test_streamed_transcription_records_confidence() {
+            let dir = tempfile::tempdir().unwrap();
+            let cfg = stub_config(stub_ffmpeg(dir.path(), 0), stub_whisper(dir.path(), false));
+            let video_path = dir.path().join("clip.mp4");
+            fs::write(&video_path, "").unwrap();
+            let audio_path = video_path.with_extension("mp3");
+
+            let model_path = dir.path().join("model.bin");
+            assert!(
+                transcribe_audio(&video_path, &audio_path, &model_path, &cfg)
+                    .await
+                    .unwrap()
+            );
+            finish_whisper_transcript(&video_path, &audio_path, "base").unwrap();
+
+            let transcript = fs::read_to_string(video_path.with_extension("txt")).unwrap();
+            assert!(transcript.contains("confidence: 0.250"));
+            assert!(transcript.contains("hello"));
+            // Streamed, so ffmpeg only ran once and never wrote an audio file
+            let calls = fs::read_to_string(dir.path().join("ffmpeg.calls")).unwrap();
+            assert_eq!(calls.lines().count(), 1);
+        }
+
+        #[tokio::test]
+        async fn test_streamed_transcription_fails_when_ffmpeg_fails() {
+            let dir = tempfile::tempdir().unwrap();
+            let cfg = stub_config(stub_ffmpeg(dir.path(), 1), stub_whisper(dir.path(), false));
+            let video_path = dir.path().join("clip.mp4");
+            let audio_path = video_path.with_extension("mp3");
+
+            let err = transcribe_audio(&video_path, &audio_path, &dir.path().join("m.bin"), &cfg)
+                .await
+                .unwrap_err();
+            assert!(err.to_string().starts_with("Audio decoding failed"));
+            assert!(!audio_path.with_extension("mp3.vtt").exists());
+        }
+
+        #[tokio::test]
+        async fn test_falls_back_to_audio_file_only_when_whisper_rejects_stdin() {
+            let dir = tempfile::tempdir().unwrap();
+            let cfg = stub_config(stub_ffmpeg(dir.path(), 0), stub_whisper(dir.path(), true));
+            let video_path = dir.path().join("clip.mp4");
+            let audio_path = video_path.with_extension("mp3");
+
+            assert!(
+                transcribe_audio(&video_path, &audio_path, &dir.path().join("m.bin"), &cfg)
+                    .await
+                    .unwrap()
+            );
+            assert!(audio_path.with_extension("mp3.vtt").exists());
+            // The streaming ffmpeg may be killed before it logs anything, so look for the extraction
+            let calls = fs::read_to_string(dir.path().join("ffmpeg.calls")).unwrap();
+            let extraction = format!("-y {}", audio_path.display());
+            assert!(calls.lines().any(|call| call.ends_with(&extraction)));
+        }
+
+        #[tokio::test]
+        async fn test_other_whisper_failures_do_not_fall_back() {
+            let dir = tempfile::tempdir().unwrap();
+            let whisper = stub_binary(
+                dir.path(),
+                "whisper-cli",
+                "cat > /dev/null\necho 'error: failed to initialize whisper context' >&2\nexit 3\n",
+            );
+            let cfg = stub_config(stub_ffmpeg(dir.path(), 0), whisper);
+            let video_path = dir.path().join("clip.mp4");
+            let audio_path = video_path.with_extension("mp3");
+
+            let err = transcribe_audio(&video_path, &audio_path, &dir.path().join("m.bin"), &cfg)
+                .await
+                .unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Whisper transcription failed: error: failed to initialize whisper context"
+            );
+            let calls = fs::read_to_string(dir.path().join("ffmpeg.calls")).unwrap();
+            assert_eq!(calls.lines().count(), 1);
+        }
+    }
+}
@@ -2,11 +2,11 @@
 // Copyright (C) 2025 Andrew Nissen
 
 use crate::{
-    Asset, auth::AuthGuard, clipper, config, files, model_manager, queue, search, tools_manager,
-    transcripts,
+    Asset, access_log::RequestLogger, auth::AuthGuard, clipper, config, files, model_manager,
+    queue, search, tools_manager, transcripts,
 };
 use rocket::form::{Form, FromForm};
-use rocket::http::{Cookie, CookieJar, SameSite, Status};
+use rocket::http::{Cookie, CookieJar, SameSite};
 use rocket::response::Redirect;
 use rocket::response::status::NotFound;
 use rocket::serde::Serialize;
@@ -15,6 +15,8 @@ use rocket::{Request, catch, catchers, get, post, response::content, routes};
 use rocket_dyn_templates::{Template, context};
 use rust_embed::RustEmbed;
 use self_update::cargo_crate_version;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
 
 #[derive(RustEmbed)]
 #[folder = "templates/"]
@@ -213,13 +215,22 @@ fn auth_submit(form: Form<AuthForm>, cookies: &CookieJar<'_>) -> Result<Redirect
         }
     };
 
-    if form.password == expected_password {
-        // Set authentication cookie
-        let cookie = Cookie::build(("auth_token", form.password.clone()))
-            .same_site(SameSite::Lax)
-            .http_only(true)
-            .path("/")
-            .build();
+    if crate::auth::constant_time_eq(form.password.as_bytes(), expected_password.as_bytes()) {
+        // Set authentication cookie, expiring along with the session
+        let issued_at = chrono::Utc::now().timestamp();
+        let mut cookie = Cookie::build((
+            "auth_token",
+            crate::auth::session_token(expected_password, issued_at),
+        ))
+        .same_site(SameSite::Lax)
+        .http_only(true)
+        .path("/")
+        .build();
+        if config.session_timeout_mins > 0 {
+            cookie.set_max_age(rocket::time::Duration::minutes(
+                config.session_timeout_mins as i64,
+            ));
+        }
         cookies.add(cookie);
 
         // Redirect to intended destination
@@ -250,18 +261,28 @@ fn api_routes() -> Vec<rocket::Route> {
         perform_update,
         config::web_get_config,
         config::web_set_config,
+        config::web_patch_config,
         files::web_get_files,
         files::web_get_sources,
+        files::web_list_files,
+        files::web_rescan_files,
         clipper::web_clip,
         clipper::web_clip_view,
         clipper::web_frame,
+        clipper::web_file_preview,
+        clipper::web_audio_segment,
         queue::web_get_queue,
         queue::web_get_queue_status,
+        queue::web_stream_queue,
         queue::web_block_path,
+        queue::web_add_to_queue,
         queue::web_set_queue,
         queue::web_cancel_queue,
         search::web_search_transcripts,
         transcripts::web_get_transcript_by_path,
+        transcripts::web_get_transcript_line,
+        transcripts::web_edit_transcript,
+        transcripts::web_similar_transcripts,
         transcripts::web_replace_transcript,
         transcripts::web_regenerate_transcript,
         transcripts::web_rename_transcript,
@@ -274,8 +295,15 @@ fn api_routes() -> Vec<rocket::Route> {
     ]
 }
 
+#[derive(rocket::Responder)]
+#[response(status = 401)]
+struct UnauthorizedResponse {
+    inner: Json<ApiResponse<()>>,
+    www_authenticate: rocket::http::Header<'static>,
+}
+
 #[catch(401)]
-fn unauthorized(req: &Request) -> Result<Redirect, Status> {
+fn unauthorized(req: &Request) -> Result<Redirect, UnauthorizedResponse> {
     // Check if this is a browser request (HTML accept header) vs API request
     let accept_header = req.headers().get_one("Accept").unwrap_or("");
     let is_browser_request = accept_header.contains("text/html");
@@ -287,12 +315,29 @@ fn unauthorized(req: &Request) -> Result<Redirect, Status> {
         );
         Ok(Redirect::to(redirect_url))
     } else {
-        // For API requests, return 401 JSON
-        Err(Status::Unauthorized)
+        // For API requests, return 401 JSON telling the client to log in again
+        let expired = req.local_cache(|| crate::auth::SessionExpired(false)).0;
+        let (challenge, message) = if expired {
+            (
+                r#"Session realm="atci", error="session_expired""#,
+                "Session expired, please log in again",
+            )
+        } else {
+            (r#"Session realm="atci""#, "Authentication required")
+        };
+        Err(UnauthorizedResponse {
+            inner: Json(ApiResponse::error(message.to_string())),
+            www_authenticate: rocket::http::Header::new("WWW-Authenticate", challenge),
+        })
     }
 }
 
 pub async fn launch_server(host: &str, port: u16) -> Result<(), rocket::Error> {
+    web_server(host, port).launch().await?;
+    Ok(())
+}
+
+fn web_server(host: &str, port: u16) -> rocket::Rocket<rocket::Build> {
     let temp_dir = std::env::temp_dir().join("atci_templates");
     std::fs::create_dir_all(&temp_dir).expect("Failed to create temp templates directory");
 
@@ -326,12 +371,96 @@ pub async fn launch_server(host: &str, port: u16) -> Result<(), rocket::Error> {
         .mount("/", all_routes)
         .register("/", catchers![unauthorized])
         .attach(Template::fairing())
+        .attach(RequestLogger)
+}
+
+/// Local ports of the loopback connections the Unix socket proxy has open, so the
+/// access log can tell proxied requests apart from ones made to the port directly
+#[derive(Clone, Default)]
+pub struct UnixSocketPeers(Arc<Mutex<HashSet<u16>>>);
+
+impl UnixSocketPeers {
+    pub fn contains(&self, port: u16) -> bool {
+        self.0.lock().unwrap().contains(&port)
+    }
+}
+
+/// Serve the web UI and API on a Unix domain socket.
+///
+/// Rocket 0.5 can only bind TCP, so the server listens on a loopback port picked by the OS
+/// and every connection accepted on the socket is forwarded to it. Forwarding starts once
+/// Rocket reports the port it bound. Forwarded requests come from 127.0.0.1 as far as
+/// Rocket is concerned, so the access log records them as `unix` instead. The loopback
+/// port stays reachable without going through the socket, so the socket's permissions
+/// aren't an access control by themselves.
+#[cfg(unix)]
+pub async fn launch_server_on_unix_socket(
+    socket_path: &std::path::Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // A socket left behind by an unclean shutdown would make bind fail
+    if socket_path.exists() {
+        std::fs::remove_file(socket_path)?;
+    }
+    let listener = tokio::net::UnixListener::bind(socket_path)?;
+
+    let peers = UnixSocketPeers::default();
+    let (port_tx, port_rx) = tokio::sync::oneshot::channel::<u16>();
+
+    let proxy_peers = peers.clone();
+    tokio::spawn(async move {
+        let Ok(port) = port_rx.await else {
+            return;
+        };
+        loop {
+            let mut unix_stream = match listener.accept().await {
+                Ok((stream, _)) => stream,
+                Err(e) => {
+                    eprintln!("Error accepting unix socket connection: {}", e);
+                    continue;
+                }
+            };
+            let peers = proxy_peers.clone();
+            tokio::spawn(async move {
+                match tokio::net::TcpStream::connect(("127.0.0.1", port)).await {
+                    Ok(mut tcp_stream) => {
+                        let local_port = tcp_stream.local_addr().map(|addr| addr.port()).ok();
+                        if let Some(local_port) = local_port {
+                            peers.0.lock().unwrap().insert(local_port);
+                        }
+                        let _ =
+                            tokio::io::copy_bidirectional(&mut unix_stream, &mut tcp_stream).await;
+                        if let Some(local_port) = local_port {
+                            peers.0.lock().unwrap().remove(&local_port);
+                        }
+                    }
+                    Err(e) => eprintln!("Error forwarding unix socket connection: {}", e),
+                }
+            });
+        }
+    });
+
+    web_server("127.0.0.1", 0)
+        .manage(peers)
+        .attach(rocket::fairing::AdHoc::on_liftoff(
+            "Unix socket proxy",
+            |rocket| {
+                Box::pin(async move {
+                    let _ = port_tx.send(rocket.config().port);
+                })
+            },
+        ))
         .launch()
         .await?;
-
     Ok(())
 }
 
+#[cfg(not(unix))]
+pub async fn launch_server_on_unix_socket(
+    _socket_path: &std::path::Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    Err("Unix sockets are not supported on this platform".into())
+}
+
 pub async fn launch_api_server(host: &str, port: u16) -> Result<(), rocket::Error> {
     let temp_dir = std::env::temp_dir().join("atci_templates");
     std::fs::create_dir_all(&temp_dir).expect("Failed to create temp templates directory");
@@ -363,6 +492,7 @@ pub async fn launch_api_server(host: &str, port: u16) -> Result<(), rocket::Erro
         .mount("/", api_routes())
         .register("/", catchers![unauthorized])
         .attach(Template::fairing())
+        .attach(RequestLogger)
         .launch()
         .await?;
 
@@ -329,3 +329,256 @@ fn test_config_field_validation_comprehensive() {
         cleanup_test_config(&config_path2);
     }
 }
+
+#[test]
+fn test_config_env_var_overrides() {
+    let (mut cmd, config_path) = setup_test_config();
+    cmd.env("ATCI_FFMPEG_PATH", "/env/ffmpeg")
+        .env("ATCI_ALLOW_WHISPER", "false")
+        .env("ATCI_WATCH_DIRECTORIES", "/videos/a:/videos/b")
+        .args(["config", "show"]);
+
+    let output = cmd.assert().success();
+    let stdout = std::str::from_utf8(&output.get_output().stdout).unwrap();
+    let json: Value = serde_json::from_str(stdout).expect("Should be valid JSON");
+
+    assert_eq!(json["ffmpeg_path"], "/env/ffmpeg");
+    assert_eq!(json["allow_whisper"], false);
+    assert_eq!(
+        json["watch_directories"],
+        serde_json::json!(["/videos/a", "/videos/b"])
+    );
+
+    cleanup_test_config(&config_path);
+}
+
+#[test]
+fn test_config_env_var_overrides_are_typed_and_not_stored() {
+    let (mut cmd, config_path) = setup_test_config();
+    cmd.env("ATCI_PASSWORD", "from-env")
+        .env("ATCI_MAX_FILE_SIZE_GB", "2.5")
+        .env("ATCI_STREAM_CHUNK_SIZE", "30")
+        .args(["config", "show"]);
+
+    let output = cmd.assert().success();
+    let stdout = std::str::from_utf8(&output.get_output().stdout).unwrap();
+    let json: Value = serde_json::from_str(stdout).expect("Should be valid JSON");
+
+    assert_eq!(json["password"], "from-env");
+    assert_eq!(json["max_file_size_gb"], 2.5);
+    assert_eq!(json["stream_chunk_size"], 30);
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path)
+        .env("ATCI_PASSWORD", "from-env")
+        .env("ATCI_MAX_FILE_SIZE_GB", "2.5")
+        .args(["config", "set", "model_name", "test-model"]);
+    cmd.assert().success();
+
+    let contents = fs::read_to_string(&config_path).unwrap();
+    assert!(contents.contains("model_name = \"test-model\""));
+    assert!(!contents.contains("from-env"));
+    assert!(!contents.contains("max_file_size_gb"));
+
+    cleanup_test_config(&config_path);
+}
+
+#[test]
+fn test_config_reset_with_yes() {
+    let (mut cmd, config_path) = setup_test_config();
+    fs::write(
+        &config_path,
+        "ffmpeg_path = \"/custom/ffmpeg\"\nffprobe_path = \"\"\nmodel_name = \"\"\nstream_chunk_size = 30\npassword = \"hunter2\"\n",
+    )
+    .unwrap();
+
+    cmd.args(["config", "reset", "--yes"]);
+    let output = cmd.assert().success();
+    let stdout = std::str::from_utf8(&output.get_output().stdout).unwrap();
+    assert!(stdout.contains(&config_path.display().to_string()));
+    assert!(stdout.contains("cleared ffmpeg_path (was \"/custom/ffmpeg\")"));
+    assert!(stdout.contains("cleared stream_chunk_size (was 30)"));
+    assert!(stdout.contains("cleared password (was set)"));
+    assert!(!stdout.contains("hunter2"));
+
+    let mut show_cmd = Command::cargo_bin("atci").unwrap();
+    show_cmd
+        .env("ATCI_CONFIG_PATH", &config_path)
+        .args(["config", "show"]);
+    let output = show_cmd.assert().success();
+    let json: Value =
+        serde_json::from_str(std::str::from_utf8(&output.get_output().stdout).unwrap())
+            .expect("Should be valid JSON");
+    assert_eq!(json["ffmpeg_path"], "");
+    assert_eq!(json["stream_chunk_size"], 60);
+
+    cleanup_test_config(&config_path);
+}
+
+#[test]
+fn test_config_set_warns_about_invalid_values() {
+    let (mut cmd, config_path) = setup_test_config();
+    cmd.args(["config", "set", "ffmpeg_path", "/nonexistent/atci/ffmpeg"]);
+
+    cmd.assert()
+        .success()
+        .stdout("Set ffmpeg_path = /nonexistent/atci/ffmpeg\n")
+        .stderr(str::contains(
+            "Warning: invalid config value ffmpeg_path = \"/nonexistent/atci/ffmpeg\": file does not exist",
+        ));
+
+    cleanup_test_config(&config_path);
+}
+
+#[test]
+fn test_auth_generate_token_requires_password() {
+    let (mut cmd, config_path) = setup_test_config();
+    cmd.args(["auth", "generate-token"]);
+    cmd.assert()
+        .failure()
+        .stderr(str::contains("no password is set"));
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    cmd.args(["config", "set", "password", "secret123"]);
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    let output = cmd.args(["auth", "generate-token"]).output().unwrap();
+    assert!(output.status.success());
+    let token = String::from_utf8(output.stdout).unwrap();
+    let token = token.trim();
+    assert_eq!(token.len(), 64);
+    assert!(token.chars().all(|c| c.is_ascii_hexdigit()));
+
+    cleanup_test_config(&config_path);
+}
+
+#[test]
+fn test_config_set_max_concurrent_transcriptions() {
+    let (mut cmd, config_path) = setup_test_config();
+    cmd.args(["config", "set", "max_concurrent_transcriptions", "3"]);
+    cmd.assert()
+        .success()
+        .stdout("Set max_concurrent_transcriptions = 3\n");
+
+    let contents = fs::read_to_string(&config_path).unwrap();
+    assert!(contents.contains("max_concurrent_transcriptions = 3"));
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    cmd.args(["config", "set", "max_concurrent_transcriptions", "0"]);
+    cmd.assert().success().stderr(str::contains(
+        "Warning: invalid config value max_concurrent_transcriptions = \"0\": must be greater than 0",
+    ));
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    cmd.args(["config", "unset", "max_concurrent_transcriptions"]);
+    cmd.assert().success();
+
+    let contents = fs::read_to_string(&config_path).unwrap();
+    assert!(contents.contains("max_concurrent_transcriptions = 1"));
+
+    cleanup_test_config(&config_path);
+}
+
+#[test]
+fn test_config_set_empty_value_clears_optional_limit() {
+    let (mut cmd, config_path) = setup_test_config();
+    cmd.args(["config", "set", "max_file_size_gb", "2.5"]);
+    cmd.assert().success();
+
+    let contents = fs::read_to_string(&config_path).unwrap();
+    assert!(contents.contains("max_file_size_gb = 2.5"));
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    cmd.args(["config", "set", "max_file_size_gb", ""]);
+    cmd.assert().success();
+
+    let contents = fs::read_to_string(&config_path).unwrap();
+    assert!(!contents.contains("max_file_size_gb"));
+
+    cleanup_test_config(&config_path);
+}
+
+#[test]
+fn test_config_set_rejects_zero_watch_poll_interval() {
+    let (mut cmd, config_path) = setup_test_config();
+    cmd.args(["config", "set", "watch_poll_interval_secs", "0"]);
+    cmd.assert().failure().stderr(str::contains(
+        "watch_poll_interval_secs must be greater than 0",
+    ));
+
+    cleanup_test_config(&config_path);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_config_edit_reloads_and_validates() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let (mut cmd, config_path) = setup_test_config();
+    let editor_path = config_path.with_extension("editor.sh");
+    fs::write(
+        &editor_path,
+        "#!/bin/sh\nsed -i.bak 's/^stream_chunk_size = .*/stream_chunk_size = 0/' \"$1\"\n",
+    )
+    .unwrap();
+    fs::set_permissions(&editor_path, fs::Permissions::from_mode(0o755)).unwrap();
+
+    cmd.env("EDITOR", &editor_path).args(["config", "edit"]);
+    cmd.assert().success().stderr(str::contains(
+        "Warning: invalid config value stream_chunk_size = \"0\": must be greater than 0",
+    ));
+
+    let contents = fs::read_to_string(&config_path).unwrap();
+    assert!(contents.contains("stream_chunk_size = 0"));
+
+    fs::remove_file(&editor_path).ok();
+    fs::remove_file(config_path.with_extension("toml.bak")).ok();
+    cleanup_test_config(&config_path);
+}
+
+#[cfg(unix)]
+fn write_fake_tool(dir: &std::path::Path, name: &str, version: &str) -> String {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = dir.join(name);
+    fs::write(
+        &path,
+        format!(
+            "#!/bin/sh\necho \"{} version {} Copyright (c) the FFmpeg developers\"\n",
+            name, version
+        ),
+    )
+    .unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    path.to_string_lossy().to_string()
+}
+
+#[cfg(unix)]
+#[test]
+fn test_config_set_warns_when_ffmpeg_and_ffprobe_versions_differ() {
+    let tools_dir = tempfile::TempDir::new().unwrap();
+    let ffmpeg = write_fake_tool(tools_dir.path(), "ffmpeg", "6.1.1");
+    let ffprobe = write_fake_tool(tools_dir.path(), "ffprobe", "7.0");
+
+    let (mut cmd, config_path) = setup_test_config();
+    cmd.args(["config", "set", "ffmpeg_path", &ffmpeg])
+        .assert()
+        .success();
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    cmd.args(["config", "set", "ffprobe_path", &ffprobe])
+        .assert()
+        .success()
+        .stderr(str::contains(
+            "is ffprobe version 7.0 but ffmpeg_path is version 6.1.1",
+        ));
+
+    cleanup_test_config(&config_path);
+}
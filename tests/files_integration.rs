@@ -0,0 +1,173 @@
+use assert_cmd::Command;
+use predicates::str;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tempfile::TempDir;
+
+static TEST_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+fn setup_test_config_with_watch_dir(watch_dir: &str) -> (Command, PathBuf) {
+    let temp_dir = env::temp_dir();
+    let counter = TEST_COUNTER.fetch_add(1, Ordering::SeqCst);
+    let test_config_path = temp_dir.join(format!(
+        "atci_files_test_config_{}_{}.toml",
+        std::process::id(),
+        counter
+    ));
+
+    let config_content = format!(
+        r#"
+ffmpeg_path = "ffmpeg"
+ffprobe_path = "ffprobe"
+whispercli_path = "whisper"
+model_name = "ggml-base"
+watch_directories = ["{}"]
+"#,
+        if cfg!(windows) {
+            watch_dir.replace("\\", "\\\\")
+        } else {
+            watch_dir.to_string()
+        }
+    );
+
+    fs::write(&test_config_path, config_content).unwrap();
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &test_config_path);
+
+    (cmd, test_config_path)
+}
+
+fn cleanup_test_config(config_path: &PathBuf) {
+    if config_path.exists() {
+        fs::remove_file(config_path).ok();
+    }
+}
+
+fn create_orphan_fixture(dir: &TempDir) {
+    fs::write(dir.path().join("kept.mp4"), b"fake video content").unwrap();
+    fs::write(dir.path().join("kept.txt"), "kept transcript").unwrap();
+    fs::write(
+        dir.path().join("gone.txt"),
+        "00:00:01.000 --> 00:00:02.000\norphaned transcript",
+    )
+    .unwrap();
+    fs::write(dir.path().join("gone.meta"), "source: ggml-base").unwrap();
+    // Neither of these is an atci transcript, so they're never reported as orphans
+    fs::write(dir.path().join("notes.txt"), "shopping list").unwrap();
+    fs::write(
+        dir.path().join("kept.progress.txt"),
+        "00:00:01.000 --> 00:00:02.000\npartial",
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_files_orphans_lists_transcripts_without_video() {
+    let temp_dir = TempDir::new().unwrap();
+    create_orphan_fixture(&temp_dir);
+
+    let (mut cmd, config_path) =
+        setup_test_config_with_watch_dir(&temp_dir.path().to_string_lossy());
+    let output = cmd.args(["files", "orphans", "--json"]).output().unwrap();
+    cleanup_test_config(&config_path);
+
+    assert!(output.status.success());
+    let orphans: Vec<String> = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(orphans.len(), 1);
+    assert!(orphans[0].ends_with("gone.txt"));
+
+    // Listing alone must not touch anything on disk
+    assert!(temp_dir.path().join("gone.txt").exists());
+    assert!(temp_dir.path().join("gone.meta").exists());
+}
+
+#[test]
+fn test_files_orphans_delete_removes_txt_and_meta() {
+    let temp_dir = TempDir::new().unwrap();
+    create_orphan_fixture(&temp_dir);
+
+    let (mut cmd, config_path) =
+        setup_test_config_with_watch_dir(&temp_dir.path().to_string_lossy());
+    cmd.args(["files", "orphans", "--delete"])
+        .assert()
+        .success()
+        .stdout(str::contains("gone.txt"))
+        .stderr(str::contains("Deleted"));
+    cleanup_test_config(&config_path);
+
+    assert!(!temp_dir.path().join("gone.txt").exists());
+    assert!(!temp_dir.path().join("gone.meta").exists());
+    assert!(temp_dir.path().join("kept.txt").exists());
+    assert!(temp_dir.path().join("kept.mp4").exists());
+    assert!(temp_dir.path().join("notes.txt").exists());
+    assert!(temp_dir.path().join("kept.progress.txt").exists());
+}
+
+#[test]
+fn test_files_remove_drops_cache_entry() {
+    let temp_dir = TempDir::new().unwrap();
+    create_orphan_fixture(&temp_dir);
+
+    let (mut cmd, config_path) =
+        setup_test_config_with_watch_dir(&temp_dir.path().to_string_lossy());
+    cmd.args(["files", "update"]).assert().success();
+
+    let files_get = |config_path: &PathBuf| -> Vec<serde_json::Value> {
+        let mut cmd = Command::cargo_bin("atci").unwrap();
+        cmd.env("ATCI_CONFIG_PATH", config_path);
+        let output = cmd.args(["files", "get"]).output().unwrap();
+        serde_json::from_slice(&output.stdout).unwrap()
+    };
+
+    let files = files_get(&config_path);
+    assert_eq!(files.len(), 1);
+    let full_path = files[0]["full_path"].as_str().unwrap().to_string();
+
+    // A relative path resolves against the working directory, like `queue remove`
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    cmd.current_dir(temp_dir.path());
+    cmd.args(["files", "remove", "kept.mp4"])
+        .assert()
+        .success()
+        .stdout(str::contains("Removed from file cache"));
+    assert!(files_get(&config_path).is_empty());
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    cmd.args(["files", "remove", &full_path])
+        .assert()
+        .failure()
+        .stderr(str::contains("is not in the file cache"));
+    cleanup_test_config(&config_path);
+
+    // Only the cache entry goes; the video itself is left alone
+    assert!(temp_dir.path().join("kept.mp4").exists());
+}
+
+#[test]
+fn test_stats_json_summarizes_cache() {
+    let temp_dir = TempDir::new().unwrap();
+    create_orphan_fixture(&temp_dir);
+    fs::write(temp_dir.path().join("pending.mp4"), b"fake video content").unwrap();
+
+    let (mut cmd, config_path) =
+        setup_test_config_with_watch_dir(&temp_dir.path().to_string_lossy());
+    cmd.args(["files", "update"]).assert().success();
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    let output = cmd.args(["stats", "--json"]).output().unwrap();
+    cleanup_test_config(&config_path);
+
+    assert!(output.status.success());
+    let stats: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(stats["total_videos"], 2);
+    assert_eq!(stats["transcribed"], 1);
+    assert_eq!(stats["pending"], 1);
+    assert_eq!(stats["top_directories"][0]["count"], 2);
+    assert!(stats["newest_video"].as_str().is_some());
+}
@@ -0,0 +1,125 @@
+use assert_cmd::Command;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str;
+use std::fs;
+use tempfile::TempDir;
+
+fn write_config(temp_dir: &TempDir, extra: &str) -> std::path::PathBuf {
+    let config_path = temp_dir.path().join("config.toml");
+    fs::write(
+        &config_path,
+        format!(
+            r#"
+ffmpeg_path = "ffmpeg"
+ffprobe_path = "ffprobe"
+whispercli_path = "whisper"
+model_name = "ggml-base"
+{}
+"#,
+            extra
+        ),
+    )
+    .unwrap();
+    config_path
+}
+
+#[test]
+fn test_queue_add_rejects_when_full() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = write_config(&temp_dir, "max_queue_size = 1");
+
+    let first = temp_dir.path().join("first.mp4");
+    let second = temp_dir.path().join("second.mp4");
+    fs::write(&first, "fake video").unwrap();
+    fs::write(&second, "fake video").unwrap();
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    cmd.args(["queue", "add", first.to_str().unwrap()]);
+    cmd.assert()
+        .success()
+        .stdout(str::contains("Added to queue"));
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    cmd.args(["queue", "add", second.to_str().unwrap()]);
+    cmd.assert()
+        .failure()
+        .stderr(str::contains("Queue is full"));
+}
+
+#[test]
+fn test_queue_add_rejects_missing_model() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = write_config(&temp_dir, "");
+
+    let video = temp_dir.path().join("interview.mp4");
+    fs::write(&video, "fake video").unwrap();
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    cmd.args([
+        "queue",
+        "add",
+        video.to_str().unwrap(),
+        "--model",
+        "ggml-does-not-exist",
+    ]);
+    cmd.assert()
+        .failure()
+        .stderr(str::contains("model ggml-does-not-exist is not downloaded"));
+}
+
+#[test]
+fn test_queue_remove_drops_path_and_all_clears_queue() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = write_config(&temp_dir, "");
+
+    let first = temp_dir.path().join("first.mp4");
+    let second = temp_dir.path().join("second.mp4");
+    fs::write(&first, "fake video").unwrap();
+    fs::write(&second, "fake video").unwrap();
+
+    for video in [&first, &second] {
+        let mut cmd = Command::cargo_bin("atci").unwrap();
+        cmd.env("ATCI_CONFIG_PATH", &config_path);
+        cmd.args(["queue", "add", video.to_str().unwrap()]);
+        cmd.assert().success();
+    }
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    cmd.args(["queue", "remove", first.to_str().unwrap()]);
+    cmd.assert()
+        .success()
+        .stdout(str::contains("Removed from queue"));
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    cmd.args(["queue", "remove", first.to_str().unwrap()]);
+    cmd.assert()
+        .failure()
+        .stderr(str::contains("is not in the queue"));
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    cmd.args(["queue", "get"]);
+    cmd.assert()
+        .success()
+        .stdout(str::contains("second.mp4"))
+        .stdout(str::contains("first.mp4").not());
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    cmd.args(["queue", "remove", "--all"]);
+    cmd.assert()
+        .success()
+        .stdout(str::contains("Removed 1 pending path(s)"));
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    cmd.args(["queue", "get"]);
+    cmd.assert()
+        .success()
+        .stdout(str::contains("second.mp4").not());
+}
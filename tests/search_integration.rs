@@ -1,7 +1,17 @@
 use assert_cmd::Command;
 use std::fs;
+use std::path::Path;
 use tempfile::TempDir;
 
+/// Run atci against `config_path` with HOME inside `dir`, so the database and search
+/// history never touch the real ~/.atci
+fn atci_command(dir: &TempDir, config_path: &Path) -> Command {
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", config_path)
+        .env("HOME", dir.path().join("home"));
+    cmd
+}
+
 fn create_test_video_with_transcript(dir: &TempDir, video_name: &str, content: &str) -> String {
     let video_path = dir.path().join(format!("{}.mp4", video_name));
     let txt_path = dir.path().join(format!("{}.txt", video_name));
@@ -39,8 +49,7 @@ watch_directories = ["{}"]
     let config_path = temp_dir.path().join("config.toml");
     fs::write(&config_path, config_content).unwrap();
 
-    let mut cmd = Command::cargo_bin("atci").unwrap();
-    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    let mut cmd = atci_command(&temp_dir, &config_path);
     cmd.args(["search", "can't"]);
 
     let output = cmd.output().unwrap();
@@ -76,8 +85,7 @@ watch_directories = ["{}"]
     let config_path = temp_dir.path().join("config.toml");
     fs::write(&config_path, config_content).unwrap();
 
-    let mut cmd = Command::cargo_bin("atci").unwrap();
-    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    let mut cmd = atci_command(&temp_dir, &config_path);
     cmd.args(["search", "can't"]); // Search with regular apostrophe
 
     let output = cmd.output().unwrap();
@@ -113,8 +121,7 @@ watch_directories = ["{}"]
     let config_path = temp_dir.path().join("config.toml");
     fs::write(&config_path, config_content).unwrap();
 
-    let mut cmd = Command::cargo_bin("atci").unwrap();
-    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    let mut cmd = atci_command(&temp_dir, &config_path);
     cmd.args(["search", "can't"]); // Search with Unicode apostrophe
 
     let output = cmd.output().unwrap();
@@ -123,3 +130,581 @@ watch_directories = ["{}"]
     // Should find the line with regular apostrophe when searching with Unicode apostrophe
     assert!(stdout.contains("you can't read it"));
 }
+
+#[test]
+fn test_search_reads_latin1_transcripts() {
+    let temp_dir = TempDir::new().unwrap();
+    let _video_path = create_test_video_with_transcript(&temp_dir, "test_video", "");
+    fs::write(
+        temp_dir.path().join("test_video.txt"),
+        b"meet me at the caf\xe9\nanother line",
+    )
+    .unwrap();
+
+    let config_content = format!(
+        r#"
+ffmpeg_path = "ffmpeg"
+ffprobe_path = "ffprobe"
+whispercli_path = "whisper"
+model_name = "ggml-base"
+watch_directories = ["{}"]
+"#,
+        if cfg!(windows) {
+            temp_dir.path().to_string_lossy().replace("\\", "\\\\")
+        } else {
+            temp_dir.path().to_string_lossy().to_string()
+        }
+    );
+
+    let config_path = temp_dir.path().join("config.toml");
+    fs::write(&config_path, config_content).unwrap();
+
+    let mut cmd = atci_command(&temp_dir, &config_path);
+    cmd.args(["search", "café"]);
+
+    let output = cmd.output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(stdout.contains("meet me at the café"));
+}
+
+#[test]
+fn test_search_json_pagination_reports_total_matches() {
+    let temp_dir = TempDir::new().unwrap();
+    let content = "00:00:01.000 --> 00:00:02.000\nhello one\n\n00:00:02.000 --> 00:00:03.000\nhello two\n\n00:00:03.000 --> 00:00:04.000\nhello three";
+    let _video_path = create_test_video_with_transcript(&temp_dir, "test_video", content);
+
+    let config_content = format!(
+        r#"
+ffmpeg_path = "ffmpeg"
+ffprobe_path = "ffprobe"
+whispercli_path = "whisper"
+model_name = "ggml-base"
+watch_directories = ["{}"]
+"#,
+        if cfg!(windows) {
+            temp_dir.path().to_string_lossy().replace("\\", "\\\\")
+        } else {
+            temp_dir.path().to_string_lossy().to_string()
+        }
+    );
+
+    let config_path = temp_dir.path().join("config.toml");
+    fs::write(&config_path, config_content).unwrap();
+
+    let mut cmd = atci_command(&temp_dir, &config_path);
+    cmd.args(["search", "hello", "--json", "--limit", "1", "--offset", "1"]);
+
+    let output = cmd.output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let response: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+
+    assert_eq!(response["total_matches"], 3);
+    let matches = response["results"][0]["matches"].as_array().unwrap();
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0]["line_text"], "hello two");
+}
+
+#[test]
+fn test_search_exclude_pattern_skips_matching_paths() {
+    let temp_dir = TempDir::new().unwrap();
+    let _kept = create_test_video_with_transcript(&temp_dir, "kept_video", "hello from kept");
+    let _skipped =
+        create_test_video_with_transcript(&temp_dir, "skipped_video", "hello from skipped");
+
+    let config_content = format!(
+        r#"
+ffmpeg_path = "ffmpeg"
+ffprobe_path = "ffprobe"
+whispercli_path = "whisper"
+model_name = "ggml-base"
+watch_directories = ["{}"]
+"#,
+        if cfg!(windows) {
+            temp_dir.path().to_string_lossy().replace("\\", "\\\\")
+        } else {
+            temp_dir.path().to_string_lossy().to_string()
+        }
+    );
+
+    let config_path = temp_dir.path().join("config.toml");
+    fs::write(&config_path, config_content).unwrap();
+
+    let mut cmd = atci_command(&temp_dir, &config_path);
+    cmd.args(["search", "hello", "--exclude-pattern", "**/skipped_*"]);
+
+    let output = cmd.output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(stdout.contains("hello from kept"));
+    assert!(!stdout.contains("hello from skipped"));
+}
+
+#[test]
+fn test_search_reuses_cache_unless_no_cache() {
+    let temp_dir = TempDir::new().unwrap();
+    let video = create_test_video_with_transcript(&temp_dir, "cached_video", "hello original");
+
+    let config_content = format!(
+        r#"
+ffmpeg_path = "ffmpeg"
+ffprobe_path = "ffprobe"
+whispercli_path = "whisper"
+model_name = "ggml-base"
+watch_directories = ["{}"]
+"#,
+        if cfg!(windows) {
+            temp_dir.path().to_string_lossy().replace("\\", "\\\\")
+        } else {
+            temp_dir.path().to_string_lossy().to_string()
+        }
+    );
+
+    let config_path = temp_dir.path().join("config.toml");
+    fs::write(&config_path, config_content).unwrap();
+
+    let run_search = |extra_args: &[&str]| {
+        let mut cmd = atci_command(&temp_dir, &config_path);
+        cmd.args(["search", "hello"]).args(extra_args);
+        String::from_utf8(cmd.output().unwrap().stdout).unwrap()
+    };
+
+    assert!(run_search(&[]).contains("hello original"));
+
+    fs::write(temp_dir.path().join("cached_video.txt"), "hello updated").unwrap();
+
+    assert!(run_search(&[]).contains("hello original"));
+    assert!(run_search(&["--no-cache"]).contains("hello updated"));
+
+    // Editing a transcript through atci drops the cached results
+    let mut cmd = atci_command(&temp_dir, &config_path);
+    cmd.args([
+        "transcripts",
+        "search-and-replace",
+        &video,
+        "--find",
+        "updated",
+        "--replace",
+        "edited",
+    ])
+    .assert()
+    .success();
+
+    assert!(run_search(&[]).contains("hello edited"));
+}
+
+#[test]
+fn test_search_fields_must_all_match() {
+    let temp_dir = TempDir::new().unwrap();
+    let _named =
+        create_test_video_with_transcript(&temp_dir, "budget_meeting", "the budget is tight");
+    let _unnamed = create_test_video_with_transcript(&temp_dir, "weekly_sync", "budget again");
+    let _metadata = create_test_video_with_transcript(
+        &temp_dir,
+        "with_metadata",
+        "source: budget import\n>>>.atcimetaend\n\n00:00:01.000 --> 00:00:02.000\nnothing here",
+    );
+
+    let config_content = format!(
+        r#"
+ffmpeg_path = "ffmpeg"
+ffprobe_path = "ffprobe"
+whispercli_path = "whisper"
+model_name = "ggml-base"
+watch_directories = ["{}"]
+"#,
+        if cfg!(windows) {
+            temp_dir.path().to_string_lossy().replace("\\", "\\\\")
+        } else {
+            temp_dir.path().to_string_lossy().to_string()
+        }
+    );
+
+    let config_path = temp_dir.path().join("config.toml");
+    fs::write(&config_path, config_content).unwrap();
+
+    let run_search = |fields: &str| {
+        let mut cmd = atci_command(&temp_dir, &config_path);
+        cmd.args(["search", "budget", "--no-cache", "--fields", fields]);
+        String::from_utf8(cmd.output().unwrap().stdout).unwrap()
+    };
+
+    let transcript_only = run_search("transcript");
+    assert!(transcript_only.contains("budget_meeting"));
+    assert!(transcript_only.contains("weekly_sync"));
+    assert!(!transcript_only.contains("with_metadata"));
+
+    let name_and_transcript = run_search("name,transcript");
+    assert!(name_and_transcript.contains("the budget is tight"));
+    assert!(!name_and_transcript.contains("weekly_sync"));
+
+    let metadata_only = run_search("metadata");
+    assert!(metadata_only.contains("source: budget import"));
+    assert!(!metadata_only.contains("budget_meeting"));
+}
+
+#[test]
+fn test_search_name_field_ignores_watch_directory_path() {
+    let temp_dir = TempDir::new().unwrap();
+    let watch_dir = temp_dir.path().join("budget_archive");
+    fs::create_dir(&watch_dir).unwrap();
+    fs::write(watch_dir.join("weekly_sync.mp4"), b"fake video content").unwrap();
+    fs::write(watch_dir.join("weekly_sync.txt"), "budget again").unwrap();
+    fs::write(watch_dir.join("budget_review.mp4"), b"fake video content").unwrap();
+    fs::write(watch_dir.join("budget_review.txt"), "budget review").unwrap();
+
+    let config_content = format!(
+        r#"
+ffmpeg_path = "ffmpeg"
+ffprobe_path = "ffprobe"
+whispercli_path = "whisper"
+model_name = "ggml-base"
+watch_directories = ["{}"]
+"#,
+        if cfg!(windows) {
+            watch_dir.to_string_lossy().replace("\\", "\\\\")
+        } else {
+            watch_dir.to_string_lossy().to_string()
+        }
+    );
+
+    let config_path = temp_dir.path().join("config.toml");
+    fs::write(&config_path, config_content).unwrap();
+
+    let mut cmd = atci_command(&temp_dir, &config_path);
+    cmd.args([
+        "search",
+        "budget",
+        "--no-cache",
+        "--fields",
+        "name,transcript",
+    ]);
+    let stdout = String::from_utf8(cmd.output().unwrap().stdout).unwrap();
+
+    assert!(stdout.contains("budget review"));
+    assert!(!stdout.contains("budget again"));
+}
+
+#[test]
+fn test_search_json_includes_file_metadata() {
+    let temp_dir = TempDir::new().unwrap();
+    let content = "length: 00:01:30.5\nsource: ggml-base\n>>>.atcimetaend\n00:00:01.000 --> 00:00:02.000\nhello there";
+    let _video_path = create_test_video_with_transcript(&temp_dir, "test_video", content);
+
+    let config_content = format!(
+        r#"
+ffmpeg_path = "ffmpeg"
+ffprobe_path = "ffprobe"
+whispercli_path = "whisper"
+model_name = "ggml-base"
+watch_directories = ["{}"]
+"#,
+        if cfg!(windows) {
+            temp_dir.path().to_string_lossy().replace("\\", "\\\\")
+        } else {
+            temp_dir.path().to_string_lossy().to_string()
+        }
+    );
+
+    let config_path = temp_dir.path().join("config.toml");
+    fs::write(&config_path, config_content).unwrap();
+
+    let mut cmd = atci_command(&temp_dir, &config_path);
+    cmd.args(["search", "hello", "--json", "--no-cache"]);
+
+    let output = cmd.output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let response: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+
+    let file_metadata = &response["results"][0]["file_metadata"];
+    assert_eq!(file_metadata["duration_secs"], 90.5);
+    assert_eq!(file_metadata["model"], "ggml-base");
+    assert_eq!(file_metadata["transcript_line_count"], 5);
+    assert!(file_metadata["created_at"].is_string());
+}
+
+#[test]
+fn test_search_in_file_limits_to_listed_videos() {
+    let temp_dir = TempDir::new().unwrap();
+    let content = "00:00:01.000 --> 00:00:02.000\nhello there";
+    let listed = create_test_video_with_transcript(&temp_dir, "listed", content);
+    let _unlisted = create_test_video_with_transcript(&temp_dir, "unlisted", content);
+
+    let list_path = temp_dir.path().join("files.txt");
+    fs::write(&list_path, format!("# manifest\n\n{}\n", listed)).unwrap();
+
+    let config_content = format!(
+        r#"
+ffmpeg_path = "ffmpeg"
+ffprobe_path = "ffprobe"
+whispercli_path = "whisper"
+model_name = "ggml-base"
+watch_directories = ["{}"]
+"#,
+        if cfg!(windows) {
+            temp_dir.path().to_string_lossy().replace("\\", "\\\\")
+        } else {
+            temp_dir.path().to_string_lossy().to_string()
+        }
+    );
+
+    let config_path = temp_dir.path().join("config.toml");
+    fs::write(&config_path, config_content).unwrap();
+
+    let mut cmd = atci_command(&temp_dir, &config_path);
+    cmd.args(["search", "hello", "--json", "--in-file"])
+        .arg(&list_path);
+
+    let output = cmd.output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let response: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+
+    let results = response["results"].as_array().unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["file_path"], listed);
+}
+
+#[test]
+fn test_search_group_by_time_interleaves_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let _morning = create_test_video_with_transcript(
+        &temp_dir,
+        "morning",
+        "00:00:01.000 --> 00:00:02.000\nbudget intro\n\n00:00:30.000 --> 00:00:31.000\nbudget wrap up",
+    );
+    let _afternoon = create_test_video_with_transcript(
+        &temp_dir,
+        "afternoon",
+        "00:00:10.000 --> 00:00:11.000\nbudget questions",
+    );
+
+    let config_content = format!(
+        r#"
+ffmpeg_path = "ffmpeg"
+ffprobe_path = "ffprobe"
+whispercli_path = "whisper"
+model_name = "ggml-base"
+watch_directories = ["{}"]
+"#,
+        if cfg!(windows) {
+            temp_dir.path().to_string_lossy().replace("\\", "\\\\")
+        } else {
+            temp_dir.path().to_string_lossy().to_string()
+        }
+    );
+
+    let config_path = temp_dir.path().join("config.toml");
+    fs::write(&config_path, config_content).unwrap();
+
+    let mut cmd = atci_command(&temp_dir, &config_path);
+    cmd.args([
+        "search",
+        "budget",
+        "--json",
+        "--no-cache",
+        "--group-by-time",
+    ]);
+
+    let output = cmd.output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let matches: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+
+    let texts: Vec<&str> = matches
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|m| m["line_text"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        texts,
+        vec!["budget intro", "budget questions", "budget wrap up"]
+    );
+    assert!(
+        matches[1]["file_path"]
+            .as_str()
+            .unwrap()
+            .ends_with("afternoon.mp4")
+    );
+}
+
+#[test]
+fn test_search_max_matches_per_file_keeps_earliest() {
+    let temp_dir = TempDir::new().unwrap();
+    let _chatty = create_test_video_with_transcript(
+        &temp_dir,
+        "chatty",
+        "00:00:01.000 --> 00:00:02.000\nbudget one\n\n00:00:03.000 --> 00:00:04.000\nbudget two\n\n00:00:05.000 --> 00:00:06.000\nbudget three",
+    );
+
+    let config_content = format!(
+        r#"
+ffmpeg_path = "ffmpeg"
+ffprobe_path = "ffprobe"
+whispercli_path = "whisper"
+model_name = "ggml-base"
+watch_directories = ["{}"]
+"#,
+        if cfg!(windows) {
+            temp_dir.path().to_string_lossy().replace("\\", "\\\\")
+        } else {
+            temp_dir.path().to_string_lossy().to_string()
+        }
+    );
+
+    let config_path = temp_dir.path().join("config.toml");
+    fs::write(&config_path, config_content).unwrap();
+
+    let mut cmd = atci_command(&temp_dir, &config_path);
+    cmd.args([
+        "search",
+        "budget",
+        "--json",
+        "--no-cache",
+        "--max-matches-per-file",
+        "2",
+    ]);
+
+    let output = cmd.output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let response: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+
+    let matches = response["results"][0]["matches"].as_array().unwrap();
+    assert_eq!(matches.len(), 2);
+    assert_eq!(matches[0]["line_text"], "budget one");
+    assert_eq!(matches[1]["line_text"], "budget two");
+}
+
+#[test]
+fn test_search_case_sensitive_matches_exact_case() {
+    let temp_dir = TempDir::new().unwrap();
+    let _video = create_test_video_with_transcript(
+        &temp_dir,
+        "polish",
+        "00:00:01.000 --> 00:00:02.000\nPolish the floor\n\n00:00:03.000 --> 00:00:04.000\npolish the car",
+    );
+
+    let config_content = format!(
+        r#"
+ffmpeg_path = "ffmpeg"
+ffprobe_path = "ffprobe"
+whispercli_path = "whisper"
+model_name = "ggml-base"
+watch_directories = ["{}"]
+"#,
+        if cfg!(windows) {
+            temp_dir.path().to_string_lossy().replace("\\", "\\\\")
+        } else {
+            temp_dir.path().to_string_lossy().to_string()
+        }
+    );
+
+    let config_path = temp_dir.path().join("config.toml");
+    fs::write(&config_path, config_content).unwrap();
+
+    let run_search = |extra_args: &[&str]| -> serde_json::Value {
+        let mut cmd = atci_command(&temp_dir, &config_path);
+        cmd.args(["search", "Polish", "--json", "--no-cache"]);
+        cmd.args(extra_args);
+        let output = cmd.output().unwrap();
+        serde_json::from_slice(&output.stdout).unwrap()
+    };
+
+    let insensitive = run_search(&[]);
+    assert_eq!(
+        insensitive["results"][0]["matches"]
+            .as_array()
+            .unwrap()
+            .len(),
+        2
+    );
+
+    let sensitive = run_search(&["--case-sensitive"]);
+    let matches = sensitive["results"][0]["matches"].as_array().unwrap();
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0]["line_text"], "Polish the floor");
+}
+
+#[test]
+fn test_search_context_includes_surrounding_lines() {
+    let temp_dir = TempDir::new().unwrap();
+    let _video = create_test_video_with_transcript(
+        &temp_dir,
+        "meeting",
+        "00:00:01.000 --> 00:00:02.000\nfirst we reviewed the agenda\n\n00:00:03.000 --> 00:00:04.000\nthen the budget came up\n\n00:00:05.000 --> 00:00:06.000\nand everyone agreed",
+    );
+
+    let config_content = format!(
+        r#"
+ffmpeg_path = "ffmpeg"
+ffprobe_path = "ffprobe"
+whispercli_path = "whisper"
+model_name = "ggml-base"
+watch_directories = ["{}"]
+"#,
+        if cfg!(windows) {
+            temp_dir.path().to_string_lossy().replace("\\", "\\\\")
+        } else {
+            temp_dir.path().to_string_lossy().to_string()
+        }
+    );
+
+    let config_path = temp_dir.path().join("config.toml");
+    fs::write(&config_path, config_content).unwrap();
+
+    let mut cmd = atci_command(&temp_dir, &config_path);
+    cmd.args(["search", "budget", "--json", "--no-cache", "-C", "1"]);
+
+    let output = cmd.output().unwrap();
+    let response: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    let search_match = &response["results"][0]["matches"][0];
+    assert_eq!(search_match["line_text"], "then the budget came up");
+    assert_eq!(
+        search_match["context_before"],
+        serde_json::json!(["first we reviewed the agenda"])
+    );
+    assert_eq!(
+        search_match["context_after"],
+        serde_json::json!(["and everyone agreed"])
+    );
+}
+
+#[test]
+fn test_search_template_renders_builtin_csv() {
+    let temp_dir = TempDir::new().unwrap();
+    let _video = create_test_video_with_transcript(
+        &temp_dir,
+        "quotes",
+        "00:00:01.000 --> 00:00:02.000\nthe budget, \"final\"",
+    );
+
+    let config_content = format!(
+        r#"
+ffmpeg_path = "ffmpeg"
+ffprobe_path = "ffprobe"
+whispercli_path = "whisper"
+model_name = "ggml-base"
+watch_directories = ["{}"]
+"#,
+        if cfg!(windows) {
+            temp_dir.path().to_string_lossy().replace("\\", "\\\\")
+        } else {
+            temp_dir.path().to_string_lossy().to_string()
+        }
+    );
+
+    let config_path = temp_dir.path().join("config.toml");
+    fs::write(&config_path, config_content).unwrap();
+
+    let mut cmd = atci_command(&temp_dir, &config_path);
+    cmd.args(["search", "budget", "--no-cache", "--template", "@csv"]);
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    assert_eq!(lines[0], "file_path,line_number,timestamp,line_text");
+    assert!(lines[1].ends_with(",2,00:00:01.000 --> 00:00:02.000,\"the budget, \"\"final\"\"\""));
+    assert_eq!(lines.len(), 2);
+}
@@ -0,0 +1,57 @@
+use assert_cmd::Command;
+use predicates::str;
+use std::fs;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use tempfile::TempDir;
+
+/// Serve a single HTTP response on a local port and return the URL to request
+fn serve_once(response: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    std::thread::spawn(move || {
+        if let Ok((mut stream, _)) = listener.accept() {
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf);
+            let _ = stream.write_all(response.as_bytes());
+        }
+    });
+    format!("http://127.0.0.1:{}/live.m3u8", port)
+}
+
+fn streamdl(temp_dir: &TempDir, url: &str) -> Command {
+    let config_path = temp_dir.path().join("config.toml");
+    fs::write(&config_path, "ffmpeg_path = \"ffmpeg\"\n").unwrap();
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path)
+        .env("HOME", temp_dir.path())
+        .args(["streamdl", "test_stream", url]);
+    cmd
+}
+
+#[test]
+fn test_streamdl_rejects_missing_playlist() {
+    let temp_dir = TempDir::new().unwrap();
+    let url =
+        serve_once("HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+
+    streamdl(&temp_dir, &url)
+        .assert()
+        .failure()
+        .stderr(str::contains("returned HTTP 404"));
+    assert!(!temp_dir.path().join("atci_streams").exists());
+}
+
+#[test]
+fn test_streamdl_rejects_non_playlist_body() {
+    let temp_dir = TempDir::new().unwrap();
+    let url = serve_once(
+        "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: 13\r\nConnection: close\r\n\r\n<html></html>",
+    );
+
+    streamdl(&temp_dir, &url)
+        .assert()
+        .failure()
+        .stderr(str::contains("is not an M3U8 playlist"));
+}
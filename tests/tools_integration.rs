@@ -0,0 +1,168 @@
+use assert_cmd::Command;
+use predicates::str;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::{Read, Write};
+use tempfile::TempDir;
+
+/// Serves `body` once over HTTP on a local port, returning the url to fetch it from
+fn serve_once(body: Vec<u8>) -> String {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/tool", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = [0; 1024];
+        let _ = stream.read(&mut request);
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            body.len()
+        )
+        .unwrap();
+        stream.write_all(&body).unwrap();
+    });
+    url
+}
+
+/// A `.tar.xz` holding `entries`, laid out like a johnvansickle.com static build
+fn tar_xz(entries: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut tar = Vec::new();
+    for (name, data) in entries {
+        let mut header = [0u8; 512];
+        header[..name.len()].copy_from_slice(name.as_bytes());
+        header[100..107].copy_from_slice(b"0000755");
+        header[124..135].copy_from_slice(format!("{:011o}", data.len()).as_bytes());
+        header[156] = b'0';
+        header[257..263].copy_from_slice(b"ustar\0");
+        // The checksum is computed with its own field set to spaces
+        header[148..156].copy_from_slice(b"        ");
+        let checksum: u32 = header.iter().map(|&b| b as u32).sum();
+        header[148..155].copy_from_slice(format!("{:06o}\0", checksum).as_bytes());
+
+        tar.extend_from_slice(&header);
+        tar.extend_from_slice(data);
+        tar.resize(tar.len().div_ceil(512) * 512, 0);
+    }
+    tar.resize(tar.len() + 1024, 0);
+
+    let mut encoder = xz2::write::XzEncoder::new(Vec::new(), 6);
+    encoder.write_all(&tar).unwrap();
+    encoder.finish().unwrap()
+}
+
+fn tools_command(temp_dir: &TempDir) -> Command {
+    let home = temp_dir.path().join("home");
+    fs::create_dir_all(&home).unwrap();
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", temp_dir.path().join("config.toml"))
+        .env("HOME", &home);
+    cmd
+}
+
+const FFMPEG_BINARY: &[u8] = b"#!/bin/sh\necho ffmpeg\n";
+
+fn static_build() -> Vec<u8> {
+    tar_xz(&[
+        ("ffmpeg-7.0.2-amd64-static/GPL.txt", b"GNU GPL"),
+        (
+            "ffmpeg-7.0.2-amd64-static/manpages/ffmpeg.txt",
+            b"ffmpeg manual",
+        ),
+        ("ffmpeg-7.0.2-amd64-static/ffmpeg", FFMPEG_BINARY),
+        (
+            "ffmpeg-7.0.2-amd64-static/ffprobe",
+            b"#!/bin/sh\necho ffprobe\n",
+        ),
+    ])
+}
+
+#[test]
+fn test_tools_download_installs_from_tar_xz() {
+    let temp_dir = TempDir::new().unwrap();
+    let hash = format!("{:x}", Sha256::digest(FFMPEG_BINARY));
+    let url = serve_once(static_build());
+
+    tools_command(&temp_dir)
+        .args([
+            "tools", "download", "ffmpeg", "--url", &url, "--sha256", &hash,
+        ])
+        .assert()
+        .success()
+        .stdout(str::contains("SHA256 verification successful for ffmpeg"));
+
+    let install_dir = temp_dir.path().join("home/.atci/ffmpeg");
+    let binary = install_dir.join("ffmpeg");
+    assert_eq!(fs::read(&binary).unwrap(), FFMPEG_BINARY);
+    assert!(install_dir.join("COPYING.GPLv2").exists());
+    assert_eq!(
+        fs::read_to_string(install_dir.join(".source_url")).unwrap(),
+        url
+    );
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(&binary).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o755);
+    }
+}
+
+#[test]
+fn test_tools_download_rejects_bad_hash() {
+    let temp_dir = TempDir::new().unwrap();
+    let url = serve_once(static_build());
+
+    tools_command(&temp_dir)
+        .args([
+            "tools",
+            "download",
+            "ffmpeg",
+            "--url",
+            &url,
+            "--sha256",
+            &"0".repeat(64),
+        ])
+        .assert()
+        .failure()
+        .stderr(str::contains("SHA256 hash verification failed for ffmpeg"));
+
+    assert!(!temp_dir.path().join("home/.atci/ffmpeg/ffmpeg").exists());
+}
+
+#[test]
+fn test_tools_download_rejects_non_archive() {
+    let temp_dir = TempDir::new().unwrap();
+    let url = serve_once(b"<html>not found</html>".to_vec());
+
+    tools_command(&temp_dir)
+        .args([
+            "tools",
+            "download",
+            "ffprobe",
+            "--url",
+            &url,
+            "--sha256",
+            &"0".repeat(64),
+        ])
+        .assert()
+        .failure()
+        .stderr(str::contains("neither a zip nor a .tar.xz archive"));
+
+    assert!(!temp_dir.path().join("home/.atci/ffprobe/ffprobe").exists());
+}
+
+#[test]
+fn test_tools_download_url_requires_sha256() {
+    let temp_dir = TempDir::new().unwrap();
+
+    tools_command(&temp_dir)
+        .args([
+            "tools",
+            "download",
+            "ffmpeg",
+            "--url",
+            "http://127.0.0.1:1/ffmpeg",
+        ])
+        .assert()
+        .failure()
+        .stderr(str::contains("--sha256"));
+}
@@ -317,3 +317,80 @@ fn test_transcripts_regenerate_requires_path() {
 
     cmd.assert().failure().stderr(str::contains("required"));
 }
+
+#[test]
+fn test_transcripts_copy_requires_overwrite_for_existing_transcript() {
+    let temp_dir = TempDir::new().unwrap();
+    let source = create_test_video_with_transcript(&temp_dir, "original", "Original transcript");
+    let dest = create_test_video_with_transcript(&temp_dir, "reencoded", "Old transcript");
+    let dest_txt = temp_dir.path().join("reencoded.txt");
+
+    let (mut cmd, config_path) =
+        setup_test_config_with_watch_dir(&temp_dir.path().to_string_lossy());
+    cmd.args(["transcripts", "copy", &source, &dest]);
+    cmd.assert()
+        .failure()
+        .stderr(str::contains("use --overwrite"));
+    assert_eq!(fs::read_to_string(&dest_txt).unwrap(), "Old transcript");
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    cmd.args(["transcripts", "copy", &source, &dest, "--overwrite"]);
+    cmd.assert()
+        .success()
+        .stdout(str::contains("reencoded.txt"));
+    assert_eq!(
+        fs::read_to_string(&dest_txt).unwrap(),
+        "Original transcript"
+    );
+
+    cleanup_test_config(&config_path);
+}
+
+#[test]
+fn test_transcripts_search_and_replace_dry_run_then_write() {
+    let temp_dir = TempDir::new().unwrap();
+    let content = "00:00:01.000 --> 00:00:02.000\nthanks cubernetes\n\n00:00:02.000 --> 00:00:03.000\ncubernetes again\n";
+    let video_path = create_test_video_with_transcript(&temp_dir, "talk", content);
+    let txt_path = temp_dir.path().join("talk.txt");
+
+    let (mut cmd, config_path) =
+        setup_test_config_with_watch_dir(&temp_dir.path().to_string_lossy());
+    cmd.args([
+        "transcripts",
+        "search-and-replace",
+        &video_path,
+        "--find",
+        "cubernetes",
+        "--replace",
+        "Kubernetes",
+        "--dry-run",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(str::contains("+ thanks Kubernetes"))
+        .stdout(str::contains("Would make 2 replacement(s) in total"));
+    assert_eq!(fs::read_to_string(&txt_path).unwrap(), content);
+
+    let mut cmd = Command::cargo_bin("atci").unwrap();
+    cmd.env("ATCI_CONFIG_PATH", &config_path);
+    cmd.args([
+        "transcripts",
+        "search-and-replace",
+        &video_path,
+        "--find",
+        "c(ubernetes)",
+        "--replace",
+        "K$1",
+        "--regex",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(str::contains("Made 2 replacement(s)"));
+    assert_eq!(
+        fs::read_to_string(&txt_path).unwrap(),
+        "00:00:01.000 --> 00:00:02.000\nthanks Kubernetes\n\n00:00:02.000 --> 00:00:03.000\nKubernetes again\n"
+    );
+
+    cleanup_test_config(&config_path);
+}